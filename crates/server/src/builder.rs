@@ -2,13 +2,17 @@ use std::convert::Infallible;
 use std::net::SocketAddr;
 use std::time::Duration;
 
-use axum::response::Response;
+use axum::middleware::{self, Next};
+use axum::response::{IntoResponse, Response};
 use axum::routing::{post, IntoMakeService};
 use axum::{Extension, Router};
+use hyper::body::HttpBody;
 use hyper::server::conn::AddrIncoming;
-use hyper::{header, Method, Request, Server};
+use hyper::{header, Body, Method, Request, Server, StatusCode};
 use starknet_core::starknet::starknet_config::StarknetConfig;
 use tower::Service;
+use tower_http::compression::predicate::SizeAbove;
+use tower_http::compression::CompressionLayer;
 use tower_http::cors::CorsLayer;
 use tower_http::timeout::TimeoutLayer;
 use tower_http::trace::TraceLayer;
@@ -93,13 +97,24 @@ impl<TJsonRpcHandler: RpcHandler, THttpApiHandler: Clone + Send + Sync + 'static
     /// https://docs.rs/axum/latest/axum/#using-request-extensions
     pub fn build(self, starknet_config: &StarknetConfig) -> StarknetDevnetServer {
         let mut svc = self.routes;
+        let max_body_size = starknet_config.max_request_body_size;
 
         svc = svc
+            .layer(middleware::from_fn(move |req: Request<Body>, next: Next<Body>| {
+                limit_body_size(req, next, max_body_size)
+            }))
             .layer(Extension(self.json_rpc_handler))
             .layer(Extension(self.http_api_handler))
             .layer(TraceLayer::new_for_http())
             .layer(TimeoutLayer::new(Duration::from_secs(starknet_config.timeout.into())));
 
+        if let Some(min_size) = starknet_config.compression_min_size {
+            // responses of unknown size, e.g. streamed blocks, are compressed as well
+            svc = svc.layer(
+                CompressionLayer::new().no_br().no_zstd().compress_when(SizeAbove::new(min_size)),
+            );
+        }
+
         if let Some(ServerConfig { allow_origin }) = self.config {
             svc = svc.layer(
                 // see https://docs.rs/tower-http/latest/tower_http/cors/index.html
@@ -114,3 +129,39 @@ impl<TJsonRpcHandler: RpcHandler, THttpApiHandler: Clone + Send + Sync + 'static
         Server::bind(&self.address).serve(svc.into_make_service())
     }
 }
+
+/// Rejects requests with a body of more than `max_body_size` bytes, without reading more of it
+/// than that. The body is passed on as a single chunk, so that extractors do not copy it again.
+async fn limit_body_size(req: Request<Body>, next: Next<Body>, max_body_size: usize) -> Response {
+    let payload_too_large = || {
+        (
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!("Request body exceeds the limit of {max_body_size} bytes"),
+        )
+            .into_response()
+    };
+
+    let content_length = req
+        .headers()
+        .get(header::CONTENT_LENGTH)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.parse::<usize>().ok());
+    if content_length.map_or(false, |content_length| content_length > max_body_size) {
+        return payload_too_large();
+    }
+
+    let (parts, mut body) = req.into_parts();
+    let mut buffer = Vec::with_capacity(content_length.unwrap_or_default());
+    while let Some(chunk) = body.data().await {
+        let chunk = match chunk {
+            Ok(chunk) => chunk,
+            Err(err) => return (StatusCode::BAD_REQUEST, err.to_string()).into_response(),
+        };
+        if buffer.len() + chunk.len() > max_body_size {
+            return payload_too_large();
+        }
+        buffer.extend_from_slice(&chunk);
+    }
+
+    next.run(Request::from_parts(parts, Body::from(buffer))).await
+}
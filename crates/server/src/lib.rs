@@ -2,5 +2,6 @@ pub mod builder;
 mod config;
 /// handlers for axum server
 pub mod rpc_handler;
+pub mod streamed_json;
 pub use config::ServerConfig;
 pub use rpc_core;
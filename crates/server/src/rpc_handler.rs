@@ -1,15 +1,30 @@
 use std::fmt::{self};
+use std::io::Write;
 
-use axum::extract::rejection::JsonRejection;
+use axum::body::{boxed, Bytes, Full};
 use axum::extract::Extension;
+use axum::response::{IntoResponse, Response as HttpResponse};
 use axum::Json;
 use futures::{future, FutureExt};
+use hyper::header::CONTENT_TYPE;
+use hyper::{HeaderMap, StatusCode};
 use rpc_core::error::RpcError;
-use rpc_core::request::{Request, RpcCall, RpcMethodCall};
+use rpc_core::request::{Id, Request, RpcCall, RpcMethodCall, Version};
 use rpc_core::response::{Response, ResponseResult, RpcResponse};
 use serde::de::DeserializeOwned;
+use serde::Deserialize;
 use tracing::{error, trace, warn};
 
+use crate::streamed_json::StreamedJson;
+
+/// Result of a single call (not in a batch), see [`RpcHandler::on_single_request`]
+pub enum SingleCallResult {
+    /// Sent as part of the serialized [`RpcResponse`]
+    Buffered(ResponseResult),
+    /// Successful result that is serialized while the response is being sent
+    Streamed(StreamedJson),
+}
+
 /// Helper trait that is used to execute starknet rpc calls
 #[async_trait::async_trait]
 pub trait RpcHandler: Clone + Send + Sync + 'static {
@@ -19,6 +34,33 @@ pub trait RpcHandler: Clone + Send + Sync + 'static {
     /// Invoked when the request was received
     async fn on_request(&self, request: Self::Request) -> ResponseResult;
 
+    /// Invoked instead of [`Self::on_request`] when the request is a single call, not in a batch
+    ///
+    /// **Note**: override this function to stream large results instead of building them in
+    /// memory
+    async fn on_single_request(&self, request: Self::Request) -> SingleCallResult {
+        SingleCallResult::Buffered(self.on_request(request).await)
+    }
+
+    /// Invoked with the HTTP headers of every incoming request, before any of its calls is
+    /// handled. Returns the handler to be used for the calls of the request, which is the same
+    /// handler by default.
+    ///
+    /// **Note**: override this function to configure the handling of a request via its headers
+    fn with_headers(&self, _headers: &HeaderMap) -> Result<Self, RpcError> {
+        Ok(self.clone())
+    }
+
+    /// Whether [`Self::on_exchange`] is invoked, which requires buffering every response, also a
+    /// streamed one
+    fn records_exchanges(&self) -> bool {
+        false
+    }
+
+    /// Invoked with the body of every handled request and the body of its response, if
+    /// [`Self::records_exchanges`]. Not invoked if there is nothing to respond.
+    fn on_exchange(&self, _request: &[u8], _response: &[u8]) {}
+
     /// Invoked for every incoming `RpcMethodCall`
     ///
     /// This will attempt to deserialize a `{ "method" : "<name>", "params": "<params>" }` message
@@ -26,7 +68,8 @@ pub trait RpcHandler: Clone + Send + Sync + 'static {
     /// successfully, [`Self::on_request`] will be invoked.
     ///
     /// **Note**: override this function if the expected `Request` deviates from `{ "method" :
-    /// "<name>", "params": "<params>" }`
+    /// "<name>", "params": "<params>" }`. A single call (not in a batch) that deserializes
+    /// directly into `Request` is handled without invoking this function, see [`handle`].
     async fn on_call(&self, call: RpcMethodCall) -> RpcResponse {
         trace!(target: "rpc",  id = ?call.id , method = ?call.method, "received method call");
         let RpcMethodCall { method, params, id, .. } = call;
@@ -47,9 +90,10 @@ pub trait RpcHandler: Clone + Send + Sync + 'static {
                 // since JSON-RPC specification requires returning a Method Not Found error,
                 // we apply a hacky way to induce this - checking the stringified error message
                 let distinctive_error = format!("unknown variant `{method}`");
-                if err.contains(&distinctive_error) {
+                if let Some(position) = err.find(&distinctive_error) {
                     error!(target: "rpc", ?method, "failed to deserialize method due to unknown variant");
-                    RpcResponse::new(id, RpcError::method_not_found())
+                    let expected = &err[position + distinctive_error.len()..];
+                    RpcResponse::new(id, method_not_found(&method, expected))
                 } else {
                     error!(target: "rpc", ?method, ?err, "failed to deserialize method");
                     RpcResponse::new(id, RpcError::invalid_params(err))
@@ -59,27 +103,177 @@ pub trait RpcHandler: Clone + Send + Sync + 'static {
     }
 }
 
+/// The maximum number of suggested methods in the error of an unsupported method
+const MAX_METHOD_SUGGESTIONS: usize = 3;
+
+/// Method Not Found error with the supported methods and the ones closest to `method` as its data.
+/// The supported methods are read from `expected`, the rest of the deserialization error, in which
+/// serde lists the variants of the request type after `expected one of`.
+fn method_not_found(method: &str, expected: &str) -> RpcError {
+    let supported_methods: Vec<&str> = expected.split('`').skip(1).step_by(2).collect();
+
+    let mut suggestions: Vec<(usize, &str)> = supported_methods
+        .iter()
+        .map(|supported| (levenshtein_distance(method, supported), *supported))
+        // a suggestion should take fewer edits than writing the method from scratch
+        .filter(|(distance, supported)| *distance <= method.len().max(supported.len()) / 2)
+        .collect();
+    suggestions.sort();
+
+    RpcError {
+        data: Some(serde_json::json!({
+            "supported_methods": supported_methods,
+            "suggestions": suggestions
+                .into_iter()
+                .take(MAX_METHOD_SUGGESTIONS)
+                .map(|(_, supported)| supported)
+                .collect::<Vec<_>>(),
+        })),
+        ..RpcError::method_not_found()
+    }
+}
+
+/// The number of single-character insertions, deletions and substitutions turning `a` into `b`
+fn levenshtein_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut distances: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut diagonal = distances[0];
+        distances[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(a_char != *b_char);
+            diagonal = distances[j + 1];
+            distances[j + 1] = substitution.min(distances[j] + 1).min(diagonal + 1);
+        }
+    }
+
+    distances[b.len()]
+}
+
+/// The members of a single method call, except its params
+#[derive(Deserialize)]
+struct MethodCallEnvelope {
+    #[allow(unused)]
+    jsonrpc: Version,
+    method: String,
+    id: Id,
+}
+
 /// Handles incoming JSON-RPC Request
+///
+/// A single method call is deserialized straight from the body into the request type of the
+/// handler, so that large params (e.g. the Sierra artifact of a declaration) are not first copied
+/// into an intermediate JSON value. Batches, and calls for which that fails, are deserialized as
+/// [`Request`], so that errors are reported as usual.
 pub async fn handle<THandler: RpcHandler>(
-    request: Result<Json<Request>, JsonRejection>,
+    headers: HeaderMap,
     Extension(handler): Extension<THandler>,
-) -> Json<Response> {
-    match request {
-        Ok(req) => handle_request(req.0, handler)
-            .await
-            .unwrap_or_else(|| Response::error(RpcError::invalid_request()))
-            .into(),
+    body: Bytes,
+) -> HttpResponse {
+    if !handler.records_exchanges() {
+        return respond(headers, handler, &body).await;
+    }
+
+    let (parts, response_body) = respond(headers, handler.clone(), &body).await.into_parts();
+    let response_bytes = match hyper::body::to_bytes(response_body).await {
+        Ok(response_bytes) => response_bytes,
         Err(err) => {
-            warn!(target: "rpc", ?err, "invalid request");
-            Response::error(RpcError::invalid_request()).into()
+            error!(target: "rpc", ?err, "failed to buffer response");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    if !response_bytes.is_empty() {
+        handler.on_exchange(&body, &response_bytes);
+    }
+
+    HttpResponse::from_parts(parts, boxed(Full::from(response_bytes)))
+}
+
+/// Responds to the request with `headers` and `body`, see [`handle`]
+async fn respond<THandler: RpcHandler>(
+    headers: HeaderMap,
+    handler: THandler,
+    body: &[u8],
+) -> HttpResponse {
+    let handler = match handler.with_headers(&headers) {
+        Ok(handler) => handler,
+        Err(err) => {
+            warn!(target: "rpc", ?err, "invalid request headers");
+            return Json(Response::error(err)).into_response();
         }
+    };
+
+    if !has_json_content_type(&headers) {
+        warn!(target: "rpc", "invalid request: expected content type application/json");
+        return Json(Response::error(RpcError::invalid_request())).into_response();
     }
+
+    if let Some((id, request)) = deserialize_single_call::<THandler::Request>(body) {
+        trace!(target: "rpc", ?id, "handling single call");
+        return match handler.on_single_request(request).await {
+            SingleCallResult::Buffered(result) => {
+                Json(Response::Single(RpcResponse::new(id, result))).into_response()
+            }
+            SingleCallResult::Streamed(result) => streamed_rpc_response(id, result).into_response(),
+        };
+    }
+
+    let response = match serde_json::from_slice::<Request>(body) {
+        Ok(Request::Batch(calls)) if calls.is_empty() => {
+            warn!(target: "rpc", "invalid request: empty batch");
+            Response::error(RpcError::invalid_request())
+        }
+        Ok(req) => match handle_request(req, handler).await {
+            Some(response) => response,
+            // nothing is returned for notifications, as required by JSON-RPC 2.0
+            None => return StatusCode::NO_CONTENT.into_response(),
+        },
+        Err(err) => {
+            warn!(target: "rpc", ?err, "invalid request");
+            Response::error(RpcError::invalid_request())
+        }
+    };
+
+    Json(response).into_response()
+}
+
+/// Wraps a streamed result into the members of a successful [`RpcResponse`], in the same order
+fn streamed_rpc_response(id: Id, result: StreamedJson) -> StreamedJson {
+    StreamedJson::from_writer(move |writer| {
+        writer.write_all(br#"{"jsonrpc":"2.0","id":"#)?;
+        serde_json::to_writer(&mut *writer, &id)?;
+        writer.write_all(br#","result":"#)?;
+        result.write_to(writer)?;
+        writer.write_all(b"}")
+    })
+}
+
+/// Returns the id and the deserialized request of a single method call, if `body` is one; the
+/// envelope is read first, skipping the params without allocating them
+fn deserialize_single_call<TRequest: DeserializeOwned>(body: &[u8]) -> Option<(Id, TRequest)> {
+    let envelope = serde_json::from_slice::<MethodCallEnvelope>(body).ok()?;
+    trace!(target: "rpc", id = ?envelope.id, method = ?envelope.method, "received method call");
+    let request = serde_json::from_slice::<TRequest>(body).ok()?;
+
+    Some((envelope.id, request))
+}
+
+/// Same check as the one of the [`Json`] extractor
+fn has_json_content_type(headers: &HeaderMap) -> bool {
+    let Some(content_type) = headers.get(CONTENT_TYPE).and_then(|value| value.to_str().ok()) else {
+        return false;
+    };
+    let mime = content_type.split(';').next().unwrap_or_default().trim().to_ascii_lowercase();
+
+    mime == "application/json" || (mime.starts_with("application/") && mime.ends_with("+json"))
 }
 
 /// Handle the JSON-RPC [Request]
 ///
 /// This will try to deserialize the payload into the request type of the handler and if successful
-/// invoke the handler.
+/// invoke the handler. The responses of a batch are in the order of its calls; `None` is returned
+/// if there is nothing to respond, i.e. if the request only consists of notifications.
 pub async fn handle_request<THandler: RpcHandler>(
     req: Request,
     handler: THandler,
@@ -0,0 +1,97 @@
+use std::io::{self, Write};
+
+use axum::body::{Bytes, StreamBody};
+use axum::response::{IntoResponse, Response};
+use futures::channel::mpsc;
+use futures::executor::block_on;
+use futures::SinkExt;
+use hyper::header::{HeaderValue, CONTENT_TYPE};
+use serde::Serialize;
+use tracing::error;
+
+/// Size of the chunks in which the body is sent
+const CHUNK_SIZE: usize = 64 * 1024;
+/// Number of serialized chunks which may wait to be sent
+const CHANNEL_CAPACITY: usize = 4;
+
+type WriteJson = Box<dyn FnOnce(&mut dyn Write) -> io::Result<()> + Send>;
+
+/// JSON response body which is serialized in a blocking thread while it is being sent, so only a
+/// few chunks of it are held in memory at a time, instead of the whole serialized value.
+///
+/// Serialization errors are only detected after the status has been sent, so they abort the
+/// response.
+pub struct StreamedJson(WriteJson);
+
+impl StreamedJson {
+    pub fn new<T: Serialize + Send + 'static>(value: T) -> Self {
+        Self::from_writer(move |writer| serde_json::to_writer(writer, &value).map_err(Into::into))
+    }
+
+    /// Streams the JSON written by `write_json`
+    pub fn from_writer(
+        write_json: impl FnOnce(&mut dyn Write) -> io::Result<()> + Send + 'static,
+    ) -> Self {
+        Self(Box::new(write_json))
+    }
+
+    /// Writes the JSON of the stream into `writer`
+    pub fn write_to(self, writer: &mut dyn Write) -> io::Result<()> {
+        (self.0)(writer)
+    }
+}
+
+impl IntoResponse for StreamedJson {
+    fn into_response(self) -> Response {
+        let (sender, receiver) = mpsc::channel::<io::Result<Bytes>>(CHANNEL_CAPACITY);
+
+        tokio::task::spawn_blocking(move || {
+            let mut writer = ChunkWriter { sender, buffer: Vec::with_capacity(CHUNK_SIZE) };
+            let result = self.write_to(&mut writer).and_then(|_| writer.flush());
+            if let Err(err) = result {
+                // the receiver is dropped if the client disconnected
+                if err.kind() != io::ErrorKind::BrokenPipe {
+                    error!(target: "rpc", ?err, "failed to serialize streamed response");
+                    let _ = block_on(writer.sender.send(Err(err)));
+                }
+            }
+        });
+
+        ([(CONTENT_TYPE, HeaderValue::from_static("application/json"))], StreamBody::new(receiver))
+            .into_response()
+    }
+}
+
+/// Collects the written bytes into chunks of [`CHUNK_SIZE`] and sends them to the response body,
+/// waiting while the channel is full
+struct ChunkWriter {
+    sender: mpsc::Sender<io::Result<Bytes>>,
+    buffer: Vec<u8>,
+}
+
+impl ChunkWriter {
+    fn send_buffer(&mut self) -> io::Result<()> {
+        let chunk = std::mem::replace(&mut self.buffer, Vec::with_capacity(CHUNK_SIZE));
+        block_on(self.sender.send(Ok(Bytes::from(chunk))))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "response body was dropped"))
+    }
+}
+
+impl Write for ChunkWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.extend_from_slice(buf);
+        if self.buffer.len() >= CHUNK_SIZE {
+            self.send_buffer()?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        self.send_buffer()
+    }
+}
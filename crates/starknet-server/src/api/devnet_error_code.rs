@@ -0,0 +1,115 @@
+use serde::{Serialize, Serializer};
+use starknet_core::error::Error;
+
+/// Failure reason of a Devnet-specific error, i.e. of a request to an admin or cheatcode endpoint
+/// or to a feature beyond the JSON-RPC spec. Serialized as `devnet_code`, next to the error
+/// message, which may change. A code is never reused or reassigned, so clients can match on it.
+/// Codes start at 1000, so they are distinct from the codes of the spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DevnetErrorCode {
+    /// No more specific reason applies
+    Unknown = 1000,
+    /// The input of the request is invalid, e.g. a malformed transaction or block id
+    InvalidInput = 1001,
+    /// A file the request refers to, e.g. a dump, does not exist
+    FileNotFound = 1002,
+    /// The action is not supported by the configuration of Devnet, e.g. minting with a custom
+    /// genesis
+    UnsupportedAction = 1003,
+    /// The endpoint is disabled, e.g. shutdown without `--shutdown-token`
+    EndpointDisabled = 1004,
+    /// The token or API key of the request is missing or invalid
+    Unauthorized = 1005,
+    /// A transaction sent by the request failed, e.g. a minting transaction or one of a batch
+    TransactionFailed = 1006,
+    /// Dumping, loading or re-executing transactions failed
+    StatePersistenceFailed = 1007,
+    /// Creating or aborting blocks or changing the time failed
+    BlockGenerationFailed = 1008,
+    /// The execution did not finish within the timeout of the request
+    ExecutionTimeout = 1009,
+    /// An unexpected internal failure, e.g. of I/O, serialization or restarting
+    Internal = 1010,
+    /// The sender of a transaction is not one of the senders allowed by `--allowed-senders`
+    SenderNotAllowed = 1011,
+}
+
+impl DevnetErrorCode {
+    pub fn code(self) -> i64 {
+        self as i64
+    }
+
+    /// Reason of a failure of Devnet itself
+    pub(crate) fn of_error(error: &Error) -> Self {
+        match error {
+            Error::FileNotFound | Error::ReadFileError { .. } => Self::FileNotFound,
+            Error::UnsupportedAction { .. } => Self::UnsupportedAction,
+            Error::SenderNotAllowed { .. } => Self::SenderNotAllowed,
+            Error::InvalidMintingTransaction { .. }
+            | Error::ExecutionError { .. }
+            | Error::TransactionBatchError { .. }
+            | Error::TransactionValidationError(_)
+            | Error::TransactionFeeError(_)
+            | Error::BlockifierTransactionError(_)
+            | Error::MaxFeeZeroError { .. }
+            | Error::MaxFeeAboveCap { .. } => Self::TransactionFailed,
+            Error::DeserializationError { .. } | Error::FormatError | Error::TypesError(_) => {
+                Self::InvalidInput
+            }
+            Error::IoError(_)
+            | Error::SerializationError { .. }
+            | Error::SerializationNotSupported
+            | Error::UnexpectedInternalError { .. } => Self::Internal,
+            _ => Self::Unknown,
+        }
+    }
+}
+
+impl Serialize for DevnetErrorCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(self.code())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_core::error::Error;
+
+    use super::DevnetErrorCode;
+
+    #[test]
+    fn codes_are_stable() {
+        let codes = [
+            (DevnetErrorCode::Unknown, 1000),
+            (DevnetErrorCode::InvalidInput, 1001),
+            (DevnetErrorCode::FileNotFound, 1002),
+            (DevnetErrorCode::UnsupportedAction, 1003),
+            (DevnetErrorCode::EndpointDisabled, 1004),
+            (DevnetErrorCode::Unauthorized, 1005),
+            (DevnetErrorCode::TransactionFailed, 1006),
+            (DevnetErrorCode::StatePersistenceFailed, 1007),
+            (DevnetErrorCode::BlockGenerationFailed, 1008),
+            (DevnetErrorCode::ExecutionTimeout, 1009),
+            (DevnetErrorCode::Internal, 1010),
+            (DevnetErrorCode::SenderNotAllowed, 1011),
+        ];
+
+        for (devnet_code, code) in codes {
+            assert_eq!(devnet_code.code(), code);
+            assert_eq!(serde_json::to_value(devnet_code).unwrap(), code);
+        }
+    }
+
+    #[test]
+    fn errors_of_devnet_are_classified() {
+        assert_eq!(
+            DevnetErrorCode::of_error(&Error::UnsupportedAction { msg: "msg".into() }),
+            DevnetErrorCode::UnsupportedAction
+        );
+        assert_eq!(
+            DevnetErrorCode::of_error(&Error::InvalidMintingTransaction { msg: "msg".into() }),
+            DevnetErrorCode::TransactionFailed
+        );
+        assert_eq!(DevnetErrorCode::of_error(&Error::NoBlock), DevnetErrorCode::Unknown);
+    }
+}
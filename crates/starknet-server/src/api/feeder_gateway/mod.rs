@@ -0,0 +1,251 @@
+pub(crate) mod models;
+
+use std::collections::BTreeMap;
+
+use starknet_core::error::{DevnetResult, Error};
+use starknet_core::starknet::Starknet;
+use starknet_rs_core::types::{BlockId, ExecutionResult, TransactionFinalityStatus};
+use starknet_types::felt::TransactionHash;
+use starknet_types::num_bigint::BigUint;
+use starknet_types::rpc::block::BlockHeader;
+use starknet_types::rpc::state::ThinStateDiff;
+use starknet_types::rpc::transaction_receipt::{ExecutionResources, TransactionReceipt};
+use starknet_types::rpc::transactions::Transaction;
+use starknet_types::traits::ToHexString;
+
+use self::models::{
+    FeederGatewayBlock, FeederGatewayExecutionResources, FeederGatewayExecutionStatus,
+    FeederGatewayReceipt, FeederGatewayStateDiff, FeederGatewayStateUpdate,
+    FeederGatewayTransaction, FeederGatewayTransactionStatus,
+};
+
+/// Builds the feeder gateway representation of a block, including its transactions and receipts
+pub(crate) fn get_block(
+    starknet: &Starknet,
+    block_id: BlockId,
+) -> DevnetResult<FeederGatewayBlock> {
+    let block = starknet.get_block(block_id)?;
+    let header = BlockHeader::from(&block);
+
+    let mut transactions = vec![];
+    let mut transaction_receipts = vec![];
+    for (transaction_index, transaction_hash) in block.get_transactions().iter().enumerate() {
+        let transaction = starknet.get_transaction_by_hash(*transaction_hash)?;
+        transactions.push(to_feeder_gateway_transaction(transaction)?);
+
+        let receipt = starknet.get_transaction_receipt_by_hash(*transaction_hash)?;
+        transaction_receipts.push(to_feeder_gateway_receipt(transaction_index, receipt));
+    }
+
+    Ok(FeederGatewayBlock {
+        block_hash: header.block_hash,
+        parent_block_hash: header.parent_hash,
+        block_number: header.block_number,
+        state_root: header.new_root,
+        status: *block.status(),
+        timestamp: header.timestamp.0,
+        gas_price: header.l1_gas_price.price_in_wei,
+        sequencer_address: header.sequencer_address,
+        starknet_version: header.starknet_version,
+        transactions,
+        transaction_receipts,
+    })
+}
+
+/// Builds the feeder gateway representation of a transaction, together with its position in the
+/// block that includes it
+pub(crate) fn get_transaction(
+    starknet: &Starknet,
+    transaction_hash: TransactionHash,
+) -> DevnetResult<FeederGatewayTransaction> {
+    let transaction = match starknet.get_transaction_by_hash(transaction_hash) {
+        Ok(transaction) => transaction,
+        Err(Error::NoTransaction) => {
+            return Ok(FeederGatewayTransaction {
+                status: FeederGatewayTransactionStatus::NotReceived,
+                block_hash: None,
+                block_number: None,
+                transaction_index: None,
+                transaction: None,
+            });
+        }
+        Err(err) => return Err(err),
+    };
+
+    let common = match starknet.get_transaction_receipt_by_hash(transaction_hash)? {
+        TransactionReceipt::Deploy(receipt) => receipt.common,
+        TransactionReceipt::Common(receipt) => receipt,
+    };
+    let status = match common.finality_status {
+        TransactionFinalityStatus::AcceptedOnL2 => FeederGatewayTransactionStatus::AcceptedOnL2,
+        TransactionFinalityStatus::AcceptedOnL1 => FeederGatewayTransactionStatus::AcceptedOnL1,
+    };
+    let block_hash = common.maybe_pending_properties.block_hash;
+    let transaction_index = match block_hash {
+        Some(block_hash) => starknet
+            .get_block(BlockId::Hash(block_hash.into()))?
+            .get_transactions()
+            .iter()
+            .position(|hash| *hash == transaction_hash),
+        None => None,
+    };
+
+    Ok(FeederGatewayTransaction {
+        status,
+        block_hash,
+        block_number: common.maybe_pending_properties.block_number,
+        transaction_index,
+        transaction: Some(to_feeder_gateway_transaction(transaction)?),
+    })
+}
+
+/// Builds the feeder gateway representation of the state update of a block
+pub(crate) fn get_state_update(
+    starknet: &Starknet,
+    block_id: BlockId,
+) -> DevnetResult<FeederGatewayStateUpdate> {
+    let state_update = starknet.block_state_update(block_id)?;
+
+    Ok(FeederGatewayStateUpdate {
+        block_hash: state_update.block_hash,
+        new_root: state_update.new_root,
+        old_root: state_update.old_root,
+        state_diff: to_feeder_gateway_state_diff(state_update.state_diff.into()),
+    })
+}
+
+fn to_feeder_gateway_transaction(transaction: &Transaction) -> DevnetResult<serde_json::Value> {
+    let mut transaction_json = serde_json::to_value(transaction)
+        .map_err(|_| Error::SerializationError { obj_name: "Transaction".to_string() })?;
+
+    if let Some(transaction_object) = transaction_json.as_object_mut() {
+        if let Transaction::Invoke(_) = transaction {
+            transaction_object.insert("type".to_string(), "INVOKE_FUNCTION".into());
+        }
+        // classes are retrievable separately via `get_class_by_hash`
+        transaction_object.remove("contract_class");
+    }
+
+    Ok(transaction_json)
+}
+
+fn to_feeder_gateway_receipt(
+    transaction_index: usize,
+    receipt: TransactionReceipt,
+) -> FeederGatewayReceipt {
+    let common = match receipt {
+        TransactionReceipt::Deploy(receipt) => receipt.common,
+        TransactionReceipt::Common(receipt) => receipt,
+    };
+    let (execution_status, revert_error) = match common.execution_status {
+        ExecutionResult::Succeeded => (FeederGatewayExecutionStatus::Succeeded, None),
+        ExecutionResult::Reverted { reason } => {
+            (FeederGatewayExecutionStatus::Reverted, Some(reason))
+        }
+    };
+
+    FeederGatewayReceipt {
+        transaction_index,
+        transaction_hash: common.transaction_hash,
+        l2_to_l1_messages: common.output.messages_sent,
+        events: common.output.events,
+        execution_resources: to_feeder_gateway_execution_resources(&common.execution_resources),
+        actual_fee: common.output.actual_fee,
+        execution_status,
+        revert_error,
+    }
+}
+
+fn to_feeder_gateway_execution_resources(
+    execution_resources: &ExecutionResources,
+) -> FeederGatewayExecutionResources {
+    fn to_u64(value: starknet_types::felt::Felt) -> u64 {
+        u64::try_from(&BigUint::from(value)).unwrap_or(u64::MAX)
+    }
+
+    let builtin_instance_counter = [
+        ("pedersen_builtin", execution_resources.pedersen_builtin_applications),
+        ("range_check_builtin", execution_resources.range_check_builtin_applications),
+        ("ecdsa_builtin", execution_resources.ecdsa_builtin_applications),
+        ("bitwise_builtin", execution_resources.bitwise_builtin_applications),
+        ("ec_op_builtin", execution_resources.ec_op_builtin_applications),
+        ("poseidon_builtin", execution_resources.poseidon_builtin_applications),
+        ("keccak_builtin", execution_resources.keccak_builtin_applications),
+    ]
+    .into_iter()
+    .map(|(name, applications)| (name.to_string(), to_u64(applications)))
+    .collect();
+
+    FeederGatewayExecutionResources {
+        n_steps: to_u64(execution_resources.steps),
+        n_memory_holes: to_u64(execution_resources.memory_holes),
+        builtin_instance_counter,
+    }
+}
+
+fn to_feeder_gateway_state_diff(state_diff: ThinStateDiff) -> FeederGatewayStateDiff {
+    let mut storage_diffs = BTreeMap::new();
+    for storage_diff in state_diff.storage_diffs {
+        storage_diffs
+            .insert(storage_diff.address.to_prefixed_hex_str(), storage_diff.storage_entries);
+    }
+
+    let nonces = state_diff
+        .nonces
+        .into_iter()
+        .map(|contract_nonce| {
+            (contract_nonce.contract_address.to_prefixed_hex_str(), contract_nonce.nonce)
+        })
+        .collect();
+
+    FeederGatewayStateDiff {
+        storage_diffs,
+        nonces,
+        deployed_contracts: state_diff.deployed_contracts,
+        old_declared_contracts: state_diff.deprecated_declared_classes,
+        declared_classes: state_diff.declared_classes,
+        replaced_classes: state_diff.replaced_classes,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_types::contract_address::ContractAddress;
+    use starknet_types::felt::Felt;
+    use starknet_types::patricia_key::PatriciaKey;
+    use starknet_types::rpc::state::{ContractNonce, StorageDiff, StorageEntry, ThinStateDiff};
+    use starknet_types::rpc::transactions::invoke_transaction_v1::InvokeTransactionV1;
+    use starknet_types::rpc::transactions::{InvokeTransaction, Transaction};
+
+    use super::{to_feeder_gateway_state_diff, to_feeder_gateway_transaction};
+
+    #[test]
+    fn invoke_transaction_has_gateway_type() {
+        let transaction =
+            Transaction::Invoke(InvokeTransaction::Version1(InvokeTransactionV1::default()));
+
+        let transaction_json = to_feeder_gateway_transaction(&transaction).unwrap();
+        assert_eq!(transaction_json["type"], "INVOKE_FUNCTION");
+        assert!(transaction_json.get("transaction_hash").is_some());
+    }
+
+    #[test]
+    fn storage_diffs_and_nonces_are_mapped_by_address() {
+        let address = ContractAddress::new(Felt::from(1)).unwrap();
+        let state_diff = ThinStateDiff {
+            storage_diffs: vec![StorageDiff {
+                address,
+                storage_entries: vec![StorageEntry {
+                    key: PatriciaKey::new(Felt::from(2)).unwrap(),
+                    value: Felt::from(3),
+                }],
+            }],
+            nonces: vec![ContractNonce { contract_address: address, nonce: Felt::from(4) }],
+            ..Default::default()
+        };
+
+        let state_diff = to_feeder_gateway_state_diff(state_diff);
+        assert_eq!(state_diff.storage_diffs["0x1"].len(), 1);
+        assert_eq!(state_diff.nonces["0x1"], Felt::from(4));
+    }
+}
@@ -0,0 +1,102 @@
+use std::collections::BTreeMap;
+
+use serde::Serialize;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::emitted_event::Event;
+use starknet_types::felt::{BlockHash, ClassHash, Felt, Nonce, TransactionHash};
+use starknet_types::rpc::block::GlobalRootHex;
+use starknet_types::rpc::state::{ClassHashes, DeployedContract, ReplacedClasses, StorageEntry};
+use starknet_types::rpc::transaction_receipt::MessageToL1;
+use starknet_types::starknet_api::block::{BlockNumber, BlockStatus};
+use starknet_types::starknet_api::transaction::Fee;
+
+/// Block as returned by `get_block` of the feeder gateway
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct FeederGatewayBlock {
+    pub(crate) block_hash: BlockHash,
+    pub(crate) parent_block_hash: BlockHash,
+    pub(crate) block_number: BlockNumber,
+    pub(crate) state_root: GlobalRootHex,
+    pub(crate) status: BlockStatus,
+    pub(crate) timestamp: u64,
+    pub(crate) gas_price: Felt,
+    pub(crate) sequencer_address: ContractAddress,
+    pub(crate) starknet_version: String,
+    /// Transactions are in the RPC format, with the gateway names of transaction types and
+    /// without contract classes of declare transactions
+    pub(crate) transactions: Vec<serde_json::Value>,
+    pub(crate) transaction_receipts: Vec<FeederGatewayReceipt>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct FeederGatewayReceipt {
+    pub(crate) transaction_index: usize,
+    pub(crate) transaction_hash: TransactionHash,
+    pub(crate) l2_to_l1_messages: Vec<MessageToL1>,
+    pub(crate) events: Vec<Event>,
+    pub(crate) execution_resources: FeederGatewayExecutionResources,
+    pub(crate) actual_fee: Fee,
+    pub(crate) execution_status: FeederGatewayExecutionStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) revert_error: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub(crate) enum FeederGatewayExecutionStatus {
+    #[serde(rename = "SUCCEEDED")]
+    Succeeded,
+    #[serde(rename = "REVERTED")]
+    Reverted,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct FeederGatewayExecutionResources {
+    pub(crate) n_steps: u64,
+    pub(crate) n_memory_holes: u64,
+    pub(crate) builtin_instance_counter: BTreeMap<String, u64>,
+}
+
+/// Transaction as returned by `get_transaction` of the feeder gateway. Unknown transactions are
+/// reported with the `NOT_RECEIVED` status and without any other property.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct FeederGatewayTransaction {
+    pub(crate) status: FeederGatewayTransactionStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) block_hash: Option<BlockHash>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) block_number: Option<BlockNumber>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) transaction_index: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) transaction: Option<serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub(crate) enum FeederGatewayTransactionStatus {
+    #[serde(rename = "NOT_RECEIVED")]
+    NotReceived,
+    #[serde(rename = "ACCEPTED_ON_L2")]
+    AcceptedOnL2,
+    #[serde(rename = "ACCEPTED_ON_L1")]
+    AcceptedOnL1,
+}
+
+/// State update as returned by `get_state_update` of the feeder gateway
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct FeederGatewayStateUpdate {
+    pub(crate) block_hash: BlockHash,
+    pub(crate) new_root: GlobalRootHex,
+    pub(crate) old_root: GlobalRootHex,
+    pub(crate) state_diff: FeederGatewayStateDiff,
+}
+
+/// Unlike in the RPC format, storage diffs and nonces are mapped by contract addresses
+#[derive(Debug, Clone, Default, Serialize)]
+pub(crate) struct FeederGatewayStateDiff {
+    pub(crate) storage_diffs: BTreeMap<String, Vec<StorageEntry>>,
+    pub(crate) nonces: BTreeMap<String, Nonce>,
+    pub(crate) deployed_contracts: Vec<DeployedContract>,
+    pub(crate) old_declared_contracts: Vec<ClassHash>,
+    pub(crate) declared_classes: Vec<ClassHashes>,
+    pub(crate) replaced_classes: Vec<ReplacedClasses>,
+}
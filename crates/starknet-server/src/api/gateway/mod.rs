@@ -0,0 +1,96 @@
+pub(crate) mod models;
+
+use base64::Engine;
+use starknet_types::rpc::transactions::BroadcastedTransaction;
+
+/// Translates a transaction in the gateway format to its broadcasted (RPC) counterpart. The
+/// formats differ only in the names of transaction types and in the Sierra program of declare
+/// transactions, which the gateway expects to be gzipped and base64-encoded.
+pub(crate) fn to_broadcasted_transaction(
+    mut transaction: serde_json::Value,
+) -> Result<BroadcastedTransaction, String> {
+    let transaction_object =
+        transaction.as_object_mut().ok_or("Transaction is expected to be an object")?;
+
+    let transaction_type = match transaction_object.get("type").and_then(|t| t.as_str()) {
+        Some("INVOKE_FUNCTION") => "INVOKE",
+        Some("DECLARE") => "DECLARE",
+        Some("DEPLOY_ACCOUNT") => "DEPLOY_ACCOUNT",
+        Some("DEPLOY") => return Err("DEPLOY transactions are not supported".to_string()),
+        Some(unknown_type) => return Err(format!("Invalid transaction type: {unknown_type}")),
+        None => return Err("Missing transaction type".to_string()),
+    };
+    transaction_object.insert("type".to_string(), transaction_type.into());
+
+    if let Some(contract_class) =
+        transaction_object.get_mut("contract_class").and_then(|c| c.as_object_mut())
+    {
+        if let Some(serde_json::Value::String(compressed_program)) =
+            contract_class.get("sierra_program")
+        {
+            let sierra_program = decompress_sierra_program(compressed_program)?;
+            contract_class.insert("sierra_program".to_string(), sierra_program);
+        }
+    }
+
+    serde_json::from_value(transaction).map_err(|err| format!("Invalid transaction: {err}"))
+}
+
+fn decompress_sierra_program(compressed_program: &str) -> Result<serde_json::Value, String> {
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(compressed_program)
+        .map_err(|_| "sierra_program: Unable to decode base64 string")?;
+
+    let decoder = flate2::read::GzDecoder::new(bytes.as_slice());
+    serde_json::from_reader(decoder)
+        .map_err(|_| "sierra_program: Unable to decode gzipped bytes".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use base64::Engine;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use serde_json::json;
+    use starknet_types::rpc::transactions::BroadcastedTransaction;
+
+    use super::{decompress_sierra_program, to_broadcasted_transaction};
+
+    #[test]
+    fn invoke_function_is_translated_to_invoke() {
+        let transaction = to_broadcasted_transaction(json!({
+            "type": "INVOKE_FUNCTION",
+            "version": "0x1",
+            "max_fee": "0x100",
+            "signature": [],
+            "nonce": "0x0",
+            "sender_address": "0x1",
+            "calldata": ["0x2"]
+        }))
+        .unwrap();
+
+        assert!(matches!(transaction, BroadcastedTransaction::Invoke(_)));
+    }
+
+    #[test]
+    fn deploy_and_unknown_types_are_rejected() {
+        assert!(to_broadcasted_transaction(json!({ "type": "DEPLOY" })).is_err());
+        assert!(to_broadcasted_transaction(json!({ "type": "INVOKE" })).is_err());
+        assert!(to_broadcasted_transaction(json!({})).is_err());
+    }
+
+    #[test]
+    fn compressed_sierra_program_is_decompressed() {
+        let sierra_program = json!(["0x1", "0x2"]);
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(sierra_program.to_string().as_bytes()).unwrap();
+        let compressed_program =
+            base64::engine::general_purpose::STANDARD.encode(encoder.finish().unwrap());
+
+        assert_eq!(decompress_sierra_program(&compressed_program).unwrap(), sierra_program);
+        assert!(decompress_sierra_program("not base64").is_err());
+    }
+}
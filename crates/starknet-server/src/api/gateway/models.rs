@@ -0,0 +1,22 @@
+use serde::Serialize;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::{ClassHash, TransactionHash};
+
+/// Response of `add_transaction` of the gateway
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct GatewayTransactionResponse {
+    pub(crate) code: GatewayTransactionCode,
+    pub(crate) transaction_hash: TransactionHash,
+    /// Address of the account deployed by a deploy account transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) address: Option<ContractAddress>,
+    /// Hash of the class declared by a declare transaction
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) class_hash: Option<ClassHash>,
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub(crate) enum GatewayTransactionCode {
+    #[serde(rename = "TRANSACTION_RECEIVED")]
+    TransactionReceived,
+}
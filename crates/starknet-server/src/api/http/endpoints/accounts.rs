@@ -12,8 +12,7 @@ pub(crate) async fn get_predeployed_accounts(
 ) -> HttpApiResult<Json<Vec<SerializableAccount>>> {
     let predeployed_accounts = state
         .api
-        .starknet
-        .read()
+        .read_starknet()
         .await
         .get_predeployed_accounts()
         .into_iter()
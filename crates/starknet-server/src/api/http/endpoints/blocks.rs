@@ -1,13 +1,15 @@
 use axum::{Extension, Json};
 
 use crate::api::http::error::HttpApiError;
-use crate::api::http::models::{AbortedBlocks, AbortingBlocks, CreatedBlock};
+use crate::api::http::models::{AbortedBlocks, AbortingBlocks, CreatedBlock, MiningStatus};
 use crate::api::http::{HttpApiHandler, HttpApiResult};
 
 pub(crate) async fn create_block(
     Extension(state): Extension<HttpApiHandler>,
 ) -> HttpApiResult<Json<CreatedBlock>> {
-    let mut starknet = state.api.starknet.write().await;
+    // transactions submitted before the block creation end up in it, or in earlier blocks
+    let _barrier = state.api.block_barrier().await;
+    let mut starknet = state.api.write_starknet().await;
     starknet
         .create_block(None)
         .map_err(|err| HttpApiError::CreateEmptyBlockError { msg: err.to_string() })?;
@@ -19,6 +21,28 @@ pub(crate) async fn create_block(
     }
 }
 
+pub(crate) async fn pause_mining(
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Json<MiningStatus>> {
+    let mut starknet = state.api.write_starknet().await;
+    starknet.pause_mining();
+
+    Ok(Json(MiningStatus { paused: starknet.is_mining_paused() }))
+}
+
+pub(crate) async fn resume_mining(
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Json<MiningStatus>> {
+    // transactions submitted before resuming are sealed together
+    let _barrier = state.api.block_barrier().await;
+    let mut starknet = state.api.write_starknet().await;
+    starknet
+        .resume_mining()
+        .map_err(|err| HttpApiError::CreateEmptyBlockError { msg: err.to_string() })?;
+
+    Ok(Json(MiningStatus { paused: starknet.is_mining_paused() }))
+}
+
 pub(crate) async fn abort_blocks(
     Json(_data): Json<AbortingBlocks>,
 ) -> HttpApiResult<Json<AbortedBlocks>> {
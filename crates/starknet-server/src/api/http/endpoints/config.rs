@@ -0,0 +1,62 @@
+use axum::{Extension, Json};
+use clap::ValueEnum;
+use starknet_types::traits::ToDecimalString;
+
+use crate::api::http::models::DevnetConfig;
+use crate::api::http::{HttpApiHandler, HttpApiResult};
+
+pub(crate) async fn get_config(
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Json<DevnetConfig>> {
+    let mut disabled_methods: Vec<String> =
+        state.api.disabled_methods.read().await.iter().cloned().collect();
+    disabled_methods.sort();
+    let starknet = state.api.read_starknet().await;
+    let config = &starknet.config;
+
+    Ok(Json(DevnetConfig {
+        seed: config.seed,
+        total_accounts: config.total_accounts,
+        account_contract_class_hash: config.account_contract_class_hash,
+        predeployed_accounts_initial_balance: config
+            .predeployed_accounts_initial_balance
+            .to_decimal_string(),
+        host: config.host.to_string(),
+        port: config.port,
+        start_time: config.start_time,
+        timeout: config.timeout,
+        max_request_body_size: config.max_request_body_size,
+        compression_min_size: config.compression_min_size,
+        record_session: config.record_session,
+        disabled_methods,
+        gas_price: config.gas_price,
+        chain_id: to_cli_value(config.chain_id),
+        fee_token_address: config.fee_token_address,
+        udc_address: config.udc_address,
+        custom_genesis: config.custom_genesis.is_some(),
+        base_dump: config.base_dump.as_ref().map(|base_dump| base_dump.path.clone()),
+        base_dump_seed: config.base_dump.as_ref().map(|base_dump| base_dump.seed),
+        dump_on: config.dump_on.map(to_cli_value),
+        dump_path: config.dump_path.clone(),
+        dump_format: to_cli_value(config.dump_format),
+        block_max_steps: config.block_max_steps,
+        block_max_txs: config.block_max_txs,
+        block_generation_on: to_cli_value(config.block_generation),
+        shutdown_enabled: config.shutdown_token.is_some(),
+        impersonation_scoped: config.impersonation_api_key.is_some(),
+        slow_query_threshold: config.slow_query_threshold_ms,
+        event_retention: config.event_retention.to_string(),
+        reject_on_revert: config.reject_on_revert,
+        max_fee_cap: config.max_fee_cap.map(|max_fee_cap| max_fee_cap.to_string()),
+        allowed_senders: config.allowed_senders.clone(),
+        verify_execution: config.verify_execution,
+        webhook_urls: config.webhook_urls.clone(),
+        proof_proving_time: config.proof_proving_time,
+        proof_verification_time: config.proof_verification_time,
+    }))
+}
+
+/// Enum values are reported as they are specified on the command line
+fn to_cli_value(value: impl ValueEnum) -> String {
+    value.to_possible_value().map(|value| value.get_name().to_string()).unwrap_or_default()
+}
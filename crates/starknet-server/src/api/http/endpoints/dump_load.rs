@@ -8,7 +8,7 @@ pub(crate) async fn dump(
     Json(path): Json<DumpPath>,
     Extension(state): Extension<HttpApiHandler>,
 ) -> HttpApiResult<()> {
-    let starknet = state.api.starknet.write().await;
+    let starknet = state.api.write_starknet().await;
     match path.path {
         None => {
             // path not present
@@ -44,7 +44,7 @@ pub(crate) async fn load(
         return Err(HttpApiError::FileNotFound);
     }
 
-    let mut starknet = state.api.starknet.write().await;
+    let mut starknet = state.api.write_starknet().await;
     let transactions = starknet
         .load_transactions_custom_path(Some(path.path))
         .map_err(|_| HttpApiError::LoadError)?;
@@ -0,0 +1,110 @@
+use axum::body::StreamBody;
+use axum::extract::Query;
+use axum::response::{IntoResponse, Response};
+use axum::{Extension, Json};
+use hyper::header;
+use serde::Serialize;
+use starknet_core::error::Error;
+use starknet_core::starknet::profiler_trace::ProfilerCallTrace;
+use starknet_rs_core::types::BlockId;
+use starknet_types::rpc::state::StateUpdate;
+
+use crate::api::feeder_gateway;
+use crate::api::http::error::HttpApiError;
+use crate::api::http::models::{
+    ExportQuery, ExportedFeederGatewayBlock, ExportedStateUpdate, ProfilerTraceQuery,
+};
+use crate::api::http::{HttpApiHandler, HttpApiResult};
+use crate::api::session_recording::SessionFixture;
+
+/// Creates a newline-delimited JSON response. The items are collected beforehand, so that the
+/// lock on Starknet is not held while streaming, but serialized only when the body is streamed.
+fn ndjson_response<T: Serialize + Send + 'static>(items: Vec<T>) -> Response {
+    let lines = items.into_iter().map(|item| {
+        serde_json::to_vec(&item).map(|mut line| {
+            line.push(b'\n');
+            line
+        })
+    });
+
+    (
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        StreamBody::new(futures::stream::iter(lines)),
+    )
+        .into_response()
+}
+
+/// Streams state updates of all blocks starting with `from` as newline-delimited JSON
+pub(crate) async fn export_state_diffs(
+    Query(query): Query<ExportQuery>,
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Response> {
+    let state_updates = state.api.read_starknet().await.state_updates_from_block(query.from);
+
+    let exported_state_updates = state_updates
+        .into_iter()
+        .map(|(block_number, state_update)| ExportedStateUpdate {
+            block_number,
+            state_update: StateUpdate {
+                block_hash: state_update.block_hash,
+                new_root: state_update.new_root,
+                old_root: state_update.old_root,
+                state_diff: state_update.state_diff.into(),
+            },
+        })
+        .collect();
+
+    Ok(ndjson_response::<ExportedStateUpdate>(exported_state_updates))
+}
+
+/// Streams blocks starting with `from`, each with its state update, in the feeder gateway format
+/// as newline-delimited JSON
+pub(crate) async fn export_feeder_gateway_blocks(
+    Query(query): Query<ExportQuery>,
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Response> {
+    let starknet = state.api.read_starknet().await;
+
+    // block numbers are consecutive, so the export ends with the first missing block
+    let mut exported_blocks = vec![];
+    for block_number in query.from.. {
+        let block_id = BlockId::Number(block_number);
+        let block = match feeder_gateway::get_block(&starknet, block_id) {
+            Ok(block) => block,
+            Err(Error::NoBlock) => break,
+            Err(err) => return Err(HttpApiError::ExportError { msg: err.to_string() }),
+        };
+        let state_update = feeder_gateway::get_state_update(&starknet, block_id)
+            .map_err(|err| HttpApiError::ExportError { msg: err.to_string() })?;
+
+        exported_blocks.push(ExportedFeederGatewayBlock { block, state_update });
+    }
+
+    Ok(ndjson_response(exported_blocks))
+}
+
+/// Exports a call of the transaction and its nested calls in the trace format of cairo-profiler
+pub(crate) async fn export_profiler_trace(
+    Query(query): Query<ProfilerTraceQuery>,
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Json<ProfilerCallTrace>> {
+    state
+        .api
+        .read_starknet()
+        .await
+        .get_profiler_trace(query.transaction_hash, query.call)
+        .map(Json::from)
+        .map_err(|err| HttpApiError::InvalidTransaction { msg: err.to_string() })
+}
+
+/// Exports the JSON-RPC calls recorded since the start of Devnet, each with its response, as a
+/// fixture for mocks of the JSON-RPC endpoint
+pub(crate) async fn export_session_fixture(
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Json<SessionFixture>> {
+    if !state.record_session {
+        return Err(HttpApiError::SessionRecordingDisabled);
+    }
+
+    Ok(Json(state.api.session_recording.to_fixture()))
+}
@@ -0,0 +1,99 @@
+use axum::extract::Query;
+use axum::{Extension, Json};
+use starknet_rs_core::types::{BlockId, BlockTag};
+
+use crate::api::feeder_gateway;
+use crate::api::feeder_gateway::models::{
+    FeederGatewayBlock, FeederGatewayStateUpdate, FeederGatewayTransaction,
+};
+use crate::api::http::error::HttpApiError;
+use crate::api::http::models::{FeederGatewayBlockQuery, FeederGatewayTransactionQuery};
+use crate::api::http::{HttpApiHandler, HttpApiResult};
+
+/// Feeder gateway `get_block`
+pub(crate) async fn get_block(
+    Query(query): Query<FeederGatewayBlockQuery>,
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Json<FeederGatewayBlock>> {
+    let block_id = to_block_id(query)?;
+    let starknet = state.api.read_starknet().await;
+
+    feeder_gateway::get_block(&starknet, block_id)
+        .map(Json::from)
+        .map_err(|err| HttpApiError::FeederGatewayError { msg: err.to_string() })
+}
+
+/// Feeder gateway `get_transaction`
+pub(crate) async fn get_transaction(
+    Query(query): Query<FeederGatewayTransactionQuery>,
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Json<FeederGatewayTransaction>> {
+    let starknet = state.api.read_starknet().await;
+
+    feeder_gateway::get_transaction(&starknet, query.transaction_hash)
+        .map(Json::from)
+        .map_err(|err| HttpApiError::FeederGatewayError { msg: err.to_string() })
+}
+
+/// Feeder gateway `get_state_update`
+pub(crate) async fn get_state_update(
+    Query(query): Query<FeederGatewayBlockQuery>,
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<Json<FeederGatewayStateUpdate>> {
+    let block_id = to_block_id(query)?;
+    let starknet = state.api.read_starknet().await;
+
+    feeder_gateway::get_state_update(&starknet, block_id)
+        .map(Json::from)
+        .map_err(|err| HttpApiError::FeederGatewayError { msg: err.to_string() })
+}
+
+fn to_block_id(query: FeederGatewayBlockQuery) -> HttpApiResult<BlockId> {
+    match (query.block_number, query.block_hash) {
+        (Some(_), Some(_)) => Err(HttpApiError::InvalidBlockId {
+            msg: "only one of blockNumber and blockHash can be specified".to_string(),
+        }),
+        (None, Some(block_hash)) => Ok(BlockId::Hash(block_hash.into())),
+        (Some(block_number), None) => match block_number.as_str() {
+            "latest" => Ok(BlockId::Tag(BlockTag::Latest)),
+            "pending" => Ok(BlockId::Tag(BlockTag::Pending)),
+            number => number.parse().map(BlockId::Number).map_err(|_| {
+                HttpApiError::InvalidBlockId { msg: format!("invalid block number: {number}") }
+            }),
+        },
+        (None, None) => Ok(BlockId::Tag(BlockTag::Latest)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_rs_core::types::{BlockId, BlockTag};
+    use starknet_types::felt::Felt;
+
+    use super::to_block_id;
+    use crate::api::http::models::FeederGatewayBlockQuery;
+
+    fn query(block_number: Option<&str>, block_hash: Option<u128>) -> FeederGatewayBlockQuery {
+        FeederGatewayBlockQuery {
+            block_number: block_number.map(String::from),
+            block_hash: block_hash.map(Felt::from),
+        }
+    }
+
+    #[test]
+    fn block_id_is_parsed_from_query() {
+        assert_eq!(to_block_id(query(None, None)).unwrap(), BlockId::Tag(BlockTag::Latest));
+        assert_eq!(
+            to_block_id(query(Some("pending"), None)).unwrap(),
+            BlockId::Tag(BlockTag::Pending)
+        );
+        assert_eq!(to_block_id(query(Some("7"), None)).unwrap(), BlockId::Number(7));
+        assert_eq!(to_block_id(query(None, Some(1))).unwrap(), BlockId::Hash(Felt::from(1).into()));
+    }
+
+    #[test]
+    fn invalid_block_id_is_rejected() {
+        assert!(to_block_id(query(Some("0x"), None)).is_err());
+        assert!(to_block_id(query(Some("1"), Some(1))).is_err());
+    }
+}
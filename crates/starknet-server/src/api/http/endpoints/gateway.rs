@@ -0,0 +1,52 @@
+use axum::{Extension, Json};
+use hyper::HeaderMap;
+use starknet_types::rpc::transactions::{BroadcastedDeclareTransaction, BroadcastedTransaction};
+
+use crate::api::gateway::models::{GatewayTransactionCode, GatewayTransactionResponse};
+use crate::api::gateway::to_broadcasted_transaction;
+use crate::api::http::error::HttpApiError;
+use crate::api::http::{HttpApiHandler, HttpApiResult};
+use crate::api::{is_impersonation_allowed, scope_impersonation};
+
+/// Gateway `add_transaction`
+pub(crate) async fn add_transaction(
+    Extension(state): Extension<HttpApiHandler>,
+    headers: HeaderMap,
+    Json(transaction): Json<serde_json::Value>,
+) -> HttpApiResult<Json<GatewayTransactionResponse>> {
+    let _in_flight = state.api.start_transaction().await;
+    let transaction = to_broadcasted_transaction(transaction)
+        .map_err(|msg| HttpApiError::InvalidTransaction { msg })?;
+
+    let impersonation_allowed =
+        is_impersonation_allowed(state.impersonation_api_key.as_deref(), &headers);
+    let mut starknet = state.api.write_starknet().await;
+    let response = scope_impersonation(impersonation_allowed, || match transaction {
+        BroadcastedTransaction::Invoke(invoke_transaction) => starknet
+            .add_invoke_transaction(invoke_transaction)
+            .map(|transaction_hash| (transaction_hash, None, None)),
+        BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V1(declare_transaction)) => {
+            starknet
+                .add_declare_transaction_v1(*declare_transaction)
+                .map(|(transaction_hash, class_hash)| (transaction_hash, None, Some(class_hash)))
+        }
+        BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V2(declare_transaction)) => {
+            starknet
+                .add_declare_transaction_v2(*declare_transaction)
+                .map(|(transaction_hash, class_hash)| (transaction_hash, None, Some(class_hash)))
+        }
+        BroadcastedTransaction::DeployAccount(deploy_account_transaction) => starknet
+            .add_deploy_account_transaction(deploy_account_transaction)
+            .map(|(transaction_hash, address)| (transaction_hash, Some(address), None)),
+    });
+
+    let (transaction_hash, address, class_hash) =
+        response.map_err(|err| HttpApiError::InvalidTransaction { msg: err.to_string() })?;
+
+    Ok(Json(GatewayTransactionResponse {
+        code: GatewayTransactionCode::TransactionReceived,
+        transaction_hash,
+        address,
+        class_hash,
+    }))
+}
@@ -0,0 +1,13 @@
+use axum::http::header::{HeaderName, CONTENT_TYPE};
+use axum::Extension;
+
+use crate::api::http::{HttpApiHandler, HttpApiResult};
+
+/// Content type of the Prometheus text exposition format
+const PROMETHEUS_CONTENT_TYPE: &str = "text/plain; version=0.0.4";
+
+pub(crate) async fn get_metrics(
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<([(HeaderName, &'static str); 1], String)> {
+    Ok(([(CONTENT_TYPE, PROMETHEUS_CONTENT_TYPE)], state.api.lock_metrics.to_prometheus_text()))
+}
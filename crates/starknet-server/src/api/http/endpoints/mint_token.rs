@@ -1,5 +1,4 @@
 use axum::{Extension, Json};
-use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
 use starknet_core::starknet::Starknet;
 use starknet_rs_core::types::{BlockId, BlockTag};
 use starknet_types::contract_address::ContractAddress;
@@ -16,8 +15,11 @@ pub(crate) async fn get_fee_token() -> HttpApiResult<Json<FeeToken>> {
 }
 
 /// get the balance of the `address`
-fn get_balance(starknet: &Starknet, address: ContractAddress) -> Result<BigUint, ApiError> {
-    let erc20_address = Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap();
+pub(crate) fn get_balance(
+    starknet: &Starknet,
+    address: ContractAddress,
+) -> Result<BigUint, ApiError> {
+    let erc20_address = Felt::from(starknet.config.fee_token_address);
     let balance_selector =
         starknet_rs_core::utils::get_selector_from_name("balanceOf").unwrap().into();
     let new_balance_raw = starknet.call(
@@ -46,8 +48,9 @@ pub(crate) async fn mint(
     Json(request): Json<MintTokensRequest>,
     Extension(state): Extension<HttpApiHandler>,
 ) -> HttpApiResult<Json<MintTokensResponse>> {
+    let _in_flight = state.api.start_transaction().await;
     // increase balance
-    let mut starknet = state.api.starknet.write().await;
+    let mut starknet = state.api.write_starknet().await;
 
     let tx_hash = starknet
         .mint(request.address, request.amount)
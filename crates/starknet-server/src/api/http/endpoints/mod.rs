@@ -1,3 +1,4 @@
+use axum::http::{header, HeaderMap};
 use axum::{Extension, Json};
 
 use super::error::HttpApiError;
@@ -22,6 +23,21 @@ pub(crate) mod accounts;
 /// Mint token - Local faucet
 pub(crate) mod mint_token;
 
+/// Bulk export
+pub(crate) mod export;
+
+/// Feeder gateway compatibility layer
+pub(crate) mod feeder_gateway;
+
+/// Gateway compatibility layer
+pub(crate) mod gateway;
+
+/// Resolved configuration
+pub(crate) mod config;
+
+/// Metrics
+pub(crate) mod metrics;
+
 /// Is alive
 pub(crate) async fn is_alive() -> HttpApiResult<String> {
     Ok("Alive!!!".to_string())
@@ -31,14 +47,31 @@ pub(crate) async fn is_alive() -> HttpApiResult<String> {
 pub(crate) async fn restart(Extension(state): Extension<HttpApiHandler>) -> HttpApiResult<()> {
     state
         .api
-        .starknet
-        .write()
+        .write_starknet()
         .await
         .restart()
         .map_err(|err| HttpApiError::RestartError { msg: err.to_string() })?;
     Ok(())
 }
 
+/// Shutdown - stops the server gracefully, after the response is sent
+pub(crate) async fn shutdown(
+    headers: HeaderMap,
+    Extension(state): Extension<HttpApiHandler>,
+) -> HttpApiResult<()> {
+    let expected_token = state.shutdown_token.as_ref().ok_or(HttpApiError::ShutdownDisabled)?;
+    let received_token = headers
+        .get(header::AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if received_token != Some(expected_token.as_str()) {
+        return Err(HttpApiError::InvalidShutdownToken);
+    }
+
+    state.api.shutdown.notify_one();
+    Ok(())
+}
+
 /// Fork
 pub(crate) async fn get_fork_status() -> HttpApiResult<Json<ForkStatus>> {
     Err(HttpApiError::GeneralError)
@@ -8,7 +8,7 @@ pub(crate) async fn set_time(
     Json(data): Json<Time>,
     Extension(state): Extension<HttpApiHandler>,
 ) -> HttpApiResult<Json<SetTimeResponse>> {
-    let mut starknet = state.api.starknet.write().await;
+    let mut starknet = state.api.write_starknet().await;
     starknet
         .set_time(data.time)
         .map_err(|err| HttpApiError::BlockSetTimeError { msg: err.to_string() })?;
@@ -27,7 +27,7 @@ pub(crate) async fn increase_time(
     Json(data): Json<Time>,
     Extension(state): Extension<HttpApiHandler>,
 ) -> HttpApiResult<Json<IncreaseTimeResponse>> {
-    let mut starknet = state.api.starknet.write().await;
+    let mut starknet = state.api.write_starknet().await;
     starknet
         .increase_time(data.time)
         .map_err(|err| HttpApiError::BlockIncreaseTimeError { msg: err.to_string() })?;
@@ -4,6 +4,8 @@ use hyper::StatusCode;
 use serde_json::json;
 use thiserror::Error;
 
+use crate::api::devnet_error_code::DevnetErrorCode;
+
 #[derive(Error, Debug)]
 pub enum HttpApiError {
     #[error("General error")]
@@ -26,10 +28,51 @@ pub enum HttpApiError {
     BlockIncreaseTimeError { msg: String },
     #[error("Could not restart: {msg}")]
     RestartError { msg: String },
+    #[error("The export failed: {msg}")]
+    ExportError { msg: String },
+    #[error("Invalid block id: {msg}")]
+    InvalidBlockId { msg: String },
+    #[error("The feeder gateway request failed: {msg}")]
+    FeederGatewayError { msg: String },
+    #[error("Invalid transaction: {msg}")]
+    InvalidTransaction { msg: String },
+    #[error("Shutdown is disabled; enable it by starting Devnet with --shutdown-token")]
+    ShutdownDisabled,
+    #[error("Invalid shutdown token")]
+    InvalidShutdownToken,
+    #[error("Session recording is disabled; enable it by starting Devnet with --record-session")]
+    SessionRecordingDisabled,
+}
+
+impl HttpApiError {
+    pub fn devnet_code(&self) -> DevnetErrorCode {
+        match self {
+            HttpApiError::GeneralError => DevnetErrorCode::Unknown,
+            HttpApiError::MintingError { .. } => DevnetErrorCode::TransactionFailed,
+            HttpApiError::FileNotFound => DevnetErrorCode::FileNotFound,
+            HttpApiError::DumpError { .. }
+            | HttpApiError::LoadError
+            | HttpApiError::ReExecutionError => DevnetErrorCode::StatePersistenceFailed,
+            HttpApiError::CreateEmptyBlockError { .. }
+            | HttpApiError::BlockSetTimeError { .. }
+            | HttpApiError::BlockIncreaseTimeError { .. } => DevnetErrorCode::BlockGenerationFailed,
+            HttpApiError::RestartError { .. }
+            | HttpApiError::ExportError { .. }
+            | HttpApiError::FeederGatewayError { .. } => DevnetErrorCode::Internal,
+            HttpApiError::InvalidBlockId { .. } | HttpApiError::InvalidTransaction { .. } => {
+                DevnetErrorCode::InvalidInput
+            }
+            HttpApiError::ShutdownDisabled | HttpApiError::SessionRecordingDisabled => {
+                DevnetErrorCode::EndpointDisabled
+            }
+            HttpApiError::InvalidShutdownToken => DevnetErrorCode::Unauthorized,
+        }
+    }
 }
 
 impl IntoResponse for HttpApiError {
     fn into_response(self) -> axum::response::Response {
+        let devnet_code = self.devnet_code();
         let (status, error_message) = match self {
             err @ HttpApiError::GeneralError => (StatusCode::BAD_REQUEST, err.to_string()),
             err @ HttpApiError::FileNotFound => (StatusCode::BAD_REQUEST, err.to_string()),
@@ -49,10 +92,26 @@ impl IntoResponse for HttpApiError {
             err @ HttpApiError::RestartError { .. } => {
                 (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
             }
+            err @ HttpApiError::ExportError { .. } => {
+                (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+            }
+            err @ HttpApiError::InvalidBlockId { .. } => (StatusCode::BAD_REQUEST, err.to_string()),
+            err @ HttpApiError::FeederGatewayError { .. } => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+            err @ HttpApiError::InvalidTransaction { .. } => {
+                (StatusCode::BAD_REQUEST, err.to_string())
+            }
+            err @ HttpApiError::ShutdownDisabled => (StatusCode::FORBIDDEN, err.to_string()),
+            err @ HttpApiError::InvalidShutdownToken => (StatusCode::UNAUTHORIZED, err.to_string()),
+            err @ HttpApiError::SessionRecordingDisabled => {
+                (StatusCode::FORBIDDEN, err.to_string())
+            }
         };
 
         let body = Json(json!({
             "error": error_message,
+            "devnet_code": devnet_code,
         }));
 
         (status, body).into_response()
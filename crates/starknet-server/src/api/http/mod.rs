@@ -13,4 +13,11 @@ type HttpApiResult<T> = Result<T, HttpApiError>;
 #[derive(Clone)]
 pub struct HttpApiHandler {
     pub api: Api,
+    /// Token required by `POST /shutdown`; shutdown via HTTP is disabled if not set
+    pub shutdown_token: Option<String>,
+    /// Key required to impersonate accounts, see
+    /// [`is_impersonation_allowed`](super::is_impersonation_allowed)
+    pub impersonation_api_key: Option<String>,
+    /// Whether JSON-RPC calls are recorded, which `GET /export/session_fixture` requires
+    pub record_session: bool,
 }
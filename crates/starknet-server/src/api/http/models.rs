@@ -1,8 +1,13 @@
 use serde::{Deserialize, Serialize};
+use starknet_core::starknet::profiler_trace::ProfiledCall;
 use starknet_types::contract_address::ContractAddress;
 use starknet_types::felt::{BlockHash, Calldata, EntryPointSelector, Felt, Nonce, TransactionHash};
+use starknet_types::rpc::state::StateUpdate;
+use starknet_types::starknet_api::block::BlockNumber;
 use starknet_types::starknet_api::transaction::Fee;
 
+use crate::api::feeder_gateway::models::{FeederGatewayBlock, FeederGatewayStateUpdate};
+
 #[derive(Deserialize, Debug)]
 pub(crate) struct DumpPath {
     pub path: Option<String>,
@@ -47,6 +52,11 @@ pub(crate) struct CreatedBlock {
     pub block_hash: BlockHash,
 }
 
+#[derive(Serialize)]
+pub(crate) struct MiningStatus {
+    pub paused: bool,
+}
+
 #[derive(Deserialize)]
 pub(crate) struct AbortingBlocks {
     #[serde(rename = "startingBlockHash")]
@@ -114,3 +124,95 @@ pub(crate) struct ForkStatus {
     url: String,
     block: u128,
 }
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ExportQuery {
+    #[serde(default)]
+    pub(crate) from: u64,
+}
+
+/// A single line of the NDJSON state diffs export
+#[derive(Serialize)]
+pub(crate) struct ExportedStateUpdate {
+    pub(crate) block_number: BlockNumber,
+    #[serde(flatten)]
+    pub(crate) state_update: StateUpdate,
+}
+
+/// A single line of the NDJSON export of blocks in the feeder gateway format
+#[derive(Serialize)]
+pub(crate) struct ExportedFeederGatewayBlock {
+    pub(crate) block: FeederGatewayBlock,
+    pub(crate) state_update: FeederGatewayStateUpdate,
+}
+
+/// Identifies a block as in the feeder gateway; if neither is provided, the latest block is used
+#[derive(Deserialize, Debug)]
+pub(crate) struct FeederGatewayBlockQuery {
+    /// A block number or one of the tags `latest` and `pending`
+    #[serde(rename = "blockNumber")]
+    pub(crate) block_number: Option<String>,
+    #[serde(rename = "blockHash")]
+    pub(crate) block_hash: Option<BlockHash>,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct FeederGatewayTransactionQuery {
+    #[serde(rename = "transactionHash")]
+    pub(crate) transaction_hash: TransactionHash,
+}
+
+#[derive(Deserialize, Debug)]
+pub(crate) struct ProfilerTraceQuery {
+    #[serde(rename = "transactionHash")]
+    pub(crate) transaction_hash: TransactionHash,
+    #[serde(default)]
+    pub(crate) call: ProfiledCall,
+}
+
+/// Configuration Devnet was started with, after resolving CLI options, environment variables and
+/// defaults
+#[derive(Serialize, Debug)]
+pub(crate) struct DevnetConfig {
+    pub(crate) seed: u32,
+    pub(crate) total_accounts: u8,
+    pub(crate) account_contract_class_hash: Felt,
+    /// decimal repr
+    pub(crate) predeployed_accounts_initial_balance: String,
+    pub(crate) host: String,
+    pub(crate) port: u16,
+    pub(crate) start_time: Option<u64>,
+    pub(crate) timeout: u16,
+    pub(crate) max_request_body_size: usize,
+    pub(crate) compression_min_size: Option<u16>,
+    pub(crate) record_session: bool,
+    pub(crate) disabled_methods: Vec<String>,
+    pub(crate) gas_price: u64,
+    pub(crate) chain_id: String,
+    pub(crate) fee_token_address: ContractAddress,
+    pub(crate) udc_address: ContractAddress,
+    /// The genesis itself is not exposed
+    pub(crate) custom_genesis: bool,
+    pub(crate) base_dump: Option<String>,
+    pub(crate) base_dump_seed: Option<u32>,
+    pub(crate) dump_on: Option<String>,
+    pub(crate) dump_path: Option<String>,
+    pub(crate) dump_format: String,
+    pub(crate) block_max_steps: Option<u64>,
+    pub(crate) block_max_txs: Option<u64>,
+    pub(crate) block_generation_on: String,
+    /// The token itself is not exposed
+    pub(crate) shutdown_enabled: bool,
+    /// The key itself is not exposed
+    pub(crate) impersonation_scoped: bool,
+    pub(crate) slow_query_threshold: Option<u64>,
+    pub(crate) event_retention: String,
+    pub(crate) reject_on_revert: bool,
+    /// decimal repr
+    pub(crate) max_fee_cap: Option<String>,
+    pub(crate) allowed_senders: Vec<ContractAddress>,
+    pub(crate) verify_execution: bool,
+    pub(crate) webhook_urls: Vec<String>,
+    pub(crate) proof_proving_time: u64,
+    pub(crate) proof_verification_time: u64,
+}
@@ -0,0 +1,579 @@
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use cairo_lang_starknet::contract_class::ContractClass as SierraContractClass;
+use server::rpc_core::error::{ErrorCode, RpcError};
+use starknet_core::error::{Error, StateError};
+use starknet_core::starknet::counterfactual_accounts::CounterfactualAccount;
+use starknet_core::starknet::Starknet;
+use starknet_rs_core::utils::{
+    cairo_short_string_to_felt, get_selector_from_name, parse_cairo_short_string,
+};
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::contract_class::{
+    compute_casm_class_hash, compute_sierra_class_hash, ContractClass,
+};
+use starknet_types::felt::{ClassHash, Felt, TransactionHash};
+use starknet_types::num_bigint::BigUint;
+use starknet_types::rpc::block::{BlockId, Tag};
+use starknet_types::rpc::state::ThinStateDiff;
+use starknet_types::rpc::transactions::{BroadcastedTransaction, FunctionCall};
+
+use super::endpoints::DEFAULT_CONTINUATION_TOKEN;
+use super::error::{ApiError, StrictRpcResult};
+use super::models::{
+    AccountTemplateSetting, BlockHashAndNumberOutput, CompileSierraOutput,
+    ContractStepLimitSetting, CounterfactualAccountOutput, DisabledMethods, EventStatsInput,
+    FeltUtilsInput, FeltUtilsOutput, GetStorageByNameInput, GetStorageByNameOutput, JournalInput,
+    MintOutput, RejectOnRevert, TransactionBatchOutput, TransactionsBySenderInput,
+    TransactionsBySenderOutput,
+};
+use super::{check_method_can_be_disabled, JsonRpcHandler, StarknetResponse};
+use crate::api::devnet_error_code::DevnetErrorCode;
+use crate::api::http::endpoints::mint_token::get_balance;
+use crate::api::{scope_impersonation, IMPERSONATION_API_KEY_HEADER};
+
+/// Number of transactions returned by `devnet_getAccountPortfolio` if not specified
+const DEFAULT_PORTFOLIO_TRANSACTIONS: usize = 10;
+
+/// here are the definitions of Devnet-specific JSON-RPC endpoints
+impl JsonRpcHandler {
+    /// devnet_compareClasses
+    pub(crate) async fn compare_classes(
+        &self,
+        class_hash_a: ClassHash,
+        class_hash_b: ClassHash,
+    ) -> StrictRpcResult {
+        match self.api.read_starknet().await.compare_classes(class_hash_a, class_hash_b) {
+            Ok(class_diff) => Ok(StarknetResponse::CompareClasses(class_diff)),
+            Err(Error::StateError(_) | Error::NoStateAtBlock { block_number: _ }) => {
+                Err(ApiError::ClassHashNotFound)
+            }
+            Err(unknown_error) => Err(ApiError::StarknetDevnetError(unknown_error)),
+        }
+    }
+
+    /// devnet_simulateUpgrade
+    pub(crate) async fn simulate_upgrade(
+        &self,
+        contract_address: ContractAddress,
+        class_hash: ClassHash,
+        transaction_hashes: Vec<TransactionHash>,
+    ) -> StrictRpcResult {
+        match self.api.read_starknet().await.simulate_upgrade(
+            contract_address,
+            class_hash,
+            &transaction_hashes,
+        ) {
+            Ok(simulation) => Ok(StarknetResponse::SimulateUpgrade(simulation)),
+            Err(Error::ContractNotFound) => Err(ApiError::ContractNotFound),
+            Err(Error::NoTransaction) => Err(ApiError::TransactionNotFound),
+            Err(Error::StateError(_)) => Err(ApiError::ClassHashNotFound),
+            Err(Error::UnsupportedAction { msg }) => Err(ApiError::UnsupportedAction { msg }),
+            Err(unknown_error) => Err(ApiError::StarknetDevnetError(unknown_error)),
+        }
+    }
+
+    /// devnet_setAllowance
+    pub(crate) async fn set_allowance(
+        &self,
+        token: ContractAddress,
+        owner: ContractAddress,
+        spender: ContractAddress,
+        amount: u128,
+    ) -> StrictRpcResult {
+        self.check_impersonation_allowed("devnet_setAllowance")?;
+        let _in_flight = self.api.start_transaction().await;
+        let mut starknet = self.api.write_starknet().await;
+        starknet.set_allowance(token, owner, spender, amount).map_err(Self::map_cheatcode_error)?;
+
+        Self::latest_block_hash_and_number(&starknet)
+    }
+
+    /// devnet_transferToken
+    pub(crate) async fn transfer_token(
+        &self,
+        token: ContractAddress,
+        from: ContractAddress,
+        to: ContractAddress,
+        amount: u128,
+    ) -> StrictRpcResult {
+        self.check_impersonation_allowed("devnet_transferToken")?;
+        let _in_flight = self.api.start_transaction().await;
+        let mut starknet = self.api.write_starknet().await;
+        starknet.transfer_token(token, from, to, amount).map_err(Self::map_cheatcode_error)?;
+
+        Self::latest_block_hash_and_number(&starknet)
+    }
+
+    /// devnet_setAccountPublicKey
+    pub(crate) async fn set_account_public_key(
+        &self,
+        address: ContractAddress,
+        new_public_key: Felt,
+    ) -> StrictRpcResult {
+        self.check_impersonation_allowed("devnet_setAccountPublicKey")?;
+        let _in_flight = self.api.start_transaction().await;
+        let mut starknet = self.api.write_starknet().await;
+        starknet
+            .set_account_public_key(address, new_public_key)
+            .map_err(Self::map_cheatcode_error)?;
+
+        Self::latest_block_hash_and_number(&starknet)
+    }
+
+    /// devnet_applyStateDiff
+    pub(crate) async fn apply_state_diff(&self, state_diff: ThinStateDiff) -> StrictRpcResult {
+        self.check_impersonation_allowed("devnet_applyStateDiff")?;
+        let _in_flight = self.api.start_transaction().await;
+        let mut starknet = self.api.write_starknet().await;
+        starknet.apply_state_diff(state_diff).map_err(|err| match err {
+            Error::StateError(StateError::NoneClassHash(_)) => ApiError::ClassHashNotFound,
+            other_error => Self::map_cheatcode_error(other_error),
+        })?;
+
+        Self::latest_block_hash_and_number(&starknet)
+    }
+
+    /// devnet_getBlockExecutionResources
+    pub(crate) async fn get_block_execution_resources(&self, block_id: BlockId) -> StrictRpcResult {
+        match self.api.read_starknet().await.get_block_execution_resources(block_id.into()) {
+            Ok(resources) => Ok(StarknetResponse::BlockExecutionResources(resources)),
+            Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
+            Err(unknown_error) => Err(ApiError::StarknetDevnetError(unknown_error)),
+        }
+    }
+
+    /// devnet_multicall
+    pub(crate) async fn multicall(
+        &self,
+        block_id: BlockId,
+        calls: Vec<FunctionCall>,
+    ) -> StrictRpcResult {
+        // holding the lock for all calls guarantees they are executed against the same state
+        let starknet = self.api.read_starknet().await;
+
+        let mut results = Vec::with_capacity(calls.len());
+        for (index, call) in calls.into_iter().enumerate() {
+            match starknet.call(
+                block_id.into(),
+                call.contract_address.into(),
+                call.entry_point_selector,
+                call.calldata,
+            ) {
+                Ok(result) => results.push(result),
+                Err(Error::NoBlock) => return Err(ApiError::BlockNotFound),
+                Err(Error::ContractNotFound) => return Err(ApiError::ContractNotFound),
+                Err(err) => {
+                    return Err(ApiError::ContractError {
+                        error: Error::ExecutionError {
+                            revert_error: format!("Call at index {index} failed: {err}"),
+                        },
+                    });
+                }
+            }
+        }
+
+        Ok(StarknetResponse::Multicall(results))
+    }
+
+    /// devnet_getAccountPortfolio
+    pub(crate) async fn get_account_portfolio(
+        &self,
+        contract_address: ContractAddress,
+        max_transactions: Option<usize>,
+    ) -> StrictRpcResult {
+        let max_transactions = max_transactions.unwrap_or(DEFAULT_PORTFOLIO_TRANSACTIONS);
+        match self
+            .api
+            .read_starknet()
+            .await
+            .get_account_portfolio(contract_address, max_transactions)
+        {
+            Ok(portfolio) => Ok(StarknetResponse::AccountPortfolio(portfolio)),
+            Err(Error::ContractNotFound) => Err(ApiError::ContractNotFound),
+            Err(unknown_error) => Err(ApiError::StarknetDevnetError(unknown_error)),
+        }
+    }
+
+    /// devnet_getPendingStateSnapshot
+    pub(crate) async fn get_pending_state_snapshot(
+        &self,
+        address: ContractAddress,
+    ) -> StrictRpcResult {
+        match self.api.read_starknet().await.get_pending_state_snapshot(address) {
+            Ok(snapshot) => Ok(StarknetResponse::PendingStateSnapshot(snapshot)),
+            Err(Error::ContractNotFound) => Err(ApiError::ContractNotFound),
+            Err(unknown_error) => Err(ApiError::StarknetDevnetError(unknown_error)),
+        }
+    }
+
+    /// devnet_getStorageByName
+    pub(crate) async fn get_storage_by_name(
+        &self,
+        input: GetStorageByNameInput,
+    ) -> StrictRpcResult {
+        let block_id = input.block_id.unwrap_or(BlockId::Tag(Tag::Latest));
+        let (storage_address, values) = self
+            .api
+            .read_starknet()
+            .await
+            .contract_storage_by_name_at_block(
+                block_id.into(),
+                input.contract_address,
+                &input.variable_name,
+                &input.keys,
+                input.length,
+            )
+            .map_err(|err| match err {
+                Error::NoBlock => ApiError::BlockNotFound,
+                Error::ContractNotFound | Error::NoStateAtBlock { block_number: _ } => {
+                    ApiError::ContractNotFound
+                }
+                unknown_error => ApiError::StarknetDevnetError(unknown_error),
+            })?;
+
+        Ok(StarknetResponse::StorageByName(GetStorageByNameOutput { storage_address, values }))
+    }
+
+    /// devnet_getStorageAtTime
+    pub(crate) async fn get_storage_at_time(
+        &self,
+        input: GetStorageAtTimeInput,
+    ) -> StrictRpcResult {
+        let (block, value) = self
+            .api
+            .read_starknet()
+            .await
+            .contract_storage_at_time(input.contract_address, input.key, input.timestamp)
+            .map_err(|err| match err {
+                Error::NoBlock => ApiError::BlockNotFound,
+                Error::ContractNotFound | Error::NoStateAtBlock { block_number: _ } => {
+                    ApiError::ContractNotFound
+                }
+                unknown_error => ApiError::StarknetDevnetError(unknown_error),
+            })?;
+
+        Ok(StarknetResponse::StorageAtTime(GetStorageAtTimeOutput {
+            block_hash: block.block_hash(),
+            block_number: block.block_number(),
+            value,
+        }))
+    }
+
+    /// devnet_getAbi
+    pub(crate) async fn get_abi(&self, contract_address: ContractAddress) -> StrictRpcResult {
+        let block_id = BlockId::Tag(Tag::Latest);
+        match self.api.read_starknet().await.get_abi_at(block_id.into(), contract_address) {
+            Ok(abi) => Ok(StarknetResponse::Abi(abi)),
+            Err(
+                Error::ContractNotFound
+                | Error::StateError(_)
+                | Error::NoStateAtBlock { block_number: _ },
+            ) => Err(ApiError::ContractNotFound),
+            Err(unknown_error) => Err(ApiError::StarknetDevnetError(unknown_error)),
+        }
+    }
+
+    /// devnet_compileSierra
+    pub(crate) fn compile_sierra(&self, artifact: SierraContractClass) -> StrictRpcResult {
+        let class_hash = compute_sierra_class_hash(&artifact)?;
+        // compiled the same way as the classes of declare transactions
+        let casm = CasmContractClass::try_from(ContractClass::Cairo1(artifact)).map_err(|err| {
+            ApiError::RpcError(RpcError::invalid_params(format!(
+                "Sierra compilation failed: {err}"
+            )))
+        })?;
+        let compiled_class_hash = compute_casm_class_hash(&casm)?;
+
+        Ok(StarknetResponse::CompileSierra(Box::new(CompileSierraOutput {
+            class_hash,
+            compiled_class_hash,
+            compiler_version: env!("CAIRO_LANG_VERSION").to_string(),
+            casm,
+        })))
+    }
+
+    /// devnet_setRejectOnRevert
+    pub(crate) async fn set_reject_on_revert(&self, enabled: bool) -> StrictRpcResult {
+        self.api.write_starknet().await.config.reject_on_revert = enabled;
+
+        Ok(StarknetResponse::SetRejectOnRevert(RejectOnRevert { enabled }))
+    }
+
+    /// devnet_setDisabledMethods
+    pub(crate) async fn set_disabled_methods(&self, methods: Vec<String>) -> StrictRpcResult {
+        for method in &methods {
+            check_method_can_be_disabled(method)
+                .map_err(|msg| ApiError::RpcError(RpcError::invalid_params(msg)))?;
+        }
+        *self.api.disabled_methods.write().await = methods.iter().cloned().collect();
+
+        Ok(StarknetResponse::SetDisabledMethods(DisabledMethods { methods }))
+    }
+
+    /// devnet_setAccountTemplate
+    pub(crate) async fn set_account_template(
+        &self,
+        setting: AccountTemplateSetting,
+    ) -> StrictRpcResult {
+        self.check_impersonation_allowed("devnet_setAccountTemplate")?;
+
+        self.api
+            .write_starknet()
+            .await
+            .set_account_template(setting.address, setting.template)
+            .map_err(ApiError::StarknetDevnetError)?;
+
+        Ok(StarknetResponse::SetAccountTemplate(setting))
+    }
+
+    /// devnet_registerCounterfactualAccount
+    pub(crate) async fn register_counterfactual_account(
+        &self,
+        account: CounterfactualAccount,
+    ) -> StrictRpcResult {
+        self.check_impersonation_allowed("devnet_registerCounterfactualAccount")?;
+        match self.api.write_starknet().await.register_counterfactual_account(account) {
+            Ok(address) => {
+                Ok(StarknetResponse::RegisterCounterfactualAccount(CounterfactualAccountOutput {
+                    address,
+                }))
+            }
+            Err(Error::StateError(StateError::NoneClassHash(_))) => {
+                Err(ApiError::ClassHashNotFound)
+            }
+            Err(err) => Err(ApiError::StarknetDevnetError(err)),
+        }
+    }
+
+    /// devnet_setContractStepLimit
+    pub(crate) async fn set_contract_step_limit(
+        &self,
+        setting: ContractStepLimitSetting,
+    ) -> StrictRpcResult {
+        match self
+            .api
+            .write_starknet()
+            .await
+            .set_contract_step_limit(setting.address, setting.steps)
+        {
+            Ok(()) => Ok(StarknetResponse::SetContractStepLimit(setting)),
+            Err(Error::ContractNotFound) => Err(ApiError::ContractNotFound),
+            Err(err) => Err(ApiError::StarknetDevnetError(err)),
+        }
+    }
+
+    /// devnet_getProofStatus
+    pub(crate) async fn get_proof_status(&self, block_id: BlockId) -> StrictRpcResult {
+        match self.api.read_starknet().await.get_proof_status(block_id.into()) {
+            Ok(proof_status) => Ok(StarknetResponse::ProofStatus(proof_status)),
+            Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
+            Err(err) => Err(ApiError::StarknetDevnetError(err)),
+        }
+    }
+
+    /// devnet_validateTransaction
+    pub(crate) async fn validate_transaction(
+        &self,
+        block_id: BlockId,
+        transaction: BroadcastedTransaction,
+    ) -> StrictRpcResult {
+        let result = self
+            .execute_within_timeout(move |starknet| {
+                starknet.validate_transaction(block_id.into(), &transaction)
+            })
+            .await?;
+        match result {
+            Ok(validation) => Ok(StarknetResponse::ValidateTransaction(validation)),
+            Err(Error::ContractNotFound) => Err(ApiError::ContractNotFound),
+            Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
+            Err(err) => Err(ApiError::ContractError { error: err }),
+        }
+    }
+
+    /// devnet_getTransactionsBySender
+    pub(crate) async fn get_transactions_by_sender(
+        &self,
+        input: TransactionsBySenderInput,
+    ) -> StrictRpcResult {
+        let page = input
+            .continuation_token
+            .unwrap_or(DEFAULT_CONTINUATION_TOKEN.to_string())
+            .parse::<usize>()
+            .map_err(|_| ApiError::InvalidContinuationToken)?;
+
+        let (transactions, has_more_transactions) = self
+            .api
+            .read_starknet()
+            .await
+            .get_transactions_by_sender(
+                input.address,
+                input.from_block.map(Into::into),
+                input.to_block.map(Into::into),
+                page * input.chunk_size,
+                input.chunk_size,
+            )
+            .map_err(|err| match err {
+                Error::NoBlock => ApiError::BlockNotFound,
+                unknown_error => ApiError::StarknetDevnetError(unknown_error),
+            })?;
+
+        Ok(StarknetResponse::TransactionsBySender(TransactionsBySenderOutput {
+            transactions,
+            continuation_token: if has_more_transactions {
+                Some((page + 1).to_string())
+            } else {
+                None
+            },
+        }))
+    }
+
+    /// devnet_getEventStats
+    pub(crate) async fn get_event_stats(&self, input: EventStatsInput) -> StrictRpcResult {
+        match self
+            .api
+            .read_starknet()
+            .await
+            .get_event_stats(input.from_block.map(Into::into), input.to_block.map(Into::into))
+        {
+            Ok(event_stats) => Ok(StarknetResponse::EventStats(event_stats)),
+            Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
+            Err(unknown_error) => Err(ApiError::StarknetDevnetError(unknown_error)),
+        }
+    }
+
+    /// devnet_getJournal
+    pub(crate) async fn get_journal(&self, input: JournalInput) -> StrictRpcResult {
+        match self
+            .api
+            .read_starknet()
+            .await
+            .get_journal(input.from_block.map(Into::into), input.to_block.map(Into::into))
+        {
+            Ok(journal) => Ok(StarknetResponse::Journal(journal)),
+            Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
+            Err(unknown_error) => Err(ApiError::StarknetDevnetError(unknown_error)),
+        }
+    }
+
+    /// devnet_sendTransactionBatch
+    pub(crate) async fn send_transaction_batch(
+        &self,
+        transactions: Vec<BroadcastedTransaction>,
+    ) -> StrictRpcResult {
+        let _in_flight = self.api.start_transaction().await;
+        let mut starknet = self.api.write_starknet().await;
+        let transaction_hashes = scope_impersonation(self.impersonation_allowed, || {
+            starknet.add_transaction_batch(transactions)
+        })
+        .map_err(|err| match err {
+            err @ Error::TransactionBatchError { .. } => ApiError::ContractError { error: err },
+            unknown_error => ApiError::StarknetDevnetError(unknown_error),
+        })?;
+
+        Ok(StarknetResponse::SendTransactionBatch(TransactionBatchOutput { transaction_hashes }))
+    }
+
+    /// devnet_mint
+    pub(crate) async fn mint(&self, address: ContractAddress, amount: u128) -> StrictRpcResult {
+        self.check_impersonation_allowed("devnet_mint")?;
+        let _in_flight = self.api.start_transaction().await;
+        let mut starknet = self.api.write_starknet().await;
+        let tx_hash = starknet.mint(address, amount).await.map_err(|err| match err {
+            Error::UnsupportedAction { msg } => ApiError::UnsupportedAction { msg },
+            unknown_error => ApiError::StarknetDevnetError(unknown_error),
+        })?;
+        let new_balance = get_balance(&starknet, address)?;
+
+        Ok(StarknetResponse::Mint(MintOutput {
+            new_balance: new_balance.to_str_radix(10),
+            unit: "WEI".to_string(),
+            tx_hash,
+        }))
+    }
+
+    /// devnet_verifyIntegrity
+    pub(crate) async fn verify_integrity(&self) -> StrictRpcResult {
+        Ok(StarknetResponse::VerifyIntegrity(self.api.read_starknet().await.verify_integrity()))
+    }
+
+    /// devnet_feltUtils
+    pub(crate) fn felt_utils(&self, input: FeltUtilsInput) -> StrictRpcResult {
+        let invalid_params = |msg: String| ApiError::RpcError(RpcError::invalid_params(msg));
+
+        let output = match input {
+            FeltUtilsInput::ShortStringToFelt { short_string } => FeltUtilsOutput::Felt(
+                cairo_short_string_to_felt(&short_string)
+                    .map_err(|err| invalid_params(format!("Invalid short string: {err}")))?
+                    .into(),
+            ),
+            FeltUtilsInput::FeltToShortString { felt } => FeltUtilsOutput::Text(
+                parse_cairo_short_string(&felt.into())
+                    .map_err(|err| invalid_params(format!("Invalid short string: {err}")))?,
+            ),
+            FeltUtilsInput::U256ToFelts { u256 } => {
+                let value = match u256.strip_prefix("0x") {
+                    Some(hex) => BigUint::parse_bytes(hex.as_bytes(), 16),
+                    None => BigUint::parse_bytes(u256.as_bytes(), 10),
+                }
+                .filter(|value| value.bits() <= 256)
+                .ok_or_else(|| invalid_params(format!("Invalid u256: {u256}")))?;
+
+                let low_mask = (BigUint::from(1_u8) << 128) - 1_u8;
+                FeltUtilsOutput::U256Felts {
+                    low: Felt::try_from(&value & low_mask)?,
+                    high: Felt::try_from(value >> 128)?,
+                }
+            }
+            FeltUtilsInput::FeltsToU256 { low, high } => {
+                let (low, high) = (BigUint::from(low), BigUint::from(high));
+                if low.bits() > 128 || high.bits() > 128 {
+                    return Err(invalid_params("Low and high must fit in 128 bits".to_string()));
+                }
+                FeltUtilsOutput::Text(((high << 128) + low).to_str_radix(10))
+            }
+            FeltUtilsInput::SelectorFromName { name } => FeltUtilsOutput::Felt(
+                get_selector_from_name(&name)
+                    .map_err(|err| invalid_params(format!("Invalid name: {err}")))?
+                    .into(),
+            ),
+        };
+
+        Ok(StarknetResponse::FeltUtils(output))
+    }
+
+    /// Account templates and the cheatcodes which rewrite the state are reserved to the
+    /// requests which may impersonate accounts, see [`JsonRpcHandler::impersonation_allowed`]
+    fn check_impersonation_allowed(&self, method: &str) -> Result<(), ApiError> {
+        if self.impersonation_allowed {
+            return Ok(());
+        }
+
+        Err(ApiError::RpcError(RpcError {
+            code: ErrorCode::InvalidRequest,
+            message: format!(
+                "{method} requires the API key of --impersonation-api-key in the \
+                 {IMPERSONATION_API_KEY_HEADER} header"
+            )
+            .into(),
+            data: Some(serde_json::json!({ "devnet_code": DevnetErrorCode::Unauthorized })),
+        }))
+    }
+
+    fn map_cheatcode_error(err: Error) -> ApiError {
+        match err {
+            Error::ContractNotFound => ApiError::ContractNotFound,
+            Error::ExecutionError { .. } => ApiError::ContractError { error: err },
+            unknown_error => ApiError::StarknetDevnetError(unknown_error),
+        }
+    }
+
+    /// Cheatcodes store their state changes in a new block, which is reported back
+    fn latest_block_hash_and_number(starknet: &Starknet) -> StrictRpcResult {
+        let block = starknet.get_latest_block()?;
+
+        Ok(StarknetResponse::BlockHashAndNumber(BlockHashAndNumberOutput {
+            block_hash: block.block_hash(),
+            block_number: block.block_number(),
+        }))
+    }
+}
@@ -3,7 +3,7 @@ use starknet_rs_core::types::MsgFromL1;
 use starknet_types::contract_address::ContractAddress;
 use starknet_types::felt::{ClassHash, TransactionHash};
 use starknet_types::patricia_key::PatriciaKey;
-use starknet_types::rpc::block::{Block, BlockHeader, BlockId};
+use starknet_types::rpc::block::{Block, BlockHeader, BlockId, Tag};
 use starknet_types::rpc::state::StateUpdate;
 use starknet_types::rpc::transactions::{
     BroadcastedTransaction, EventFilter, EventsChunk, FunctionCall, SimulationFlag,
@@ -11,10 +11,16 @@ use starknet_types::rpc::transactions::{
 use starknet_types::traits::ToHexString;
 
 use super::error::{ApiError, StrictRpcResult};
-use super::models::{BlockHashAndNumberOutput, SyncingOutput, TransactionStatusOutput};
+use super::models::{
+    BlockHashAndNumberOutput, SyncingOutput, TransactionStatus, TransactionStatusOutput,
+};
 use super::{JsonRpcHandler, StarknetResponse};
 
-const DEFAULT_CONTINUATION_TOKEN: &str = "0";
+pub(crate) const DEFAULT_CONTINUATION_TOKEN: &str = "0";
+/// Limits of a `starknet_getEvents` request, so that a single request cannot hold the lock of the
+/// state while it iterates over all events
+pub(crate) const MAX_EVENTS_CHUNK_SIZE: usize = 1000;
+pub(crate) const MAX_EVENT_FILTER_KEYS: usize = 100;
 
 /// here are the definitions and stub implementations of all JSON-RPC read endpoints
 impl JsonRpcHandler {
@@ -25,11 +31,14 @@ impl JsonRpcHandler {
 
     /// starknet_getBlockWithTxHashes
     pub(crate) async fn get_block_with_tx_hashes(&self, block_id: BlockId) -> StrictRpcResult {
-        let block =
-            self.api.starknet.read().await.get_block(block_id.into()).map_err(|err| match err {
+        let starknet = self.api.read_starknet().await;
+        let block = match block_id {
+            BlockId::Tag(Tag::Pending) => starknet.get_pending_block(),
+            _ => starknet.get_block(block_id.into()).map_err(|err| match err {
                 Error::NoBlock => ApiError::BlockNotFound,
                 unknown_error => ApiError::StarknetDevnetError(unknown_error),
-            })?;
+            })?,
+        };
 
         Ok(StarknetResponse::BlockWithTransactionHashes(Block {
             status: *block.status(),
@@ -43,7 +52,7 @@ impl JsonRpcHandler {
     /// starknet_getBlockWithTxs
     pub(crate) async fn get_block_with_txs(&self, block_id: BlockId) -> StrictRpcResult {
         let block =
-            self.api.starknet.read().await.get_block_with_transactions(block_id.into()).map_err(
+            self.api.read_starknet().await.get_block_with_transactions(block_id.into()).map_err(
                 |err| match err {
                     Error::NoBlock => ApiError::BlockNotFound,
                     Error::NoTransaction => ApiError::TransactionNotFound,
@@ -54,10 +63,24 @@ impl JsonRpcHandler {
         Ok(StarknetResponse::BlockWithFullTransactions(block))
     }
 
+    /// starknet_getBlockWithReceipts
+    pub(crate) async fn get_block_with_receipts(&self, block_id: BlockId) -> StrictRpcResult {
+        let block =
+            self.api.read_starknet().await.get_block_with_receipts(block_id.into()).map_err(
+                |err| match err {
+                    Error::NoBlock => ApiError::BlockNotFound,
+                    Error::NoTransaction => ApiError::TransactionNotFound,
+                    unknown_error => ApiError::StarknetDevnetError(unknown_error),
+                },
+            )?;
+
+        Ok(StarknetResponse::BlockWithReceipts(block))
+    }
+
     /// starknet_getStateUpdate
     pub(crate) async fn get_state_update(&self, block_id: BlockId) -> StrictRpcResult {
         let state_update =
-            self.api.starknet.read().await.block_state_update(block_id.into()).map_err(|err| {
+            self.api.read_starknet().await.block_state_update(block_id.into()).map_err(|err| {
                 match err {
                     Error::NoBlock => ApiError::BlockNotFound,
                     unknown_error => ApiError::StarknetDevnetError(unknown_error),
@@ -83,13 +106,13 @@ impl JsonRpcHandler {
     ) -> StrictRpcResult {
         let felt = self
             .api
-            .starknet
-            .read()
+            .read_starknet()
             .await
             .contract_storage_at_block(block_id.into(), contract_address, key)
             .map_err(|err| match err {
                 Error::NoBlock => ApiError::BlockNotFound,
-                Error::StateError(StateError::NoneStorage(_))
+                Error::ContractNotFound
+                | Error::StateError(StateError::NoneStorage(_))
                 | Error::NoStateAtBlock { block_number: _ } => ApiError::ContractNotFound,
                 unknown_error => ApiError::StarknetDevnetError(unknown_error),
             })?;
@@ -102,7 +125,7 @@ impl JsonRpcHandler {
         &self,
         transaction_hash: TransactionHash,
     ) -> StrictRpcResult {
-        match self.api.starknet.read().await.get_transaction_by_hash(transaction_hash) {
+        match self.api.read_starknet().await.get_transaction_by_hash(transaction_hash) {
             Ok(transaction) => Ok(StarknetResponse::TransactionByHash(transaction.clone())),
             Err(Error::NoTransaction) => Err(ApiError::TransactionNotFound),
             Err(err) => Err(err.into()),
@@ -114,17 +137,21 @@ impl JsonRpcHandler {
         &self,
         transaction_hash: TransactionHash,
     ) -> StrictRpcResult {
-        match self
-            .api
-            .starknet
-            .read()
-            .await
-            .get_transaction_execution_and_finality_status(transaction_hash)
-        {
-            Ok((execution_status, finality_status)) => {
+        let starknet = self.api.read_starknet().await;
+        let status =
+            starknet.get_transaction_execution_and_finality_status(transaction_hash).and_then(
+                |statuses| Ok((statuses, starknet.is_transaction_pending(transaction_hash)?)),
+            );
+
+        match status {
+            Ok(((execution_status, finality_status), is_pending)) => {
                 Ok(StarknetResponse::TransactionStatusByHash(TransactionStatusOutput {
                     execution_status,
-                    finality_status,
+                    finality_status: if is_pending {
+                        TransactionStatus::Received
+                    } else {
+                        finality_status.into()
+                    },
                 }))
             }
             Err(Error::NoTransaction) => Err(ApiError::TransactionNotFound),
@@ -140,8 +167,7 @@ impl JsonRpcHandler {
     ) -> StrictRpcResult {
         match self
             .api
-            .starknet
-            .read()
+            .read_starknet()
             .await
             .get_transaction_by_block_id_and_index(block_id.into(), index)
         {
@@ -161,7 +187,7 @@ impl JsonRpcHandler {
         &self,
         transaction_hash: TransactionHash,
     ) -> StrictRpcResult {
-        match self.api.starknet.read().await.get_transaction_receipt_by_hash(transaction_hash) {
+        match self.api.read_starknet().await.get_transaction_receipt_by_hash(transaction_hash) {
             Ok(receipt) => {
                 Ok(StarknetResponse::TransactionReceiptByTransactionHash(Box::new(receipt)))
             }
@@ -176,7 +202,7 @@ impl JsonRpcHandler {
         block_id: BlockId,
         class_hash: ClassHash,
     ) -> StrictRpcResult {
-        match self.api.starknet.read().await.get_class(block_id.into(), class_hash) {
+        match self.api.read_starknet().await.get_class(block_id.into(), class_hash) {
             Ok(contract_class) => Ok(StarknetResponse::ClassByHash(contract_class.try_into()?)),
             Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
             Err(Error::StateError(_) | Error::NoStateAtBlock { block_number: _ }) => {
@@ -192,7 +218,7 @@ impl JsonRpcHandler {
         block_id: BlockId,
         contract_address: ContractAddress,
     ) -> StrictRpcResult {
-        match self.api.starknet.read().await.get_class_at(block_id.into(), contract_address) {
+        match self.api.read_starknet().await.get_class_at(block_id.into(), contract_address) {
             Ok(contract_class) => {
                 Ok(StarknetResponse::ClassAtContractAddress(contract_class.try_into()?))
             }
@@ -206,13 +232,24 @@ impl JsonRpcHandler {
         }
     }
 
+    /// starknet_getCompiledCasm
+    pub(crate) async fn get_compiled_casm(&self, class_hash: ClassHash) -> StrictRpcResult {
+        match self.api.read_starknet().await.get_compiled_casm(class_hash) {
+            Ok(casm) => Ok(StarknetResponse::CompiledCasm(Box::new(casm))),
+            Err(Error::StateError(_) | Error::NoStateAtBlock { block_number: _ }) => {
+                Err(ApiError::ClassHashNotFound)
+            }
+            Err(unknown_error) => Err(ApiError::StarknetDevnetError(unknown_error)),
+        }
+    }
+
     /// starknet_getClassHashAt
     pub(crate) async fn get_class_hash_at(
         &self,
         block_id: BlockId,
         contract_address: ContractAddress,
     ) -> StrictRpcResult {
-        match self.api.starknet.read().await.get_class_hash_at(block_id.into(), contract_address) {
+        match self.api.read_starknet().await.get_class_hash_at(block_id.into(), contract_address) {
             Ok(class_hash) => Ok(StarknetResponse::ClassHashAtContractAddress(class_hash)),
             Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
             Err(Error::ContractNotFound | Error::NoStateAtBlock { block_number: _ }) => {
@@ -224,7 +261,7 @@ impl JsonRpcHandler {
 
     /// starknet_getBlockTransactionCount
     pub(crate) async fn get_block_txs_count(&self, block_id: BlockId) -> StrictRpcResult {
-        let num_trans_count = self.api.starknet.read().await.get_block_txs_count(block_id.into());
+        let num_trans_count = self.api.read_starknet().await.get_block_txs_count(block_id.into());
         match num_trans_count {
             Ok(count) => Ok(StarknetResponse::BlockTransactionCount(count)),
             Err(_) => Err(ApiError::NoBlocks),
@@ -233,14 +270,18 @@ impl JsonRpcHandler {
 
     /// starknet_call
     pub(crate) async fn call(&self, block_id: BlockId, request: FunctionCall) -> StrictRpcResult {
-        let starknet = self.api.starknet.read().await;
-
-        match starknet.call(
-            block_id.into(),
-            request.contract_address.into(),
-            request.entry_point_selector,
-            request.calldata,
-        ) {
+        let result = self
+            .execute_within_timeout(move |starknet| {
+                starknet.call(
+                    block_id.into(),
+                    request.contract_address.into(),
+                    request.entry_point_selector,
+                    request.calldata,
+                )
+            })
+            .await?;
+
+        match result {
             Ok(result) => Ok(StarknetResponse::Call(result)),
             Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
             Err(Error::ContractNotFound) => Err(ApiError::ContractNotFound),
@@ -254,8 +295,12 @@ impl JsonRpcHandler {
         block_id: BlockId,
         request: Vec<BroadcastedTransaction>,
     ) -> StrictRpcResult {
-        let starknet = self.api.starknet.read().await;
-        match starknet.estimate_fee(block_id.into(), &request) {
+        let result = self
+            .execute_within_timeout(move |starknet| {
+                starknet.estimate_fee(block_id.into(), &request)
+            })
+            .await?;
+        match result {
             Ok(result) => Ok(StarknetResponse::EsimateFee(result)),
             Err(Error::ContractNotFound) => Err(ApiError::ContractNotFound),
             Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
@@ -268,7 +313,12 @@ impl JsonRpcHandler {
         block_id: BlockId,
         message: MsgFromL1,
     ) -> StrictRpcResult {
-        match self.api.starknet.read().await.estimate_message_fee(block_id.into(), message) {
+        let result = self
+            .execute_within_timeout(move |starknet| {
+                starknet.estimate_message_fee(block_id.into(), message)
+            })
+            .await?;
+        match result {
             Ok(result) => Ok(StarknetResponse::EstimateMessageFee(result)),
             Err(Error::ContractNotFound) => Err(ApiError::ContractNotFound),
             Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
@@ -278,7 +328,7 @@ impl JsonRpcHandler {
 
     /// starknet_blockNumber
     pub(crate) async fn block_number(&self) -> StrictRpcResult {
-        let block = self.api.starknet.read().await.get_latest_block().map_err(|err| match err {
+        let block = self.api.read_starknet().await.get_latest_block().map_err(|err| match err {
             Error::NoBlock => ApiError::BlockNotFound,
             unknown_error => ApiError::StarknetDevnetError(unknown_error),
         })?;
@@ -288,7 +338,7 @@ impl JsonRpcHandler {
 
     /// starknet_blockHashAndNumber
     pub(crate) async fn block_hash_and_number(&self) -> StrictRpcResult {
-        let block = self.api.starknet.read().await.get_latest_block().map_err(|err| match err {
+        let block = self.api.read_starknet().await.get_latest_block().map_err(|err| match err {
             Error::NoBlock => ApiError::BlockNotFound,
             unknown_error => ApiError::StarknetDevnetError(unknown_error),
         })?;
@@ -301,7 +351,7 @@ impl JsonRpcHandler {
 
     /// starknet_chainId
     pub(crate) async fn chain_id(&self) -> StrictRpcResult {
-        let chain_id = self.api.starknet.read().await.chain_id();
+        let chain_id = self.api.read_starknet().await.chain_id();
 
         Ok(StarknetResponse::ChainId(chain_id.to_felt().to_prefixed_hex_str()))
     }
@@ -311,9 +361,29 @@ impl JsonRpcHandler {
         Ok(StarknetResponse::Syncing(SyncingOutput::False(false)))
     }
 
+    /// starknet_pendingTransactions
+    pub(crate) async fn pending_transactions(&self) -> StrictRpcResult {
+        let transactions = self
+            .api
+            .read_starknet()
+            .await
+            .get_pending_transactions()
+            .map_err(ApiError::StarknetDevnetError)?;
+
+        Ok(StarknetResponse::PendingTransactions(transactions))
+    }
+
     /// starknet_getEvents
     pub(crate) async fn get_events(&self, filter: EventFilter) -> StrictRpcResult {
-        let starknet = self.api.starknet.read().await;
+        if filter.chunk_size > MAX_EVENTS_CHUNK_SIZE {
+            return Err(ApiError::RequestPageSizeTooBig);
+        }
+        let keys_count: usize = filter.keys.iter().flatten().map(Vec::len).sum();
+        if keys_count > MAX_EVENT_FILTER_KEYS {
+            return Err(ApiError::TooManyKeysInFilter);
+        }
+
+        let starknet = self.api.read_starknet().await;
 
         let page = filter
             .continuation_token
@@ -344,8 +414,7 @@ impl JsonRpcHandler {
     ) -> StrictRpcResult {
         let nonce = self
             .api
-            .starknet
-            .read()
+            .read_starknet()
             .await
             .contract_nonce_at_block(block_id.into(), contract_address)
             .map_err(|err| match err {
@@ -366,12 +435,37 @@ impl JsonRpcHandler {
         transactions: Vec<BroadcastedTransaction>,
         simulation_flags: Vec<SimulationFlag>,
     ) -> StrictRpcResult {
-        let starknet = self.api.starknet.read().await;
-        match starknet.simulate_transactions(block_id.into(), &transactions, simulation_flags) {
+        let result = self
+            .execute_within_timeout(move |starknet| {
+                starknet.simulate_transactions(block_id.into(), &transactions, simulation_flags)
+            })
+            .await?;
+        match result {
             Ok(result) => Ok(StarknetResponse::SimulateTransactions(result)),
             Err(Error::ContractNotFound) => Err(ApiError::ContractNotFound),
             Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
             Err(err) => Err(ApiError::ContractError { error: err }),
         }
     }
+
+    /// starknet_traceTransaction
+    pub(crate) async fn trace_transaction(
+        &self,
+        transaction_hash: TransactionHash,
+    ) -> StrictRpcResult {
+        match self.api.read_starknet().await.get_transaction_trace(transaction_hash) {
+            Ok(trace) => Ok(StarknetResponse::TraceTransaction(trace)),
+            Err(Error::NoTransaction) => Err(ApiError::TransactionNotFound),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// starknet_traceBlockTransactions
+    pub(crate) async fn trace_block_transactions(&self, block_id: BlockId) -> StrictRpcResult {
+        match self.api.read_starknet().await.get_block_transaction_traces(block_id.into()) {
+            Ok(traces) => Ok(StarknetResponse::TraceBlockTransactions(traces)),
+            Err(Error::NoBlock) => Err(ApiError::BlockNotFound),
+            Err(err) => Err(err.into()),
+        }
+    }
 }
@@ -1,10 +1,12 @@
 use serde_json::json;
 use server::rpc_core::error::RpcError;
 use starknet_types;
+use starknet_types::felt::Felt;
 use thiserror::Error;
 use tracing::error;
 
 use super::{StarknetResponse, WILDCARD_RPC_ERROR_CODE};
+use crate::api::devnet_error_code::DevnetErrorCode;
 
 #[allow(unused)]
 #[derive(Error, Debug)]
@@ -39,18 +41,26 @@ pub enum ApiError {
     ClassAlreadyDeclared,
     #[error("Invalid contract class")]
     InvalidContractClass,
-    #[error("Only latest/pending block is supported")]
-    OnlyLatestBlock,
     #[error("{msg}")]
     UnsupportedAction { msg: String },
     #[error("Invalid transaction nonce")]
     InvalidTransactionNonce,
+    #[error("Invalid transaction nonce")]
+    NonceAheadOfAccount { account_nonce: Felt, transaction_nonce: Felt },
     #[error("Max fee is smaller than the minimal transaction cost (validation plus fee transfer)")]
     InsufficientMaxFee,
     #[error("Account balance is smaller than the transaction's max_fee")]
     InsufficientAccountBalance,
     #[error("Account validation failed")]
     ValidationFailure,
+    #[error("Account validation failed")]
+    ChainIdMismatch { chain_id: String, matching_chain_id: String },
+    #[error("Compiled class hash mismatch")]
+    CompiledClassHashMismatch { compiled_class_hash: Felt, expected_compiled_class_hash: Felt },
+    #[error("Execution did not finish within the timeout of {timeout_ms} ms")]
+    ExecutionTimeout { timeout_ms: u64 },
+    #[error("Method not supported: {method} is disabled")]
+    MethodNotSupported { method: String },
 }
 
 impl ApiError {
@@ -127,15 +137,10 @@ impl ApiError {
                 message: error_message.into(),
                 data: None,
             },
-            ApiError::OnlyLatestBlock => RpcError {
-                code: server::rpc_core::error::ErrorCode::ServerError(24),
-                message: error_message.into(),
-                data: None,
-            },
             ApiError::UnsupportedAction { msg } => RpcError {
                 code: server::rpc_core::error::ErrorCode::InvalidRequest,
                 message: msg.into(),
-                data: None,
+                data: Some(json!({ "devnet_code": DevnetErrorCode::UnsupportedAction })),
             },
             ApiError::InsufficientMaxFee => RpcError {
                 code: server::rpc_core::error::ErrorCode::ServerError(53),
@@ -147,6 +152,17 @@ impl ApiError {
                 message: error_message.into(),
                 data: None,
             },
+            ApiError::NonceAheadOfAccount { account_nonce, transaction_nonce } => RpcError {
+                code: server::rpc_core::error::ErrorCode::ServerError(52),
+                message: error_message.into(),
+                data: Some(json!({
+                    "account_nonce": account_nonce,
+                    "transaction_nonce": transaction_nonce,
+                    "reason": "The transaction nonce is ahead of the account nonce. Devnet executes \
+                               transactions on submission and does not queue them, so the \
+                               transactions with the skipped nonces have to be sent first."
+                })),
+            },
             ApiError::InsufficientAccountBalance => RpcError {
                 code: server::rpc_core::error::ErrorCode::ServerError(54),
                 message: error_message.into(),
@@ -157,22 +173,60 @@ impl ApiError {
                 message: error_message.into(),
                 data: None,
             },
+            ApiError::ChainIdMismatch { chain_id, matching_chain_id } => RpcError {
+                code: server::rpc_core::error::ErrorCode::ServerError(55),
+                message: error_message.into(),
+                data: Some(json!({
+                    "chain_id": chain_id,
+                    "matching_chain_id": matching_chain_id,
+                    "reason": format!(
+                        "The signature is valid for the transaction hash computed with chain \
+                         ID {matching_chain_id}, but Devnet computes the hash with chain ID \
+                         {chain_id}. Sign the transaction for chain ID {chain_id}, which is set \
+                         with --chain-id."
+                    )
+                })),
+            },
+            ApiError::CompiledClassHashMismatch {
+                compiled_class_hash,
+                expected_compiled_class_hash,
+            } => RpcError {
+                code: server::rpc_core::error::ErrorCode::ServerError(60),
+                message: error_message.into(),
+                data: Some(json!({
+                    "compiled_class_hash": compiled_class_hash,
+                    "expected_compiled_class_hash": expected_compiled_class_hash,
+                })),
+            },
+            ApiError::ExecutionTimeout { .. } => RpcError {
+                code: server::rpc_core::error::ErrorCode::ServerError(WILDCARD_RPC_ERROR_CODE),
+                message: error_message.into(),
+                data: Some(json!({ "devnet_code": DevnetErrorCode::ExecutionTimeout })),
+            },
+            ApiError::MethodNotSupported { .. } => RpcError {
+                code: server::rpc_core::error::ErrorCode::MethodNotFound,
+                message: error_message.into(),
+                data: Some(json!({ "devnet_code": DevnetErrorCode::EndpointDisabled })),
+            },
             ApiError::StarknetDevnetError(
                 starknet_core::error::Error::TransactionValidationError(validation_error),
             ) => {
                 let api_err = match validation_error {
                     starknet_core::error::TransactionValidationError::InsufficientMaxFee => ApiError::InsufficientMaxFee,
                     starknet_core::error::TransactionValidationError::InvalidTransactionNonce => ApiError::InvalidTransactionNonce,
+                    starknet_core::error::TransactionValidationError::NonceAheadOfAccount { account_nonce, transaction_nonce } => ApiError::NonceAheadOfAccount { account_nonce, transaction_nonce },
                     starknet_core::error::TransactionValidationError::InsufficientAccountBalance => ApiError::InsufficientAccountBalance,
                     starknet_core::error::TransactionValidationError::ValidationFailure => ApiError::ValidationFailure,
+                    starknet_core::error::TransactionValidationError::ChainIdMismatch { chain_id, matching_chain_id } => ApiError::ChainIdMismatch { chain_id, matching_chain_id },
+                    starknet_core::error::TransactionValidationError::CompiledClassHashMismatch { compiled_class_hash, expected_compiled_class_hash } => ApiError::CompiledClassHashMismatch { compiled_class_hash, expected_compiled_class_hash },
                 };
 
                 api_err.api_error_to_rpc_error()
             }
             ApiError::StarknetDevnetError(error) => RpcError {
                 code: server::rpc_core::error::ErrorCode::ServerError(WILDCARD_RPC_ERROR_CODE),
+                data: Some(json!({ "devnet_code": DevnetErrorCode::of_error(&error) })),
                 message: anyhow::format_err!(error).root_cause().to_string().into(),
-                data: None,
             },
         }
     }
@@ -182,6 +236,9 @@ pub(crate) type StrictRpcResult = Result<StarknetResponse, ApiError>;
 
 #[cfg(test)]
 mod tests {
+    use server::rpc_core::error::ErrorCode;
+    use starknet_types::felt::Felt;
+
     use super::StrictRpcResult;
     use crate::api::json_rpc::error::ApiError;
     use crate::api::json_rpc::ToRpcResponseResult;
@@ -251,6 +308,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn execution_timeout_error() {
+        error_expected_code_and_message(
+            ApiError::ExecutionTimeout { timeout_ms: 100 },
+            -1,
+            "Execution did not finish within the timeout of 100 ms",
+        );
+    }
+
+    #[test]
+    fn devnet_errors_carry_devnet_code() {
+        let error = ApiError::StarknetDevnetError(starknet_core::error::Error::UnsupportedAction {
+            msg: "msg".into(),
+        })
+        .api_error_to_rpc_error();
+        assert_eq!(error.code, ErrorCode::ServerError(-1));
+        assert_eq!(error.data.unwrap()["devnet_code"], 1003);
+
+        let error = ApiError::ExecutionTimeout { timeout_ms: 100 }.api_error_to_rpc_error();
+        assert_eq!(error.data.unwrap()["devnet_code"], 1009);
+    }
+
+    #[test]
+    fn method_not_supported_error() {
+        let error = ApiError::MethodNotSupported { method: "starknet_getEvents".into() }
+            .api_error_to_rpc_error();
+        assert_eq!(error.code, ErrorCode::MethodNotFound);
+        assert_eq!(error.message, "Method not supported: starknet_getEvents is disabled");
+        assert_eq!(error.data.unwrap()["devnet_code"], 1004);
+    }
+
     #[test]
     fn contract_error() {
         fn test_error() -> starknet_core::error::Error {
@@ -293,6 +381,62 @@ mod tests {
         );
     }
 
+    #[test]
+    fn nonce_ahead_of_account_error() {
+        let devnet_error =
+            ApiError::StarknetDevnetError(starknet_core::error::Error::TransactionValidationError(
+                starknet_core::error::TransactionValidationError::NonceAheadOfAccount {
+                    account_nonce: Felt::from(1),
+                    transaction_nonce: Felt::from(3),
+                },
+            ));
+
+        let error = devnet_error.api_error_to_rpc_error();
+        assert_eq!(error.code, ErrorCode::ServerError(52));
+        assert_eq!(error.message, "Invalid transaction nonce");
+        let data = error.data.unwrap();
+        assert_eq!(data["account_nonce"], "0x1");
+        assert_eq!(data["transaction_nonce"], "0x3");
+        assert!(data["reason"].as_str().unwrap().contains("does not queue"));
+    }
+
+    #[test]
+    fn chain_id_mismatch_error() {
+        let devnet_error =
+            ApiError::StarknetDevnetError(starknet_core::error::Error::TransactionValidationError(
+                starknet_core::error::TransactionValidationError::ChainIdMismatch {
+                    chain_id: "SN_GOERLI".to_string(),
+                    matching_chain_id: "SN_SEPOLIA".to_string(),
+                },
+            ));
+
+        let error = devnet_error.api_error_to_rpc_error();
+        assert_eq!(error.code, ErrorCode::ServerError(55));
+        assert_eq!(error.message, "Account validation failed");
+        let data = error.data.unwrap();
+        assert_eq!(data["chain_id"], "SN_GOERLI");
+        assert_eq!(data["matching_chain_id"], "SN_SEPOLIA");
+        assert!(data["reason"].as_str().unwrap().contains("--chain-id"));
+    }
+
+    #[test]
+    fn compiled_class_hash_mismatch_error() {
+        let devnet_error =
+            ApiError::StarknetDevnetError(starknet_core::error::Error::TransactionValidationError(
+                starknet_core::error::TransactionValidationError::CompiledClassHashMismatch {
+                    compiled_class_hash: Felt::from(1),
+                    expected_compiled_class_hash: Felt::from(2),
+                },
+            ));
+
+        let error = devnet_error.api_error_to_rpc_error();
+        assert_eq!(error.code, ErrorCode::ServerError(60));
+        assert_eq!(error.message, "Compiled class hash mismatch");
+        let data = error.data.unwrap();
+        assert_eq!(data["compiled_class_hash"], "0x1");
+        assert_eq!(data["expected_compiled_class_hash"], "0x2");
+    }
+
     #[test]
     fn insufficient_max_fee_error() {
         let devnet_error =
@@ -1,3 +1,4 @@
+mod devnet_endpoints;
 mod endpoints;
 pub mod error;
 mod models;
@@ -5,34 +6,65 @@ mod models;
 mod spec_reader;
 mod write_endpoints;
 
+use std::cell::Cell;
+use std::collections::hash_map::DefaultHasher;
+use std::future::Future;
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use hyper::HeaderMap;
 use models::{
-    BlockAndClassHashInput, BlockAndContractAddressInput, BlockAndIndexInput, CallInput,
-    EstimateFeeInput, EventsInput, GetStorageInput, TransactionHashInput,
+    AccountPortfolioInput, AccountTemplateSetting, ApplyStateDiffInput, BlockAndClassHashInput,
+    BlockAndContractAddressInput, BlockAndIndexInput, CallInput, ClassHashInput,
+    CompareClassesInput, CompileSierraInput, CompileSierraOutput, ContractStepLimitSetting,
+    CounterfactualAccountOutput, DisabledMethods, EstimateFeeInput, EventStatsInput, EventsInput,
+    FeltUtilsInput, FeltUtilsOutput, GetAbiInput, GetStorageAtTimeInput, GetStorageAtTimeOutput,
+    GetStorageByNameInput, GetStorageByNameOutput, GetStorageInput, JournalInput,
+    LenientBlockIdInput, MintInput, MintOutput, MulticallInput, PendingStateSnapshotInput,
+    RejectOnRevert, SetAccountPublicKeyInput, SetAllowanceInput, SimulateUpgradeInput,
+    TransactionBatchInput, TransactionBatchOutput, TransactionHashInput, TransferTokenInput,
+    ValidateTransactionInput,
 };
 use serde::{Deserialize, Serialize};
-use server::rpc_core::error::RpcError;
+use server::rpc_core::error::{ErrorCode, RpcError};
 use server::rpc_core::response::ResponseResult;
-use server::rpc_handler::RpcHandler;
+use server::rpc_handler::{RpcHandler, SingleCallResult};
+use server::streamed_json::StreamedJson;
+use starknet_core::starknet::account_portfolio::{AccountPortfolio, PendingStateSnapshot};
+use starknet_core::starknet::block_resources::BlockExecutionResources;
+use starknet_core::starknet::class_diff::ClassDiff;
+use starknet_core::starknet::counterfactual_accounts::CounterfactualAccount;
+use starknet_core::starknet::event_stats::EventStats;
+use starknet_core::starknet::integrity::IntegrityReport;
+use starknet_core::starknet::journal::JournalEntry;
+use starknet_core::starknet::proof_jobs::ProofStatus;
+use starknet_core::starknet::transaction_validation::TransactionValidation;
+use starknet_core::starknet::upgrade_simulation::UpgradeSimulation;
+use starknet_core::starknet::Starknet;
 use starknet_rs_core::types::ContractClass as CodegenContractClass;
 use starknet_types::felt::{ClassHash, Felt};
-use starknet_types::rpc::block::Block;
+use starknet_types::rpc::block::{Block, BlockWithReceipts};
 use starknet_types::rpc::estimate_message_fee::{
     EstimateMessageFeeRequestWrapper, FeeEstimateWrapper,
 };
 use starknet_types::rpc::state::StateUpdate;
 use starknet_types::rpc::transaction_receipt::TransactionReceipt;
-use starknet_types::rpc::transactions::{EventsChunk, SimulatedTransaction, Transaction};
+use starknet_types::rpc::transactions::{
+    BlockTransactionTrace, EventsChunk, SimulatedTransaction, Transaction, TransactionTrace,
+};
 use starknet_types::starknet_api::block::BlockNumber;
-use tracing::{error, info, trace};
+use tracing::{error, info, trace, warn};
 
-use self::error::StrictRpcResult;
+use self::error::{ApiError, StrictRpcResult};
 use self::models::{
     BlockHashAndNumberOutput, BlockIdInput, BroadcastedDeclareTransactionInput,
     BroadcastedDeployAccountTransactionInput, BroadcastedInvokeTransactionInput,
     DeclareTransactionOutput, DeployAccountTransactionOutput, InvokeTransactionOutput,
     SyncingOutput, TransactionStatusOutput,
 };
-use super::Api;
+use super::devnet_error_code::DevnetErrorCode;
+use super::{is_impersonation_allowed, scope_impersonation, Api, LOCK_WAIT};
 use crate::api::json_rpc::models::{
     BroadcastedDeclareTransactionEnumWrapper, BroadcastedDeployAccountTransactionEnumWrapper,
     BroadcastedInvokeTransactionEnumWrapper, SimulateTransactionsInput,
@@ -73,19 +105,198 @@ impl ToRpcResponseResult for StrictRpcResult {
 #[derive(Clone)]
 pub struct JsonRpcHandler {
     pub api: Api,
+    /// If set, methods taking at least this long are logged as slow
+    pub slow_query_threshold: Option<Duration>,
+    /// If set, bounds the execution of calls, fee estimations and simulations of the current
+    /// request, as specified by its [`EXECUTION_TIMEOUT_HEADER`]
+    pub execution_timeout: Option<Duration>,
+    /// If set, only requests with this key in their [`IMPERSONATION_API_KEY_HEADER`] may
+    /// impersonate accounts
+    pub impersonation_api_key: Option<String>,
+    /// Whether the current request may impersonate accounts, see [`is_impersonation_allowed`]
+    pub impersonation_allowed: bool,
+    /// Whether calls and their responses are recorded in [`Api::session_recording`]
+    pub record_session: bool,
 }
 
+/// Header of a JSON-RPC request with the timeout in milliseconds of its calls, fee estimations
+/// and simulations
+pub const EXECUTION_TIMEOUT_HEADER: &str = "x-devnet-timeout-ms";
+
 #[async_trait::async_trait]
 impl RpcHandler for JsonRpcHandler {
     type Request = StarknetRequest;
 
     async fn on_request(&self, request: Self::Request) -> ResponseResult {
         info!(target: "rpc", "received method in on_request {}", request);
-        self.execute(request).await
+        if let Err(err) = self.check_method_enabled(&request).await {
+            return err.api_error_to_rpc_error().into();
+        }
+        self.instrumented(request, |request| self.execute(request)).await
+    }
+
+    async fn on_single_request(&self, request: Self::Request) -> SingleCallResult {
+        info!(target: "rpc", "received method in on_single_request {}", request);
+        if let Err(err) = self.check_method_enabled(&request).await {
+            return SingleCallResult::Buffered(err.api_error_to_rpc_error().into());
+        }
+        self.instrumented(request, |request| self.execute_single(request)).await
+    }
+
+    fn with_headers(&self, headers: &HeaderMap) -> Result<Self, RpcError> {
+        let impersonation_allowed =
+            is_impersonation_allowed(self.impersonation_api_key.as_deref(), headers);
+        let Some(header_value) = headers.get(EXECUTION_TIMEOUT_HEADER) else {
+            return Ok(Self { impersonation_allowed, ..self.clone() });
+        };
+
+        let timeout_ms = header_value
+            .to_str()
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .filter(|timeout_ms| *timeout_ms > 0)
+            .ok_or_else(|| RpcError {
+                code: ErrorCode::InvalidRequest,
+                message: format!(
+                    "Invalid {EXECUTION_TIMEOUT_HEADER} header: expected a positive number of \
+                     milliseconds"
+                )
+                .into(),
+                data: Some(serde_json::json!({ "devnet_code": DevnetErrorCode::InvalidInput })),
+            })?;
+
+        Ok(Self {
+            execution_timeout: Some(Duration::from_millis(timeout_ms)),
+            impersonation_allowed,
+            ..self.clone()
+        })
+    }
+
+    fn records_exchanges(&self) -> bool {
+        self.record_session
+    }
+
+    fn on_exchange(&self, request: &[u8], response: &[u8]) {
+        self.api.session_recording.record(request, response);
     }
 }
 
+/// Identifies requests with the same params without logging the params themselves, which can be
+/// as large as a contract class
+fn params_digest(params: &str) -> String {
+    let mut hasher = DefaultHasher::new();
+    params.hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
 impl JsonRpcHandler {
+    /// Rejects `request` if its method is one of [`Api::disabled_methods`]
+    async fn check_method_enabled(&self, request: &StarknetRequest) -> Result<(), ApiError> {
+        let method = request.to_string();
+        if self.api.disabled_methods.read().await.contains(&method) {
+            warn!(target: "rpc", rpc_method = %method, "rejected disabled method");
+            return Err(ApiError::MethodNotSupported { method });
+        }
+
+        Ok(())
+    }
+
+    /// Runs `handle` with `request`, logging its duration and, if it is slow, the time spent
+    /// waiting for the Devnet state
+    async fn instrumented<T, Fut>(
+        &self,
+        request: StarknetRequest,
+        handle: impl FnOnce(StarknetRequest) -> Fut,
+    ) -> T
+    where
+        Fut: Future<Output = T>,
+    {
+        let rpc_method = request.to_string();
+        // the params are only needed for the digest of a slow method
+        let params_digest_source = self.slow_query_threshold.map(|_| format!("{request:?}"));
+        let started_at = Instant::now();
+
+        let (result, lock_wait) = LOCK_WAIT
+            .scope(Cell::new(Duration::ZERO), async {
+                let result = handle(request).await;
+                (result, LOCK_WAIT.with(Cell::get))
+            })
+            .await;
+        let duration = started_at.elapsed();
+        info!(
+            target: "rpc",
+            rpc_method = %rpc_method,
+            duration_ms = duration.as_millis() as u64,
+            "handled method"
+        );
+
+        if let (Some(threshold), Some(params)) = (self.slow_query_threshold, params_digest_source) {
+            if duration >= threshold {
+                warn!(
+                    target: "rpc",
+                    rpc_method = %rpc_method,
+                    params_digest = %params_digest(&params),
+                    lock_wait_ms = lock_wait.as_millis() as u64,
+                    execution_ms = duration.saturating_sub(lock_wait).as_millis() as u64,
+                    "slow method"
+                );
+            }
+        }
+
+        result
+    }
+
+    /// Runs `execute` on the state of Devnet, failing if it does not finish within
+    /// [`JsonRpcHandler::execution_timeout`] of acquiring the state, so waiting for the state is
+    /// not counted. With a timeout, `execute` runs in a blocking thread, which cannot be
+    /// interrupted: after the timeout it still runs to completion and holds the read lock until
+    /// then, so writes wait for it.
+    pub(crate) async fn execute_within_timeout<T, F>(&self, execute: F) -> Result<T, ApiError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&Starknet) -> T + Send + 'static,
+    {
+        let impersonation_allowed = self.impersonation_allowed;
+        let Some(timeout) = self.execution_timeout else {
+            let starknet = self.api.read_starknet().await;
+            return Ok(scope_impersonation(impersonation_allowed, || execute(&starknet)));
+        };
+
+        let starknet = self.api.read_starknet_owned().await;
+        let execution = tokio::task::spawn_blocking(move || {
+            scope_impersonation(impersonation_allowed, || execute(&starknet))
+        });
+        match tokio::time::timeout(timeout, execution).await {
+            Ok(Ok(result)) => Ok(result),
+            Ok(Err(join_error)) => Err(ApiError::StarknetDevnetError(
+                starknet_core::error::Error::UnexpectedInternalError {
+                    msg: format!("Execution failed: {join_error}"),
+                },
+            )),
+            Err(_) => Err(ApiError::ExecutionTimeout { timeout_ms: timeout.as_millis() as u64 }),
+        }
+    }
+
+    /// Same as [`Self::execute`], except that the results which can get large, blocks with their
+    /// transactions or receipts and chunks of events, are streamed
+    async fn execute_single(&self, request: StarknetRequest) -> SingleCallResult {
+        let result = match request {
+            StarknetRequest::BlockWithFullTransactions(block) => {
+                self.get_block_with_txs(block.block_id).await
+            }
+            StarknetRequest::BlockWithReceipts(block) => {
+                self.get_block_with_receipts(block.block_id).await
+            }
+            StarknetRequest::Events(EventsInput { filter }) => self.get_events(filter).await,
+            request => return SingleCallResult::Buffered(self.execute(request).await),
+        };
+
+        match result {
+            Ok(response) => SingleCallResult::Streamed(StreamedJson::new(response)),
+            Err(err) => SingleCallResult::Buffered(err.api_error_to_rpc_error().into()),
+        }
+    }
+
     /// The method matches the request to the corresponding enum variant and executes the request
     async fn execute(&self, request: StarknetRequest) -> ResponseResult {
         trace!(target: "JsonRpcHandler::execute", "executing starknet request");
@@ -98,6 +309,9 @@ impl JsonRpcHandler {
             StarknetRequest::BlockWithFullTransactions(block) => {
                 self.get_block_with_txs(block.block_id).await.to_rpc_result()
             }
+            StarknetRequest::BlockWithReceipts(block) => {
+                self.get_block_with_receipts(block.block_id).await.to_rpc_result()
+            }
             StarknetRequest::StateUpdate(block) => {
                 self.get_state_update(block.block_id).await.to_rpc_result()
             }
@@ -127,6 +341,9 @@ impl JsonRpcHandler {
                 block_id,
                 contract_address,
             }) => self.get_class_at(block_id, contract_address).await.to_rpc_result(),
+            StarknetRequest::CompiledCasm(ClassHashInput { class_hash }) => {
+                self.get_compiled_casm(class_hash).await.to_rpc_result()
+            }
             StarknetRequest::BlockTransactionCount(block) => {
                 self.get_block_txs_count(block.block_id).await.to_rpc_result()
             }
@@ -142,6 +359,9 @@ impl JsonRpcHandler {
             }
             StarknetRequest::ChainId => self.chain_id().await.to_rpc_result(),
             StarknetRequest::Syncing => self.syncing().await.to_rpc_result(),
+            StarknetRequest::PendingTransactions => {
+                self.pending_transactions().await.to_rpc_result()
+            }
             StarknetRequest::Events(EventsInput { filter }) => {
                 self.get_events(filter).await.to_rpc_result()
             }
@@ -183,6 +403,100 @@ impl JsonRpcHandler {
                 .simulate_transactions(block_id, transactions, simulation_flags)
                 .await
                 .to_rpc_result(),
+            StarknetRequest::TraceTransaction(TransactionHashInput { transaction_hash }) => {
+                self.trace_transaction(transaction_hash).await.to_rpc_result()
+            }
+            StarknetRequest::TraceBlockTransactions(BlockIdInput { block_id }) => {
+                self.trace_block_transactions(block_id).await.to_rpc_result()
+            }
+            StarknetRequest::CompareClasses(CompareClassesInput { class_hash_a, class_hash_b }) => {
+                self.compare_classes(class_hash_a, class_hash_b).await.to_rpc_result()
+            }
+            StarknetRequest::SimulateUpgrade(SimulateUpgradeInput {
+                contract_address,
+                class_hash,
+                transaction_hashes,
+            }) => self
+                .simulate_upgrade(contract_address, class_hash, transaction_hashes)
+                .await
+                .to_rpc_result(),
+            StarknetRequest::SetAllowance(SetAllowanceInput { token, owner, spender, amount }) => {
+                self.set_allowance(token, owner, spender, amount).await.to_rpc_result()
+            }
+            StarknetRequest::TransferToken(TransferTokenInput { token, from, to, amount }) => {
+                self.transfer_token(token, from, to, amount).await.to_rpc_result()
+            }
+            StarknetRequest::BlockExecutionResources(block) => {
+                self.get_block_execution_resources(block.block_id).await.to_rpc_result()
+            }
+            StarknetRequest::Multicall(MulticallInput { calls, block_id }) => {
+                self.multicall(block_id, calls).await.to_rpc_result()
+            }
+            StarknetRequest::AccountPortfolio(AccountPortfolioInput {
+                contract_address,
+                max_transactions,
+            }) => {
+                self.get_account_portfolio(contract_address, max_transactions).await.to_rpc_result()
+            }
+            StarknetRequest::FeltUtils(felt_utils_input) => {
+                self.felt_utils(felt_utils_input).to_rpc_result()
+            }
+            StarknetRequest::VerifyIntegrity => self.verify_integrity().await.to_rpc_result(),
+            StarknetRequest::SetAccountPublicKey(SetAccountPublicKeyInput {
+                address,
+                new_public_key,
+            }) => self.set_account_public_key(address, new_public_key).await.to_rpc_result(),
+            StarknetRequest::Abi(GetAbiInput { contract_address }) => {
+                self.get_abi(contract_address).await.to_rpc_result()
+            }
+            StarknetRequest::CompileSierra(CompileSierraInput { artifact }) => {
+                self.compile_sierra(artifact).to_rpc_result()
+            }
+            StarknetRequest::SetRejectOnRevert(RejectOnRevert { enabled }) => {
+                self.set_reject_on_revert(enabled).await.to_rpc_result()
+            }
+            StarknetRequest::TransactionsBySender(input) => {
+                self.get_transactions_by_sender(input).await.to_rpc_result()
+            }
+            StarknetRequest::PendingStateSnapshot(PendingStateSnapshotInput { address }) => {
+                self.get_pending_state_snapshot(address).await.to_rpc_result()
+            }
+            StarknetRequest::StorageByName(input) => {
+                self.get_storage_by_name(input).await.to_rpc_result()
+            }
+            StarknetRequest::EventStats(input) => self.get_event_stats(input).await.to_rpc_result(),
+            StarknetRequest::SetAccountTemplate(setting) => {
+                self.set_account_template(setting).await.to_rpc_result()
+            }
+            StarknetRequest::ValidateTransaction(ValidateTransactionInput {
+                transaction,
+                block_id,
+            }) => self.validate_transaction(block_id, transaction).await.to_rpc_result(),
+            StarknetRequest::RegisterCounterfactualAccount(account) => {
+                self.register_counterfactual_account(account).await.to_rpc_result()
+            }
+            StarknetRequest::ProofStatus(LenientBlockIdInput { block_id }) => {
+                self.get_proof_status(block_id).await.to_rpc_result()
+            }
+            StarknetRequest::SetContractStepLimit(setting) => {
+                self.set_contract_step_limit(setting).await.to_rpc_result()
+            }
+            StarknetRequest::ApplyStateDiff(ApplyStateDiffInput { state_diff }) => {
+                self.apply_state_diff(state_diff).await.to_rpc_result()
+            }
+            StarknetRequest::StorageAtTime(input) => {
+                self.get_storage_at_time(input).await.to_rpc_result()
+            }
+            StarknetRequest::Journal(input) => self.get_journal(input).await.to_rpc_result(),
+            StarknetRequest::SendTransactionBatch(TransactionBatchInput { transactions }) => {
+                self.send_transaction_batch(transactions).await.to_rpc_result()
+            }
+            StarknetRequest::Mint(MintInput { address, amount }) => {
+                self.mint(address, amount).await.to_rpc_result()
+            }
+            StarknetRequest::SetDisabledMethods(DisabledMethods { methods }) => {
+                self.set_disabled_methods(methods).await.to_rpc_result()
+            }
         }
     }
 }
@@ -196,6 +510,8 @@ pub enum StarknetRequest {
     BlockWithTransactionHashes(BlockIdInput),
     #[serde(rename = "starknet_getBlockWithTxs")]
     BlockWithFullTransactions(BlockIdInput),
+    #[serde(rename = "starknet_getBlockWithReceipts")]
+    BlockWithReceipts(BlockIdInput),
     #[serde(rename = "starknet_getStateUpdate")]
     StateUpdate(BlockIdInput),
     #[serde(rename = "starknet_getStorageAt")]
@@ -214,6 +530,8 @@ pub enum StarknetRequest {
     ClassHashAtContractAddress(BlockAndContractAddressInput),
     #[serde(rename = "starknet_getClassAt")]
     ClassAtContractAddress(BlockAndContractAddressInput),
+    #[serde(rename = "starknet_getCompiledCasm")]
+    CompiledCasm(ClassHashInput),
     #[serde(rename = "starknet_getBlockTransactionCount")]
     BlockTransactionCount(BlockIdInput),
     #[serde(rename = "starknet_call")]
@@ -228,6 +546,8 @@ pub enum StarknetRequest {
     ChainId,
     #[serde(rename = "starknet_syncing", with = "empty_params")]
     Syncing,
+    #[serde(rename = "starknet_pendingTransactions", with = "empty_params")]
+    PendingTransactions,
     #[serde(rename = "starknet_getEvents")]
     Events(EventsInput),
     #[serde(rename = "starknet_getNonce")]
@@ -242,6 +562,86 @@ pub enum StarknetRequest {
     EstimateMessageFee(EstimateMessageFeeRequestWrapper),
     #[serde(rename = "starknet_simulateTransactions")]
     SimulateTransactions(SimulateTransactionsInput),
+    #[serde(rename = "starknet_traceTransaction")]
+    TraceTransaction(TransactionHashInput),
+    #[serde(rename = "starknet_traceBlockTransactions")]
+    TraceBlockTransactions(BlockIdInput),
+    #[serde(rename = "devnet_compareClasses")]
+    CompareClasses(CompareClassesInput),
+    #[serde(rename = "devnet_simulateUpgrade")]
+    SimulateUpgrade(SimulateUpgradeInput),
+    #[serde(rename = "devnet_setAllowance")]
+    SetAllowance(SetAllowanceInput),
+    #[serde(rename = "devnet_transferToken")]
+    TransferToken(TransferTokenInput),
+    #[serde(rename = "devnet_getBlockExecutionResources")]
+    BlockExecutionResources(LenientBlockIdInput),
+    #[serde(rename = "devnet_multicall")]
+    Multicall(MulticallInput),
+    #[serde(rename = "devnet_getAccountPortfolio")]
+    AccountPortfolio(AccountPortfolioInput),
+    #[serde(rename = "devnet_feltUtils")]
+    FeltUtils(FeltUtilsInput),
+    #[serde(rename = "devnet_verifyIntegrity", with = "empty_params")]
+    VerifyIntegrity,
+    #[serde(rename = "devnet_setAccountPublicKey")]
+    SetAccountPublicKey(SetAccountPublicKeyInput),
+    #[serde(rename = "devnet_getAbi")]
+    Abi(GetAbiInput),
+    #[serde(rename = "devnet_compileSierra")]
+    CompileSierra(CompileSierraInput),
+    #[serde(rename = "devnet_setRejectOnRevert")]
+    SetRejectOnRevert(RejectOnRevert),
+    #[serde(rename = "devnet_getTransactionsBySender")]
+    TransactionsBySender(TransactionsBySenderInput),
+    #[serde(rename = "devnet_getPendingStateSnapshot")]
+    PendingStateSnapshot(PendingStateSnapshotInput),
+    #[serde(rename = "devnet_getStorageByName")]
+    StorageByName(GetStorageByNameInput),
+    #[serde(rename = "devnet_getEventStats")]
+    EventStats(EventStatsInput),
+    #[serde(rename = "devnet_setAccountTemplate")]
+    SetAccountTemplate(AccountTemplateSetting),
+    #[serde(rename = "devnet_validateTransaction")]
+    ValidateTransaction(ValidateTransactionInput),
+    #[serde(rename = "devnet_registerCounterfactualAccount")]
+    RegisterCounterfactualAccount(CounterfactualAccount),
+    #[serde(rename = "devnet_getProofStatus")]
+    ProofStatus(LenientBlockIdInput),
+    #[serde(rename = "devnet_setContractStepLimit")]
+    SetContractStepLimit(ContractStepLimitSetting),
+    #[serde(rename = "devnet_applyStateDiff")]
+    ApplyStateDiff(ApplyStateDiffInput),
+    #[serde(rename = "devnet_getStorageAtTime")]
+    StorageAtTime(GetStorageAtTimeInput),
+    #[serde(rename = "devnet_getJournal")]
+    Journal(JournalInput),
+    #[serde(rename = "devnet_sendTransactionBatch")]
+    SendTransactionBatch(TransactionBatchInput),
+    #[serde(rename = "devnet_mint")]
+    Mint(MintInput),
+    #[serde(rename = "devnet_setDisabledMethods")]
+    SetDisabledMethods(DisabledMethods),
+}
+
+/// Method which re-enables disabled methods, so it cannot be disabled itself
+const SET_DISABLED_METHODS: &str = "devnet_setDisabledMethods";
+
+/// Checks that `method` can be disabled, i.e. that it is one of the methods of [`StarknetRequest`]
+/// and not [`SET_DISABLED_METHODS`]
+pub(crate) fn check_method_can_be_disabled(method: &str) -> Result<(), String> {
+    if method == SET_DISABLED_METHODS {
+        return Err(format!("{method} cannot be disabled"));
+    }
+
+    // as in RpcHandler::handle, an unknown method is told apart by the deserialization error;
+    // known methods fail only due to the missing params, if they have any
+    match serde_json::from_value::<StarknetRequest>(serde_json::json!({ "method": method })) {
+        Err(err) if err.to_string().contains("unknown variant") => {
+            Err(format!("Unknown JSON-RPC method: {method}"))
+        }
+        _ => Ok(()),
+    }
 }
 
 impl std::fmt::Display for StarknetRequest {
@@ -252,6 +652,7 @@ impl std::fmt::Display for StarknetRequest {
                 write!(f, "starknet_getBlockWithTxHashes")
             }
             StarknetRequest::BlockWithFullTransactions(_) => write!(f, "starknet_getBlockWithTxs"),
+            StarknetRequest::BlockWithReceipts(_) => write!(f, "starknet_getBlockWithReceipts"),
             StarknetRequest::StateUpdate(_) => write!(f, "starknet_getStateUpdate"),
             StarknetRequest::StorageAt(_) => write!(f, "starknet_getStorageAt"),
             StarknetRequest::TransactionByHash(_) => write!(f, "starknet_getTransactionByHash"),
@@ -267,6 +668,7 @@ impl std::fmt::Display for StarknetRequest {
             StarknetRequest::ClassByHash(_) => write!(f, "starknet_getClass"),
             StarknetRequest::ClassHashAtContractAddress(_) => write!(f, "starknet_getClassHashAt"),
             StarknetRequest::ClassAtContractAddress(_) => write!(f, "starknet_getClassAt"),
+            StarknetRequest::CompiledCasm(_) => write!(f, "starknet_getCompiledCasm"),
             StarknetRequest::BlockTransactionCount(_) => {
                 write!(f, "starknet_getBlockTransactionCount")
             }
@@ -276,6 +678,7 @@ impl std::fmt::Display for StarknetRequest {
             StarknetRequest::BlockHashAndNumber => write!(f, "starknet_blockHashAndNumber"),
             StarknetRequest::ChainId => write!(f, "starknet_chainId"),
             StarknetRequest::Syncing => write!(f, "starknet_syncing"),
+            StarknetRequest::PendingTransactions => write!(f, "starknet_pendingTransactions"),
             StarknetRequest::Events(_) => write!(f, "starknet_getEvents"),
             StarknetRequest::ContractNonce(_) => write!(f, "starknet_getNonce"),
             StarknetRequest::AddDeclareTransaction(_) => {
@@ -287,6 +690,46 @@ impl std::fmt::Display for StarknetRequest {
             StarknetRequest::AddInvokeTransaction(_) => write!(f, "starknet_addInvokeTransaction"),
             StarknetRequest::EstimateMessageFee(_) => write!(f, "starknet_estimateMessageFee"),
             StarknetRequest::SimulateTransactions(_) => write!(f, "starknet_simulateTransactions"),
+            StarknetRequest::TraceTransaction(_) => write!(f, "starknet_traceTransaction"),
+            StarknetRequest::TraceBlockTransactions(_) => {
+                write!(f, "starknet_traceBlockTransactions")
+            }
+            StarknetRequest::CompareClasses(_) => write!(f, "devnet_compareClasses"),
+            StarknetRequest::SimulateUpgrade(_) => write!(f, "devnet_simulateUpgrade"),
+            StarknetRequest::SetAllowance(_) => write!(f, "devnet_setAllowance"),
+            StarknetRequest::TransferToken(_) => write!(f, "devnet_transferToken"),
+            StarknetRequest::BlockExecutionResources(_) => {
+                write!(f, "devnet_getBlockExecutionResources")
+            }
+            StarknetRequest::Multicall(_) => write!(f, "devnet_multicall"),
+            StarknetRequest::AccountPortfolio(_) => write!(f, "devnet_getAccountPortfolio"),
+            StarknetRequest::FeltUtils(_) => write!(f, "devnet_feltUtils"),
+            StarknetRequest::VerifyIntegrity => write!(f, "devnet_verifyIntegrity"),
+            StarknetRequest::SetAccountPublicKey(_) => write!(f, "devnet_setAccountPublicKey"),
+            StarknetRequest::Abi(_) => write!(f, "devnet_getAbi"),
+            StarknetRequest::CompileSierra(_) => write!(f, "devnet_compileSierra"),
+            StarknetRequest::SetRejectOnRevert(_) => write!(f, "devnet_setRejectOnRevert"),
+            StarknetRequest::TransactionsBySender(_) => {
+                write!(f, "devnet_getTransactionsBySender")
+            }
+            StarknetRequest::PendingStateSnapshot(_) => {
+                write!(f, "devnet_getPendingStateSnapshot")
+            }
+            StarknetRequest::StorageByName(_) => write!(f, "devnet_getStorageByName"),
+            StarknetRequest::EventStats(_) => write!(f, "devnet_getEventStats"),
+            StarknetRequest::SetAccountTemplate(_) => write!(f, "devnet_setAccountTemplate"),
+            StarknetRequest::ValidateTransaction(_) => write!(f, "devnet_validateTransaction"),
+            StarknetRequest::RegisterCounterfactualAccount(_) => {
+                write!(f, "devnet_registerCounterfactualAccount")
+            }
+            StarknetRequest::ProofStatus(_) => write!(f, "devnet_getProofStatus"),
+            StarknetRequest::SetContractStepLimit(_) => write!(f, "devnet_setContractStepLimit"),
+            StarknetRequest::ApplyStateDiff(_) => write!(f, "devnet_applyStateDiff"),
+            StarknetRequest::StorageAtTime(_) => write!(f, "devnet_getStorageAtTime"),
+            StarknetRequest::Journal(_) => write!(f, "devnet_getJournal"),
+            StarknetRequest::SendTransactionBatch(_) => write!(f, "devnet_sendTransactionBatch"),
+            StarknetRequest::Mint(_) => write!(f, "devnet_mint"),
+            StarknetRequest::SetDisabledMethods(_) => write!(f, "devnet_setDisabledMethods"),
         }
     }
 }
@@ -296,6 +739,7 @@ impl std::fmt::Display for StarknetRequest {
 pub(crate) enum StarknetResponse {
     BlockWithTransactionHashes(Block),
     BlockWithFullTransactions(Block),
+    BlockWithReceipts(BlockWithReceipts),
     StateUpdate(StateUpdate),
     StorageAt(Felt),
     TransactionByHash(Transaction),
@@ -305,6 +749,7 @@ pub(crate) enum StarknetResponse {
     ClassByHash(CodegenContractClass),
     ClassHashAtContractAddress(ClassHash),
     ClassAtContractAddress(CodegenContractClass),
+    CompiledCasm(Box<CasmContractClass>),
     BlockTransactionCount(u64),
     Call(Vec<Felt>),
     EsimateFee(Vec<FeeEstimateWrapper>),
@@ -312,6 +757,7 @@ pub(crate) enum StarknetResponse {
     BlockHashAndNumber(BlockHashAndNumberOutput),
     ChainId(String),
     Syncing(SyncingOutput),
+    PendingTransactions(Vec<Transaction>),
     Events(EventsChunk),
     ContractNonce(Felt),
     AddDeclareTransaction(DeclareTransactionOutput),
@@ -319,7 +765,33 @@ pub(crate) enum StarknetResponse {
     AddInvokeTransaction(InvokeTransactionOutput),
     EstimateMessageFee(FeeEstimateWrapper),
     SimulateTransactions(Vec<SimulatedTransaction>),
+    TraceTransaction(TransactionTrace),
+    TraceBlockTransactions(Vec<BlockTransactionTrace>),
     SpecVersion(String),
+    CompareClasses(ClassDiff),
+    SimulateUpgrade(UpgradeSimulation),
+    BlockExecutionResources(BlockExecutionResources),
+    Multicall(Vec<Vec<Felt>>),
+    AccountPortfolio(AccountPortfolio),
+    FeltUtils(FeltUtilsOutput),
+    VerifyIntegrity(IntegrityReport),
+    Abi(Vec<serde_json::Value>),
+    CompileSierra(Box<CompileSierraOutput>),
+    SetRejectOnRevert(RejectOnRevert),
+    TransactionsBySender(TransactionsBySenderOutput),
+    PendingStateSnapshot(PendingStateSnapshot),
+    StorageByName(GetStorageByNameOutput),
+    EventStats(EventStats),
+    SetAccountTemplate(AccountTemplateSetting),
+    ValidateTransaction(TransactionValidation),
+    RegisterCounterfactualAccount(CounterfactualAccountOutput),
+    ProofStatus(ProofStatus),
+    SetContractStepLimit(ContractStepLimitSetting),
+    StorageAtTime(GetStorageAtTimeOutput),
+    Journal(Vec<JournalEntry>),
+    SendTransactionBatch(TransactionBatchOutput),
+    Mint(MintOutput),
+    SetDisabledMethods(DisabledMethods),
 }
 
 #[cfg(test)]
@@ -328,7 +800,7 @@ mod requests_tests {
     use serde_json::json;
     use starknet_types::felt::Felt;
 
-    use super::StarknetRequest;
+    use super::{check_method_can_be_disabled, StarknetRequest};
 
     /// Panics if `text` does not contain `pattern`
     pub fn assert_contains(text: &str, pattern: &str) {
@@ -367,6 +839,19 @@ mod requests_tests {
         );
     }
 
+    #[test]
+    fn deserialize_get_block_with_receipts_request() {
+        let json_str =
+            r#"{"method":"starknet_getBlockWithReceipts","params":{"block_id":"latest"}}"#;
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(&json_str.replace("latest", "pending"));
+
+        assert_deserialization_fails(
+            &json_str.replace("latest", "0x134134"),
+            "Invalid block ID: unknown variant `0x134134`, expected `latest` or `pending`",
+        );
+    }
+
     #[test]
     fn deserialize_get_state_update_request() {
         let json_str = r#"{"method":"starknet_getStateUpdate","params":{"block_id":"latest"}}"#;
@@ -444,6 +929,28 @@ mod requests_tests {
         assert_deserialization_fails(json_str.replace("0x", "").as_str(), "Missing prefix 0x in");
     }
 
+    #[test]
+    fn deserialize_trace_transaction_request() {
+        let json_str =
+            r#"{"method":"starknet_traceTransaction","params":{"transaction_hash":"0xAAABB"}}"#;
+        assert_deserialization_succeeds(json_str);
+
+        assert_deserialization_fails(json_str.replace("0x", "").as_str(), "Missing prefix 0x in");
+    }
+
+    #[test]
+    fn deserialize_trace_block_transactions_request() {
+        let json_str =
+            r#"{"method":"starknet_traceBlockTransactions","params":{"block_id":"latest"}}"#;
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(&json_str.replace(r#""latest""#, r#"{"block_number":1}"#));
+
+        assert_deserialization_fails(
+            json_str.replace(r#"{"block_id":"latest"}"#, "{}").as_str(),
+            "missing field `block_id`",
+        );
+    }
+
     #[test]
     fn deserialize_get_class_request() {
         let json_str = r#"{"method":"starknet_getClass","params":{"block_id":"latest","class_hash":"0xAAABB"}}"#;
@@ -468,6 +975,18 @@ mod requests_tests {
         assert_deserialization_fails(json_str.replace("0x", "").as_str(), "Missing prefix 0x");
     }
 
+    #[test]
+    fn deserialize_get_compiled_casm_request() {
+        let json_str = r#"{"method":"starknet_getCompiledCasm","params":{"class_hash":"0xAAABB"}}"#;
+        assert_deserialization_succeeds(json_str);
+
+        assert_deserialization_fails(json_str.replace("0x", "").as_str(), "Missing prefix 0x");
+        assert_deserialization_fails(
+            json_str.replace(r#""class_hash""#, r#""block_id":"latest","class_hash""#).as_str(),
+            "unknown field `block_id`",
+        );
+    }
+
     #[test]
     fn deserialize_get_block_transaction_count_request() {
         let json_str =
@@ -758,6 +1277,46 @@ mod requests_tests {
         );
     }
 
+    #[test]
+    fn deserialize_transaction_of_version_3_request_fails_naming_the_property() {
+        let json_str = r#"{
+            "method":"starknet_addDeployAccountTransaction",
+            "params":{
+                "deploy_account_transaction":{
+                    "type":"DEPLOY_ACCOUNT",
+                    "max_fee": "0xA",
+                    "version": "0x1",
+                    "signature": ["0xFF", "0xAA"],
+                    "nonce": "0x0",
+                    "contract_address_salt": "0x01",
+                    "class_hash": "0x01",
+                    "constructor_calldata": ["0x01"]
+                }
+            }
+        }"#;
+
+        assert_deserialization_fails(
+            json_str.replace(r#""max_fee": "0xA","#, r#""resource_bounds": {},"#).as_str(),
+            "Unsupported property resource_bounds: transactions of version 0x3 are not supported",
+        );
+        assert_deserialization_fails(
+            json_str.replace(r#""version": "0x1""#, r#""version": "0x3""#).as_str(),
+            "Unsupported version 0x3: transactions of version 0x3 are not supported",
+        );
+        assert_deserialization_fails(
+            json_str
+                .replace(r#""max_fee": "0xA","#, r#""nonce_data_availability_mode": "L3","#)
+                .as_str(),
+            r#"Invalid nonce_data_availability_mode "L3": expected one of ["L1", "L2"]"#,
+        );
+        assert_deserialization_fails(
+            &create_declare_request(sample_declare_v2_body())
+                .to_string()
+                .replace(r#""version":"0x2""#, r#""version":"0x3""#),
+            "Unsupported version 0x3",
+        );
+    }
+
     #[test]
     fn deserialize_add_declare_transaction_v1_request() {
         assert_deserialization_succeeds(
@@ -836,6 +1395,566 @@ mod requests_tests {
         );
     }
 
+    #[test]
+    fn deserialize_compare_classes_request() {
+        let json_str = r#"{
+            "method":"devnet_compareClasses",
+            "params":{
+                "class_hash_a":"0x01",
+                "class_hash_b":"0x02"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""class_hash_b":"0x02""#, r#""class_hash_c":"0x02""#).as_str(),
+            "unknown field `class_hash_c`",
+        );
+    }
+
+    #[test]
+    fn deserialize_simulate_upgrade_request() {
+        let json_str = r#"{
+            "method":"devnet_simulateUpgrade",
+            "params":{
+                "contract_address":"0x01",
+                "class_hash":"0x02",
+                "transaction_hashes":["0x03", "0x04"]
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#","transaction_hashes":["0x03", "0x04"]"#, "").as_str(),
+            "missing field `transaction_hashes`",
+        );
+    }
+
+    #[test]
+    fn deserialize_set_allowance_request() {
+        let json_str = r#"{
+            "method":"devnet_setAllowance",
+            "params":{
+                "token":"0x01",
+                "owner":"0x02",
+                "spender":"0x03",
+                "amount":100
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""amount":100"#, r#""amount":"0x64""#).as_str(),
+            "invalid type: string",
+        );
+    }
+
+    #[test]
+    fn deserialize_transfer_token_request() {
+        let json_str = r#"{
+            "method":"devnet_transferToken",
+            "params":{
+                "token":"0x01",
+                "from":"0x02",
+                "to":"0x03",
+                "amount":100
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""to":"0x03""#, r#""recipient":"0x03""#).as_str(),
+            "unknown field `recipient`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_block_execution_resources_request() {
+        let json_str = r#"{
+            "method":"devnet_getBlockExecutionResources",
+            "params":{
+                "block_id":{"block_number":1}
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(json_str.replace("1", r#""1""#).as_str());
+        assert_deserialization_succeeds(json_str.replace("1", r#""0x1""#).as_str());
+        assert_deserialization_fails(
+            json_str.replace("1", r#""one""#).as_str(),
+            "Invalid block ID: invalid block number \"one\"",
+        );
+        assert_deserialization_fails(
+            json_str.replace(r#""block_id""#, r#""block""#).as_str(),
+            "unknown field `block`",
+        );
+    }
+
+    #[test]
+    fn deserialize_multicall_request() {
+        let json_str = r#"{
+            "method":"devnet_multicall",
+            "params":{
+                "calls":[
+                    {
+                        "contract_address":"0x01",
+                        "entry_point_selector":"0x02",
+                        "calldata":["0x03"]
+                    }
+                ],
+                "block_id":"latest"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(json_str.replace(r#""0x03""#, "").as_str());
+        assert_deserialization_succeeds(
+            json_str.replace(r#""latest""#, r#"{"block_number":"123"}"#).as_str(),
+        );
+        assert_deserialization_fails(
+            json_str.replace(r#""calls""#, r#""requests""#).as_str(),
+            "unknown field `requests`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_account_portfolio_request() {
+        let json_str = r#"{
+            "method":"devnet_getAccountPortfolio",
+            "params":{
+                "contract_address":"0x01",
+                "max_transactions":5
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(json_str.replace(r#","max_transactions":5"#, "").as_str());
+        assert_deserialization_fails(
+            json_str.replace(r#""contract_address""#, r#""address""#).as_str(),
+            "unknown field `address`",
+        );
+    }
+
+    #[test]
+    fn deserialize_felt_utils_request() {
+        let json_str = r#"{
+            "method":"devnet_feltUtils",
+            "params":{
+                "operation":"short_string_to_felt",
+                "short_string":"hello"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(
+            r#"{"method":"devnet_feltUtils","params":{"operation":"felts_to_u256","low":"0x1","high":"0x0"}}"#,
+        );
+        assert_deserialization_fails(
+            json_str.replace("short_string_to_felt", "string_to_felt").as_str(),
+            "unknown variant `string_to_felt`",
+        );
+        assert_deserialization_fails(
+            json_str.replace(r#""short_string""#, r#""string""#).as_str(),
+            "unknown field `string`",
+        );
+    }
+
+    #[test]
+    fn deserialize_verify_integrity_request() {
+        assert_deserialization_succeeds(r#"{"method":"devnet_verifyIntegrity"}"#);
+        assert_deserialization_succeeds(r#"{"method":"devnet_verifyIntegrity","params":[]}"#);
+        assert_deserialization_fails(
+            r#"{"method":"devnet_verifyIntegrity","params":["0x1"]}"#,
+            "expected params sequence with length 0",
+        );
+    }
+
+    #[test]
+    fn deserialize_set_account_public_key_request() {
+        let json_str = r#"{
+            "method":"devnet_setAccountPublicKey",
+            "params":{
+                "address":"0x01",
+                "new_public_key":"0x02"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""new_public_key""#, r#""public_key""#).as_str(),
+            "unknown field `public_key`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_abi_request() {
+        let json_str = r#"{
+            "method":"devnet_getAbi",
+            "params":{
+                "contract_address":"0x01"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""contract_address""#, r#""address""#).as_str(),
+            "unknown field `address`",
+        );
+    }
+
+    #[test]
+    fn deserialize_compile_sierra_request() {
+        let artifact =
+            std::fs::read_to_string("test_data/rpc/contract_cairo_v1/output.json").unwrap();
+        let json_str = format!(
+            r#"{{
+                "method":"devnet_compileSierra",
+                "params":{{
+                    "artifact":{artifact}
+                }}
+            }}"#
+        );
+
+        assert_deserialization_succeeds(&json_str);
+        assert_deserialization_fails(
+            &json_str.replace(r#""artifact""#, r#""contract_class""#),
+            "unknown field `contract_class`",
+        );
+    }
+
+    #[test]
+    fn deserialize_set_reject_on_revert_request() {
+        let json_str = r#"{
+            "method":"devnet_setRejectOnRevert",
+            "params":{
+                "enabled":true
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""enabled""#, r#""reject_on_revert""#).as_str(),
+            "unknown field `reject_on_revert`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_transactions_by_sender_request() {
+        let json_str = r#"{
+            "method":"devnet_getTransactionsBySender",
+            "params":{
+                "address":"0x01",
+                "from_block":{"block_number":"0x1"},
+                "to_block":"latest",
+                "chunk_size":10
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(
+            json_str
+                .replace(r#""from_block":{"block_number":"0x1"},"#, "")
+                .replace(r#""to_block":"latest","#, "")
+                .as_str(),
+        );
+        assert_deserialization_fails(
+            json_str.replace(r#""chunk_size":10"#, r#""limit":10"#).as_str(),
+            "unknown field `limit`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_pending_state_snapshot_request() {
+        let json_str = r#"{
+            "method":"devnet_getPendingStateSnapshot",
+            "params":{
+                "address":"0x01"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""address""#, r#""contract_address""#).as_str(),
+            "unknown field `contract_address`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_storage_by_name_request() {
+        let json_str = r#"{
+            "method":"devnet_getStorageByName",
+            "params":{
+                "contract_address":"0x01",
+                "variable_name":"ERC20_balances",
+                "keys":["0x02"],
+                "length":2,
+                "block_id":"latest"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        // keys, length and block_id are optional
+        assert_deserialization_succeeds(
+            json_str
+                .replace(r#""keys":["0x02"],"#, "")
+                .replace(r#""length":2,"#, "")
+                .replace(r#","block_id":"latest""#, "")
+                .as_str(),
+        );
+        assert_deserialization_fails(
+            json_str.replace(r#""variable_name""#, r#""name""#).as_str(),
+            "unknown field `name`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_event_stats_request() {
+        let json_str = r#"{
+            "method":"devnet_getEventStats",
+            "params":{
+                "from_block":{"block_number":1},
+                "to_block":"latest"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(r#"{"method":"devnet_getEventStats","params":{}}"#);
+        assert_deserialization_fails(
+            json_str.replace(r#""to_block""#, r#""address""#).as_str(),
+            "unknown field `address`",
+        );
+    }
+
+    #[test]
+    fn deserialize_set_account_template_request() {
+        let json_str = r#"{
+            "method":"devnet_setAccountTemplate",
+            "params":{
+                "address":"0x1",
+                "template":{"name":"counter_gated","remaining":2}
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(
+            r#"{"method":"devnet_setAccountTemplate","params":{"address":"0x1","template":{"name":"always_reject"}}}"#,
+        );
+        assert_deserialization_fails(
+            json_str.replace("counter_gated", "always_hang").as_str(),
+            "unknown variant `always_hang`",
+        );
+    }
+
+    #[test]
+    fn deserialize_validate_transaction_request() {
+        let json_str = r#"{
+            "method":"devnet_validateTransaction",
+            "params":{
+                "transaction":{
+                    "type":"INVOKE",
+                    "max_fee":"0xA",
+                    "version":"0x1",
+                    "signature":["0x2"],
+                    "nonce":"0x0",
+                    "sender_address":"0x1",
+                    "calldata":["0x1"]
+                },
+                "block_id":"pending"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""block_id""#, r#""block""#).as_str(),
+            "unknown field `block`",
+        );
+    }
+
+    #[test]
+    fn deserialize_register_counterfactual_account_request() {
+        let json_str = r#"{
+            "method":"devnet_registerCounterfactualAccount",
+            "params":{
+                "class_hash":"0x1",
+                "contract_address_salt":"0x2",
+                "constructor_calldata":["0x3"]
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""contract_address_salt""#, r#""salt""#).as_str(),
+            "unknown field `salt`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_proof_status_request() {
+        assert_deserialization_succeeds(
+            r#"{"method":"devnet_getProofStatus","params":{"block_id":{"block_number":"0x7b"}}}"#,
+        );
+        assert_deserialization_succeeds(
+            r#"{"method":"devnet_getProofStatus","params":{"block_id":"latest"}}"#,
+        );
+        assert_deserialization_fails(
+            r#"{"method":"devnet_getProofStatus","params":{"block":"latest"}}"#,
+            "unknown field `block`",
+        );
+    }
+
+    #[test]
+    fn deserialize_set_contract_step_limit_request() {
+        assert_deserialization_succeeds(
+            r#"{"method":"devnet_setContractStepLimit","params":{"address":"0x1","steps":1000}}"#,
+        );
+        assert_deserialization_succeeds(
+            r#"{"method":"devnet_setContractStepLimit","params":{"address":"0x1","steps":null}}"#,
+        );
+        assert_deserialization_fails(
+            r#"{"method":"devnet_setContractStepLimit","params":{"address":"0x1","limit":1000}}"#,
+            "unknown field `limit`",
+        );
+    }
+
+    #[test]
+    fn deserialize_apply_state_diff_request() {
+        let json_str = r#"{
+            "method":"devnet_applyStateDiff",
+            "params":{
+                "state_diff":{
+                    "deployed_contracts":[],
+                    "storage_diffs":[
+                        {"address":"0x1","storage_entries":[{"key":"0x2","value":"0x3"}]}
+                    ],
+                    "declared_classes":[],
+                    "deprecated_declared_classes":[],
+                    "nonces":[{"contract_address":"0x1","nonce":"0x4"}],
+                    "replaced_classes":[]
+                }
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""state_diff""#, r#""diff""#).as_str(),
+            "unknown field `diff`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_storage_at_time_request() {
+        let json_str = r#"{
+            "method":"devnet_getStorageAtTime",
+            "params":{"contract_address":"0x1","key":"0x2","timestamp":1700000000}
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""timestamp""#, r#""time""#).as_str(),
+            "unknown field `time`",
+        );
+    }
+
+    #[test]
+    fn deserialize_get_journal_request() {
+        let json_str = r#"{
+            "method":"devnet_getJournal",
+            "params":{
+                "from_block":{"block_number":1},
+                "to_block":"latest"
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_succeeds(r#"{"method":"devnet_getJournal","params":{}}"#);
+        assert_deserialization_fails(
+            json_str.replace(r#""to_block""#, r#""block_id""#).as_str(),
+            "unknown field `block_id`",
+        );
+    }
+
+    #[test]
+    fn deserialize_send_transaction_batch_request() {
+        let json_str = r#"{
+            "method":"devnet_sendTransactionBatch",
+            "params":{
+                "transactions":[{
+                    "type":"INVOKE",
+                    "max_fee":"0xA",
+                    "version":"0x1",
+                    "signature":["0x2"],
+                    "nonce":"0x0",
+                    "sender_address":"0x1",
+                    "calldata":["0x1"]
+                }]
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""transactions""#, r#""request""#).as_str(),
+            "unknown field `request`",
+        );
+    }
+
+    #[test]
+    fn deserialize_mint_request() {
+        let json_str = r#"{
+            "method":"devnet_mint",
+            "params":{
+                "address":"0x01",
+                "amount":100
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""amount":100"#, r#""amount":100,"unit":"WEI""#).as_str(),
+            "unknown field `unit`",
+        );
+    }
+
+    #[test]
+    fn deserialize_set_disabled_methods_request() {
+        let json_str = r#"{
+            "method":"devnet_setDisabledMethods",
+            "params":{
+                "methods":["starknet_getEvents"]
+            }
+        }"#;
+
+        assert_deserialization_succeeds(json_str);
+        assert_deserialization_fails(
+            json_str.replace(r#""methods""#, r#""disabled_methods""#).as_str(),
+            "unknown field `disabled_methods`",
+        );
+    }
+
+    #[test]
+    fn only_known_methods_can_be_disabled() {
+        assert_eq!(check_method_can_be_disabled("starknet_chainId"), Ok(()));
+        assert_eq!(check_method_can_be_disabled("starknet_getEvents"), Ok(()));
+        assert_eq!(check_method_can_be_disabled("devnet_mint"), Ok(()));
+        assert_eq!(
+            check_method_can_be_disabled("starknet_getEvent"),
+            Err("Unknown JSON-RPC method: starknet_getEvent".to_string())
+        );
+        assert_eq!(
+            check_method_can_be_disabled("devnet_setDisabledMethods"),
+            Err("devnet_setDisabledMethods cannot be disabled".to_string())
+        );
+    }
+
+    #[test]
+    fn deserialize_pending_transactions_request() {
+        assert_deserialization_succeeds(r#"{"method":"starknet_pendingTransactions"}"#);
+        assert_deserialization_succeeds(r#"{"method":"starknet_pendingTransactions","params":[]}"#);
+        assert_deserialization_fails(
+            r#"{"method":"starknet_pendingTransactions","params":["latest"]}"#,
+            "expected params sequence with length 0",
+        );
+    }
+
     fn assert_deserialization_succeeds(json_str: &str) {
         serde_json::from_str::<StarknetRequest>(json_str).unwrap();
     }
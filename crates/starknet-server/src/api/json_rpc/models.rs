@@ -1,23 +1,38 @@
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use cairo_lang_starknet::contract_class::ContractClass as SierraContractClass;
 use serde::{Deserialize, Serialize};
+use starknet_core::starknet::account_templates::AccountTemplate;
 use starknet_rs_core::types::{TransactionExecutionStatus, TransactionFinalityStatus};
 use starknet_types::contract_address::ContractAddress;
-use starknet_types::felt::{BlockHash, ClassHash, TransactionHash};
+use starknet_types::felt::{BlockHash, ClassHash, Felt, TransactionHash};
 use starknet_types::patricia_key::PatriciaKey;
 use starknet_types::rpc::block::{BlockId, SyncStatus};
+use starknet_types::rpc::state::ThinStateDiff;
 use starknet_types::rpc::transactions::broadcasted_deploy_account_transaction::BroadcastedDeployAccountTransaction;
 use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
 use starknet_types::rpc::transactions::{
     BroadcastedDeclareTransaction, BroadcastedTransaction, EventFilter, FunctionCall,
-    SimulationFlag,
+    SimulationFlag, Transaction,
 };
+use starknet_types::serde_helpers::rpc_sierra_contract_class_to_sierra_contract_class::deserialize_to_sierra_contract_class;
 use starknet_types::starknet_api::block::BlockNumber;
 
+use crate::api::serde_helpers::lenient_block_id;
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct BlockIdInput {
     pub(crate) block_id: BlockId,
 }
 
+/// Like [`BlockIdInput`], but also accepting block numbers as strings
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct LenientBlockIdInput {
+    #[serde(deserialize_with = "lenient_block_id::deserialize")]
+    pub(crate) block_id: BlockId,
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct TransactionHashInput {
@@ -67,6 +82,283 @@ pub struct EstimateFeeInput {
     pub block_id: BlockId,
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CompareClassesInput {
+    pub(crate) class_hash_a: ClassHash,
+    pub(crate) class_hash_b: ClassHash,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ClassHashInput {
+    pub(crate) class_hash: ClassHash,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SimulateUpgradeInput {
+    pub(crate) contract_address: ContractAddress,
+    pub(crate) class_hash: ClassHash,
+    pub(crate) transaction_hashes: Vec<TransactionHash>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SetAllowanceInput {
+    pub(crate) token: ContractAddress,
+    pub(crate) owner: ContractAddress,
+    pub(crate) spender: ContractAddress,
+    pub(crate) amount: u128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TransferTokenInput {
+    pub(crate) token: ContractAddress,
+    pub(crate) from: ContractAddress,
+    pub(crate) to: ContractAddress,
+    pub(crate) amount: u128,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct SetAccountPublicKeyInput {
+    pub(crate) address: ContractAddress,
+    pub(crate) new_public_key: Felt,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct MulticallInput {
+    pub(crate) calls: Vec<FunctionCall>,
+    #[serde(deserialize_with = "lenient_block_id::deserialize")]
+    pub(crate) block_id: BlockId,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct AccountPortfolioInput {
+    pub(crate) contract_address: ContractAddress,
+    /// Number of the most recent transactions to return; defaults to 10
+    pub(crate) max_transactions: Option<usize>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct GetAbiInput {
+    pub(crate) contract_address: ContractAddress,
+}
+
+/// Conversions of `devnet_feltUtils`, selected by `operation`
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(tag = "operation", rename_all = "snake_case", deny_unknown_fields)]
+pub enum FeltUtilsInput {
+    /// Encodes an ASCII string of at most 31 characters as a felt
+    ShortStringToFelt { short_string: String },
+    /// Decodes a felt into an ASCII string
+    FeltToShortString { felt: Felt },
+    /// Splits a u256, specified as a decimal or a 0x-prefixed hexadecimal string, into low and
+    /// high felts of 128 bits
+    U256ToFelts { u256: String },
+    /// Joins low and high felts of 128 bits into a u256, returned as a decimal string
+    FeltsToU256 { low: Felt, high: Felt },
+    /// Computes the entry point selector (starknet_keccak) of a function name
+    SelectorFromName { name: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum FeltUtilsOutput {
+    Felt(Felt),
+    U256Felts { low: Felt, high: Felt },
+    Text(String),
+}
+
+/// The artifact may be in the format of the Cairo compiler or in the RPC format, with the ABI
+/// stored as a string
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct CompileSierraInput {
+    #[serde(deserialize_with = "deserialize_to_sierra_contract_class")]
+    pub(crate) artifact: SierraContractClass,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CompileSierraOutput {
+    pub(crate) class_hash: ClassHash,
+    pub(crate) compiled_class_hash: Felt,
+    /// Version of the Cairo compiler used by Devnet
+    pub(crate) compiler_version: String,
+    pub(crate) casm: CasmContractClass,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct PendingStateSnapshotInput {
+    pub(crate) address: ContractAddress,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct GetStorageByNameInput {
+    pub(crate) contract_address: ContractAddress,
+    pub(crate) variable_name: String,
+    /// Keys of a mapping, in the order in which they are passed to `read`
+    #[serde(default)]
+    pub(crate) keys: Vec<Felt>,
+    /// Number of consecutive slots to read, e.g. 2 for a `u256`
+    #[serde(default = "default_storage_length")]
+    pub(crate) length: usize,
+    #[serde(default, deserialize_with = "lenient_block_id::deserialize_optional")]
+    pub(crate) block_id: Option<BlockId>,
+}
+
+fn default_storage_length() -> usize {
+    1
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct GetStorageByNameOutput {
+    pub(crate) storage_address: PatriciaKey,
+    pub(crate) values: Vec<Felt>,
+}
+
+/// `timestamp` is in seconds, like block timestamps
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct GetStorageAtTimeInput {
+    pub(crate) contract_address: ContractAddress,
+    pub(crate) key: PatriciaKey,
+    pub(crate) timestamp: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct GetStorageAtTimeOutput {
+    /// The last block with a timestamp not later than the requested one
+    pub(crate) block_hash: BlockHash,
+    pub(crate) block_number: BlockNumber,
+    pub(crate) value: Felt,
+}
+
+/// Both bounds are optional; the range is resolved as in `starknet_getEvents`
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct EventStatsInput {
+    #[serde(default, deserialize_with = "lenient_block_id::deserialize_optional")]
+    pub(crate) from_block: Option<BlockId>,
+    #[serde(default, deserialize_with = "lenient_block_id::deserialize_optional")]
+    pub(crate) to_block: Option<BlockId>,
+}
+
+/// Both bounds are optional; entries of the pending block are included if `to_block` is not
+/// specified
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct JournalInput {
+    #[serde(default, deserialize_with = "lenient_block_id::deserialize_optional")]
+    pub(crate) from_block: Option<BlockId>,
+    #[serde(default, deserialize_with = "lenient_block_id::deserialize_optional")]
+    pub(crate) to_block: Option<BlockId>,
+}
+
+/// Paginated like `starknet_getEvents`
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TransactionsBySenderInput {
+    pub(crate) address: ContractAddress,
+    #[serde(default, deserialize_with = "lenient_block_id::deserialize_optional")]
+    pub(crate) from_block: Option<BlockId>,
+    #[serde(default, deserialize_with = "lenient_block_id::deserialize_optional")]
+    pub(crate) to_block: Option<BlockId>,
+    pub(crate) continuation_token: Option<String>,
+    pub(crate) chunk_size: usize,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TransactionsBySenderOutput {
+    pub(crate) transactions: Vec<Transaction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) continuation_token: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct TransactionBatchInput {
+    pub(crate) transactions: Vec<BroadcastedTransaction>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct TransactionBatchOutput {
+    pub(crate) transaction_hashes: Vec<TransactionHash>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct MintInput {
+    pub(crate) address: ContractAddress,
+    pub(crate) amount: u128,
+}
+
+/// Same as the response of `POST /mint`
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct MintOutput {
+    /// decimal repr
+    pub(crate) new_balance: String,
+    pub(crate) unit: String,
+    pub(crate) tx_hash: TransactionHash,
+}
+
+/// Whether invoke transactions that would revert are rejected; used in the request and the response
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RejectOnRevert {
+    pub(crate) enabled: bool,
+}
+
+/// The JSON-RPC methods rejected as not supported; used in the request and the response
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct DisabledMethods {
+    pub(crate) methods: Vec<String>,
+}
+
+/// The validation template of a predeployed account; used in the request and the response
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct AccountTemplateSetting {
+    pub(crate) address: ContractAddress,
+    pub(crate) template: AccountTemplate,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ValidateTransactionInput {
+    pub(crate) transaction: BroadcastedTransaction,
+    #[serde(deserialize_with = "lenient_block_id::deserialize")]
+    pub(crate) block_id: BlockId,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+pub struct CounterfactualAccountOutput {
+    pub(crate) address: ContractAddress,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ApplyStateDiffInput {
+    pub(crate) state_diff: ThinStateDiff,
+}
+
+/// The step limit of calls into a contract, `null` if not limited; used in the request and the
+/// response
+#[derive(Serialize, Deserialize, Clone, Debug, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct ContractStepLimitSetting {
+    pub(crate) address: ContractAddress,
+    pub(crate) steps: Option<u64>,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(deny_unknown_fields)]
 pub struct BlockHashAndNumberOutput {
@@ -156,10 +448,29 @@ pub struct SimulateTransactionsInput {
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct TransactionStatusOutput {
-    pub finality_status: TransactionFinalityStatus,
+    pub finality_status: TransactionStatus,
     pub execution_status: TransactionExecutionStatus,
 }
 
+/// The finality status of a transaction, which is `RECEIVED` while the transaction is in the
+/// pending block
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TransactionStatus {
+    Received,
+    AcceptedOnL2,
+    AcceptedOnL1,
+}
+
+impl From<TransactionFinalityStatus> for TransactionStatus {
+    fn from(finality_status: TransactionFinalityStatus) -> Self {
+        match finality_status {
+            TransactionFinalityStatus::AcceptedOnL2 => Self::AcceptedOnL2,
+            TransactionFinalityStatus::AcceptedOnL1 => Self::AcceptedOnL1,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use starknet_types::contract_address::ContractAddress;
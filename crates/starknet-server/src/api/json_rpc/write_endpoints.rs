@@ -8,26 +8,24 @@ use super::models::{
 };
 use super::StarknetResponse;
 use crate::api::json_rpc::JsonRpcHandler;
+use crate::api::scope_impersonation;
 
 impl JsonRpcHandler {
     pub(crate) async fn add_declare_transaction(
         &self,
         request: BroadcastedDeclareTransaction,
     ) -> StrictRpcResult {
-        let (transaction_hash, class_hash) = match request {
-            BroadcastedDeclareTransaction::V1(broadcasted_declare_txn) => self
-                .api
-                .starknet
-                .write()
-                .await
-                .add_declare_transaction_v1(*broadcasted_declare_txn)?,
-            BroadcastedDeclareTransaction::V2(broadcasted_declare_txn) => self
-                .api
-                .starknet
-                .write()
-                .await
-                .add_declare_transaction_v2(*broadcasted_declare_txn)?,
-        };
+        let _in_flight = self.api.start_transaction().await;
+        let mut starknet = self.api.write_starknet().await;
+        let (transaction_hash, class_hash) =
+            scope_impersonation(self.impersonation_allowed, || match request {
+                BroadcastedDeclareTransaction::V1(broadcasted_declare_txn) => {
+                    starknet.add_declare_transaction_v1(*broadcasted_declare_txn)
+                }
+                BroadcastedDeclareTransaction::V2(broadcasted_declare_txn) => {
+                    starknet.add_declare_transaction_v2(*broadcasted_declare_txn)
+                }
+            })?;
 
         Ok(StarknetResponse::AddDeclareTransaction(DeclareTransactionOutput {
             transaction_hash,
@@ -39,8 +37,9 @@ impl JsonRpcHandler {
         &self,
         request: BroadcastedDeployAccountTransaction,
     ) -> StrictRpcResult {
+        let _in_flight = self.api.start_transaction().await;
         let (transaction_hash, contract_address) =
-            self.api.starknet.write().await.add_deploy_account_transaction(request).map_err(
+            self.api.write_starknet().await.add_deploy_account_transaction(request).map_err(
                 |err| match err {
                     starknet_core::error::Error::StateError(
                         starknet_core::error::StateError::NoneClassHash(_),
@@ -59,7 +58,21 @@ impl JsonRpcHandler {
         &self,
         request: BroadcastedInvokeTransaction,
     ) -> StrictRpcResult {
-        let transaction_hash = self.api.starknet.write().await.add_invoke_transaction(request)?;
+        let _in_flight = self.api.start_transaction().await;
+        let mut starknet = self.api.write_starknet().await;
+        let transaction_hash = scope_impersonation(self.impersonation_allowed, || {
+            starknet.add_invoke_transaction(request)
+        })
+        .map_err(|err| {
+            match err {
+                // reported if Devnet rejects transactions that would revert or exceed a step
+                // limit
+                starknet_core::error::Error::ExecutionError { .. } => {
+                    ApiError::ContractError { error: err }
+                }
+                other_error => ApiError::StarknetDevnetError(other_error),
+            }
+        })?;
 
         Ok(StarknetResponse::AddInvokeTransaction(InvokeTransactionOutput { transaction_hash }))
     }
@@ -0,0 +1,159 @@
+use std::fmt::Write;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Which kind of access to the lock of the Devnet state is measured
+#[derive(Clone, Copy, Debug)]
+pub(crate) enum LockKind {
+    Read,
+    Write,
+}
+
+impl LockKind {
+    fn label(&self) -> &'static str {
+        match self {
+            LockKind::Read => "read",
+            LockKind::Write => "write",
+        }
+    }
+}
+
+/// Cumulative wait-time metrics of the lock of the Devnet state, since the start of Devnet
+#[derive(Default, Debug)]
+pub(crate) struct LockMetrics {
+    read: LockKindMetrics,
+    write: LockKindMetrics,
+}
+
+#[derive(Default, Debug)]
+struct LockKindMetrics {
+    /// Number of tasks currently waiting for the lock
+    waiting: AtomicU64,
+    acquisitions: AtomicU64,
+    wait_micros_total: AtomicU64,
+    wait_micros_max: AtomicU64,
+}
+
+/// Keeps a task counted as waiting until dropped, also if the waiting future is cancelled
+pub(crate) struct WaitingGuard<'a>(&'a LockKindMetrics);
+
+impl Drop for WaitingGuard<'_> {
+    fn drop(&mut self) {
+        self.0.waiting.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+impl LockMetrics {
+    fn of(&self, kind: LockKind) -> &LockKindMetrics {
+        match kind {
+            LockKind::Read => &self.read,
+            LockKind::Write => &self.write,
+        }
+    }
+
+    pub(crate) fn start_waiting(&self, kind: LockKind) -> WaitingGuard<'_> {
+        let metrics = self.of(kind);
+        metrics.waiting.fetch_add(1, Ordering::Relaxed);
+        WaitingGuard(metrics)
+    }
+
+    pub(crate) fn record_acquisition(&self, kind: LockKind, wait: Duration) {
+        let metrics = self.of(kind);
+        let wait_micros = wait.as_micros().try_into().unwrap_or(u64::MAX);
+        metrics.acquisitions.fetch_add(1, Ordering::Relaxed);
+        metrics.wait_micros_total.fetch_add(wait_micros, Ordering::Relaxed);
+        metrics.wait_micros_max.fetch_max(wait_micros, Ordering::Relaxed);
+    }
+
+    /// Renders the metrics in the Prometheus text exposition format
+    pub(crate) fn to_prometheus_text(&self) -> String {
+        let mut text = String::new();
+        self.write_metric(
+            &mut text,
+            "devnet_state_lock_waiting",
+            "gauge",
+            "Number of tasks currently waiting for the state lock.",
+            |metrics| metrics.waiting.load(Ordering::Relaxed).to_string(),
+        );
+        self.write_metric(
+            &mut text,
+            "devnet_state_lock_acquisitions_total",
+            "counter",
+            "Number of acquisitions of the state lock.",
+            |metrics| metrics.acquisitions.load(Ordering::Relaxed).to_string(),
+        );
+        self.write_metric(
+            &mut text,
+            "devnet_state_lock_wait_seconds_total",
+            "counter",
+            "Total time spent waiting for the state lock.",
+            |metrics| micros_to_seconds(metrics.wait_micros_total.load(Ordering::Relaxed)),
+        );
+        self.write_metric(
+            &mut text,
+            "devnet_state_lock_wait_seconds_max",
+            "gauge",
+            "Longest time spent waiting for the state lock.",
+            |metrics| micros_to_seconds(metrics.wait_micros_max.load(Ordering::Relaxed)),
+        );
+
+        text
+    }
+
+    /// Writes the metric `name` with a sample per kind of lock access
+    fn write_metric(
+        &self,
+        text: &mut String,
+        name: &str,
+        metric_type: &str,
+        help: &str,
+        value: impl Fn(&LockKindMetrics) -> String,
+    ) {
+        // writing to a String does not fail
+        let _ = writeln!(text, "# HELP {name} {help}");
+        let _ = writeln!(text, "# TYPE {name} {metric_type}");
+        for kind in [LockKind::Read, LockKind::Write] {
+            let _ = writeln!(text, "{name}{{kind=\"{}\"}} {}", kind.label(), value(self.of(kind)));
+        }
+    }
+}
+
+fn micros_to_seconds(micros: u64) -> String {
+    Duration::from_micros(micros).as_secs_f64().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{LockKind, LockMetrics};
+
+    #[test]
+    fn acquisitions_are_summed_per_kind() {
+        let metrics = LockMetrics::default();
+        metrics.record_acquisition(LockKind::Read, Duration::from_millis(2));
+        metrics.record_acquisition(LockKind::Read, Duration::from_millis(1));
+        metrics.record_acquisition(LockKind::Write, Duration::from_millis(500));
+
+        let text = metrics.to_prometheus_text();
+        assert!(text.contains("devnet_state_lock_acquisitions_total{kind=\"read\"} 2\n"));
+        assert!(text.contains("devnet_state_lock_acquisitions_total{kind=\"write\"} 1\n"));
+        assert!(text.contains("devnet_state_lock_wait_seconds_total{kind=\"read\"} 0.003\n"));
+        assert!(text.contains("devnet_state_lock_wait_seconds_max{kind=\"read\"} 0.002\n"));
+        assert!(text.contains("devnet_state_lock_wait_seconds_max{kind=\"write\"} 0.5\n"));
+    }
+
+    #[test]
+    fn waiting_tasks_are_counted_until_guard_is_dropped() {
+        let metrics = LockMetrics::default();
+        let guard = metrics.start_waiting(LockKind::Write);
+        assert!(metrics
+            .to_prometheus_text()
+            .contains("devnet_state_lock_waiting{kind=\"write\"} 1\n"));
+
+        drop(guard);
+        assert!(metrics
+            .to_prometheus_text()
+            .contains("devnet_state_lock_waiting{kind=\"write\"} 0\n"));
+    }
+}
@@ -1,11 +1,57 @@
+pub(crate) mod devnet_error_code;
+pub(crate) mod feeder_gateway;
+pub(crate) mod gateway;
 pub(crate) mod http;
 pub(crate) mod json_rpc;
+pub(crate) mod lock_metrics;
 pub(crate) mod serde_helpers;
+pub(crate) mod session_recording;
 
+use std::cell::Cell;
+use std::collections::HashSet;
+use std::future::Future;
 use std::sync::Arc;
+use std::time::{Duration, Instant};
 
+use hyper::HeaderMap;
+use starknet_core::starknet::account_templates::without_account_templates;
 use starknet_core::starknet::Starknet;
-use tokio::sync::RwLock;
+use tokio::sync::{Notify, OwnedRwLockReadGuard, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+use self::lock_metrics::{LockKind, LockMetrics};
+use self::session_recording::SessionRecording;
+
+tokio::task_local! {
+    /// Time spent waiting for the lock of [`Starknet`] while handling the current request. Only
+    /// tracked within [`LOCK_WAIT.scope`](tokio::task::LocalKey::scope).
+    pub(crate) static LOCK_WAIT: Cell<Duration>;
+}
+
+/// Header of the requests which may impersonate accounts via account templates, if Devnet is
+/// started with `--impersonation-api-key`
+pub const IMPERSONATION_API_KEY_HEADER: &str = "x-devnet-api-key";
+
+/// Whether a request with `headers` may impersonate accounts, i.e. whether the account templates
+/// apply to its transactions. Without `api_key`, every request may.
+pub(crate) fn is_impersonation_allowed(api_key: Option<&str>, headers: &HeaderMap) -> bool {
+    api_key.map_or(true, |api_key| {
+        headers.get(IMPERSONATION_API_KEY_HEADER).and_then(|value| value.to_str().ok())
+            == Some(api_key)
+    })
+}
+
+/// Runs `execute` with the account templates applied only if `impersonation_allowed`. Has to
+/// wrap the synchronous execution itself, since it applies to the current thread.
+pub(crate) fn scope_impersonation<T>(
+    impersonation_allowed: bool,
+    execute: impl FnOnce() -> T,
+) -> T {
+    if impersonation_allowed {
+        execute()
+    } else {
+        without_account_templates(execute)
+    }
+}
 
 /// Data that can be shared between threads with read write lock access
 /// Whatever needs to be accessed as information outside of Starknet could be added to this struct
@@ -13,10 +59,130 @@ use tokio::sync::RwLock;
 pub struct Api {
     // maybe the config should be added here next to the starknet instance
     pub starknet: Arc<RwLock<Starknet>>,
+    /// Notified when a graceful shutdown of the server is requested
+    pub shutdown: Arc<Notify>,
+    /// Wait-time metrics of the lock of `starknet`, when acquired via [`Api::read_starknet`] or
+    /// [`Api::write_starknet`]
+    pub(crate) lock_metrics: Arc<LockMetrics>,
+    /// JSON-RPC calls recorded if Devnet is started with `--record-session`, kept across restarts
+    pub(crate) session_recording: Arc<SessionRecording>,
+    /// Shared by transaction submissions in flight and exclusively held while creating a block,
+    /// see [`Api::start_transaction`] and [`Api::block_barrier`]
+    transaction_barrier: Arc<RwLock<()>>,
+    /// JSON-RPC methods rejected as not supported, initially the ones of the config. Kept out of
+    /// `starknet`, so that checking a request does not wait for its lock.
+    pub(crate) disabled_methods: Arc<RwLock<HashSet<String>>>,
 }
 
 impl Api {
     pub fn new(starknet: Starknet) -> Self {
-        Self { starknet: Arc::new(RwLock::new(starknet)) }
+        let disabled_methods = starknet.config.disabled_methods.iter().cloned().collect();
+        Self {
+            starknet: Arc::new(RwLock::new(starknet)),
+            shutdown: Arc::new(Notify::new()),
+            lock_metrics: Arc::new(LockMetrics::default()),
+            session_recording: Arc::new(SessionRecording::default()),
+            transaction_barrier: Arc::new(RwLock::new(())),
+            disabled_methods: Arc::new(RwLock::new(disabled_methods)),
+        }
+    }
+
+    /// Acquires the read lock of [`Starknet`], recording the time spent waiting in
+    /// [`Api::lock_metrics`] and [`LOCK_WAIT`]
+    pub(crate) async fn read_starknet(&self) -> RwLockReadGuard<'_, Starknet> {
+        self.acquire_lock(LockKind::Read, self.starknet.read()).await
+    }
+
+    /// Same as [`Api::read_starknet`], but the guard can be moved to another thread
+    pub(crate) async fn read_starknet_owned(&self) -> OwnedRwLockReadGuard<Starknet> {
+        self.acquire_lock(LockKind::Read, self.starknet.clone().read_owned()).await
+    }
+
+    /// Acquires the write lock of [`Starknet`], recording the time spent waiting in
+    /// [`Api::lock_metrics`] and [`LOCK_WAIT`]
+    pub(crate) async fn write_starknet(&self) -> RwLockWriteGuard<'_, Starknet> {
+        self.acquire_lock(LockKind::Write, self.starknet.write()).await
+    }
+
+    /// Marks a transaction submission as in flight until the returned guard is dropped. Waits while
+    /// a block is being created after [`Api::block_barrier`].
+    pub(crate) async fn start_transaction(&self) -> RwLockReadGuard<'_, ()> {
+        self.transaction_barrier.read().await
+    }
+
+    /// Waits for the transaction submissions in flight to finish. Submissions started meanwhile,
+    /// or while the returned guard is held, wait until it is dropped, since the lock is fair.
+    pub(crate) async fn block_barrier(&self) -> RwLockWriteGuard<'_, ()> {
+        self.transaction_barrier.write().await
+    }
+
+    async fn acquire_lock<G>(&self, kind: LockKind, lock: impl Future<Output = G>) -> G {
+        let waiting = self.lock_metrics.start_waiting(kind);
+        let started_at = Instant::now();
+        let guard = lock.await;
+        drop(waiting);
+
+        let wait = started_at.elapsed();
+        self.lock_metrics.record_acquisition(kind, wait);
+        // outside of a scope, e.g. in HTTP handlers, the wait is not tracked per request
+        let _ = LOCK_WAIT.try_with(|lock_wait| lock_wait.set(lock_wait.get() + wait));
+        guard
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use starknet_core::starknet::starknet_config::StarknetConfig;
+    use starknet_core::starknet::Starknet;
+    use tokio::time::timeout;
+
+    use super::Api;
+
+    const WAIT: Duration = Duration::from_millis(50);
+
+    #[tokio::test]
+    async fn block_barrier_waits_for_transactions_in_flight() {
+        let api = Api::new(Starknet::new(&StarknetConfig::default()).unwrap());
+
+        let transaction = api.start_transaction().await;
+        assert!(timeout(WAIT, api.block_barrier()).await.is_err());
+
+        drop(transaction);
+        assert!(timeout(WAIT, api.block_barrier()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn transactions_started_after_block_barrier_wait_for_it() {
+        let api = Api::new(Starknet::new(&StarknetConfig::default()).unwrap());
+
+        let transaction = api.start_transaction().await;
+        let barrier_api = api.clone();
+        let barrier = tokio::spawn(async move {
+            let _barrier = barrier_api.block_barrier().await;
+            tokio::time::sleep(WAIT).await;
+        });
+        tokio::time::sleep(WAIT).await;
+
+        // the barrier is queued, so a later transaction does not overtake it
+        assert!(timeout(WAIT, api.start_transaction()).await.is_err());
+
+        drop(transaction);
+        barrier.await.unwrap();
+        assert!(timeout(WAIT, api.start_transaction()).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn disabled_methods_are_checked_while_starknet_is_locked() {
+        let config = StarknetConfig {
+            disabled_methods: vec!["starknet_chainId".to_string()],
+            ..StarknetConfig::default()
+        };
+        let api = Api::new(Starknet::new(&config).unwrap());
+
+        let _starknet = api.write_starknet().await;
+        let disabled_methods = timeout(WAIT, api.disabled_methods.read()).await.unwrap();
+        assert!(disabled_methods.contains("starknet_chainId"));
     }
 }
@@ -16,3 +16,45 @@ pub mod empty_params {
         Ok(())
     }
 }
+
+/// A module that deserializes a [`BlockId`](starknet_types::rpc::block::BlockId) also if its block
+/// number is a string, decimal (`{"block_number": "123"}`) or hexadecimal (`"0x7b"`), as
+/// serialized by several client libraries. Meant for `devnet_` methods; `starknet_` methods stick
+/// to the specification.
+pub mod lenient_block_id {
+    use serde::{Deserialize, Deserializer};
+    use starknet_types::rpc::block::BlockId;
+
+    pub fn deserialize<'de, D>(d: D) -> Result<BlockId, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let mut value = serde_json::Value::deserialize(d)?;
+        if let Some(block_number) = value.get_mut("block_number") {
+            if let Some(block_number_str) = block_number.as_str() {
+                let parsed = match block_number_str.strip_prefix("0x") {
+                    Some(hex) => u64::from_str_radix(hex, 16),
+                    None => block_number_str.parse::<u64>(),
+                }
+                .map_err(|e| {
+                    serde::de::Error::custom(format!(
+                        "Invalid block ID: invalid block number \"{block_number_str}\": {e}"
+                    ))
+                })?;
+                *block_number = parsed.into();
+            }
+        }
+
+        BlockId::deserialize(value).map_err(serde::de::Error::custom)
+    }
+
+    /// Like [`deserialize`], for optional block IDs; meant to be used with `#[serde(default)]`
+    pub fn deserialize_optional<'de, D>(d: D) -> Result<Option<BlockId>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        Option::<serde_json::Value>::deserialize(d)?
+            .map(|value| deserialize(value).map_err(serde::de::Error::custom))
+            .transpose()
+    }
+}
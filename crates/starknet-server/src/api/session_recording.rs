@@ -0,0 +1,138 @@
+use std::sync::Mutex;
+
+use serde::Serialize;
+use serde_json::Value;
+
+/// A JSON-RPC call and what Devnet responded to it
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub(crate) struct RecordedCall {
+    pub(crate) method: String,
+    pub(crate) params: Value,
+    /// The `result` or the `error` of the response, without its `jsonrpc` and `id`, so that a
+    /// mock can respond with the id of the call it replays
+    pub(crate) response: Value,
+}
+
+/// The recorded calls in a format that mocks of the JSON-RPC endpoint, e.g. of `fetch` in Jest
+/// tests of starknet.js, can replay
+#[derive(Debug, Serialize)]
+pub(crate) struct SessionFixture {
+    pub(crate) calls: Vec<RecordedCall>,
+}
+
+/// JSON-RPC calls handled since the start of Devnet, if started with `--record-session`
+#[derive(Default, Debug)]
+pub(crate) struct SessionRecording {
+    calls: Mutex<Vec<RecordedCall>>,
+}
+
+impl SessionRecording {
+    /// Records the calls of the request with `request_body`, each with its response in
+    /// `response_body`. Calls of a batch are matched with their responses by id; notifications
+    /// and requests which are not valid JSON are not recorded.
+    pub(crate) fn record(&self, request_body: &[u8], response_body: &[u8]) {
+        let (Ok(request), Ok(response)) = (
+            serde_json::from_slice::<Value>(request_body),
+            serde_json::from_slice::<Value>(response_body),
+        ) else {
+            return;
+        };
+
+        let calls = match request {
+            Value::Array(calls) => calls,
+            call => vec![call],
+        };
+        let responses = match response {
+            Value::Array(responses) => responses,
+            response => vec![response],
+        };
+
+        let mut recorded_calls = calls
+            .into_iter()
+            .filter_map(|call| {
+                let id = call.get("id")?;
+                let response = responses.iter().find(|response| response.get("id") == Some(id))?;
+                let response = match (response.get("result"), response.get("error")) {
+                    (Some(result), _) => serde_json::json!({ "result": result }),
+                    (None, Some(error)) => serde_json::json!({ "error": error }),
+                    (None, None) => return None,
+                };
+
+                Some(RecordedCall {
+                    method: call.get("method")?.as_str()?.to_string(),
+                    params: call.get("params").cloned().unwrap_or(Value::Null),
+                    response,
+                })
+            })
+            .collect();
+
+        self.calls.lock().unwrap().append(&mut recorded_calls);
+    }
+
+    pub(crate) fn to_fixture(&self) -> SessionFixture {
+        SessionFixture { calls: self.calls.lock().unwrap().clone() }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::SessionRecording;
+
+    fn record(request: serde_json::Value, response: serde_json::Value) -> serde_json::Value {
+        let recording = SessionRecording::default();
+        recording.record(request.to_string().as_bytes(), response.to_string().as_bytes());
+        serde_json::to_value(recording.to_fixture()).unwrap()
+    }
+
+    #[test]
+    fn single_call_is_recorded_without_id() {
+        let fixture = record(
+            json!({ "jsonrpc": "2.0", "id": 7, "method": "starknet_chainId", "params": [] }),
+            json!({ "jsonrpc": "2.0", "id": 7, "result": "0x1" }),
+        );
+
+        assert_eq!(
+            fixture,
+            json!({ "calls": [
+                { "method": "starknet_chainId", "params": [], "response": { "result": "0x1" } }
+            ]})
+        );
+    }
+
+    #[test]
+    fn calls_of_batch_are_matched_with_responses_by_id() {
+        let fixture = record(
+            json!([
+                { "jsonrpc": "2.0", "id": 1, "method": "starknet_chainId" },
+                { "jsonrpc": "2.0", "method": "starknet_blockNumber" },
+                { "jsonrpc": "2.0", "id": 2, "method": "starknet_getNonce", "params": {} }
+            ]),
+            json!([
+                { "jsonrpc": "2.0", "id": 2, "error": { "code": -32602, "message": "msg" } },
+                { "jsonrpc": "2.0", "id": 1, "result": "0x1" }
+            ]),
+        );
+
+        assert_eq!(
+            fixture["calls"],
+            json!([
+                { "method": "starknet_chainId", "params": null, "response": { "result": "0x1" } },
+                {
+                    "method": "starknet_getNonce",
+                    "params": {},
+                    "response": { "error": { "code": -32602, "message": "msg" } }
+                }
+            ])
+        );
+    }
+
+    #[test]
+    fn invalid_json_is_not_recorded() {
+        let recording = SessionRecording::default();
+        recording.record(b"{", br#"{"jsonrpc":"2.0","id":null,"error":{}}"#);
+
+        assert!(recording.to_fixture().calls.is_empty());
+    }
+}
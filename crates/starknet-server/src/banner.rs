@@ -0,0 +1,112 @@
+use serde::Serialize;
+use starknet_core::starknet::genesis::GenesisSummary;
+use starknet_types::traits::ToHexString;
+
+/// The banner printed with `--json`, on a single line
+#[derive(Serialize)]
+struct JsonBanner<'a> {
+    rpc_url: &'a str,
+    #[serde(flatten)]
+    genesis: &'a GenesisSummary,
+}
+
+pub(crate) fn format_json_banner(
+    genesis: &GenesisSummary,
+    rpc_url: &str,
+) -> Result<String, serde_json::Error> {
+    serde_json::to_string(&JsonBanner { rpc_url, genesis })
+}
+
+pub(crate) fn format_text_banner(genesis: &GenesisSummary, rpc_url: &str) -> String {
+    let mut banner = format!(
+        "RPC URL:  {rpc_url}\nChain ID: {}\nSeed:     {}\n",
+        genesis.chain_id, genesis.seed
+    );
+
+    banner.push_str("\nPredeployed contracts\n");
+    banner.push_str(&format_table(
+        &["Name", "Address", "Class hash"],
+        &genesis
+            .predeployed_contracts
+            .iter()
+            .map(|contract| {
+                vec![
+                    contract.name.clone(),
+                    contract.address.to_prefixed_hex_str(),
+                    contract.class_hash.to_prefixed_hex_str(),
+                ]
+            })
+            .collect::<Vec<_>>(),
+    ));
+
+    if !genesis.predeployed_accounts.is_empty() {
+        banner.push_str(&format!(
+            "\nPredeployed accounts using class with hash {}, with an initial balance of {} WEI \
+             each; use seed {} to replicate this account sequence\n",
+            genesis.account_class_hash.to_prefixed_hex_str(),
+            genesis.initial_balance,
+            genesis.seed
+        ));
+        banner.push_str(&format_table(
+            &["#", "Address", "Private key", "Public key"],
+            &genesis
+                .predeployed_accounts
+                .iter()
+                .enumerate()
+                .map(|(index, account)| {
+                    vec![
+                        index.to_string(),
+                        account.address.to_prefixed_hex_str(),
+                        account.private_key.to_prefixed_hex_str(),
+                        account.public_key.to_prefixed_hex_str(),
+                    ]
+                })
+                .collect::<Vec<_>>(),
+        ));
+    }
+
+    banner
+}
+
+/// Formats the rows as a table with a header row, padding each column to its widest cell
+fn format_table(header: &[&str], rows: &[Vec<String>]) -> String {
+    let mut widths: Vec<usize> = header.iter().map(|title| title.len()).collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.len());
+        }
+    }
+
+    let format_row = |cells: Vec<&str>| {
+        let padded_cells: Vec<String> =
+            cells.iter().zip(&widths).map(|(cell, width)| format!("{cell:<width$}")).collect();
+        format!("| {} |\n", padded_cells.join(" | "))
+    };
+
+    let mut table = format_row(header.to_vec());
+    let separators: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    table.push_str(&format_row(separators.iter().map(String::as_str).collect()));
+    for row in rows {
+        table.push_str(&format_row(row.iter().map(String::as_str).collect()));
+    }
+
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::format_table;
+
+    #[test]
+    fn columns_are_padded_to_widest_cell() {
+        let table = format_table(
+            &["#", "Address"],
+            &[vec!["0".to_string(), "0x1".to_string()], vec!["10".to_string(), "0x2".to_string()]],
+        );
+
+        assert_eq!(
+            table,
+            "| #  | Address |\n| -- | ------- |\n| 0  | 0x1     |\n| 10 | 0x2     |\n"
+        );
+    }
+}
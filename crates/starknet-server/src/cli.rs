@@ -1,11 +1,21 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use starknet_core::constants::{
-    DEVNET_DEFAULT_GAS_PRICE, DEVNET_DEFAULT_PORT, DEVNET_DEFAULT_TIMEOUT,
-    DEVNET_DEFAULT_TOTAL_ACCOUNTS,
+    DEVNET_DEFAULT_GAS_PRICE, DEVNET_DEFAULT_MAX_REQUEST_BODY_SIZE, DEVNET_DEFAULT_PORT,
+    DEVNET_DEFAULT_PROOF_PROVING_TIME, DEVNET_DEFAULT_PROOF_VERIFICATION_TIME,
+    DEVNET_DEFAULT_TIMEOUT, DEVNET_DEFAULT_TOTAL_ACCOUNTS, ERC20_CONTRACT_ADDRESS,
+    UDC_CONTRACT_ADDRESS,
+};
+use starknet_core::starknet::base_dump::BaseDump;
+use starknet_core::starknet::custom_genesis::CustomGenesis;
+use starknet_core::starknet::starknet_config::{
+    BlockGeneration, DumpFormat, DumpOn, EventRetention, StarknetConfig,
 };
-use starknet_core::starknet::starknet_config::{DumpOn, StarknetConfig};
 use starknet_types::chain_id::ChainId;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::Felt;
 
+use crate::api::json_rpc::check_method_can_be_disabled;
+use crate::compat_check::CompatTool;
 use crate::contract_class_choice::{AccountClassWrapper, AccountContractClassChoice};
 use crate::initial_balance_wrapper::InitialBalanceWrapper;
 use crate::ip_addr_wrapper::IpAddrWrapper;
@@ -17,6 +27,7 @@ use crate::ip_addr_wrapper::IpAddrWrapper;
 pub(crate) struct Args {
     /// Number of accounts
     #[arg(long = "accounts")]
+    #[arg(env = "STARKNET_DEVNET_ACCOUNTS")]
     #[arg(value_name = "ACCOUNTS")]
     #[arg(default_value_t = DEVNET_DEFAULT_TOTAL_ACCOUNTS)]
     #[arg(help = "Specify the number of accounts to be predeployed;")]
@@ -24,12 +35,14 @@ pub(crate) struct Args {
 
     /// Class used for account predeployment
     #[arg(long = "account-class")]
+    #[arg(env = "STARKNET_DEVNET_ACCOUNT_CLASS")]
     #[arg(value_name = "ACCOUNT_CLASS")]
     #[arg(default_value = "cairo0")]
     #[arg(help = "Specify the class used by predeployed accounts;")]
     account_class_choice: AccountContractClassChoice,
 
     #[arg(long = "account-class-custom")]
+    #[arg(env = "STARKNET_DEVNET_ACCOUNT_CLASS_CUSTOM")]
     #[arg(value_name = "PATH")]
     #[arg(conflicts_with = "account_class_choice")]
     #[arg(help = "Specify the path to a Cairo Sierra artifact to be used by predeployed accounts;")]
@@ -37,6 +50,7 @@ pub(crate) struct Args {
 
     /// Initial balance of predeployed accounts
     #[arg(long = "initial-balance")]
+    #[arg(env = "STARKNET_DEVNET_INITIAL_BALANCE")]
     #[arg(short = 'e')]
     #[arg(value_name = "DECIMAL_VALUE")]
     #[arg(default_value_t = InitialBalanceWrapper::default())]
@@ -45,6 +59,7 @@ pub(crate) struct Args {
 
     // Seed for predeployed accounts
     #[arg(long = "seed")]
+    #[arg(env = "STARKNET_DEVNET_SEED")]
     #[arg(value_name = "SEED")]
     #[arg(help = "Specify the seed for randomness of accounts to be predeployed; if not \
                   provided, it is randomly generated")]
@@ -52,6 +67,7 @@ pub(crate) struct Args {
 
     // Host address
     #[arg(long = "host")]
+    #[arg(env = "STARKNET_DEVNET_HOST")]
     #[arg(value_name = "HOST")]
     #[arg(default_value_t = IpAddrWrapper::LOCALHOST)]
     #[arg(help = "Specify the address to listen at;")]
@@ -59,6 +75,7 @@ pub(crate) struct Args {
 
     // Port number
     #[arg(long = "port")]
+    #[arg(env = "STARKNET_DEVNET_PORT")]
     #[arg(value_name = "PORT")]
     #[arg(default_value_t = DEVNET_DEFAULT_PORT)]
     #[arg(help = "Specify the port to listen at;")]
@@ -66,31 +83,143 @@ pub(crate) struct Args {
 
     // Set start time in seconds
     #[arg(long = "start-time")]
+    #[arg(env = "STARKNET_DEVNET_START_TIME")]
     #[arg(value_name = "START_TIME_IN_SECONDS")]
     #[arg(help = "Specify start time in seconds;")]
     start_time: Option<u64>,
 
     // Server timeout in seconds
     #[arg(long = "timeout")]
+    #[arg(env = "STARKNET_DEVNET_TIMEOUT")]
     #[arg(value_name = "TIMEOUT")]
     #[arg(default_value_t = DEVNET_DEFAULT_TIMEOUT)]
     #[arg(help = "Specify the server timeout in seconds;")]
     timeout: u16,
 
+    #[arg(long = "max-request-body-size")]
+    #[arg(env = "STARKNET_DEVNET_MAX_REQUEST_BODY_SIZE")]
+    #[arg(value_name = "BYTES")]
+    #[arg(default_value_t = DEVNET_DEFAULT_MAX_REQUEST_BODY_SIZE)]
+    #[arg(help = "Specify the maximum size of a request body in bytes; larger requests are \
+                  rejected with status 413;")]
+    max_request_body_size: usize,
+
+    #[arg(long = "compression-min-size")]
+    #[arg(env = "STARKNET_DEVNET_COMPRESSION_MIN_SIZE")]
+    #[arg(value_name = "BYTES")]
+    #[arg(help = "Compress responses larger than the specified number of bytes if the client \
+                  accepts gzip or deflate encoding; if not specified, responses are not \
+                  compressed;")]
+    compression_min_size: Option<u16>,
+
+    #[arg(long = "record-session")]
+    #[arg(env = "STARKNET_DEVNET_RECORD_SESSION")]
+    #[arg(help = "Record JSON-RPC calls and their responses, to be exported via GET \
+                  /export/session_fixture as a fixture for offline tests;")]
+    record_session: bool,
+
+    #[arg(long = "disabled-methods")]
+    #[arg(env = "STARKNET_DEVNET_DISABLED_METHODS")]
+    #[arg(value_name = "METHOD")]
+    #[arg(value_delimiter = ',')]
+    #[arg(value_parser = parse_disabled_method)]
+    #[arg(help = "Reject the specified JSON-RPC methods, separated by commas, as not supported, \
+                  to emulate nodes implementing only a part of the API;")]
+    disabled_methods: Vec<String>,
+
     // Gas price in wei
     #[arg(long = "gas-price")]
+    #[arg(env = "STARKNET_DEVNET_GAS_PRICE")]
     #[arg(value_name = "GAS_PRICE")]
     #[arg(default_value_t = DEVNET_DEFAULT_GAS_PRICE)]
     #[arg(help = "Specify the gas price in wei per gas unit;")]
     gas_price: u64,
 
     #[arg(long = "chain-id")]
+    #[arg(env = "STARKNET_DEVNET_CHAIN_ID")]
     #[arg(value_name = "CHAIN_ID")]
     #[arg(default_value = "TESTNET")]
     #[arg(help = "Specify the chain ID;")]
     chain_id: ChainId,
 
+    #[arg(long = "fee-token-address")]
+    #[arg(env = "STARKNET_DEVNET_FEE_TOKEN_ADDRESS")]
+    #[arg(value_name = "ADDRESS")]
+    #[arg(default_value = ERC20_CONTRACT_ADDRESS)]
+    #[arg(value_parser = parse_contract_address)]
+    #[arg(help = "Specify the address at which the fee token is predeployed;")]
+    fee_token_address: ContractAddress,
+
+    #[arg(long = "udc-address")]
+    #[arg(env = "STARKNET_DEVNET_UDC_ADDRESS")]
+    #[arg(value_name = "ADDRESS")]
+    #[arg(default_value = UDC_CONTRACT_ADDRESS)]
+    #[arg(value_parser = parse_contract_address)]
+    #[arg(help = "Specify the address at which the Universal Deployer Contract is predeployed;")]
+    udc_address: ContractAddress,
+
+    #[arg(long = "genesis-file")]
+    #[arg(env = "STARKNET_DEVNET_GENESIS_FILE")]
+    #[arg(value_name = "PATH")]
+    #[arg(value_parser = parse_genesis_file)]
+    #[arg(help = "Specify the path to a JSON file of classes and contracts to be predeployed \
+                  instead of the fee token, the UDC and any account;")]
+    custom_genesis: Option<CustomGenesis>,
+
+    #[arg(long = "base-dump")]
+    #[arg(env = "STARKNET_DEVNET_BASE_DUMP")]
+    #[arg(value_name = "PATH")]
+    #[arg(conflicts_with = "custom_genesis")]
+    #[arg(requires = "base_dump_seed")]
+    #[arg(help = "Specify the path to a dump whose head state is used as the genesis state, on \
+                  top of which the accounts of this instance are predeployed;")]
+    base_dump: Option<String>,
+
+    #[arg(long = "base-dump-seed")]
+    #[arg(env = "STARKNET_DEVNET_BASE_DUMP_SEED")]
+    #[arg(value_name = "SEED")]
+    #[arg(requires = "base_dump")]
+    #[arg(help = "Specify the seed of the instance that produced the base dump, which has to \
+                  differ from --seed;")]
+    base_dump_seed: Option<u32>,
+
+    #[arg(long = "base-dump-accounts")]
+    #[arg(env = "STARKNET_DEVNET_BASE_DUMP_ACCOUNTS")]
+    #[arg(value_name = "ACCOUNTS")]
+    #[arg(default_value_t = DEVNET_DEFAULT_TOTAL_ACCOUNTS)]
+    #[arg(requires = "base_dump")]
+    #[arg(help = "Specify the number of accounts predeployed by the instance that produced the \
+                  base dump;")]
+    base_dump_accounts_count: u8,
+
+    #[arg(long = "base-dump-account-class")]
+    #[arg(env = "STARKNET_DEVNET_BASE_DUMP_ACCOUNT_CLASS")]
+    #[arg(value_name = "ACCOUNT_CLASS")]
+    #[arg(default_value = "cairo0")]
+    #[arg(requires = "base_dump")]
+    #[arg(help = "Specify the class of the accounts predeployed by the instance that produced \
+                  the base dump;")]
+    base_dump_account_class_choice: AccountContractClassChoice,
+
+    #[arg(long = "base-dump-initial-balance")]
+    #[arg(env = "STARKNET_DEVNET_BASE_DUMP_INITIAL_BALANCE")]
+    #[arg(value_name = "DECIMAL_VALUE")]
+    #[arg(default_value_t = InitialBalanceWrapper::default())]
+    #[arg(requires = "base_dump")]
+    #[arg(help = "Specify the initial balance in WEI of the accounts predeployed by the \
+                  instance that produced the base dump;")]
+    base_dump_initial_balance: InitialBalanceWrapper,
+
+    #[arg(long = "base-dump-chain-id")]
+    #[arg(env = "STARKNET_DEVNET_BASE_DUMP_CHAIN_ID")]
+    #[arg(value_name = "CHAIN_ID")]
+    #[arg(default_value = "TESTNET")]
+    #[arg(requires = "base_dump")]
+    #[arg(help = "Specify the chain ID of the instance that produced the base dump;")]
+    base_dump_chain_id: ChainId,
+
     #[arg(long = "dump-on")]
+    #[arg(env = "STARKNET_DEVNET_DUMP_ON")]
     #[arg(value_name = "WHEN")]
     #[arg(help = "Specify when to dump the state of Devnet;")]
     #[arg(requires = "dump_path")]
@@ -98,9 +227,215 @@ pub(crate) struct Args {
 
     // Dump path as string
     #[arg(long = "dump-path")]
+    #[arg(env = "STARKNET_DEVNET_DUMP_PATH")]
     #[arg(value_name = "DUMP_PATH")]
     #[arg(help = "Specify the path to dump to;")]
     dump_path: Option<String>,
+
+    #[arg(long = "dump-format")]
+    #[arg(env = "STARKNET_DEVNET_DUMP_FORMAT")]
+    #[arg(value_name = "FORMAT")]
+    #[arg(default_value = "json")]
+    #[arg(help = "Specify the format of dumps; the format of a loaded dump is detected;")]
+    dump_format: DumpFormat,
+
+    #[arg(long = "block-max-steps")]
+    #[arg(env = "STARKNET_DEVNET_BLOCK_MAX_STEPS")]
+    #[arg(value_name = "STEPS")]
+    #[arg(help = "Specify the number of steps after which a block is mined; if set, \
+                  transactions are packed into the pending block until a limit is reached;")]
+    block_max_steps: Option<u64>,
+
+    #[arg(long = "block-max-txs")]
+    #[arg(env = "STARKNET_DEVNET_BLOCK_MAX_TXS")]
+    #[arg(value_name = "TRANSACTIONS")]
+    #[arg(help = "Specify the number of transactions after which a block is mined; if set, \
+                  transactions are packed into the pending block until a limit is reached;")]
+    block_max_txs: Option<u64>,
+
+    #[arg(long = "block-generation-on")]
+    #[arg(env = "STARKNET_DEVNET_BLOCK_GENERATION_ON")]
+    #[arg(value_name = "WHEN")]
+    #[arg(default_value = "transaction")]
+    #[arg(conflicts_with_all = ["block_max_steps", "block_max_txs"])]
+    #[arg(help = "Specify when a block is mined: on every transaction, or on demand, keeping \
+                  transactions in the pending block until a block is created;")]
+    block_generation: BlockGeneration,
+
+    #[arg(long = "shutdown-token")]
+    #[arg(env = "STARKNET_DEVNET_SHUTDOWN_TOKEN", hide_env_values = true)]
+    #[arg(value_name = "TOKEN")]
+    #[arg(help = "Specify the token enabling graceful shutdown via POST /shutdown, which has to \
+                  be sent as a bearer token in the Authorization header;")]
+    shutdown_token: Option<String>,
+
+    #[arg(long = "impersonation-api-key")]
+    #[arg(env = "STARKNET_DEVNET_IMPERSONATION_API_KEY", hide_env_values = true)]
+    #[arg(value_name = "KEY")]
+    #[arg(help = "Specify the key which has to be sent in the x-devnet-api-key header to set \
+                  account templates and to have them applied; transactions of other requests \
+                  are validated normally;")]
+    impersonation_api_key: Option<String>,
+
+    #[arg(long = "slow-query-threshold")]
+    #[arg(env = "STARKNET_DEVNET_SLOW_QUERY_THRESHOLD")]
+    #[arg(value_name = "MILLISECONDS")]
+    #[arg(help = "Specify the duration after which a JSON-RPC method is logged as slow, with \
+                  its params digest and the time spent waiting for the state lock vs executing;")]
+    slow_query_threshold: Option<u64>,
+
+    #[arg(long = "event-retention")]
+    #[arg(env = "STARKNET_DEVNET_EVENT_RETENTION")]
+    #[arg(value_name = "BLOCKS")]
+    #[arg(default_value = "unlimited")]
+    #[arg(help = "Specify the number of latest blocks whose events are kept queryable, or \
+                  unlimited; events of older blocks are pruned;")]
+    event_retention: EventRetention,
+
+    #[arg(long = "reject-on-revert")]
+    #[arg(env = "STARKNET_DEVNET_REJECT_ON_REVERT")]
+    #[arg(help = "Reject invoke transactions that would revert, instead of including them as \
+                  reverted; can be switched at runtime via devnet_setRejectOnRevert;")]
+    reject_on_revert: bool,
+
+    #[arg(long = "max-fee-cap")]
+    #[arg(env = "STARKNET_DEVNET_MAX_FEE_CAP")]
+    #[arg(value_name = "WEI")]
+    #[arg(help = "Reject transactions whose max fee exceeds the specified amount, in WEI; \
+                  minting is not affected;")]
+    max_fee_cap: Option<u128>,
+
+    #[arg(long = "allowed-senders")]
+    #[arg(env = "STARKNET_DEVNET_ALLOWED_SENDERS")]
+    #[arg(value_name = "ADDRESS")]
+    #[arg(value_delimiter = ',')]
+    #[arg(value_parser = parse_contract_address)]
+    #[arg(help = "Only accept transactions sent by the specified accounts, separated by commas; \
+                  minting is not affected;")]
+    allowed_senders: Vec<ContractAddress>,
+
+    #[arg(long = "verify-execution")]
+    #[arg(env = "STARKNET_DEVNET_VERIFY_EXECUTION")]
+    #[arg(help = "Re-execute each sealed block from the state of its parent in the background \
+                  and log an error if the resulting state diff differs;")]
+    verify_execution: bool,
+
+    #[arg(long = "log-format")]
+    #[arg(env = "STARKNET_DEVNET_LOG_FORMAT")]
+    #[arg(value_name = "FORMAT")]
+    #[arg(default_value = "text")]
+    #[arg(help = "Specify the format of log records; json emits a record with timestamp, level \
+                  and fields (e.g. rpc_method, transaction_hash, duration_ms) per line;")]
+    pub(crate) log_format: LogFormat,
+
+    #[arg(long = "self-test")]
+    #[arg(env = "STARKNET_DEVNET_SELF_TEST")]
+    #[arg(help = "Run a smoke sequence (declare, deploy, invoke, read event, estimate fee) on \
+                  startup and exit with an error if it fails;")]
+    pub(crate) self_test: bool,
+
+    #[arg(long = "compat-check")]
+    #[arg(env = "STARKNET_DEVNET_COMPAT_CHECK")]
+    #[arg(value_name = "TOOL")]
+    #[arg(help = "Once listening, replay the requests which the tool issues when declaring, \
+                  deploying, invoking and multicalling, and exit with an error naming the step \
+                  that fails;")]
+    pub(crate) compat_check: Option<CompatTool>,
+
+    #[arg(long = "quiet")]
+    #[arg(env = "STARKNET_DEVNET_QUIET")]
+    #[arg(conflicts_with = "json")]
+    #[arg(help = "Do not print the startup banner with predeployed contracts and accounts;")]
+    pub(crate) quiet: bool,
+
+    #[arg(long = "json")]
+    #[arg(env = "STARKNET_DEVNET_JSON")]
+    #[arg(help = "Print the startup banner as a single line of JSON, for scripts;")]
+    pub(crate) json: bool,
+
+    #[arg(long = "webhook-url")]
+    #[arg(env = "STARKNET_DEVNET_WEBHOOK_URL")]
+    #[arg(value_name = "URL")]
+    #[arg(value_delimiter = ',')]
+    #[arg(value_parser = parse_webhook_url)]
+    #[arg(help = "Specify an HTTP URL to which a JSON notification is posted whenever a \
+                  transaction is included or a block is sealed; can be repeated;")]
+    webhook_urls: Vec<String>,
+
+    #[arg(long = "proof-proving-time")]
+    #[arg(env = "STARKNET_DEVNET_PROOF_PROVING_TIME")]
+    #[arg(value_name = "SECONDS")]
+    #[arg(default_value_t = DEVNET_DEFAULT_PROOF_PROVING_TIME)]
+    #[arg(help = "Specify the time after which the simulated proof of a sealed block is proven, \
+                  in seconds;")]
+    proof_proving_time: u64,
+
+    #[arg(long = "proof-verification-time")]
+    #[arg(env = "STARKNET_DEVNET_PROOF_VERIFICATION_TIME")]
+    #[arg(value_name = "SECONDS")]
+    #[arg(default_value_t = DEVNET_DEFAULT_PROOF_VERIFICATION_TIME)]
+    #[arg(help = "Specify the time after which the simulated proof of a proven block is \
+                  verified on L1, in seconds;")]
+    proof_verification_time: u64,
+
+    #[command(subcommand)]
+    pub(crate) command: Option<Command>,
+}
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, clap::ValueEnum)]
+pub(crate) enum LogFormat {
+    Text,
+    Json,
+}
+
+#[derive(Subcommand, Debug)]
+pub(crate) enum Command {
+    /// Write signed transactions, valid for the specified seed and chain ID, and exit
+    Fixtures {
+        #[arg(long = "output")]
+        #[arg(value_name = "PATH")]
+        #[arg(help = "Specify the path of the JSON file to write the transactions to;")]
+        output: String,
+    },
+    /// Wait until the Devnet instance at the specified URL is ready, and exit; useful as a
+    /// container health probe or in an init container
+    WaitReady {
+        #[arg(long = "url")]
+        #[arg(value_name = "URL")]
+        #[arg(default_value = "http://127.0.0.1:5050")]
+        #[arg(help = "Specify the URL of the Devnet instance to wait for;")]
+        url: String,
+
+        #[arg(long = "timeout")]
+        #[arg(value_name = "SECONDS")]
+        #[arg(default_value_t = 60)]
+        #[arg(help = "Specify the maximum time to wait, in seconds; exits with an error if the \
+                      instance is not ready by then;")]
+        timeout: u64,
+
+        #[arg(long = "interval")]
+        #[arg(value_name = "MILLISECONDS")]
+        #[arg(default_value_t = 500)]
+        #[arg(help = "Specify the time between two checks, in milliseconds;")]
+        interval: u64,
+    },
+    /// Convert a dump to the specified format, without loading it, and exit
+    ConvertDump {
+        #[arg(long = "input")]
+        #[arg(value_name = "PATH")]
+        #[arg(help = "Specify the path of the dump to convert, in any format;")]
+        input: String,
+
+        #[arg(long = "output")]
+        #[arg(value_name = "PATH")]
+        #[arg(help = "Specify the path to write the converted dump to;")]
+        output: String,
+
+        #[arg(long = "format")]
+        #[arg(value_name = "FORMAT")]
+        #[arg(help = "Specify the format to convert the dump to;")]
+        format: DumpFormat,
+    },
 }
 
 impl Args {
@@ -111,6 +446,23 @@ impl Args {
             None => self.account_class_choice.get_class_wrapper()?,
         };
 
+        let base_dump = match self.base_dump.clone().zip(self.base_dump_seed) {
+            Some((path, seed)) => {
+                let base_account_class_wrapper =
+                    self.base_dump_account_class_choice.get_class_wrapper()?;
+                Some(BaseDump {
+                    path,
+                    seed,
+                    total_accounts: self.base_dump_accounts_count,
+                    account_contract_class: base_account_class_wrapper.contract_class,
+                    account_contract_class_hash: base_account_class_wrapper.class_hash,
+                    predeployed_accounts_initial_balance: self.base_dump_initial_balance.0,
+                    chain_id: self.base_dump_chain_id,
+                })
+            }
+            None => None,
+        };
+
         Ok(StarknetConfig {
             seed: match self.seed {
                 Some(seed) => seed,
@@ -124,21 +476,76 @@ impl Args {
             port: self.port,
             start_time: self.start_time,
             timeout: self.timeout,
+            max_request_body_size: self.max_request_body_size,
+            compression_min_size: self.compression_min_size,
+            record_session: self.record_session,
+            disabled_methods: self.disabled_methods.clone(),
             gas_price: self.gas_price,
             chain_id: self.chain_id,
+            fee_token_address: self.fee_token_address,
+            udc_address: self.udc_address,
+            custom_genesis: self.custom_genesis.clone(),
+            base_dump,
             dump_on: self.dump_on,
             dump_path: self.dump_path.clone(),
+            dump_format: self.dump_format,
             re_execute_on_init: true,
+            block_max_steps: self.block_max_steps,
+            block_max_txs: self.block_max_txs,
+            block_generation: self.block_generation,
+            shutdown_token: self.shutdown_token.clone(),
+            impersonation_api_key: self.impersonation_api_key.clone(),
+            slow_query_threshold_ms: self.slow_query_threshold,
+            event_retention: self.event_retention,
+            reject_on_revert: self.reject_on_revert,
+            max_fee_cap: self.max_fee_cap,
+            allowed_senders: self.allowed_senders.clone(),
+            verify_execution: self.verify_execution,
+            webhook_urls: self.webhook_urls.clone(),
+            proof_proving_time: self.proof_proving_time,
+            proof_verification_time: self.proof_verification_time,
         })
     }
 }
 
+/// Webhooks are posted with a plain HTTP client, so other schemes are rejected on startup
+fn parse_webhook_url(value: &str) -> Result<String, String> {
+    let url = url::Url::parse(value).map_err(|err| format!("Invalid URL: {err}"))?;
+    if url.scheme() != "http" {
+        return Err(format!("Unsupported scheme {}; only http is supported", url.scheme()));
+    }
+
+    Ok(value.to_string())
+}
+
+fn parse_disabled_method(value: &str) -> Result<String, String> {
+    let method = value.trim();
+    check_method_can_be_disabled(method)?;
+
+    Ok(method.to_string())
+}
+
+/// Addresses are given in the same hex representation in which they are printed on startup
+fn parse_contract_address(value: &str) -> Result<ContractAddress, String> {
+    Felt::from_prefixed_hex_str(value)
+        .and_then(ContractAddress::new)
+        .map_err(|_| format!("Invalid address: {value}; expected a 0x-prefixed hex string"))
+}
+
+fn parse_genesis_file(value: &str) -> Result<CustomGenesis, String> {
+    CustomGenesis::from_path(value).map_err(|err| format!("Invalid genesis file: {err}"))
+}
+
 #[cfg(test)]
 mod tests {
-    use clap::Parser;
+    use clap::{CommandFactory, Parser};
     use starknet_core::constants::{CAIRO_1_ACCOUNT_CONTRACT_SIERRA_PATH, ERC20_CONTRACT_PATH};
+    use starknet_core::starknet::base_dump::BaseDump;
+    use starknet_core::starknet::starknet_config::{BlockGeneration, DumpFormat};
+    use starknet_types::chain_id::ChainId;
+    use starknet_types::felt::Felt;
 
-    use super::Args;
+    use super::{Args, Command};
     use crate::ip_addr_wrapper::IpAddrWrapper;
 
     #[test]
@@ -169,6 +576,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn block_limits_are_passed_to_config() {
+        let args = Args::parse_from(["--", "--block-max-steps", "1000", "--block-max-txs", "2"]);
+        let config = args.to_starknet_config().unwrap();
+        assert_eq!(config.block_max_steps, Some(1000));
+        assert_eq!(config.block_max_txs, Some(2));
+
+        let config = Args::parse_from(["--"]).to_starknet_config().unwrap();
+        assert_eq!(config.block_max_steps, None);
+        assert_eq!(config.block_max_txs, None);
+    }
+
+    #[test]
+    fn block_generation_on_demand_conflicts_with_block_limits() {
+        let config = Args::parse_from(["--", "--block-generation-on", "demand"])
+            .to_starknet_config()
+            .unwrap();
+        assert_eq!(config.block_generation, BlockGeneration::Demand);
+
+        match Args::try_parse_from([
+            "--",
+            "--block-generation-on",
+            "demand",
+            "--block-max-txs",
+            "2",
+        ]) {
+            Err(_) => (),
+            Ok(parsed) => panic!("Should have failed; got: {parsed:?}"),
+        }
+    }
+
     fn get_first_line(text: &str) -> &str {
         text.split('\n').next().unwrap()
     }
@@ -244,4 +682,203 @@ mod tests {
             Ok(parsed) => panic!("Should have failed; got: {parsed:?}"),
         }
     }
+
+    #[test]
+    fn fixtures_subcommand_is_parsed_after_options() {
+        let args =
+            Args::parse_from(["--", "--seed", "42", "fixtures", "--output", "fixtures.json"]);
+        assert_eq!(args.seed, Some(42));
+        match args.command {
+            Some(Command::Fixtures { output }) => assert_eq!(output, "fixtures.json"),
+            other => panic!("Should have parsed the fixtures subcommand; got: {other:?}"),
+        }
+
+        assert!(Args::parse_from(["--"]).command.is_none());
+    }
+
+    #[test]
+    fn wait_ready_subcommand_has_defaults() {
+        match Args::parse_from(["--", "wait-ready"]).command {
+            Some(Command::WaitReady { url, timeout, interval }) => {
+                assert_eq!(url, "http://127.0.0.1:5050");
+                assert_eq!(timeout, 60);
+                assert_eq!(interval, 500);
+            }
+            other => panic!("Should have parsed the wait-ready subcommand; got: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn convert_dump_subcommand_requires_format() {
+        let args = Args::parse_from([
+            "--",
+            "convert-dump",
+            "--input",
+            "dump.json",
+            "--output",
+            "dump.bin",
+            "--format",
+            "binary",
+        ]);
+        match args.command {
+            Some(Command::ConvertDump { input, output, format }) => {
+                assert_eq!(input, "dump.json");
+                assert_eq!(output, "dump.bin");
+                assert_eq!(format, DumpFormat::Binary);
+            }
+            other => panic!("Should have parsed the convert-dump subcommand; got: {other:?}"),
+        }
+
+        assert!(
+            Args::try_parse_from(["--", "convert-dump", "--input", "a", "--output", "b"]).is_err()
+        );
+    }
+
+    #[test]
+    fn base_dump_requires_its_seed() {
+        let config = Args::parse_from(["--", "--base-dump", "base.json", "--base-dump-seed", "42"])
+            .to_starknet_config()
+            .unwrap();
+        assert_eq!(
+            config.base_dump,
+            Some(BaseDump::with_default_options("base.json".to_string(), 42))
+        );
+
+        assert!(Args::try_parse_from(["--", "--base-dump", "base.json"]).is_err());
+        assert!(Args::try_parse_from(["--", "--base-dump-seed", "42"]).is_err());
+        assert!(Args::try_parse_from(["--", "--base-dump-accounts", "2"]).is_err());
+    }
+
+    #[test]
+    fn base_dump_has_its_own_predeployment_options() {
+        let config = Args::parse_from([
+            "--",
+            "--accounts",
+            "2",
+            "--chain-id",
+            "MAINNET",
+            "--base-dump",
+            "base.json",
+            "--base-dump-seed",
+            "42",
+            "--base-dump-accounts",
+            "10",
+            "--base-dump-initial-balance",
+            "5",
+        ])
+        .to_starknet_config()
+        .unwrap();
+        let base_dump = config.base_dump.unwrap();
+
+        assert_eq!(config.total_accounts, 2);
+        assert_eq!(base_dump.total_accounts, 10);
+        assert_eq!(base_dump.predeployed_accounts_initial_balance, Felt::from(5));
+        assert_eq!(base_dump.chain_id, ChainId::Testnet);
+    }
+
+    #[test]
+    fn not_allowing_quiet_and_json_banner() {
+        match Args::try_parse_from(["--", "--quiet", "--json"]) {
+            Err(err) => {
+                assert_eq!(
+                    get_first_line(&err.to_string()),
+                    "error: the argument '--quiet' cannot be used with '--json'"
+                );
+            }
+            Ok(parsed) => panic!("Should have failed; got: {parsed:?}"),
+        }
+    }
+
+    #[test]
+    fn webhook_urls_are_repeatable_and_only_http() {
+        let config = Args::parse_from([
+            "--",
+            "--webhook-url",
+            "http://localhost:3000/devnet",
+            "--webhook-url",
+            "http://localhost:3001",
+        ])
+        .to_starknet_config()
+        .unwrap();
+        assert_eq!(
+            config.webhook_urls,
+            vec!["http://localhost:3000/devnet", "http://localhost:3001"]
+        );
+
+        match Args::try_parse_from(["--", "--webhook-url", "https://example.com"]) {
+            Err(err) => assert!(
+                get_first_line(&err.to_string()).contains("only http is supported"),
+                "Unexpected error: {err}"
+            ),
+            Ok(parsed) => panic!("Should have failed; got: {parsed:?}"),
+        }
+    }
+
+    #[test]
+    fn disabled_methods_are_trimmed_and_must_be_known() {
+        let config =
+            Args::parse_from(["--", "--disabled-methods", "starknet_getEvents, starknet_chainId"])
+                .to_starknet_config()
+                .unwrap();
+        assert_eq!(config.disabled_methods, vec!["starknet_getEvents", "starknet_chainId"]);
+
+        for (method, expected_error) in [
+            ("starknet_getEvent", "Unknown JSON-RPC method: starknet_getEvent"),
+            ("devnet_setDisabledMethods", "devnet_setDisabledMethods cannot be disabled"),
+        ] {
+            match Args::try_parse_from(["--", "--disabled-methods", method]) {
+                Err(err) => assert!(
+                    get_first_line(&err.to_string()).contains(expected_error),
+                    "Unexpected error: {err}"
+                ),
+                Ok(parsed) => panic!("Should have failed; got: {parsed:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn event_retention_is_either_unlimited_or_positive_number_of_blocks() {
+        let config = Args::parse_from(["--"]).to_starknet_config().unwrap();
+        assert_eq!(config.event_retention, EventRetention::Unlimited);
+
+        let config =
+            Args::parse_from(["--", "--event-retention", "10"]).to_starknet_config().unwrap();
+        assert_eq!(config.event_retention, EventRetention::Blocks(10));
+
+        for invalid_value in ["0", "-1", "forever"] {
+            assert!(Args::try_parse_from(["--", "--event-retention", invalid_value]).is_err());
+        }
+    }
+
+    #[test]
+    fn every_option_is_configurable_via_environment() {
+        let command = Args::command();
+        for arg in command.get_arguments().filter(|arg| arg.get_long().is_some()) {
+            let long = arg.get_long().unwrap();
+            if ["help", "version"].contains(&long) {
+                continue;
+            }
+
+            let expected_env = format!("STARKNET_DEVNET_{}", long.to_uppercase().replace('-', "_"));
+            assert_eq!(
+                arg.get_env().and_then(|env| env.to_str()),
+                Some(expected_env.as_str()),
+                "Unexpected environment variable of --{long}"
+            );
+        }
+    }
+
+    #[test]
+    fn cli_takes_precedence_over_environment() {
+        // not set by any other test, since tests share the environment
+        std::env::set_var("STARKNET_DEVNET_START_TIME", "123");
+
+        let config = Args::parse_from(["--"]).to_starknet_config().unwrap();
+        assert_eq!(config.start_time, Some(123));
+
+        let config = Args::parse_from(["--", "--start-time", "456"]).to_starknet_config().unwrap();
+        assert_eq!(config.start_time, Some(456));
+
+        std::env::remove_var("STARKNET_DEVNET_START_TIME");
+    }
 }
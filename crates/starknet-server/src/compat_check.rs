@@ -0,0 +1,367 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use starknet_core::constants::{
+    CHARGEABLE_ACCOUNT_ADDRESS, CHARGEABLE_ACCOUNT_PRIVATE_KEY, SELF_TEST_CONTRACT_JSON,
+};
+use starknet_core::starknet::starknet_config::StarknetConfig;
+use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+use starknet_rs_core::types::contract::legacy::LegacyContractClass;
+use starknet_rs_core::types::{
+    BlockId, BlockTag, ExecutionResult, FieldElement, FunctionCall, StarknetError,
+    TransactionExecutionStatus, TransactionStatus,
+};
+use starknet_rs_core::utils::{get_selector_from_name, get_udc_deployed_address, UdcUniqueness};
+use starknet_rs_providers::jsonrpc::{HttpTransport, JsonRpcClient};
+use starknet_rs_providers::{
+    MaybeUnknownErrorCode, Provider, ProviderError, StarknetErrorWithMessage,
+};
+use starknet_rs_signers::{LocalWallet, SigningKey};
+use starknet_types::contract_class::Cairo0Json;
+use starknet_types::felt::Felt;
+use tokio::time::sleep;
+use tracing::info;
+use url::Url;
+
+/// How often, and how many times, the status of a sent transaction is polled
+const WAIT_INTERVAL: Duration = Duration::from_millis(100);
+const WAIT_ATTEMPTS: usize = 50;
+
+/// Command line tool whose call sequences are replayed by the compatibility check
+#[derive(clap::ValueEnum, Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum CompatTool {
+    Starkli,
+    Sncast,
+}
+
+impl CompatTool {
+    fn name(self) -> &'static str {
+        match self {
+            CompatTool::Starkli => "starkli",
+            CompatTool::Sncast => "sncast",
+        }
+    }
+}
+
+type CompatAccount<'a> = SingleOwnerAccount<&'a JsonRpcClient<HttpTransport>, LocalWallet>;
+
+/// Sends the JSON-RPC requests which `tool` issues when declaring, deploying, invoking and
+/// multicalling to the instance served at `rpc_url`, and fails with the name of the first step
+/// that does not succeed. The transactions are sent from the chargeable account and remain in the
+/// state of the instance.
+pub(crate) async fn run_compat_check(
+    tool: CompatTool,
+    rpc_url: &str,
+    config: &StarknetConfig,
+) -> Result<(), anyhow::Error> {
+    if config.custom_genesis.is_some() {
+        return Err(anyhow!(
+            "Compatibility check requires the chargeable account, which is not predeployed with a \
+             custom genesis"
+        ));
+    }
+
+    let provider = JsonRpcClient::new(HttpTransport::new(Url::parse(rpc_url)?));
+    let chain_id = provider
+        .chain_id()
+        .await
+        .map_err(|err| step_error(tool, "chain ID", anyhow!("{err:?}")))?;
+    let signer = LocalWallet::from(SigningKey::from_secret_scalar(FieldElement::from_hex_be(
+        CHARGEABLE_ACCOUNT_PRIVATE_KEY,
+    )?));
+    let mut account = SingleOwnerAccount::new(
+        &provider,
+        signer,
+        FieldElement::from_hex_be(CHARGEABLE_ACCOUNT_ADDRESS)?,
+        chain_id,
+        ExecutionEncoding::Legacy,
+    );
+    // both tools fetch nonces and estimate fees against the pending block
+    account.set_block_id(BlockId::Tag(BlockTag::Pending));
+    let checker = CompatChecker {
+        tool,
+        provider: &provider,
+        account,
+        udc_address: Felt::from(config.udc_address).into(),
+    };
+
+    let class_hash = checker.declare().await.map_err(|err| step_error(tool, "declare", err))?;
+    let contract_address =
+        checker.deploy(class_hash).await.map_err(|err| step_error(tool, "deploy", err))?;
+    checker
+        .invoke(contract_address, FieldElement::from(10_u32))
+        .await
+        .map_err(|err| step_error(tool, "invoke", err))?;
+    checker
+        .multicall(class_hash, contract_address)
+        .await
+        .map_err(|err| step_error(tool, "multicall", err))?;
+
+    info!("Compatibility check for {} passed", tool.name());
+    Ok(())
+}
+
+fn step_error(tool: CompatTool, step: &str, err: impl std::fmt::Display) -> anyhow::Error {
+    anyhow!("Compatibility check for {} failed at step `{step}`: {err}", tool.name())
+}
+
+struct CompatChecker<'a> {
+    tool: CompatTool,
+    provider: &'a JsonRpcClient<HttpTransport>,
+    account: CompatAccount<'a>,
+    udc_address: FieldElement,
+}
+
+impl<'a> CompatChecker<'a> {
+    /// Declares the Cairo 0 test class, unless it is already declared (e.g. by an earlier
+    /// check), which is looked up as starkli does before declaring
+    async fn declare(&self) -> Result<FieldElement, anyhow::Error> {
+        let contract_class: LegacyContractClass = serde_json::from_value(
+            Cairo0Json::raw_json_from_json_str(SELF_TEST_CONTRACT_JSON)?.inner,
+        )?;
+        let class_hash = contract_class.class_hash()?;
+
+        match self.provider.get_class(BlockId::Tag(BlockTag::Pending), class_hash).await {
+            Ok(_) => info!("Class {class_hash:#x} is already declared"),
+            Err(ProviderError::StarknetError(StarknetErrorWithMessage {
+                code: MaybeUnknownErrorCode::Known(StarknetError::ClassHashNotFound),
+                ..
+            })) => {
+                let result = self
+                    .account
+                    .declare_legacy(Arc::new(contract_class))
+                    .send()
+                    .await
+                    .map_err(|err| anyhow!("{err:?}"))?;
+                self.wait_for_transaction(result.transaction_hash).await?;
+            }
+            Err(err) => return Err(anyhow!("{err:?}")),
+        }
+
+        self.log_passed("declare");
+        Ok(class_hash)
+    }
+
+    /// Deploys an instance of `class_hash` through the UDC and checks that it is deployed at the
+    /// address computed locally by both tools
+    async fn deploy(&self, class_hash: FieldElement) -> Result<FieldElement, anyhow::Error> {
+        let (deploy_call, contract_address) = self.deploy_call(class_hash).await?;
+        self.execute(vec![deploy_call]).await?;
+        self.assert_deployed(contract_address, class_hash).await?;
+
+        self.log_passed("deploy");
+        Ok(contract_address)
+    }
+
+    async fn invoke(
+        &self,
+        contract_address: FieldElement,
+        amount: FieldElement,
+    ) -> Result<(), anyhow::Error> {
+        let balance_before = self.get_balance(contract_address).await?;
+        self.execute(vec![increase_balance_call(contract_address, amount)?]).await?;
+        self.assert_balance(contract_address, balance_before + amount).await?;
+
+        self.log_passed("invoke");
+        Ok(())
+    }
+
+    /// starkli invokes several calls of the deployed contract in one transaction, while
+    /// `sncast multicall` deploys another contract and invokes it in one transaction
+    async fn multicall(
+        &self,
+        class_hash: FieldElement,
+        deployed_address: FieldElement,
+    ) -> Result<(), anyhow::Error> {
+        let amount = FieldElement::from(10_u32);
+        match self.tool {
+            CompatTool::Starkli => {
+                let balance_before = self.get_balance(deployed_address).await?;
+                self.execute(vec![
+                    increase_balance_call(deployed_address, amount)?,
+                    increase_balance_call(deployed_address, amount)?,
+                ])
+                .await?;
+                self.assert_balance(deployed_address, balance_before + amount + amount).await?;
+            }
+            CompatTool::Sncast => {
+                let (deploy_call, contract_address) = self.deploy_call(class_hash).await?;
+                self.execute(vec![deploy_call, increase_balance_call(contract_address, amount)?])
+                    .await?;
+                self.assert_deployed(contract_address, class_hash).await?;
+                self.assert_balance(contract_address, amount).await?;
+            }
+        }
+
+        self.log_passed("multicall");
+        Ok(())
+    }
+
+    /// Returns the call of the UDC deploying `class_hash` without constructor calldata, with the
+    /// nonce of the account as the salt to get a new address per deployment, and the address
+    async fn deploy_call(
+        &self,
+        class_hash: FieldElement,
+    ) -> Result<(Call, FieldElement), anyhow::Error> {
+        let salt = self
+            .provider
+            .get_nonce(BlockId::Tag(BlockTag::Pending), self.account.address())
+            .await
+            .map_err(|err| anyhow!("{err:?}"))?;
+        let contract_address =
+            get_udc_deployed_address(salt, class_hash, &UdcUniqueness::NotUnique, &[]);
+        let deploy_call = Call {
+            to: self.udc_address,
+            selector: get_selector_from_name("deployContract")?,
+            calldata: vec![
+                class_hash,
+                salt,
+                FieldElement::ZERO, // unique
+                FieldElement::ZERO, // constructor calldata length
+            ],
+        };
+
+        Ok((deploy_call, contract_address))
+    }
+
+    /// Sends `calls` in one transaction, with the nonce and the fee obtained from the instance
+    async fn execute(&self, calls: Vec<Call>) -> Result<(), anyhow::Error> {
+        let result = self.account.execute(calls).send().await.map_err(|err| anyhow!("{err:?}"))?;
+        self.wait_for_transaction(result.transaction_hash).await
+    }
+
+    /// Polls the sent transaction as `tool` does until it is accepted: starkli polls the receipt,
+    /// while sncast polls the status
+    async fn wait_for_transaction(
+        &self,
+        transaction_hash: FieldElement,
+    ) -> Result<(), anyhow::Error> {
+        for _ in 0..WAIT_ATTEMPTS {
+            match self.tool {
+                CompatTool::Starkli => {
+                    match self.provider.get_transaction_receipt(transaction_hash).await {
+                        Ok(receipt) => {
+                            return match receipt.execution_result() {
+                                ExecutionResult::Succeeded => Ok(()),
+                                ExecutionResult::Reverted { reason } => Err(anyhow!(
+                                    "transaction {transaction_hash:#x} reverted: {reason}"
+                                )),
+                            };
+                        }
+                        Err(err) if !is_transaction_not_found(&err) => {
+                            return Err(anyhow!("{err:?}"));
+                        }
+                        Err(_) => (),
+                    }
+                }
+                CompatTool::Sncast => {
+                    match self.provider.get_transaction_status(transaction_hash).await {
+                        Ok(
+                            TransactionStatus::AcceptedOnL2(execution_status)
+                            | TransactionStatus::AcceptedOnL1(execution_status),
+                        ) => {
+                            return match execution_status {
+                                TransactionExecutionStatus::Succeeded => Ok(()),
+                                TransactionExecutionStatus::Reverted => {
+                                    Err(anyhow!("transaction {transaction_hash:#x} reverted"))
+                                }
+                            };
+                        }
+                        Ok(TransactionStatus::Rejected) => {
+                            return Err(anyhow!("transaction {transaction_hash:#x} was rejected"));
+                        }
+                        Ok(TransactionStatus::Received) => (),
+                        Err(err) if !is_transaction_not_found(&err) => {
+                            return Err(anyhow!("{err:?}"));
+                        }
+                        Err(_) => (),
+                    }
+                }
+            }
+            sleep(WAIT_INTERVAL).await;
+        }
+
+        Err(anyhow!("transaction {transaction_hash:#x} was not accepted in time"))
+    }
+
+    async fn assert_deployed(
+        &self,
+        contract_address: FieldElement,
+        expected_class_hash: FieldElement,
+    ) -> Result<(), anyhow::Error> {
+        let class_hash = self
+            .provider
+            .get_class_hash_at(BlockId::Tag(BlockTag::Pending), contract_address)
+            .await
+            .map_err(|err| anyhow!("{err:?}"))?;
+        if class_hash != expected_class_hash {
+            return Err(anyhow!(
+                "contract at {contract_address:#x} is of class {class_hash:#x} instead of \
+                 {expected_class_hash:#x}"
+            ));
+        }
+
+        Ok(())
+    }
+
+    async fn get_balance(
+        &self,
+        contract_address: FieldElement,
+    ) -> Result<FieldElement, anyhow::Error> {
+        let result = self
+            .provider
+            .call(
+                FunctionCall {
+                    contract_address,
+                    entry_point_selector: get_selector_from_name("get_balance")?,
+                    calldata: vec![],
+                },
+                BlockId::Tag(BlockTag::Pending),
+            )
+            .await
+            .map_err(|err| anyhow!("{err:?}"))?;
+
+        result.first().copied().ok_or_else(|| anyhow!("get_balance returned no value"))
+    }
+
+    async fn assert_balance(
+        &self,
+        contract_address: FieldElement,
+        expected_balance: FieldElement,
+    ) -> Result<(), anyhow::Error> {
+        let balance = self.get_balance(contract_address).await?;
+        if balance != expected_balance {
+            return Err(anyhow!(
+                "balance of {contract_address:#x} is {balance:#x} instead of {expected_balance:#x}"
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn log_passed(&self, step: &str) {
+        info!("Compatibility check for {}: `{step}` passed", self.tool.name());
+    }
+}
+
+fn increase_balance_call(
+    contract_address: FieldElement,
+    amount: FieldElement,
+) -> Result<Call, anyhow::Error> {
+    Ok(Call {
+        to: contract_address,
+        selector: get_selector_from_name("increase_balance")?,
+        calldata: vec![amount],
+    })
+}
+
+fn is_transaction_not_found<E>(err: &ProviderError<E>) -> bool {
+    matches!(
+        err,
+        ProviderError::StarknetError(StarknetErrorWithMessage {
+            code: MaybeUnknownErrorCode::Known(StarknetError::TransactionHashNotFound),
+            ..
+        })
+    )
+}
@@ -1,98 +1,98 @@
 use std::net::SocketAddr;
+use std::time::Duration;
 
 use ::server::ServerConfig;
 use anyhow::Ok;
 use api::Api;
+use banner::{format_json_banner, format_text_banner};
 use clap::Parser;
-use cli::Args;
-use starknet_core::account::Account;
-use starknet_core::constants::{
-    ERC20_CONTRACT_ADDRESS, ERC20_CONTRACT_CLASS_HASH, UDC_CONTRACT_ADDRESS,
-    UDC_CONTRACT_CLASS_HASH,
-};
+use cli::{Args, Command, LogFormat};
+use starknet_core::starknet::dump_format::convert_dump;
+use starknet_core::starknet::fixtures::generate_fixtures;
+use starknet_core::starknet::genesis::get_genesis_summary;
+use starknet_core::starknet::self_test::run_self_test;
 use starknet_core::starknet::starknet_config::DumpOn;
 use starknet_core::starknet::Starknet;
-use starknet_types::felt::Felt;
-use starknet_types::traits::{ToDecimalString, ToHexString};
 use tracing::info;
 use tracing_subscriber::EnvFilter;
 
 mod api;
+mod banner;
 mod cli;
+mod compat_check;
 mod contract_class_choice;
 mod initial_balance_wrapper;
 mod ip_addr_wrapper;
 mod server;
+mod wait_ready;
+mod webhooks;
 
 /// Configures tracing with default level INFO,
 /// If the environment variable `RUST_LOG` is set, it will be used instead.
-fn configure_tracing() {
+fn configure_tracing(log_format: LogFormat) {
     let level_filter_layer =
         EnvFilter::builder().with_default_directive(tracing::Level::INFO.into()).from_env_lossy();
 
-    tracing_subscriber::fmt().with_env_filter(level_filter_layer).init();
-}
-
-fn log_predeployed_accounts(predeployed_accounts: &Vec<Account>, seed: u32, initial_balance: Felt) {
-    for account in predeployed_accounts {
-        let formatted_str = format!(
-            r"
-| Account address |  {} 
-| Private key     |  {}
-| Public key      |  {}",
-            account.account_address.to_prefixed_hex_str(),
-            account.private_key.to_prefixed_hex_str(),
-            account.public_key.to_prefixed_hex_str()
-        );
-
-        println!("{}", formatted_str);
+    match log_format {
+        LogFormat::Text => tracing_subscriber::fmt().with_env_filter(level_filter_layer).init(),
+        // fields of events are flattened, so that e.g. `rpc_method` is a top-level property
+        LogFormat::Json => tracing_subscriber::fmt()
+            .json()
+            .flatten_event(true)
+            .with_env_filter(level_filter_layer)
+            .init(),
     }
-
-    if !predeployed_accounts.is_empty() {
-        println!();
-        let class_hash = predeployed_accounts.get(0).unwrap().class_hash.to_prefixed_hex_str();
-        println!("Predeployed accounts using class with hash: {class_hash}");
-        println!("Initial balance of each account: {} WEI", initial_balance.to_decimal_string());
-        println!("Seed to replicate this account sequence: {seed}");
-    }
-}
-
-fn print_predeployed_contracts() {
-    println!("Predeployed FeeToken");
-    println!("Address: {ERC20_CONTRACT_ADDRESS}");
-    println!("Class Hash: {ERC20_CONTRACT_CLASS_HASH}");
-    println!();
-    println!("Predeployed UDC");
-    println!("Address: {UDC_CONTRACT_ADDRESS}");
-    println!("Class Hash: {UDC_CONTRACT_CLASS_HASH}");
 }
 
 #[tokio::main]
 async fn main() -> Result<(), anyhow::Error> {
-    configure_tracing();
-
     // parse arguments
     let args = Args::parse();
+    configure_tracing(args.log_format);
+
+    if let Some(Command::WaitReady { url, timeout, interval }) = &args.command {
+        wait_ready::wait_ready(
+            url,
+            Duration::from_secs(*timeout),
+            Duration::from_millis(*interval),
+        )
+        .await?;
+        info!("Devnet at {url} is ready");
+        return Ok(());
+    }
+
+    if let Some(Command::ConvertDump { input, output, format }) = &args.command {
+        convert_dump(input, output, *format)?;
+        info!("Converted the dump at {input} to {output}");
+        return Ok(());
+    }
+
     let starknet_config = args.to_starknet_config()?;
     let mut addr: SocketAddr = SocketAddr::new(starknet_config.host, starknet_config.port);
 
-    let api = api::Api::new(Starknet::new(&starknet_config)?);
-
-    // set block timestamp shift during startup if start time is set
-    if let Some(start_time) = starknet_config.start_time {
-        api.starknet.write().await.set_block_timestamp_shift(
-            start_time as i64 - Starknet::get_unix_timestamp_as_seconds() as i64,
+    if let Some(Command::Fixtures { output }) = &args.command {
+        let fixtures = generate_fixtures(&starknet_config).await?;
+        std::fs::write(output, serde_json::to_string_pretty(&fixtures)?)?;
+        info!(
+            "Wrote {} transactions for seed {} to {output}",
+            fixtures.transactions.len(),
+            fixtures.seed
         );
-    };
+        return Ok(());
+    }
 
-    print_predeployed_contracts();
+    if args.self_test {
+        run_self_test(&starknet_config).await?;
+        info!("Self-test passed");
+    }
 
-    let predeployed_accounts = api.starknet.read().await.get_predeployed_accounts();
-    log_predeployed_accounts(
-        &predeployed_accounts,
-        starknet_config.seed,
-        starknet_config.predeployed_accounts_initial_balance,
-    );
+    let mut starknet = Starknet::new(&starknet_config)?;
+    if !starknet_config.webhook_urls.is_empty() {
+        starknet.set_notification_listener(webhooks::spawn_webhook_dispatcher(
+            starknet_config.webhook_urls.clone(),
+        ));
+    }
+    let api = api::Api::new(starknet);
 
     let server = server::serve_http_api_json_rpc(
         addr,
@@ -101,22 +101,50 @@ async fn main() -> Result<(), anyhow::Error> {
         &starknet_config,
     );
     addr = server.local_addr();
+    let rpc_url = format!("http://{addr}/rpc");
+
+    if !args.quiet {
+        let genesis = get_genesis_summary(&*api.read_starknet().await)?;
+        if args.json {
+            println!("{}", format_json_banner(&genesis, &rpc_url)?);
+        } else {
+            println!("{}", format_text_banner(&genesis, &rpc_url));
+        }
+    }
 
     info!("Starknet Devnet listening on {}", addr);
 
     // spawn the server on a new task
-    let serve = if starknet_config.dump_on == Some(DumpOn::Exit) {
-        tokio::task::spawn(server.with_graceful_shutdown(shutdown_signal(api.clone())))
-    } else {
-        tokio::task::spawn(server)
-    };
+    let dump_on_exit = starknet_config.dump_on == Some(DumpOn::Exit);
+    let serve = tokio::task::spawn(
+        server.with_graceful_shutdown(shutdown_signal(api.clone(), dump_on_exit)),
+    );
+
+    if let Some(tool) = args.compat_check {
+        compat_check::run_compat_check(tool, &rpc_url, &starknet_config).await?;
+    }
 
     Ok(serve.await??)
 }
 
-pub async fn shutdown_signal(api: Api) {
-    tokio::signal::ctrl_c().await.expect("Failed to install CTRL+C signal handler");
+/// Resolves when a shutdown is requested via HTTP or, if dumping on exit, on CTRL+C. Without
+/// dumping on exit, CTRL+C is left to terminate the process as usual.
+pub async fn shutdown_signal(api: Api, dump_on_exit: bool) {
+    let ctrl_c = async {
+        if dump_on_exit {
+            tokio::signal::ctrl_c().await.expect("Failed to install CTRL+C signal handler");
+        } else {
+            std::future::pending::<()>().await;
+        }
+    };
 
-    let starknet = api.starknet.read().await;
-    starknet.dump_transactions().expect("Failed to dump starknet transactions");
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = api.shutdown.notified() => info!("Shutdown requested"),
+    }
+
+    if dump_on_exit {
+        let starknet = api.read_starknet().await;
+        starknet.dump_transactions().expect("Failed to dump starknet transactions");
+    }
 }
@@ -1,4 +1,5 @@
 use std::net::SocketAddr;
+use std::time::Duration;
 
 use axum::routing::{get, post};
 use server::builder::StarknetDevnetServer;
@@ -16,14 +17,29 @@ pub fn serve_http_api_json_rpc(
     api: Api,
     starknet_config: &StarknetConfig,
 ) -> StarknetDevnetServer {
-    let http = HttpApiHandler { api: api.clone() };
-    let json_rpc = JsonRpcHandler { api };
+    let http = HttpApiHandler {
+        api: api.clone(),
+        shutdown_token: starknet_config.shutdown_token.clone(),
+        impersonation_api_key: starknet_config.impersonation_api_key.clone(),
+        record_session: starknet_config.record_session,
+    };
+    let json_rpc = JsonRpcHandler {
+        api,
+        slow_query_threshold: starknet_config.slow_query_threshold_ms.map(Duration::from_millis),
+        impersonation_api_key: starknet_config.impersonation_api_key.clone(),
+        // set per request
+        execution_timeout: None,
+        impersonation_allowed: true,
+        record_session: starknet_config.record_session,
+    };
 
     server::builder::Builder::<JsonRpcHandler, HttpApiHandler>::new(addr, json_rpc, http)
         .set_config(config)
         .json_rpc_route("/")
         .json_rpc_route("/rpc")
         .http_api_route("/is_alive", get(http::is_alive))
+        .http_api_route("/config", get(http::config::get_config))
+        .http_api_route("/metrics", get(http::metrics::get_metrics))
         .http_api_route("/dump", post(http::dump_load::dump))
         .http_api_route("/load", post(http::dump_load::load))
         .http_api_route("/postman/load_l1_messaging_contract", post(http::postman::postman_load))
@@ -37,8 +53,11 @@ pub fn serve_http_api_json_rpc(
             post(http::postman::postman_consume_message_from_l2),
         )
         .http_api_route("/create_block", post(http::blocks::create_block))
+        .http_api_route("/mining/pause", post(http::blocks::pause_mining))
+        .http_api_route("/mining/resume", post(http::blocks::resume_mining))
         .http_api_route("/abort_blocks", post(http::blocks::abort_blocks))
         .http_api_route("/restart", post(http::restart))
+        .http_api_route("/shutdown", post(http::shutdown))
         .http_api_route("/set_time", post(http::time::set_time))
         .http_api_route("/increase_time", post(http::time::increase_time))
         .http_api_route("/predeployed_accounts", get(http::accounts::get_predeployed_accounts))
@@ -46,5 +65,22 @@ pub fn serve_http_api_json_rpc(
         .http_api_route("/fee_token", get(http::mint_token::get_fee_token))
         .http_api_route("/mint", post(http::mint_token::mint))
         .http_api_route("/fork_status", get(http::get_fork_status))
+        .http_api_route("/export/state_diffs", get(http::export::export_state_diffs))
+        .http_api_route(
+            "/export/feeder_gateway/blocks",
+            get(http::export::export_feeder_gateway_blocks),
+        )
+        .http_api_route("/export/profiler_trace", get(http::export::export_profiler_trace))
+        .http_api_route("/export/session_fixture", get(http::export::export_session_fixture))
+        .http_api_route("/feeder_gateway/get_block", get(http::feeder_gateway::get_block))
+        .http_api_route(
+            "/feeder_gateway/get_transaction",
+            get(http::feeder_gateway::get_transaction),
+        )
+        .http_api_route(
+            "/feeder_gateway/get_state_update",
+            get(http::feeder_gateway::get_state_update),
+        )
+        .http_api_route("/gateway/add_transaction", post(http::gateway::add_transaction))
         .build(starknet_config)
 }
@@ -0,0 +1,53 @@
+use std::time::Duration;
+
+use anyhow::anyhow;
+use hyper::{Client, StatusCode, Uri};
+use tokio::time::{sleep, Instant};
+
+/// Polls `/is_alive` of the Devnet instance at `url` until it responds successfully. Devnet
+/// starts serving only after genesis is complete, so a successful response means it is ready.
+pub(crate) async fn wait_ready(
+    url: &str,
+    timeout: Duration,
+    interval: Duration,
+) -> Result<(), anyhow::Error> {
+    let is_alive_uri: Uri = format!("{}/is_alive", url.trim_end_matches('/')).parse()?;
+    let client = Client::new();
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        match client.get(is_alive_uri.clone()).await {
+            Ok(resp) if resp.status() == StatusCode::OK => return Ok(()),
+            Ok(resp) => tracing::debug!("Devnet at {url} responded with {}", resp.status()),
+            Err(err) => tracing::debug!("Devnet at {url} is not reachable: {err}"),
+        }
+
+        if Instant::now() + interval > deadline {
+            return Err(anyhow!(
+                "Devnet at {url} was not ready within {} seconds",
+                timeout.as_secs()
+            ));
+        }
+        sleep(interval).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::wait_ready;
+
+    #[tokio::test]
+    async fn unreachable_instance_times_out() {
+        // nothing listens on port 1
+        let result = wait_ready(
+            "http://127.0.0.1:1",
+            Duration::from_millis(300),
+            Duration::from_millis(100),
+        )
+        .await;
+
+        assert!(result.unwrap_err().to_string().contains("was not ready"));
+    }
+}
@@ -0,0 +1,164 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use hyper::client::HttpConnector;
+use hyper::header::CONTENT_TYPE;
+use hyper::{Body, Client, Request};
+use starknet_core::starknet::notifications::{DevnetNotification, NotificationListener};
+use tokio::sync::mpsc;
+use tracing::warn;
+
+/// Time after which a webhook that has not responded is considered failed
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Number of notifications waiting to be posted, beyond which new notifications are dropped
+const WEBHOOK_QUEUE_CAPACITY: usize = 1000;
+
+/// Spawns a task posting each notification as JSON to every URL and returns the listener feeding
+/// it. Notifications are posted one at a time, in the order in which they occurred, so a slow
+/// webhook delays later notifications, but never the handling of transactions. Failed posts are
+/// logged and not retried. If the webhooks fall behind by [`WEBHOOK_QUEUE_CAPACITY`]
+/// notifications, e.g. because one of them does not respond, further notifications are dropped
+/// with a warning until the queue drains.
+pub(crate) fn spawn_webhook_dispatcher(urls: Vec<String>) -> NotificationListener {
+    let (sender, mut receiver) = mpsc::channel::<DevnetNotification>(WEBHOOK_QUEUE_CAPACITY);
+
+    tokio::spawn(async move {
+        let client = Client::new();
+        while let Some(notification) = receiver.recv().await {
+            let payload = match serde_json::to_vec(&notification) {
+                Ok(payload) => payload,
+                Err(err) => {
+                    warn!("Could not serialize webhook notification: {err}");
+                    continue;
+                }
+            };
+
+            for url in &urls {
+                if let Err(err) = post_notification(&client, url, payload.clone()).await {
+                    warn!("Posting to webhook {url} failed: {err}");
+                }
+            }
+        }
+    });
+
+    Arc::new(move |notification| queue_notification(&sender, notification))
+}
+
+/// Queues `notification` for posting, without waiting for room in the queue
+fn queue_notification(sender: &mpsc::Sender<DevnetNotification>, notification: DevnetNotification) {
+    match sender.try_send(notification) {
+        Ok(()) => (),
+        Err(mpsc::error::TrySendError::Full(_)) => {
+            warn!("Webhook queue is full, dropping notification");
+        }
+        // the receiving task runs until the runtime shuts down
+        Err(mpsc::error::TrySendError::Closed(_)) => (),
+    }
+}
+
+async fn post_notification(
+    client: &Client<HttpConnector>,
+    url: &str,
+    payload: Vec<u8>,
+) -> Result<(), anyhow::Error> {
+    let request =
+        Request::post(url).header(CONTENT_TYPE, "application/json").body(Body::from(payload))?;
+
+    let resp = tokio::time::timeout(WEBHOOK_TIMEOUT, client.request(request))
+        .await
+        .map_err(|_| anyhow!("no response within {} seconds", WEBHOOK_TIMEOUT.as_secs()))??;
+    if !resp.status().is_success() {
+        return Err(anyhow!("responded with {}", resp.status()));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use axum::routing::post;
+    use axum::{Extension, Json, Router};
+    use starknet_core::starknet::notifications::DevnetNotification;
+    use starknet_core::starknet::starknet_config::StarknetConfig;
+    use starknet_core::starknet::Starknet;
+    use starknet_types::felt::Felt;
+    use starknet_types::starknet_api::block::{BlockNumber, BlockTimestamp};
+    use tokio::sync::mpsc;
+
+    use super::{queue_notification, spawn_webhook_dispatcher};
+
+    type Received = Arc<Mutex<Vec<serde_json::Value>>>;
+
+    async fn receive(
+        Extension(received): Extension<Received>,
+        Json(notification): Json<serde_json::Value>,
+    ) {
+        received.lock().unwrap().push(notification);
+    }
+
+    #[tokio::test]
+    async fn notifications_are_posted_in_order() {
+        let received = Received::default();
+        let app = Router::new().route("/", post(receive)).layer(Extension(received.clone()));
+        let server = axum::Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0)))
+            .serve(app.into_make_service());
+        let url = format!("http://{}/", server.local_addr());
+        tokio::spawn(server);
+
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        starknet.set_notification_listener(spawn_webhook_dispatcher(vec![url]));
+        for _ in 0..3 {
+            starknet.create_block(None).unwrap();
+        }
+
+        for _ in 0..50 {
+            if received.lock().unwrap().len() == 3 {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(20)).await;
+        }
+
+        let received = received.lock().unwrap();
+        let block_numbers: Vec<u64> = received
+            .iter()
+            .map(|notification| notification["block_number"].as_u64().unwrap())
+            .collect();
+        assert_eq!(block_numbers, vec![1, 2, 3]);
+        assert!(received.iter().all(|notification| notification["event"] == "block_sealed"));
+    }
+
+    #[tokio::test]
+    async fn notifications_beyond_capacity_are_dropped() {
+        let (sender, mut receiver) = mpsc::channel(1);
+        for block_number in [1, 2] {
+            queue_notification(
+                &sender,
+                DevnetNotification::BlockSealed {
+                    block_number: BlockNumber(block_number),
+                    block_hash: Felt::from(u128::from(block_number)),
+                    timestamp: BlockTimestamp(0),
+                    transaction_hashes: vec![],
+                },
+            );
+        }
+        drop(sender);
+
+        let mut queued = vec![];
+        while let Some(notification) = receiver.recv().await {
+            queued.push(notification);
+        }
+        assert!(
+            matches!(
+                queued.as_slice(),
+                [DevnetNotification::BlockSealed { block_number: BlockNumber(1), .. }]
+            ),
+            "Unexpected notifications: {queued:?}"
+        );
+    }
+}
@@ -55,7 +55,7 @@ pub struct BackgroundDevnet {
     pub http_client: Client<HttpConnector>,
     pub json_rpc_client: JsonRpcClient<HttpTransport>,
     pub process: Child,
-    url: String,
+    pub url: String,
     rpc_url: Url,
 }
 
@@ -114,6 +114,14 @@ impl BackgroundDevnet {
     }
 
     pub(crate) async fn spawn_with_additional_args(args: &[&str]) -> Result<Self, TestError> {
+        Self::spawn_with_additional_args_and_env(args, &[]).await
+    }
+
+    /// Spawns with the provided environment variables set, in addition to the inherited ones
+    pub(crate) async fn spawn_with_additional_args_and_env(
+        args: &[&str],
+        env: &[(&str, &str)],
+    ) -> Result<Self, TestError> {
         // we keep the reference, otherwise the mutex unlocks immediately
         let _mutex_guard = BACKGROUND_DEVNET_MUTEX.lock().await;
 
@@ -130,6 +138,7 @@ impl BackgroundDevnet {
                 .arg("--port")
                 .arg(free_port.to_string())
                 .args(Self::add_default_args(args))
+                .envs(env.iter().copied())
                 .stdout(Stdio::piped()) // comment this out for complete devnet stdout
                 .spawn()
                 .expect("Could not start background devnet");
@@ -180,6 +189,15 @@ impl BackgroundDevnet {
         &self,
         method: &str,
         params: serde_json::Value,
+    ) -> serde_json::Value {
+        self.send_custom_rpc_with_headers(method, params, &[]).await
+    }
+
+    pub async fn send_custom_rpc_with_headers(
+        &self,
+        method: &str,
+        params: serde_json::Value,
+        headers: &[(&str, &str)],
     ) -> serde_json::Value {
         let body_json = json!({
             "jsonrpc": "2.0",
@@ -188,8 +206,16 @@ impl BackgroundDevnet {
             "params": params
         });
 
+        let mut req = request::Request::builder()
+            .method("POST")
+            .uri(format!("{}{RPC_PATH}", self.url.as_str()))
+            .header("content-type", "application/json");
+        for (name, value) in headers {
+            req = req.header(*name, *value);
+        }
+
         let body = hyper::Body::from(body_json.to_string());
-        let resp = self.post_json(RPC_PATH.into(), body).await.unwrap();
+        let resp = self.http_client.request(req.body(body).unwrap()).await.unwrap();
         get_json_body(resp).await
     }
 
@@ -263,6 +289,17 @@ impl BackgroundDevnet {
     pub async fn restart(&self) -> Result<Response<Body>, hyper::Error> {
         self.post_json("/restart".into(), Body::empty()).await
     }
+
+    /// Requests a graceful shutdown, optionally authorized by `token`
+    pub async fn shutdown(&self, token: Option<&str>) -> Result<Response<Body>, hyper::Error> {
+        let mut req = request::Request::builder()
+            .method("POST")
+            .uri(format!("{}/shutdown", self.url.as_str()));
+        if let Some(token) = token {
+            req = req.header("authorization", format!("Bearer {token}"));
+        }
+        self.http_client.request(req.body(Body::empty()).unwrap()).await
+    }
 }
 
 /// By implementing Drop, we ensure there are no zombie background Devnet processes
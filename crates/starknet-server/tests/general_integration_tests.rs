@@ -9,4 +9,12 @@ mod general_integration_tests {
     async fn spawnable() {
         BackgroundDevnet::spawn().await.expect("Could not start Devnet");
     }
+
+    #[tokio::test]
+    /// Asserts that the self-test passes, otherwise Devnet would exit before becoming alive
+    async fn spawnable_with_self_test() {
+        BackgroundDevnet::spawn_with_additional_args(&["--self-test"])
+            .await
+            .expect("Could not start Devnet");
+    }
 }
@@ -2,8 +2,9 @@
 pub mod common;
 
 mod general_rpc_tests {
-    use hyper::Body;
+    use hyper::{Body, StatusCode};
     use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
 
     use crate::common::background_devnet::BackgroundDevnet;
     use crate::common::constants::RPC_PATH;
@@ -82,6 +83,28 @@ mod general_rpc_tests {
         }
     }
 
+    #[tokio::test]
+    async fn method_not_found_lists_supported_methods_and_suggestions() {
+        let devnet = BackgroundDevnet::spawn().await.unwrap();
+        let resp_body = devnet.send_custom_rpc("starknet_specversion", json!([])).await;
+
+        let error_data = &resp_body["error"]["data"];
+        let supported_methods = error_data["supported_methods"].as_array().unwrap();
+        for method in ["starknet_specVersion", "starknet_getEvents", "devnet_compareClasses"] {
+            assert!(supported_methods.contains(&json!(method)), "{method} not in {error_data}");
+        }
+        assert_eq!(error_data["suggestions"][0], "starknet_specVersion");
+    }
+
+    #[tokio::test]
+    async fn method_of_newer_spec_version_gets_suggestions() {
+        let devnet = BackgroundDevnet::spawn().await.unwrap();
+        let resp_body = devnet.send_custom_rpc("starknet_getStorageProof", json!([])).await;
+
+        let suggestions = resp_body["error"]["data"]["suggestions"].as_array().unwrap();
+        assert!(suggestions.contains(&json!("starknet_getStorageAt")), "{resp_body}");
+    }
+
     #[tokio::test]
     async fn rpc_returns_invalid_params() {
         let devnet = BackgroundDevnet::spawn().await.unwrap();
@@ -101,4 +124,66 @@ mod general_rpc_tests {
             _ => panic!("Invalid resp: {resp_body}"),
         }
     }
+
+    #[tokio::test]
+    async fn batch_returns_responses_in_order_of_calls() {
+        let devnet = BackgroundDevnet::spawn().await.unwrap();
+        let tx_hashes = [devnet.mint(0x1_u32, 1).await, devnet.mint(0x2_u32, 1).await];
+
+        let receipt_call = |id: u64, tx_hash: &FieldElement| {
+            json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "method": "starknet_getTransactionReceipt",
+                "params": { "transaction_hash": format!("{tx_hash:#x}") }
+            })
+        };
+        let batch = json!([
+            receipt_call(1, &tx_hashes[1]),
+            { "jsonrpc": "2.0", "id": 2, "method": "invalid_method", "params": [] },
+            receipt_call(3, &tx_hashes[0]),
+        ]);
+        let resp = devnet.post_json(RPC_PATH.into(), Body::from(batch.to_string())).await.unwrap();
+        let resp_body = get_json_body(resp).await;
+
+        let responses = resp_body.as_array().unwrap();
+        assert_eq!(responses.len(), 3, "Invalid resp: {resp_body}");
+        for (response, id, tx_hash) in
+            [(&responses[0], 1, tx_hashes[1]), (&responses[2], 3, tx_hashes[0])]
+        {
+            assert_eq!(response["id"], id);
+            let received_hash = response["result"]["transaction_hash"].as_str().unwrap();
+            assert_eq!(FieldElement::from_hex_be(received_hash).unwrap(), tx_hash);
+        }
+        assert_eq!(responses[1]["id"], 2);
+        assert_eq!(
+            responses[1]["error"]["code"],
+            server::rpc_core::error::ErrorCode::MethodNotFound.code()
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_batch_is_invalid_request() {
+        let devnet = BackgroundDevnet::spawn().await.unwrap();
+
+        let resp = devnet.post_json(RPC_PATH.into(), Body::from("[]")).await.unwrap();
+        let resp_body = get_json_body(resp).await;
+        assert_eq!(
+            resp_body["error"]["code"],
+            server::rpc_core::error::ErrorCode::InvalidRequest.code(),
+            "Invalid resp: {resp_body}"
+        );
+    }
+
+    #[tokio::test]
+    async fn notifications_get_no_response() {
+        let devnet = BackgroundDevnet::spawn().await.unwrap();
+        let notification = json!({ "jsonrpc": "2.0", "method": "starknet_specVersion" });
+
+        for body in [notification.clone(), json!([notification])] {
+            let resp =
+                devnet.post_json(RPC_PATH.into(), Body::from(body.to_string())).await.unwrap();
+            assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        }
+    }
 }
@@ -71,12 +71,47 @@ mod get_transaction_by_block_id_and_index_integration_tests {
         }
     }
 
+    #[tokio::test]
+    async fn get_transaction_of_pending_block_by_index() {
+        // the minting transactions are kept in the pending block
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--block-max-txs", "10"])
+            .await
+            .expect("Could not start Devnet");
+        devnet.mint(FieldElement::ONE, 1).await;
+        let second_tx_hash = devnet.mint(FieldElement::ONE, 1).await;
+
+        let result = devnet
+            .json_rpc_client
+            .get_transaction_by_block_id_and_index(BlockId::Tag(BlockTag::Pending), 1)
+            .await
+            .unwrap();
+        match result {
+            starknet_rs_core::types::Transaction::Invoke(
+                starknet_rs_core::types::InvokeTransaction::V1(invoke_v1),
+            ) => assert_eq!(invoke_v1.transaction_hash, second_tx_hash),
+            _ => panic!("Could not unpack the transaction from {result:?}"),
+        }
+
+        let result = devnet
+            .json_rpc_client
+            .get_transaction_by_block_id_and_index(BlockId::Tag(BlockTag::Pending), 2)
+            .await
+            .unwrap_err();
+        match result {
+            ProviderError::StarknetError(StarknetErrorWithMessage {
+                code: MaybeUnknownErrorCode::Known(StarknetError::InvalidTransactionIndex),
+                ..
+            }) => (),
+            _ => panic!("Invalid error: {result:?}"),
+        }
+    }
+
     #[tokio::test]
     async fn get_transaction_by_block_id_and_index_wrong_block() {
         let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
         let result = devnet
             .json_rpc_client
-            .get_transaction_by_block_id_and_index(BlockId::Tag(BlockTag::Latest), 1)
+            .get_transaction_by_block_id_and_index(BlockId::Number(1), 0)
             .await
             .unwrap_err();
 
@@ -1,21 +1,27 @@
 pub mod common;
 
 mod get_transaction_receipt_by_hash_integration_tests {
+    /// The L1 contract to which the L1-L2 test contract sends its messages
+    const L1_ADDRESS: &str = "0x8359E4B0152ed5A731162D3c7B0D8D56edB165A0";
 
     use std::sync::Arc;
 
     use starknet_core::constants::{CAIRO_0_ACCOUNT_CONTRACT_HASH, ERC20_CONTRACT_ADDRESS};
+    use starknet_core::utils::exported_test_utils::dummy_cairo_l1l2_contract;
     use starknet_rs_accounts::{
         Account, AccountFactory, Call, ExecutionEncoding, OpenZeppelinAccountFactory,
         SingleOwnerAccount,
     };
     use starknet_rs_contract::ContractFactory;
     use starknet_rs_core::chain_id;
+    use starknet_rs_core::types::contract::legacy::LegacyContractClass;
     use starknet_rs_core::types::{
         BroadcastedDeclareTransactionV1, ExecutionResult, FieldElement,
         MaybePendingTransactionReceipt, StarknetError, TransactionReceipt,
     };
-    use starknet_rs_core::utils::{get_selector_from_name, get_udc_deployed_address};
+    use starknet_rs_core::utils::{
+        get_selector_from_name, get_udc_deployed_address, UdcUniqueness,
+    };
     use starknet_rs_providers::{
         MaybeUnknownErrorCode, Provider, ProviderError, StarknetErrorWithMessage,
     };
@@ -297,6 +303,82 @@ mod get_transaction_receipt_by_hash_integration_tests {
         }
     }
 
+    #[tokio::test]
+    async fn invoke_transaction_receipt_lists_messages_sent_to_l1() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let predeployed_account = Arc::new(SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            chain_id::TESTNET,
+            ExecutionEncoding::Legacy,
+        ));
+
+        let contract_artifact: Arc<LegacyContractClass> =
+            Arc::new(serde_json::from_value(dummy_cairo_l1l2_contract().inner).unwrap());
+        let class_hash = contract_artifact.class_hash().unwrap();
+        predeployed_account
+            .declare_legacy(contract_artifact)
+            .max_fee(FieldElement::from(1e18 as u128))
+            .send()
+            .await
+            .unwrap();
+
+        let salt = FieldElement::ZERO;
+        ContractFactory::new(class_hash, predeployed_account.clone())
+            .deploy(vec![], salt, false)
+            .max_fee(FieldElement::from(1e18 as u128))
+            .send()
+            .await
+            .unwrap();
+        let contract_address =
+            get_udc_deployed_address(salt, class_hash, &UdcUniqueness::NotUnique, &[]);
+
+        // withdrawing sends a message to the L1 contract
+        let user = FieldElement::ONE;
+        let withdraw_result = predeployed_account
+            .execute(vec![
+                Call {
+                    to: contract_address,
+                    selector: get_selector_from_name("increase_balance").unwrap(),
+                    calldata: vec![user, FieldElement::from(10_u32)],
+                },
+                Call {
+                    to: contract_address,
+                    selector: get_selector_from_name("withdraw").unwrap(),
+                    calldata: vec![user, FieldElement::from(5_u32)],
+                },
+            ])
+            .max_fee(FieldElement::from(1e18 as u128))
+            .send()
+            .await
+            .unwrap();
+
+        let withdraw_receipt = devnet
+            .json_rpc_client
+            .get_transaction_receipt(withdraw_result.transaction_hash)
+            .await
+            .unwrap();
+
+        match withdraw_receipt {
+            MaybePendingTransactionReceipt::Receipt(TransactionReceipt::Invoke(receipt)) => {
+                assert!(matches!(receipt.execution_result, ExecutionResult::Succeeded));
+                assert_eq!(receipt.messages_sent.len(), 1);
+
+                let message = &receipt.messages_sent[0];
+                assert_eq!(message.from_address, contract_address);
+                assert_eq!(message.to_address, FieldElement::from_hex_be(L1_ADDRESS).unwrap());
+                assert_eq!(
+                    message.payload,
+                    vec![FieldElement::ZERO, user, FieldElement::from(5_u32)]
+                );
+            }
+            _ => panic!("Invalid receipt {:?}", withdraw_receipt),
+        }
+    }
+
     #[tokio::test]
     async fn get_non_existing_transaction() {
         let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
@@ -0,0 +1,81 @@
+pub mod common;
+
+mod account_portfolio_tests {
+    use serde_json::json;
+    use starknet_core::constants::{CAIRO_0_ACCOUNT_CONTRACT_HASH, ERC20_CONTRACT_ADDRESS};
+    use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_core::utils::get_selector_from_name;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::{CHAIN_ID, PREDEPLOYED_ACCOUNT_INITIAL_BALANCE};
+
+    #[tokio::test]
+    async fn portfolio_of_predeployed_account_after_transfers() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        let mut transaction_hashes = vec![];
+        for _ in 0..3 {
+            let result = account
+                .execute(vec![Call {
+                    to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                    selector: get_selector_from_name("transfer").unwrap(),
+                    calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+                }])
+                .max_fee(FieldElement::from(1e18 as u128))
+                .send()
+                .await
+                .unwrap();
+            transaction_hashes.push(result.transaction_hash);
+        }
+
+        let portfolio = &devnet
+            .send_custom_rpc(
+                "devnet_getAccountPortfolio",
+                json!({ "contract_address": format!("{account_address:#x}"), "max_transactions": 2 }),
+            )
+            .await["result"];
+
+        let to_felt =
+            |value: &serde_json::Value| FieldElement::from_hex_be(value.as_str().unwrap()).unwrap();
+        assert_eq!(to_felt(&portfolio["address"]), account_address);
+        assert_eq!(
+            to_felt(&portfolio["class_hash"]),
+            FieldElement::from_hex_be(CAIRO_0_ACCOUNT_CONTRACT_HASH).unwrap()
+        );
+        assert_eq!(to_felt(&portfolio["nonce"]), FieldElement::from(3_u32));
+
+        // most recent first, limited to the requested number
+        let recent_transactions: Vec<_> =
+            portfolio["recent_transactions"].as_array().unwrap().iter().map(to_felt).collect();
+        assert_eq!(recent_transactions, vec![transaction_hashes[2], transaction_hashes[1]]);
+
+        let fee_token_balance = &portfolio["balances"][0];
+        assert_eq!(
+            to_felt(&fee_token_balance["token_address"]),
+            FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap()
+        );
+        assert_eq!(fee_token_balance["unit"], "WEI");
+        let balance = devnet.get_balance(&account_address).await.unwrap();
+        assert_eq!(fee_token_balance["amount"], balance.to_string());
+        assert!(balance < FieldElement::from(PREDEPLOYED_ACCOUNT_INITIAL_BALANCE));
+    }
+
+    #[tokio::test]
+    async fn portfolio_of_undeployed_contract_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc("devnet_getAccountPortfolio", json!({ "contract_address": "0x1234" }))
+            .await;
+        assert_eq!(resp["error"]["code"], 20);
+    }
+}
@@ -0,0 +1,139 @@
+pub mod common;
+
+mod account_templates_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_signers::LocalWallet;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+    use crate::common::utils::{assert_tx_successful, get_deployable_account_signer};
+
+    async fn transfer_from(
+        devnet: &BackgroundDevnet,
+        signer: LocalWallet,
+        account_address: FieldElement,
+    ) -> Result<FieldElement, String> {
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        account
+            .execute(vec![Call {
+                to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                selector: get_selector_from_name("transfer").unwrap(),
+                calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+            }])
+            .max_fee(FieldElement::from(1e18 as u128))
+            .send()
+            .await
+            .map(|result| result.transaction_hash)
+            .map_err(|err| format!("{err:?}"))
+    }
+
+    async fn set_template(
+        devnet: &BackgroundDevnet,
+        account_address: FieldElement,
+        template: serde_json::Value,
+    ) -> serde_json::Value {
+        devnet
+            .send_custom_rpc(
+                "devnet_setAccountTemplate",
+                json!({ "address": format!("{account_address:#x}"), "template": template }),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn always_accepting_account_ignores_signature() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+
+        let resp = set_template(&devnet, account_address, json!({ "name": "always_accept" })).await;
+        assert_eq!(resp["result"]["template"], json!({ "name": "always_accept" }));
+
+        let wrong_signer = get_deployable_account_signer();
+        let transaction_hash = transfer_from(&devnet, wrong_signer, account_address).await.unwrap();
+        assert_tx_successful(&transaction_hash, &devnet.json_rpc_client).await;
+    }
+
+    #[tokio::test]
+    async fn always_rejecting_account_rejects_valid_signature() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+
+        set_template(&devnet, account_address, json!({ "name": "always_reject" })).await;
+        let err = transfer_from(&devnet, signer.clone(), account_address).await.unwrap_err();
+        assert!(err.contains("ValidationFailure"), "Unexpected error: {err}");
+
+        // the standard template restores the validation of the class
+        set_template(&devnet, account_address, json!({ "name": "standard" })).await;
+        let transaction_hash = transfer_from(&devnet, signer, account_address).await.unwrap();
+        assert_tx_successful(&transaction_hash, &devnet.json_rpc_client).await;
+    }
+
+    #[tokio::test]
+    async fn counter_gated_account_rejects_once_counter_is_exhausted() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+
+        set_template(&devnet, account_address, json!({ "name": "counter_gated", "remaining": 2 }))
+            .await;
+        for _ in 0..2 {
+            let transaction_hash =
+                transfer_from(&devnet, signer.clone(), account_address).await.unwrap();
+            assert_tx_successful(&transaction_hash, &devnet.json_rpc_client).await;
+        }
+
+        assert!(transfer_from(&devnet, signer, account_address).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn template_of_other_contract_is_not_set() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = set_template(
+            &devnet,
+            FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+            json!({ "name": "always_accept" }),
+        )
+        .await;
+        assert_eq!(resp["error"]["code"], -1);
+    }
+
+    #[tokio::test]
+    async fn templates_are_scoped_to_requests_with_impersonation_api_key() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--impersonation-api-key", "secret"])
+                .await
+                .expect("Could not start Devnet");
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+
+        let resp = set_template(&devnet, account_address, json!({ "name": "always_accept" })).await;
+        assert_eq!(resp["error"]["code"], -32600, "Invalid resp: {resp}");
+
+        let resp = devnet
+            .send_custom_rpc_with_headers(
+                "devnet_setAccountTemplate",
+                json!({
+                    "address": format!("{account_address:#x}"),
+                    "template": { "name": "always_accept" }
+                }),
+                &[("x-devnet-api-key", "secret")],
+            )
+            .await;
+        assert_eq!(resp["result"]["template"], json!({ "name": "always_accept" }));
+
+        // sent without the key, so the signature is validated
+        let wrong_signer = get_deployable_account_signer();
+        let err = transfer_from(&devnet, wrong_signer, account_address).await.unwrap_err();
+        assert!(err.contains("ValidationFailure"), "Unexpected error: {err}");
+    }
+}
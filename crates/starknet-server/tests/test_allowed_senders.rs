@@ -0,0 +1,59 @@
+pub mod common;
+
+mod allowed_senders_tests {
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::PREDEPLOYED_ACCOUNT_ADDRESS;
+    use crate::common::utils::get_json_body;
+
+    #[tokio::test]
+    async fn transaction_of_sender_not_allowed_is_rejected() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&[
+            "--allowed-senders",
+            PREDEPLOYED_ACCOUNT_ADDRESS,
+        ])
+        .await
+        .expect("Could not start Devnet");
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(config["allowed_senders"].as_array().unwrap().len(), 1);
+
+        let resp = devnet
+            .send_custom_rpc(
+                "starknet_addInvokeTransaction",
+                json!({ "invoke_transaction": {
+                    "type": "INVOKE",
+                    "max_fee": "0xA",
+                    "version": "0x1",
+                    "signature": [],
+                    "nonce": "0x0",
+                    "sender_address": "0x1",
+                    "calldata": []
+                }}),
+            )
+            .await;
+        assert_eq!(
+            resp["error"]["message"],
+            "invoke transaction: sender 0x1 is not allowed to submit transactions",
+            "Invalid resp: {resp}"
+        );
+        assert_eq!(resp["error"]["data"]["devnet_code"], 1011);
+    }
+
+    #[tokio::test]
+    async fn minting_is_not_restricted() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&[
+            "--allowed-senders",
+            PREDEPLOYED_ACCOUNT_ADDRESS,
+        ])
+        .await
+        .expect("Could not start Devnet");
+
+        let address = FieldElement::ONE;
+        devnet.mint(address, 1000).await;
+
+        assert_eq!(devnet.get_balance(&address).await.unwrap(), FieldElement::from(1000_u32));
+    }
+}
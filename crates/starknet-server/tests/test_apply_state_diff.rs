@@ -0,0 +1,111 @@
+pub mod common;
+
+mod apply_state_diff_tests {
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1000;
+
+    #[tokio::test]
+    async fn diff_exported_from_one_instance_is_applied_to_another() {
+        let args = ["--seed", "0"];
+        let origin = BackgroundDevnet::spawn_with_additional_args(&args)
+            .await
+            .expect("Could not start Devnet");
+        origin.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        let state_update = origin
+            .send_custom_rpc("starknet_getStateUpdate", json!({ "block_id": "latest" }))
+            .await;
+        let state_diff = &state_update["result"]["state_diff"];
+
+        let target = BackgroundDevnet::spawn_with_additional_args(&args)
+            .await
+            .expect("Could not start Devnet");
+        let resp = target
+            .send_custom_rpc("devnet_applyStateDiff", json!({ "state_diff": state_diff }))
+            .await;
+        assert_eq!(resp["result"]["block_number"], 1);
+
+        let address = FieldElement::from(DUMMY_ADDRESS);
+        assert_eq!(target.get_balance(&address).await.unwrap(), FieldElement::from(DUMMY_AMOUNT));
+
+        let applied_update = target
+            .send_custom_rpc("starknet_getStateUpdate", json!({ "block_id": "latest" }))
+            .await;
+        assert_eq!(
+            applied_update["result"]["state_diff"]["storage_diffs"]
+                .as_array()
+                .map(|storage_diffs| storage_diffs.len()),
+            state_diff["storage_diffs"].as_array().map(|storage_diffs| storage_diffs.len())
+        );
+    }
+
+    #[tokio::test]
+    async fn diff_with_undeclared_class_is_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_applyStateDiff",
+                json!({ "state_diff": {
+                    "deployed_contracts": [{ "address": "0x1234", "class_hash": "0x5678" }],
+                    "storage_diffs": [],
+                    "declared_classes": [],
+                    "deprecated_declared_classes": [],
+                    "nonces": [],
+                    "replaced_classes": []
+                }}),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 28);
+    }
+
+    #[tokio::test]
+    async fn storage_of_undeployed_contract_is_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_applyStateDiff",
+                json!({ "state_diff": {
+                    "deployed_contracts": [],
+                    "storage_diffs": [{
+                        "address": "0x1234",
+                        "storage_entries": [{ "key": "0x1", "value": "0x2" }]
+                    }],
+                    "declared_classes": [],
+                    "deprecated_declared_classes": [],
+                    "nonces": [],
+                    "replaced_classes": []
+                }}),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 20);
+    }
+
+    #[tokio::test]
+    async fn diff_is_not_applied_without_impersonation_api_key() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--impersonation-api-key", "secret"])
+                .await
+                .expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_applyStateDiff",
+                json!({ "state_diff": {
+                    "deployed_contracts": [],
+                    "storage_diffs": [],
+                    "declared_classes": [],
+                    "deprecated_declared_classes": [],
+                    "nonces": [],
+                    "replaced_classes": []
+                }}),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -32600, "Invalid resp: {resp}");
+    }
+}
@@ -0,0 +1,54 @@
+pub mod common;
+
+mod block_execution_resources_tests {
+    use hyper::Body;
+    use serde_json::json;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    #[tokio::test]
+    async fn resources_of_block_transactions_are_aggregated() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let resources = &devnet
+            .send_custom_rpc(
+                "devnet_getBlockExecutionResources",
+                json!({ "block_id": { "block_number": 1 } }),
+            )
+            .await["result"];
+        assert_eq!(resources["block_number"], 1);
+        assert_eq!(resources["transaction_count"], 1);
+        assert!(resources["steps"].as_u64().unwrap() > 0);
+        assert!(resources["builtin_instance_counter"]["range_check_builtin"].as_u64().unwrap() > 0);
+    }
+
+    #[tokio::test]
+    async fn empty_block_has_no_resources() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.post_json("/create_block".into(), Body::empty()).await.unwrap();
+
+        let resources = &devnet
+            .send_custom_rpc("devnet_getBlockExecutionResources", json!({ "block_id": "latest" }))
+            .await["result"];
+        assert_eq!(resources["transaction_count"], 0);
+        assert_eq!(resources["steps"], 0);
+        assert_eq!(resources["builtin_instance_counter"], json!({}));
+    }
+
+    #[tokio::test]
+    async fn unknown_block_is_reported() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_getBlockExecutionResources",
+                json!({ "block_id": { "block_number": 5 } }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 24);
+    }
+}
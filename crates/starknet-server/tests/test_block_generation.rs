@@ -0,0 +1,84 @@
+pub mod common;
+
+mod block_generation_tests {
+    use hyper::Body;
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::utils::get_json_body;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    async fn get_pending_transaction_hashes(devnet: &BackgroundDevnet) -> Vec<FieldElement> {
+        let resp_body = devnet.send_custom_rpc("starknet_pendingTransactions", json!([])).await;
+        let transactions =
+            resp_body["result"].as_array().unwrap_or_else(|| panic!("Invalid resp: {resp_body}"));
+
+        transactions
+            .iter()
+            .map(|transaction| {
+                FieldElement::from_hex_be(transaction["transaction_hash"].as_str().unwrap())
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn transactions_are_pending_until_block_is_created() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--block-generation-on", "demand"])
+                .await
+                .expect("Could not start Devnet");
+
+        let first_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        let second_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert_eq!(
+            get_pending_transaction_hashes(&devnet).await,
+            vec![first_tx_hash, second_tx_hash]
+        );
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 0);
+
+        devnet.post_json("/create_block".into(), Body::empty()).await.unwrap();
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 1);
+        assert!(get_pending_transaction_hashes(&devnet).await.is_empty());
+
+        let balance = devnet.get_balance(&FieldElement::from(DUMMY_ADDRESS)).await.unwrap();
+        assert_eq!(balance, FieldElement::from(2 * DUMMY_AMOUNT));
+    }
+
+    #[tokio::test]
+    async fn transactions_added_while_mining_is_paused_are_sealed_together_on_resume() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet.post_json("/mining/pause".into(), Body::empty()).await.unwrap();
+        assert_eq!(get_json_body(resp).await, json!({ "paused": true }));
+
+        let first_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        let second_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert_eq!(
+            get_pending_transaction_hashes(&devnet).await,
+            vec![first_tx_hash, second_tx_hash]
+        );
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 0);
+
+        let resp = devnet.post_json("/mining/resume".into(), Body::empty()).await.unwrap();
+        assert_eq!(get_json_body(resp).await, json!({ "paused": false }));
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 1);
+        assert!(get_pending_transaction_hashes(&devnet).await.is_empty());
+
+        // mined per transaction again
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 2);
+    }
+
+    #[tokio::test]
+    async fn pending_transactions_are_empty_when_generating_block_per_transaction() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert!(get_pending_transaction_hashes(&devnet).await.is_empty());
+    }
+}
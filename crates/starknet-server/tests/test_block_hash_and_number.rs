@@ -0,0 +1,49 @@
+pub mod common;
+
+mod block_hash_and_number_tests {
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement, MaybePendingBlockWithTxHashes};
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    async fn get_latest_block(devnet: &BackgroundDevnet) -> (FieldElement, u64, Vec<FieldElement>) {
+        match devnet
+            .json_rpc_client
+            .get_block_with_tx_hashes(BlockId::Tag(BlockTag::Latest))
+            .await
+            .unwrap()
+        {
+            MaybePendingBlockWithTxHashes::Block(block) => {
+                (block.block_hash, block.block_number, block.transactions)
+            }
+            other => panic!("Invalid block: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn genesis_block_is_returned_on_fresh_devnet() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let latest = devnet.json_rpc_client.block_hash_and_number().await.unwrap();
+        assert_eq!(latest.block_number, 0);
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 0);
+
+        let (block_hash, block_number, transactions) = get_latest_block(&devnet).await;
+        assert_eq!(latest.block_hash, block_hash);
+        assert_eq!(block_number, 0);
+        assert!(transactions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn latest_block_is_returned_after_mint() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_hash = devnet.mint(FieldElement::ONE, 1).await;
+
+        let latest = devnet.json_rpc_client.block_hash_and_number().await.unwrap();
+        assert_eq!(latest.block_number, 1);
+
+        let (block_hash, _, transactions) = get_latest_block(&devnet).await;
+        assert_eq!(latest.block_hash, block_hash);
+        assert_eq!(transactions, vec![mint_hash]);
+    }
+}
@@ -0,0 +1,85 @@
+pub mod common;
+
+mod block_limits_tests {
+    use hyper::Body;
+    use serde_json::json;
+    use starknet_rs_core::types::{FieldElement, MaybePendingTransactionReceipt};
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    async fn is_pending(devnet: &BackgroundDevnet, transaction_hash: FieldElement) -> bool {
+        match devnet.json_rpc_client.get_transaction_receipt(transaction_hash).await.unwrap() {
+            MaybePendingTransactionReceipt::PendingReceipt(_) => true,
+            MaybePendingTransactionReceipt::Receipt(_) => false,
+        }
+    }
+
+    #[tokio::test]
+    async fn transactions_are_deferred_when_max_txs_reached() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--block-max-txs", "2"])
+            .await
+            .expect("Could not start Devnet");
+
+        let first_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert!(is_pending(&devnet, first_tx_hash).await);
+
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert!(!is_pending(&devnet, first_tx_hash).await);
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 1);
+
+        // the third transaction goes to the next block, which is mined on request
+        let third_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert!(is_pending(&devnet, third_tx_hash).await);
+
+        devnet.post_json("/create_block".into(), Body::empty()).await.unwrap();
+        assert!(!is_pending(&devnet, third_tx_hash).await);
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 2);
+
+        let balance = devnet.get_balance(&FieldElement::from(DUMMY_ADDRESS)).await.unwrap();
+        assert_eq!(balance, FieldElement::from(3 * DUMMY_AMOUNT));
+    }
+
+    #[tokio::test]
+    async fn transaction_exceeding_max_steps_opens_the_next_block() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        let resources = &devnet
+            .send_custom_rpc(
+                "devnet_getBlockExecutionResources",
+                json!({ "block_id": { "block_number": 1 } }),
+            )
+            .await["result"];
+        let mint_steps = resources["steps"].as_u64().unwrap();
+
+        // one mint fits, two do not
+        let max_steps = (mint_steps + mint_steps / 2).to_string();
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--block-max-steps", &max_steps])
+                .await
+                .expect("Could not start Devnet");
+
+        let first_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert!(is_pending(&devnet, first_tx_hash).await);
+
+        // the pending block is sealed before the second mint, which would exceed the limit
+        let second_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert!(!is_pending(&devnet, first_tx_hash).await);
+        assert!(is_pending(&devnet, second_tx_hash).await);
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn block_is_mined_when_max_steps_reached() {
+        // a single mint exceeds the limit, but is still accepted
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--block-max-steps", "1"])
+            .await
+            .expect("Could not start Devnet");
+
+        let tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        assert!(!is_pending(&devnet, tx_hash).await);
+    }
+}
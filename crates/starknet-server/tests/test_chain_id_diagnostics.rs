@@ -0,0 +1,80 @@
+pub mod common;
+
+mod chain_id_diagnostics_tests {
+    use serde_json::json;
+    use starknet_core::constants::CAIRO_0_ACCOUNT_CONTRACT_HASH;
+    use starknet_rs_accounts::{AccountFactory, OpenZeppelinAccountFactory};
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_signers::Signer;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+    use crate::common::utils::{get_deployable_account_signer, iter_to_hex_felt, to_hex_felt};
+
+    /// Sends a deployment of a new account, signed for `chain_id`
+    async fn deploy_account_signed_for(
+        devnet: &BackgroundDevnet,
+        chain_id: FieldElement,
+    ) -> serde_json::Value {
+        let signer = get_deployable_account_signer();
+        let account_factory = OpenZeppelinAccountFactory::new(
+            FieldElement::from_hex_be(CAIRO_0_ACCOUNT_CONTRACT_HASH).unwrap(),
+            chain_id,
+            signer.clone(),
+            devnet.clone_provider(),
+        )
+        .await
+        .unwrap();
+
+        let salt = FieldElement::from_hex_be("0x123").unwrap();
+        let max_fee = FieldElement::from(1e18 as u128);
+        let deployment = account_factory
+            .deploy(salt)
+            .max_fee(max_fee)
+            .nonce(FieldElement::ZERO)
+            .prepared()
+            .unwrap();
+        devnet.mint(deployment.address(), 1e18 as u128).await;
+
+        let signature = signer.sign_hash(&deployment.transaction_hash()).await.unwrap();
+        let public_key = signer.get_public_key().await.unwrap().scalar();
+        devnet
+            .send_custom_rpc(
+                "starknet_addDeployAccountTransaction",
+                json!({
+                    "deploy_account_transaction": {
+                        "type": "DEPLOY_ACCOUNT",
+                        "max_fee": to_hex_felt(&max_fee),
+                        "version": "0x1",
+                        "signature": iter_to_hex_felt(&[signature.r, signature.s]),
+                        "nonce": "0x0",
+                        "contract_address_salt": to_hex_felt(&salt),
+                        "constructor_calldata": [to_hex_felt(&public_key)],
+                        "class_hash": CAIRO_0_ACCOUNT_CONTRACT_HASH
+                    }
+                }),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn transaction_signed_for_other_chain_id_is_diagnosed() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = deploy_account_signed_for(&devnet, starknet_rs_core::chain_id::MAINNET).await;
+
+        assert_eq!(resp["error"]["code"], 55);
+        let data = &resp["error"]["data"];
+        assert_eq!(data["chain_id"], "SN_GOERLI");
+        assert_eq!(data["matching_chain_id"], "SN_MAIN");
+    }
+
+    #[tokio::test]
+    async fn transaction_signed_for_devnet_chain_id_is_accepted() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = deploy_account_signed_for(&devnet, CHAIN_ID).await;
+
+        assert!(resp["result"]["transaction_hash"].is_string(), "Unexpected response: {resp}");
+    }
+}
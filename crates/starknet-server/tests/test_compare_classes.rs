@@ -0,0 +1,72 @@
+pub mod common;
+
+mod compare_classes_tests {
+    use serde_json::json;
+    use starknet_core::constants::{CAIRO_0_ACCOUNT_CONTRACT_HASH, ERC20_CONTRACT_CLASS_HASH};
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    #[tokio::test]
+    async fn same_class_is_identical() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let class_diff = &devnet
+            .send_custom_rpc(
+                "devnet_compareClasses",
+                json!({
+                    "class_hash_a": ERC20_CONTRACT_CLASS_HASH,
+                    "class_hash_b": ERC20_CONTRACT_CLASS_HASH
+                }),
+            )
+            .await["result"];
+
+        assert_eq!(class_diff["identical"], true);
+        assert_eq!(class_diff["abi"]["added"], json!([]));
+        assert_eq!(class_diff["abi"]["removed"], json!([]));
+        assert_eq!(class_diff["abi"]["changed"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn different_classes_are_diffed() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let class_diff = &devnet
+            .send_custom_rpc(
+                "devnet_compareClasses",
+                json!({
+                    "class_hash_a": CAIRO_0_ACCOUNT_CONTRACT_HASH,
+                    "class_hash_b": ERC20_CONTRACT_CLASS_HASH
+                }),
+            )
+            .await["result"];
+
+        assert_eq!(class_diff["identical"], false);
+
+        let added_abi_entries = class_diff["abi"]["added"].as_array().unwrap();
+        assert!(added_abi_entries.iter().any(|entry| entry["name"] == "transfer"));
+        let removed_abi_entries = class_diff["abi"]["removed"].as_array().unwrap();
+        assert!(removed_abi_entries.iter().any(|entry| entry["name"] == "__execute__"));
+
+        assert!(!class_diff["entry_points_by_type"]["EXTERNAL"]["added"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn undeclared_class_is_reported() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_compareClasses",
+                json!({
+                    "class_hash_a": ERC20_CONTRACT_CLASS_HASH,
+                    "class_hash_b": "0x123"
+                }),
+            )
+            .await;
+
+        assert_eq!(resp["error"]["code"], 28);
+    }
+}
@@ -0,0 +1,57 @@
+pub mod common;
+
+mod compat_check_tests {
+    use std::time::Duration;
+
+    use starknet_core::constants::CHARGEABLE_ACCOUNT_ADDRESS;
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement};
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    /// Declare, deploy, invoke and multicall are sent in one transaction each
+    const CHECK_TRANSACTIONS: u32 = 4;
+
+    /// The check runs once Devnet is listening, so its transactions are waited for
+    async fn assert_check_transactions_sent(devnet: &BackgroundDevnet) {
+        let chargeable_address = FieldElement::from_hex_be(CHARGEABLE_ACCOUNT_ADDRESS).unwrap();
+        for _ in 0..100 {
+            let nonce = devnet
+                .json_rpc_client
+                .get_nonce(BlockId::Tag(BlockTag::Latest), chargeable_address)
+                .await
+                .unwrap();
+            if nonce == FieldElement::from(CHECK_TRANSACTIONS) {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        panic!("Transactions of the compatibility check were not sent");
+    }
+
+    #[tokio::test]
+    async fn starkli_sequence_succeeds() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--compat-check", "starkli"])
+            .await
+            .expect("Could not start Devnet");
+
+        assert_check_transactions_sent(&devnet).await;
+    }
+
+    #[tokio::test]
+    async fn sncast_sequence_succeeds() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--compat-check", "sncast"])
+            .await
+            .expect("Could not start Devnet");
+
+        assert_check_transactions_sent(&devnet).await;
+    }
+
+    #[tokio::test]
+    async fn unknown_tool_is_rejected() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--compat-check", "unknown"]).await;
+        assert!(devnet.is_err());
+    }
+}
@@ -0,0 +1,58 @@
+pub mod common;
+
+mod compile_sierra_tests {
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CAIRO_1_CONTRACT_PATH;
+    use crate::common::utils::{get_flattened_sierra_contract_and_casm_hash, load_json};
+
+    #[tokio::test]
+    async fn compiled_class_hash_matches_declared_one() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let artifact: serde_json::Value = load_json(CAIRO_1_CONTRACT_PATH);
+
+        let compiled = &devnet
+            .send_custom_rpc("devnet_compileSierra", json!({ "artifact": artifact }))
+            .await["result"];
+
+        let (flattened_contract_artifact, casm_hash) =
+            get_flattened_sierra_contract_and_casm_hash(CAIRO_1_CONTRACT_PATH);
+        let to_felt =
+            |value: &serde_json::Value| FieldElement::from_hex_be(value.as_str().unwrap()).unwrap();
+        assert_eq!(to_felt(&compiled["compiled_class_hash"]), casm_hash);
+        assert_eq!(to_felt(&compiled["class_hash"]), flattened_contract_artifact.class_hash());
+        assert!(compiled["compiler_version"].as_str().unwrap().starts_with('2'));
+        assert!(!compiled["casm"]["bytecode"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn artifact_in_rpc_format_is_accepted() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (flattened_contract_artifact, casm_hash) =
+            get_flattened_sierra_contract_and_casm_hash(CAIRO_1_CONTRACT_PATH);
+
+        let compiled = &devnet
+            .send_custom_rpc(
+                "devnet_compileSierra",
+                json!({ "artifact": flattened_contract_artifact }),
+            )
+            .await["result"];
+        assert_eq!(
+            FieldElement::from_hex_be(compiled["compiled_class_hash"].as_str().unwrap()).unwrap(),
+            casm_hash
+        );
+    }
+
+    #[tokio::test]
+    async fn invalid_sierra_program_is_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mut artifact: serde_json::Value = load_json(CAIRO_1_CONTRACT_PATH);
+        artifact["sierra_program"] = json!(["0x1"]);
+
+        let resp =
+            devnet.send_custom_rpc("devnet_compileSierra", json!({ "artifact": artifact })).await;
+        assert_eq!(resp["error"]["code"], -32602, "{resp}");
+    }
+}
@@ -0,0 +1,73 @@
+pub mod common;
+
+mod compression_tests {
+    use std::io::Read;
+
+    use hyper::header::{ACCEPT_ENCODING, CONTENT_ENCODING};
+    use hyper::{Body, Request, Response, StatusCode};
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::utils::get_json_body;
+
+    /// The predeployed accounts, whose serialization is a few kilobytes long
+    async fn get_predeployed_accounts(
+        devnet: &BackgroundDevnet,
+        accept_encoding: Option<&str>,
+    ) -> Response<Body> {
+        let mut req =
+            Request::builder().method("GET").uri(format!("{}/predeployed_accounts", devnet.url));
+        if let Some(accept_encoding) = accept_encoding {
+            req = req.header(ACCEPT_ENCODING, accept_encoding);
+        }
+
+        devnet.http_client.request(req.body(Body::empty()).unwrap()).await.unwrap()
+    }
+
+    async fn body_bytes(resp: Response<Body>) -> Vec<u8> {
+        hyper::body::to_bytes(resp.into_body()).await.unwrap().to_vec()
+    }
+
+    #[tokio::test]
+    async fn response_is_compressed_if_accepted() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--compression-min-size", "1024"])
+                .await
+                .expect("Could not start Devnet");
+
+        let resp = get_predeployed_accounts(&devnet, Some("gzip")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get(CONTENT_ENCODING).unwrap(), "gzip");
+        let compressed = body_bytes(resp).await;
+
+        let mut decompressed = String::new();
+        flate2::read::GzDecoder::new(compressed.as_slice())
+            .read_to_string(&mut decompressed)
+            .unwrap();
+        let uncompressed = body_bytes(get_predeployed_accounts(&devnet, None).await).await;
+        assert_eq!(decompressed.as_bytes(), uncompressed.as_slice());
+        assert!(compressed.len() < uncompressed.len());
+    }
+
+    #[tokio::test]
+    async fn small_response_is_not_compressed() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--compression-min-size", "1024"])
+                .await
+                .expect("Could not start Devnet");
+
+        let resp = devnet.get("/is_alive", None).await.unwrap();
+        assert!(resp.headers().get(CONTENT_ENCODING).is_none());
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(config["compression_min_size"], 1024);
+    }
+
+    #[tokio::test]
+    async fn responses_are_not_compressed_by_default() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = get_predeployed_accounts(&devnet, Some("gzip, deflate")).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(resp.headers().get(CONTENT_ENCODING).is_none());
+    }
+}
@@ -0,0 +1,50 @@
+pub mod common;
+
+mod config_tests {
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::SEED;
+    use crate::common::utils::get_json_body;
+
+    #[tokio::test]
+    async fn resolved_config_is_returned() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&[
+            "--block-max-txs",
+            "2",
+            "--shutdown-token",
+            "secret",
+        ])
+        .await
+        .expect("Could not start Devnet");
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(config["seed"], SEED);
+        assert_eq!(config["chain_id"], "TESTNET");
+        assert_eq!(config["block_max_txs"], 2);
+        assert_eq!(config["block_max_steps"], serde_json::Value::Null);
+        assert_eq!(config["block_generation_on"], "transaction");
+        assert_eq!(config["dump_on"], serde_json::Value::Null);
+        assert_eq!(config["base_dump"], serde_json::Value::Null);
+        assert_eq!(config["shutdown_enabled"], true);
+        assert!(config.get("shutdown_token").is_none());
+    }
+
+    #[tokio::test]
+    async fn environment_variables_are_used_unless_overridden_by_cli() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args_and_env(
+            &["--gas-price", "7"],
+            &[("STARKNET_DEVNET_GAS_PRICE", "5"), ("STARKNET_DEVNET_BLOCK_MAX_STEPS", "1000")],
+        )
+        .await
+        .expect("Could not start Devnet");
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(config["gas_price"], 7);
+        assert_eq!(config["block_max_steps"], 1000);
+        assert_eq!(config["shutdown_enabled"], false);
+        assert_eq!(config["impersonation_scoped"], false);
+        assert_eq!(config["compression_min_size"], serde_json::Value::Null);
+        assert_eq!(config["record_session"], false);
+        assert_eq!(config["disabled_methods"], serde_json::json!([]));
+        assert_eq!(config["allowed_senders"], serde_json::json!([]));
+    }
+}
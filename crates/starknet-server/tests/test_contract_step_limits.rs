@@ -0,0 +1,108 @@
+pub mod common;
+
+mod contract_step_limits_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{
+        Account, AccountError, Call, ExecutionEncoding, SingleOwnerAccount,
+    };
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement, FunctionCall, StarknetError};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::{
+        MaybeUnknownErrorCode, Provider, ProviderError, StarknetErrorWithMessage,
+    };
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+
+    async fn set_erc20_step_limit(devnet: &BackgroundDevnet, steps: Option<u64>) {
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_setContractStepLimit",
+                json!({ "address": ERC20_CONTRACT_ADDRESS, "steps": steps }),
+            )
+            .await;
+        assert_eq!(resp["result"]["steps"], json!(steps));
+    }
+
+    #[tokio::test]
+    async fn call_exceeding_step_limit_fails() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+        let balance_call = FunctionCall {
+            contract_address: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+            entry_point_selector: get_selector_from_name("balanceOf").unwrap(),
+            calldata: vec![account_address],
+        };
+        let latest = BlockId::Tag(BlockTag::Latest);
+
+        set_erc20_step_limit(&devnet, Some(1)).await;
+        match devnet.json_rpc_client.call(balance_call.clone(), latest).await {
+            Err(ProviderError::StarknetError(StarknetErrorWithMessage {
+                code: MaybeUnknownErrorCode::Known(StarknetError::ContractError),
+                message,
+            })) => assert!(message.contains("step limit"), "{message}"),
+            other => panic!("Invalid result: {other:?}"),
+        }
+
+        set_erc20_step_limit(&devnet, None).await;
+        devnet.json_rpc_client.call(balance_call, latest).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn invoke_exceeding_step_limit_is_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+        let transfer = || {
+            account
+                .execute(vec![Call {
+                    to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                    selector: get_selector_from_name("transfer").unwrap(),
+                    calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+                }])
+                .max_fee(FieldElement::from(1e18 as u128))
+        };
+
+        set_erc20_step_limit(&devnet, Some(1)).await;
+        match transfer().send().await {
+            Err(AccountError::Provider(ProviderError::StarknetError(
+                StarknetErrorWithMessage {
+                    code: MaybeUnknownErrorCode::Known(StarknetError::ContractError),
+                    ..
+                },
+            ))) => (),
+            other => panic!("Invalid result: {other:?}"),
+        }
+        assert_eq!(
+            devnet
+                .json_rpc_client
+                .get_nonce(BlockId::Tag(BlockTag::Pending), account_address)
+                .await
+                .unwrap(),
+            FieldElement::ZERO
+        );
+
+        set_erc20_step_limit(&devnet, None).await;
+        transfer().send().await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn limit_of_undeployed_contract_is_not_set() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_setContractStepLimit",
+                json!({ "address": "0x1234", "steps": 100 }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 20);
+    }
+}
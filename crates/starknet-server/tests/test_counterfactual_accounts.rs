@@ -0,0 +1,101 @@
+pub mod common;
+
+mod counterfactual_accounts_tests {
+    use serde_json::json;
+    use starknet_core::constants::{CAIRO_0_ACCOUNT_CONTRACT_HASH, ERC20_CONTRACT_ADDRESS};
+    use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::Provider;
+    use starknet_rs_signers::Signer;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+    use crate::common::utils::{assert_tx_successful, get_deployable_account_signer, to_hex_felt};
+
+    #[tokio::test]
+    async fn registered_account_is_deployed_on_first_invoke() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let signer = get_deployable_account_signer();
+        let public_key = signer.get_public_key().await.unwrap().scalar();
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_registerCounterfactualAccount",
+                json!({
+                    "class_hash": CAIRO_0_ACCOUNT_CONTRACT_HASH,
+                    "contract_address_salt": "0x123",
+                    "constructor_calldata": [to_hex_felt(&public_key)],
+                }),
+            )
+            .await;
+        let account_address =
+            FieldElement::from_hex_be(resp["result"]["address"].as_str().unwrap()).unwrap();
+        devnet.mint(account_address, 1e18 as u128).await;
+
+        // the deploy account transaction sent by Devnet does not use up a nonce, so the wallet
+        // sends the invoke with the nonce reported for the undeployed account
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+        let result = account
+            .execute(vec![Call {
+                to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                selector: get_selector_from_name("transfer").unwrap(),
+                calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+            }])
+            .max_fee(FieldElement::from(1e17 as u128))
+            .send()
+            .await
+            .unwrap();
+        assert_tx_successful(&result.transaction_hash, &devnet.json_rpc_client).await;
+
+        let class_hash = devnet
+            .json_rpc_client
+            .get_class_hash_at(BlockId::Tag(BlockTag::Latest), account_address)
+            .await
+            .unwrap();
+        assert_eq!(class_hash, FieldElement::from_hex_be(CAIRO_0_ACCOUNT_CONTRACT_HASH).unwrap());
+    }
+
+    #[tokio::test]
+    async fn account_of_undeclared_class_is_not_registered() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_registerCounterfactualAccount",
+                json!({
+                    "class_hash": "0x1",
+                    "contract_address_salt": "0x123",
+                    "constructor_calldata": [],
+                }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 28);
+    }
+
+    #[tokio::test]
+    async fn account_is_not_registered_without_impersonation_api_key() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--impersonation-api-key", "secret"])
+                .await
+                .expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_registerCounterfactualAccount",
+                json!({
+                    "class_hash": CAIRO_0_ACCOUNT_CONTRACT_HASH,
+                    "contract_address_salt": "0x123",
+                    "constructor_calldata": ["0x1"],
+                }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -32600, "Invalid resp: {resp}");
+    }
+}
@@ -0,0 +1,81 @@
+pub mod common;
+
+mod custom_genesis_tests {
+    use hyper::{Body, StatusCode};
+    use serde_json::json;
+    use starknet_core::constants::{
+        CAIRO_0_ACCOUNT_CONTRACT_HASH, CAIRO_0_ACCOUNT_CONTRACT_PATH, ERC20_CONTRACT_ADDRESS,
+    };
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement};
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::utils::{get_json_body, UniqueAutoDeletableFile};
+
+    const GENESIS_CONTRACT_ADDRESS: &str = "0x1234";
+
+    async fn spawn_with_custom_genesis(genesis_file: &UniqueAutoDeletableFile) -> BackgroundDevnet {
+        let genesis = json!({
+            "classes": [CAIRO_0_ACCOUNT_CONTRACT_PATH],
+            "contracts": [{
+                "name": "Custom",
+                "address": GENESIS_CONTRACT_ADDRESS,
+                "class_hash": CAIRO_0_ACCOUNT_CONTRACT_HASH,
+                "storage": [{ "key": "0x5", "value": "0x7" }],
+                "nonce": "0x2"
+            }]
+        });
+        std::fs::write(&genesis_file.path, genesis.to_string()).unwrap();
+
+        BackgroundDevnet::spawn_with_additional_args(&["--genesis-file", &genesis_file.path])
+            .await
+            .expect("Could not start Devnet")
+    }
+
+    #[tokio::test]
+    async fn genesis_contracts_are_deployed() {
+        let genesis_file = UniqueAutoDeletableFile::new("custom_genesis");
+        let devnet = spawn_with_custom_genesis(&genesis_file).await;
+        let address = FieldElement::from_hex_be(GENESIS_CONTRACT_ADDRESS).unwrap();
+        let block_id = BlockId::Tag(BlockTag::Latest);
+
+        let class_hash = devnet.json_rpc_client.get_class_hash_at(block_id, address).await.unwrap();
+        assert_eq!(class_hash, FieldElement::from_hex_be(CAIRO_0_ACCOUNT_CONTRACT_HASH).unwrap());
+
+        let nonce = devnet.json_rpc_client.get_nonce(block_id, address).await.unwrap();
+        assert_eq!(nonce, FieldElement::TWO);
+
+        let storage_value = devnet
+            .json_rpc_client
+            .get_storage_at(address, FieldElement::from(5_u32), block_id)
+            .await
+            .unwrap();
+        assert_eq!(storage_value, FieldElement::from(7_u32));
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(config["custom_genesis"], true);
+    }
+
+    #[tokio::test]
+    async fn canonical_contracts_are_not_predeployed() {
+        let genesis_file = UniqueAutoDeletableFile::new("custom_genesis");
+        let devnet = spawn_with_custom_genesis(&genesis_file).await;
+
+        devnet
+            .json_rpc_client
+            .get_class_hash_at(
+                BlockId::Tag(BlockTag::Latest),
+                FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+            )
+            .await
+            .expect_err("Fee token should not be deployed");
+
+        let predeployed_accounts =
+            get_json_body(devnet.get("/predeployed_accounts", None).await.unwrap()).await;
+        assert_eq!(predeployed_accounts, json!([]));
+
+        let mint_body = json!({ "address": GENESIS_CONTRACT_ADDRESS, "amount": 1 }).to_string();
+        let resp = devnet.post_json("/mint".into(), Body::from(mint_body)).await.unwrap();
+        assert_ne!(resp.status(), StatusCode::OK);
+    }
+}
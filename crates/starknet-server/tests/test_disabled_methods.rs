@@ -0,0 +1,98 @@
+pub mod common;
+
+mod disabled_methods_tests {
+    use hyper::Body;
+    use serde_json::json;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::utils::get_json_body;
+
+    #[tokio::test]
+    async fn disabled_method_is_not_supported() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&[
+            "--disabled-methods",
+            "starknet_blockNumber,starknet_getEvents",
+        ])
+        .await
+        .expect("Could not start Devnet");
+
+        let resp = devnet.send_custom_rpc("starknet_blockNumber", json!([])).await;
+        assert_eq!(
+            resp["error"],
+            json!({
+                "code": -32601,
+                "message": "Method not supported: starknet_blockNumber is disabled",
+                "data": { "devnet_code": 1004 }
+            })
+        );
+
+        let resp = devnet.send_custom_rpc("starknet_chainId", json!([])).await;
+        assert!(resp["result"].is_string(), "Invalid resp: {resp}");
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(
+            config["disabled_methods"],
+            json!(["starknet_blockNumber", "starknet_getEvents"])
+        );
+    }
+
+    #[tokio::test]
+    async fn disabled_method_in_batch_is_not_supported() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&[
+            "--disabled-methods",
+            "starknet_chainId",
+        ])
+        .await
+        .expect("Could not start Devnet");
+
+        let batch = json!([
+            { "jsonrpc": "2.0", "id": 0, "method": "starknet_chainId" },
+            { "jsonrpc": "2.0", "id": 1, "method": "starknet_blockNumber" }
+        ]);
+        let resp = devnet.post_json("/rpc".into(), Body::from(batch.to_string())).await.unwrap();
+        let responses = get_json_body(resp).await;
+
+        assert_eq!(responses[0]["error"]["code"], -32601, "Invalid resp: {responses}");
+        assert!(responses[1]["result"].is_number(), "Invalid resp: {responses}");
+    }
+
+    #[tokio::test]
+    async fn disabled_methods_are_replaced_at_runtime() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_setDisabledMethods",
+                json!({ "methods": ["starknet_chainId"] }),
+            )
+            .await;
+        assert_eq!(resp["result"], json!({ "methods": ["starknet_chainId"] }));
+        let resp = devnet.send_custom_rpc("starknet_chainId", json!([])).await;
+        assert_eq!(resp["error"]["code"], -32601, "Invalid resp: {resp}");
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(config["disabled_methods"], json!(["starknet_chainId"]));
+
+        devnet.send_custom_rpc("devnet_setDisabledMethods", json!({ "methods": [] })).await;
+        let resp = devnet.send_custom_rpc("starknet_chainId", json!([])).await;
+        assert!(resp["result"].is_string(), "Invalid resp: {resp}");
+    }
+
+    #[tokio::test]
+    async fn unknown_methods_cannot_be_disabled() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_setDisabledMethods",
+                json!({ "methods": ["starknet_chainId", "starknet_getEvent"] }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -32602, "Invalid resp: {resp}");
+        assert_eq!(resp["error"]["message"], "Unknown JSON-RPC method: starknet_getEvent");
+
+        // the list is not partly applied
+        let resp = devnet.send_custom_rpc("starknet_chainId", json!([])).await;
+        assert!(resp["result"].is_string(), "Invalid resp: {resp}");
+    }
+}
@@ -9,7 +9,8 @@ mod dump_and_load_tests {
     use starknet_rs_providers::Provider;
 
     use crate::common::background_devnet::BackgroundDevnet;
-    use crate::common::utils::{send_ctrl_c_signal, UniqueAutoDeletableFile};
+    use crate::common::constants::{ACCOUNTS, PREDEPLOYED_ACCOUNT_INITIAL_BALANCE, SEED};
+    use crate::common::utils::{get_json_body, send_ctrl_c_signal, UniqueAutoDeletableFile};
 
     static DUMMY_ADDRESS: u128 = 1;
     static DUMMY_AMOUNT: u128 = 1;
@@ -333,4 +334,172 @@ mod dump_and_load_tests {
             panic!("Could not unpack the transaction from {loaded_transaction:?}");
         }
     }
+
+    #[tokio::test]
+    async fn load_dump_of_incompatible_version_by_replaying_transactions() {
+        let dump_file = UniqueAutoDeletableFile::new("dump_incompatible_version");
+        let devnet_dump =
+            BackgroundDevnet::spawn_with_additional_args(&["--dump-path", &dump_file.path])
+                .await
+                .expect("Could not start Devnet");
+        devnet_dump.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        devnet_dump.post_json("/dump".into(), Body::from(json!({}).to_string())).await.unwrap();
+
+        // simulate a dump of a version storing additional properties of transactions
+        let mut dump: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&dump_file.path).unwrap()).unwrap();
+        for transaction in dump.as_array_mut().unwrap() {
+            transaction["property_of_another_version"] = json!("0x1");
+        }
+        std::fs::write(&dump_file.path, dump.to_string()).unwrap();
+
+        let devnet_load =
+            BackgroundDevnet::spawn_with_additional_args(&["--dump-path", &dump_file.path])
+                .await
+                .expect("Could not start Devnet");
+        let balance_result =
+            devnet_load.get_balance(&FieldElement::from(DUMMY_ADDRESS)).await.unwrap();
+        assert_eq!(balance_result, DUMMY_AMOUNT.into());
+    }
+
+    #[tokio::test]
+    async fn mint_dump_in_binary_format_and_load() {
+        let dump_file = UniqueAutoDeletableFile::new("dump_binary");
+        let devnet_dump = BackgroundDevnet::spawn_with_additional_args(&[
+            "--dump-path",
+            &dump_file.path,
+            "--dump-on",
+            "transaction",
+            "--dump-format",
+            "binary",
+        ])
+        .await
+        .expect("Could not start Devnet");
+        devnet_dump.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        let mint_tx_hash = devnet_dump.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        // the dump is loaded without specifying its format
+        assert!(serde_json::from_slice::<serde_json::Value>(
+            &std::fs::read(&dump_file.path).unwrap()
+        )
+        .is_err());
+        let devnet_load =
+            BackgroundDevnet::spawn_with_additional_args(&["--dump-path", &dump_file.path])
+                .await
+                .expect("Could not start Devnet");
+
+        let balance_result =
+            devnet_load.get_balance(&FieldElement::from(DUMMY_ADDRESS)).await.unwrap();
+        assert_eq!(balance_result, (2 * DUMMY_AMOUNT).into());
+        let loaded_transaction =
+            devnet_load.json_rpc_client.get_transaction_by_hash(mint_tx_hash).await.unwrap();
+        if let starknet_rs_core::types::Transaction::Invoke(
+            starknet_rs_core::types::InvokeTransaction::V1(invoke_v1),
+        ) = loaded_transaction
+        {
+            assert_eq!(invoke_v1.transaction_hash, mint_tx_hash);
+        } else {
+            panic!("Could not unpack the transaction from {loaded_transaction:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn converted_dump_is_loaded() {
+        let json_dump_file = UniqueAutoDeletableFile::new("dump_to_convert");
+        let devnet_dump =
+            BackgroundDevnet::spawn_with_additional_args(&["--dump-path", &json_dump_file.path])
+                .await
+                .expect("Could not start Devnet");
+        let mint_tx_hash = devnet_dump.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        devnet_dump.post_json("/dump".into(), Body::from(json!({}).to_string())).await.unwrap();
+
+        let binary_dump_file = UniqueAutoDeletableFile::new("dump_converted");
+        let status = std::process::Command::new("cargo")
+            .args(["run", "--release", "--", "convert-dump"])
+            .args(["--input", &json_dump_file.path, "--output", &binary_dump_file.path])
+            .args(["--format", "binary"])
+            .status()
+            .expect("Could not convert dump");
+        assert!(status.success());
+        assert!(Path::new(&binary_dump_file.path).exists());
+
+        let devnet_load = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let load_body = Body::from(json!({ "path": binary_dump_file.path }).to_string());
+        devnet_load.post_json("/load".into(), load_body).await.unwrap();
+
+        let loaded_transaction =
+            devnet_load.json_rpc_client.get_transaction_by_hash(mint_tx_hash).await.unwrap();
+        if let starknet_rs_core::types::Transaction::Invoke(
+            starknet_rs_core::types::InvokeTransaction::V1(invoke_v1),
+        ) = loaded_transaction
+        {
+            assert_eq!(invoke_v1.transaction_hash, mint_tx_hash);
+        } else {
+            panic!("Could not unpack the transaction from {loaded_transaction:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn base_dump_is_the_genesis_state_with_fresh_accounts() {
+        let dump_file = UniqueAutoDeletableFile::new("base_dump");
+        let devnet_base = BackgroundDevnet::spawn_with_additional_args(&[
+            "--dump-path",
+            &dump_file.path,
+            "--dump-on",
+            "transaction",
+        ])
+        .await
+        .expect("Could not start Devnet");
+        devnet_base.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        let (_, base_account_address) = devnet_base.get_first_predeployed_account().await;
+
+        let base_dump_seed = SEED.to_string();
+        let base_dump_accounts = ACCOUNTS.to_string();
+        let base_dump_initial_balance = PREDEPLOYED_ACCOUNT_INITIAL_BALANCE.to_string();
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&[
+            "--seed",
+            "7",
+            "--accounts",
+            "1",
+            "--base-dump",
+            &dump_file.path,
+            "--base-dump-seed",
+            &base_dump_seed,
+            "--base-dump-accounts",
+            &base_dump_accounts,
+            "--base-dump-initial-balance",
+            &base_dump_initial_balance,
+        ])
+        .await
+        .expect("Could not start Devnet");
+
+        // the state of the base is in the genesis block, without its transactions
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), 0);
+        let balance = devnet.get_balance(&FieldElement::from(DUMMY_ADDRESS)).await.unwrap();
+        assert_eq!(balance, DUMMY_AMOUNT.into());
+
+        let accounts =
+            get_json_body(devnet.get("/predeployed_accounts", None).await.unwrap()).await;
+        let accounts = accounts.as_array().unwrap();
+        assert_eq!(accounts.len(), ACCOUNTS + 1);
+        let address_at = |index: usize| {
+            FieldElement::from_hex_be(accounts[index]["address"].as_str().unwrap()).unwrap()
+        };
+        assert_eq!(address_at(0), base_account_address);
+        assert_ne!(address_at(ACCOUNTS), base_account_address);
+    }
+
+    #[tokio::test]
+    async fn base_dump_with_the_seed_of_the_new_instance_is_rejected() {
+        let dump_file = UniqueAutoDeletableFile::new("base_dump_same_seed");
+        std::fs::write(&dump_file.path, "[]").unwrap();
+
+        let seed = SEED.to_string();
+        let status = std::process::Command::new("cargo")
+            .args(["run", "--release", "--", "--seed", &seed])
+            .args(["--base-dump", &dump_file.path, "--base-dump-seed", &seed])
+            .status()
+            .expect("Could not run Devnet");
+        assert!(!status.success());
+    }
 }
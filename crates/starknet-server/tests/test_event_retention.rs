@@ -0,0 +1,44 @@
+pub mod common;
+
+mod event_retention_tests {
+    use serde_json::json;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 0x42;
+    static DUMMY_AMOUNT: u128 = 42;
+
+    #[tokio::test]
+    async fn events_of_blocks_outside_retention_are_not_queryable() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--event-retention", "1"])
+            .await
+            .expect("Could not start Devnet");
+        // each mint is in a block of its own
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let resp = devnet
+            .send_custom_rpc(
+                "starknet_getEvents",
+                json!({ "filter": { "from_block": { "block_number": 0 }, "chunk_size": 100 } }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -1);
+        assert_eq!(
+            resp["error"]["message"],
+            "Events of blocks before block 2 are pruned due to the event retention"
+        );
+
+        let latest_events = &devnet
+            .send_custom_rpc(
+                "starknet_getEvents",
+                json!({ "filter": { "from_block": "latest", "chunk_size": 100 } }),
+            )
+            .await["result"]["events"];
+        let all_events = &devnet
+            .send_custom_rpc("starknet_getEvents", json!({ "filter": { "chunk_size": 100 } }))
+            .await["result"]["events"];
+        assert!(!latest_events.as_array().unwrap().is_empty());
+        assert_eq!(all_events, latest_events);
+    }
+}
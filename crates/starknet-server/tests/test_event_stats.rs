@@ -0,0 +1,66 @@
+pub mod common;
+
+mod event_stats_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_core::utils::get_selector_from_name;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    fn to_felt(value: &serde_json::Value) -> FieldElement {
+        FieldElement::from_hex_be(value.as_str().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn events_of_mints_are_counted() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        // each mint is in a block of its own
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let stats = &devnet.send_custom_rpc("devnet_getEventStats", json!({})).await["result"];
+        let total_events = stats["total_events"].as_u64().unwrap();
+        assert!(total_events > 0);
+
+        // minting and charging the fee are transfers of the fee token
+        let top_contract = &stats["contracts"][0];
+        assert_eq!(
+            to_felt(&top_contract["from_address"]),
+            FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap()
+        );
+        let top_first_key = &stats["first_keys"][0];
+        assert_eq!(to_felt(&top_first_key["key"]), get_selector_from_name("Transfer").unwrap());
+
+        let count_sum = |counts: &serde_json::Value| -> u64 {
+            counts.as_array().unwrap().iter().map(|count| count["count"].as_u64().unwrap()).sum()
+        };
+        assert_eq!(count_sum(&stats["contracts"]), total_events);
+        assert_eq!(count_sum(&stats["first_keys"]), total_events);
+
+        // the first mint is in the block after the genesis block
+        let first_block_stats = &devnet
+            .send_custom_rpc(
+                "devnet_getEventStats",
+                json!({ "from_block": { "block_number": 1 }, "to_block": { "block_number": 1 } }),
+            )
+            .await["result"];
+        assert_eq!(first_block_stats["total_events"].as_u64().unwrap() * 2, total_events);
+    }
+
+    #[tokio::test]
+    async fn unknown_block_is_reported() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_getEventStats",
+                json!({ "from_block": { "block_number": 1000 } }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 24);
+    }
+}
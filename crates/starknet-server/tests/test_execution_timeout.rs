@@ -0,0 +1,92 @@
+pub mod common;
+
+mod execution_timeout_tests {
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::{CAIRO_1_CONTRACT_PATH, PREDEPLOYED_ACCOUNT_ADDRESS};
+    use crate::common::utils::{get_flattened_sierra_contract_and_casm_hash, to_hex_felt};
+
+    const TIMEOUT_HEADER: &str = "X-Devnet-Timeout-Ms";
+
+    /// Simulates `count` declarations, each of which computes the hash of the class
+    fn declare_simulation_params(count: usize) -> serde_json::Value {
+        let (flattened_contract_artifact, casm_hash) =
+            get_flattened_sierra_contract_and_casm_hash(CAIRO_1_CONTRACT_PATH);
+        let declaration = json!({
+            "type": "DECLARE",
+            "sender_address": PREDEPLOYED_ACCOUNT_ADDRESS,
+            "compiled_class_hash": to_hex_felt(&casm_hash),
+            "max_fee": to_hex_felt(&FieldElement::ZERO),
+            "version": "0x2",
+            "signature": [],
+            "nonce": "0x0",
+            "contract_class": flattened_contract_artifact,
+        });
+
+        json!({
+            "block_id": "latest",
+            "simulation_flags": ["SKIP_VALIDATE"],
+            "transactions": vec![declaration; count]
+        })
+    }
+
+    #[tokio::test]
+    async fn simulation_within_timeout_succeeds() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc_with_headers(
+                "starknet_simulateTransactions",
+                declare_simulation_params(1),
+                &[(TIMEOUT_HEADER, "60000")],
+            )
+            .await;
+        assert_eq!(resp["result"].as_array().unwrap().len(), 1, "{resp}");
+    }
+
+    #[tokio::test]
+    async fn simulation_exceeding_timeout_fails() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc_with_headers(
+                "starknet_simulateTransactions",
+                declare_simulation_params(20),
+                &[(TIMEOUT_HEADER, "1")],
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -1);
+        assert_eq!(resp["error"]["message"], "Execution did not finish within the timeout of 1 ms");
+
+        // the state is not locked for other readers
+        let resp = devnet.send_custom_rpc("starknet_chainId", json!([])).await;
+        assert!(resp["result"].is_string(), "{resp}");
+
+        // a write is only delayed until the interrupted simulation finishes
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_mint",
+                json!({ "address": PREDEPLOYED_ACCOUNT_ADDRESS, "amount": 1 }),
+            )
+            .await;
+        assert!(resp["result"]["tx_hash"].is_string(), "{resp}");
+    }
+
+    #[tokio::test]
+    async fn invalid_timeout_is_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        for invalid_timeout in ["0", "-1", "soon"] {
+            let resp = devnet
+                .send_custom_rpc_with_headers(
+                    "starknet_chainId",
+                    json!([]),
+                    &[(TIMEOUT_HEADER, invalid_timeout)],
+                )
+                .await;
+            assert_eq!(resp["error"]["code"], -32600, "{resp}");
+        }
+    }
+}
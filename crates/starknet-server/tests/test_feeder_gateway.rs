@@ -0,0 +1,102 @@
+pub mod common;
+
+mod feeder_gateway_tests {
+    use hyper::{Body, StatusCode};
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    async fn get_json(
+        devnet: &BackgroundDevnet,
+        path: &str,
+        query: Option<String>,
+    ) -> (StatusCode, serde_json::Value) {
+        let resp = devnet.get(path, query).await.unwrap();
+        let status = resp.status();
+        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
+        (status, serde_json::from_slice(&body).unwrap())
+    }
+
+    #[tokio::test]
+    async fn get_block_by_number_and_hash() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let (status, block) =
+            get_json(&devnet, "/feeder_gateway/get_block", Some("blockNumber=1".into())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(block["block_number"], 1);
+        assert_eq!(block["transactions"][0]["transaction_hash"], format!("{mint_tx_hash:#x}"));
+        assert_eq!(block["transaction_receipts"][0]["execution_status"], "SUCCEEDED");
+
+        let block_hash = block["block_hash"].as_str().unwrap();
+        let (_, block_by_hash) =
+            get_json(&devnet, "/feeder_gateway/get_block", Some(format!("blockHash={block_hash}")))
+                .await;
+        assert_eq!(block, block_by_hash);
+
+        let (_, latest_block) = get_json(&devnet, "/feeder_gateway/get_block", None).await;
+        assert_eq!(block, latest_block);
+    }
+
+    #[tokio::test]
+    async fn get_block_of_unknown_number() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.post_json("/create_block".into(), Body::empty()).await.unwrap();
+
+        let (status, resp) =
+            get_json(&devnet, "/feeder_gateway/get_block", Some("blockNumber=5".into())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(!resp["error"].as_str().unwrap().is_empty());
+
+        let (status, _) =
+            get_json(&devnet, "/feeder_gateway/get_block", Some("blockNumber=abc".into())).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn get_transaction_with_position_in_block() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let (status, transaction) = get_json(
+            &devnet,
+            "/feeder_gateway/get_transaction",
+            Some(format!("transactionHash={mint_tx_hash:#x}")),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(transaction["status"], "ACCEPTED_ON_L2");
+        assert_eq!(transaction["block_number"], 1);
+        assert_eq!(transaction["transaction_index"], 0);
+        assert_eq!(transaction["transaction"]["type"], "INVOKE_FUNCTION");
+    }
+
+    #[tokio::test]
+    async fn get_unknown_transaction() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let (status, transaction) = get_json(
+            &devnet,
+            "/feeder_gateway/get_transaction",
+            Some("transactionHash=0x123".into()),
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(transaction, serde_json::json!({ "status": "NOT_RECEIVED" }));
+    }
+
+    #[tokio::test]
+    async fn get_state_update_of_block() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let (status, state_update) =
+            get_json(&devnet, "/feeder_gateway/get_state_update", Some("blockNumber=1".into()))
+                .await;
+        assert_eq!(status, StatusCode::OK);
+        assert!(!state_update["state_diff"]["storage_diffs"].as_object().unwrap().is_empty());
+    }
+}
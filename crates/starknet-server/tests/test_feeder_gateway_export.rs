@@ -0,0 +1,55 @@
+pub mod common;
+
+mod feeder_gateway_export_tests {
+    use hyper::StatusCode;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    #[tokio::test]
+    async fn blocks_are_exported_in_feeder_gateway_format() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let resp = devnet.get("/export/feeder_gateway/blocks", None).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK, "Checking status of {resp:?}");
+        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
+        let lines: Vec<serde_json::Value> = String::from_utf8(body.to_vec())
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        // the genesis block, followed by the block of the mint
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["block"]["transactions"], serde_json::json!([]));
+
+        let block = &lines[1]["block"];
+        assert_eq!(block["block_number"], 1);
+        assert_eq!(block["status"], "ACCEPTED_ON_L2");
+        assert_eq!(block["transactions"][0]["type"], "INVOKE_FUNCTION");
+
+        let receipt = &block["transaction_receipts"][0];
+        assert_eq!(receipt["transaction_index"], 0);
+        assert_eq!(receipt["transaction_hash"], format!("{mint_tx_hash:#x}"));
+        assert_eq!(receipt["execution_status"], "SUCCEEDED");
+        assert!(receipt["execution_resources"]["n_steps"].as_u64().unwrap() > 0);
+
+        let state_update = &lines[1]["state_update"];
+        assert_eq!(state_update["block_hash"], block["block_hash"]);
+        assert!(!state_update["state_diff"]["storage_diffs"].as_object().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn export_starts_from_requested_block() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let resp =
+            devnet.get("/export/feeder_gateway/blocks", Some("from=2".into())).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK, "Checking status of {resp:?}");
+        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
+        assert!(body.is_empty());
+    }
+}
@@ -0,0 +1,85 @@
+pub mod common;
+
+mod felt_utils_tests {
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_core::utils::{cairo_short_string_to_felt, get_selector_from_name};
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    fn to_felt(value: &serde_json::Value) -> FieldElement {
+        FieldElement::from_hex_be(value.as_str().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn short_string_round_trip() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let felt = &devnet
+            .send_custom_rpc(
+                "devnet_feltUtils",
+                json!({ "operation": "short_string_to_felt", "short_string": "hello" }),
+            )
+            .await["result"];
+        assert_eq!(to_felt(felt), cairo_short_string_to_felt("hello").unwrap());
+
+        let short_string = &devnet
+            .send_custom_rpc(
+                "devnet_feltUtils",
+                json!({ "operation": "felt_to_short_string", "felt": felt }),
+            )
+            .await["result"];
+        assert_eq!(short_string, "hello");
+    }
+
+    #[tokio::test]
+    async fn u256_round_trip() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        // 2^128 + 5
+        let u256 = "340282366920938463463374607431768211461";
+
+        let felts = &devnet
+            .send_custom_rpc(
+                "devnet_feltUtils",
+                json!({ "operation": "u256_to_felts", "u256": u256 }),
+            )
+            .await["result"];
+        assert_eq!(to_felt(&felts["low"]), FieldElement::from(5_u32));
+        assert_eq!(to_felt(&felts["high"]), FieldElement::ONE);
+
+        let joined = &devnet
+            .send_custom_rpc(
+                "devnet_feltUtils",
+                json!({ "operation": "felts_to_u256", "low": felts["low"], "high": felts["high"] }),
+            )
+            .await["result"];
+        assert_eq!(joined, u256);
+    }
+
+    #[tokio::test]
+    async fn selector_from_name() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let selector = &devnet
+            .send_custom_rpc(
+                "devnet_feltUtils",
+                json!({ "operation": "selector_from_name", "name": "transfer" }),
+            )
+            .await["result"];
+        assert_eq!(to_felt(selector), get_selector_from_name("transfer").unwrap());
+    }
+
+    #[tokio::test]
+    async fn invalid_input_is_reported_as_invalid_params() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        for params in [
+            json!({ "operation": "short_string_to_felt", "short_string": "a".repeat(32) }),
+            json!({ "operation": "u256_to_felts", "u256": format!("0x1{}", "0".repeat(64)) }),
+            json!({ "operation": "u256_to_felts", "u256": "twelve" }),
+        ] {
+            let resp = devnet.send_custom_rpc("devnet_feltUtils", params).await;
+            assert_eq!(resp["error"]["code"], -32602, "{resp}");
+        }
+    }
+}
@@ -0,0 +1,71 @@
+pub mod common;
+
+mod fixtures_tests {
+    use std::process::Command;
+
+    use serde_json::json;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::{CHAIN_ID_CLI_PARAM, SEED};
+    use crate::common::utils::UniqueAutoDeletableFile;
+
+    fn generate_fixtures(output: &str) -> serde_json::Value {
+        let status = Command::new("cargo")
+            .args(["run", "--release", "--"])
+            .args(["--seed", &SEED.to_string(), "--chain-id", CHAIN_ID_CLI_PARAM])
+            .args(["fixtures", "--output", output])
+            .status()
+            .expect("Could not generate fixtures");
+        assert!(status.success());
+
+        serde_json::from_str(&std::fs::read_to_string(output).unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn fixtures_are_accepted_by_devnet_with_same_seed() {
+        let fixtures_file = UniqueAutoDeletableFile::new("fixtures_same_seed");
+        let fixtures = generate_fixtures(&fixtures_file.path);
+        assert_eq!(fixtures["seed"], SEED);
+
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let names: Vec<_> = fixtures["transactions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|fixture| fixture["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(names, vec!["transfer", "declare", "deploy", "invoke"]);
+
+        for fixture in fixtures["transactions"].as_array().unwrap() {
+            let transaction = &fixture["transaction"];
+            let resp = match transaction["type"].as_str().unwrap() {
+                "INVOKE" => {
+                    devnet
+                        .send_custom_rpc(
+                            "starknet_addInvokeTransaction",
+                            json!({ "invoke_transaction": transaction }),
+                        )
+                        .await
+                }
+                "DECLARE" => {
+                    devnet
+                        .send_custom_rpc(
+                            "starknet_addDeclareTransaction",
+                            json!({ "declare_transaction": transaction }),
+                        )
+                        .await
+                }
+                other => panic!("Unexpected fixture type: {other}"),
+            };
+            assert_eq!(resp["result"]["transaction_hash"], fixture["transaction_hash"]);
+
+            let receipt = &devnet
+                .send_custom_rpc(
+                    "starknet_getTransactionReceipt",
+                    json!({ "transaction_hash": fixture["transaction_hash"] }),
+                )
+                .await["result"];
+            assert_eq!(receipt["execution_status"], "SUCCEEDED");
+        }
+    }
+}
@@ -0,0 +1,83 @@
+pub mod common;
+
+mod gateway_tests {
+    use hyper::{Body, StatusCode};
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{
+        Account, Call, ConnectedAccount, ExecutionEncoding, SingleOwnerAccount,
+    };
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_core::utils::get_selector_from_name;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+    use crate::common::utils::{assert_tx_successful, get_json_body};
+
+    const GATEWAY_PATH: &str = "/gateway/add_transaction";
+
+    #[tokio::test]
+    async fn invoke_function_is_added() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        let recipient = FieldElement::from(0x42_u32);
+        let calls = vec![Call {
+            to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+            selector: get_selector_from_name("transfer").unwrap(),
+            calldata: vec![recipient, FieldElement::from(1_u32), FieldElement::ZERO],
+        }];
+        let invoke_request = account
+            .execute(calls)
+            .nonce(account.get_nonce().await.unwrap())
+            .max_fee(FieldElement::from(1e18 as u128))
+            .prepared()
+            .unwrap()
+            .get_invoke_request(false)
+            .await
+            .unwrap();
+
+        // the gateway format differs from the RPC one in the name of the type
+        let mut gateway_transaction = serde_json::to_value(invoke_request).unwrap();
+        gateway_transaction["type"] = json!("INVOKE_FUNCTION");
+
+        let resp = devnet
+            .post_json(GATEWAY_PATH.into(), Body::from(gateway_transaction.to_string()))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let resp_body = get_json_body(resp).await;
+        assert_eq!(resp_body["code"], "TRANSACTION_RECEIVED");
+        let transaction_hash =
+            FieldElement::from_hex_be(resp_body["transaction_hash"].as_str().unwrap()).unwrap();
+        assert_tx_successful(&transaction_hash, &devnet.json_rpc_client).await;
+
+        let recipient_balance = devnet.get_balance(&recipient).await.unwrap();
+        assert_eq!(recipient_balance, FieldElement::from(1_u32));
+    }
+
+    #[tokio::test]
+    async fn unsupported_transaction_type_is_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        for transaction in [json!({ "type": "DEPLOY" }), json!({ "type": "INVOKE" })] {
+            let resp = devnet
+                .post_json(GATEWAY_PATH.into(), Body::from(transaction.to_string()))
+                .await
+                .unwrap();
+            assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+            let resp_body = get_json_body(resp).await;
+            assert!(resp_body["error"].as_str().unwrap().starts_with("Invalid transaction"));
+            assert_eq!(resp_body["devnet_code"], 1001);
+        }
+    }
+}
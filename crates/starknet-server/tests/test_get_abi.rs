@@ -0,0 +1,44 @@
+pub mod common;
+
+mod get_abi_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::PREDEPLOYED_ACCOUNT_ADDRESS;
+
+    fn has_function(abi: &serde_json::Value, function_name: &str) -> bool {
+        abi.as_array()
+            .unwrap()
+            .iter()
+            .any(|entry| entry["type"] == "function" && entry["name"] == function_name)
+    }
+
+    #[tokio::test]
+    async fn abi_of_predeployed_contracts() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let account_abi = &devnet
+            .send_custom_rpc(
+                "devnet_getAbi",
+                json!({ "contract_address": PREDEPLOYED_ACCOUNT_ADDRESS }),
+            )
+            .await["result"];
+        assert!(has_function(account_abi, "__execute__"), "{account_abi}");
+
+        let token_abi = &devnet
+            .send_custom_rpc("devnet_getAbi", json!({ "contract_address": ERC20_CONTRACT_ADDRESS }))
+            .await["result"];
+        assert!(has_function(token_abi, "balanceOf"), "{token_abi}");
+        assert!(!has_function(token_abi, "__execute__"));
+    }
+
+    #[tokio::test]
+    async fn abi_of_undeployed_contract_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp =
+            devnet.send_custom_rpc("devnet_getAbi", json!({ "contract_address": "0x1234" })).await;
+        assert_eq!(resp["error"]["code"], 20);
+    }
+}
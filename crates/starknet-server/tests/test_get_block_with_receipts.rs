@@ -0,0 +1,47 @@
+pub mod common;
+
+mod get_block_with_receipts_tests {
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    async fn get_block(
+        devnet: &BackgroundDevnet,
+        block_id: serde_json::Value,
+    ) -> serde_json::Value {
+        devnet
+            .send_custom_rpc("starknet_getBlockWithReceipts", json!({ "block_id": block_id }))
+            .await
+    }
+
+    #[tokio::test]
+    async fn block_contains_transactions_with_their_receipts() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_hash = devnet.mint(FieldElement::ONE, 1).await;
+        let hash_param = json!({ "transaction_hash": format!("{mint_hash:#x}") });
+
+        let block = &get_block(&devnet, json!("latest")).await["result"];
+        let transaction =
+            devnet.send_custom_rpc("starknet_getTransactionByHash", hash_param.clone()).await;
+        let receipt = devnet.send_custom_rpc("starknet_getTransactionReceipt", hash_param).await;
+        assert_eq!(
+            block["transactions"],
+            json!([{ "transaction": transaction["result"], "receipt": receipt["result"] }])
+        );
+
+        let block_with_txs = &devnet
+            .send_custom_rpc("starknet_getBlockWithTxs", json!({ "block_id": "latest" }))
+            .await["result"];
+        assert_eq!(block["block_hash"], block_with_txs["block_hash"]);
+        assert_eq!(block["block_number"], 1);
+    }
+
+    #[tokio::test]
+    async fn block_of_unknown_number_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp_body = get_block(&devnet, json!({ "block_number": 1 })).await;
+        assert_eq!(resp_body["error"]["code"], 24, "Invalid resp: {resp_body}");
+    }
+}
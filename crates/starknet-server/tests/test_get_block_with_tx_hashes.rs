@@ -0,0 +1,72 @@
+pub mod common;
+
+mod get_block_with_tx_hashes_tests {
+    use hyper::Body;
+    use serde_json::json;
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement, MaybePendingBlockWithTxHashes};
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    async fn get_block(
+        devnet: &BackgroundDevnet,
+        block_id: serde_json::Value,
+    ) -> serde_json::Value {
+        devnet
+            .send_custom_rpc("starknet_getBlockWithTxHashes", json!({ "block_id": block_id }))
+            .await
+    }
+
+    #[tokio::test]
+    async fn block_is_found_by_tag_number_and_hash() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_hash = devnet.mint(FieldElement::ONE, 1).await;
+
+        let latest_block = match devnet
+            .json_rpc_client
+            .get_block_with_tx_hashes(BlockId::Tag(BlockTag::Latest))
+            .await
+            .unwrap()
+        {
+            MaybePendingBlockWithTxHashes::Block(block) => block,
+            other => panic!("Invalid block: {other:?}"),
+        };
+        assert_eq!(latest_block.transactions, vec![mint_hash]);
+
+        let by_number =
+            get_block(&devnet, json!({ "block_number": latest_block.block_number })).await;
+        let by_hash =
+            get_block(&devnet, json!({ "block_hash": format!("{:#x}", latest_block.block_hash) }))
+                .await;
+        let latest = get_block(&devnet, json!("latest")).await;
+        assert_eq!(by_number["result"], latest["result"]);
+        assert_eq!(by_hash["result"], latest["result"]);
+        assert_eq!(latest["result"]["status"], "ACCEPTED_ON_L2");
+    }
+
+    #[tokio::test]
+    async fn pending_block_lists_transactions_not_yet_in_a_block() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--block-max-txs", "10"])
+            .await
+            .expect("Could not start Devnet");
+        devnet.post_json("/create_block".into(), Body::empty()).await.unwrap();
+        let mint_hash = devnet.mint(FieldElement::ONE, 1).await;
+
+        let latest = get_block(&devnet, json!("latest")).await["result"].clone();
+        let pending = get_block(&devnet, json!("pending")).await["result"].clone();
+
+        assert_eq!(pending["status"], "PENDING");
+        assert_eq!(pending["parent_hash"], latest["block_hash"]);
+        assert_eq!(pending["block_number"], latest["block_number"].as_u64().unwrap() + 1);
+        assert_eq!(pending["transactions"], json!([format!("{mint_hash:#x}")]));
+        assert_eq!(latest["transactions"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn unknown_block_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = get_block(&devnet, json!({ "block_number": 5 })).await;
+        assert_eq!(resp["error"]["code"], 24);
+    }
+}
@@ -0,0 +1,50 @@
+pub mod common;
+
+mod get_block_with_txs_tests {
+    use hyper::Body;
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    async fn get_block(
+        devnet: &BackgroundDevnet,
+        block_id: serde_json::Value,
+    ) -> serde_json::Value {
+        devnet.send_custom_rpc("starknet_getBlockWithTxs", json!({ "block_id": block_id })).await
+    }
+
+    #[tokio::test]
+    async fn block_contains_full_transactions() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_hash = devnet.mint(FieldElement::ONE, 1).await;
+
+        let block = &get_block(&devnet, json!("latest")).await["result"];
+        let transaction = devnet
+            .send_custom_rpc(
+                "starknet_getTransactionByHash",
+                json!({ "transaction_hash": format!("{mint_hash:#x}") }),
+            )
+            .await;
+        assert_eq!(block["transactions"], json!([transaction["result"]]));
+    }
+
+    #[tokio::test]
+    async fn pending_block_contains_transactions_not_yet_in_a_block() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--block-max-txs", "10"])
+            .await
+            .expect("Could not start Devnet");
+        devnet.post_json("/create_block".into(), Body::empty()).await.unwrap();
+        let mint_hash = devnet.mint(FieldElement::ONE, 1).await;
+
+        let pending = &get_block(&devnet, json!("pending")).await["result"];
+        assert_eq!(pending["status"], "PENDING");
+        assert_eq!(pending["block_number"], 2);
+        let transactions = pending["transactions"].as_array().unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0]["transaction_hash"], format!("{mint_hash:#x}"));
+
+        let latest = &get_block(&devnet, json!("latest")).await["result"];
+        assert_eq!(latest["transactions"], json!([]));
+    }
+}
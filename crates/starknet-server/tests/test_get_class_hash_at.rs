@@ -54,10 +54,21 @@ mod get_class_hash_at_integration_tests {
         let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
         let contract_address = FieldElement::from_hex_be(PREDEPLOYED_ACCOUNT_ADDRESS).unwrap();
 
-        let err = devnet
+        // predeployed accounts are already in the state of the genesis block
+        let retrieved_hash = devnet
             .json_rpc_client
             .get_class_hash_at(BlockId::Number(0), contract_address)
             .await
+            .unwrap();
+        assert_eq!(
+            retrieved_hash,
+            FieldElement::from_hex_be(CAIRO_0_ACCOUNT_CONTRACT_HASH).unwrap()
+        );
+
+        let err = devnet
+            .json_rpc_client
+            .get_class_hash_at(BlockId::Number(1), contract_address)
+            .await
             .expect_err("Should have failed");
 
         match err {
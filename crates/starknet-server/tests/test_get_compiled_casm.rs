@@ -0,0 +1,77 @@
+pub mod common;
+
+mod get_compiled_casm_tests {
+    use std::sync::Arc;
+
+    use serde_json::json;
+    use starknet_core::constants::CAIRO_0_ACCOUNT_CONTRACT_HASH;
+    use starknet_rs_accounts::{Account, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::{CAIRO_1_CONTRACT_PATH, CHAIN_ID};
+    use crate::common::utils::{get_flattened_sierra_contract_and_casm_hash, load_json};
+
+    #[tokio::test]
+    async fn compiled_casm_of_declared_class_is_returned() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        let (flattened_contract_artifact, casm_hash) =
+            get_flattened_sierra_contract_and_casm_hash(CAIRO_1_CONTRACT_PATH);
+        let declaration_result =
+            account.declare(Arc::new(flattened_contract_artifact), casm_hash).send().await.unwrap();
+
+        let resp_body = devnet
+            .send_custom_rpc(
+                "starknet_getCompiledCasm",
+                json!({ "class_hash": format!("{:#x}", declaration_result.class_hash) }),
+            )
+            .await;
+        let casm = &resp_body["result"];
+        assert!(!casm["bytecode"].as_array().unwrap().is_empty(), "Invalid resp: {resp_body}");
+
+        // the same compilation as of the declared class
+        let artifact: serde_json::Value = load_json(CAIRO_1_CONTRACT_PATH);
+        let compiled = &devnet
+            .send_custom_rpc("devnet_compileSierra", json!({ "artifact": artifact }))
+            .await["result"];
+        assert_eq!(&compiled["casm"], casm);
+        assert_eq!(
+            FieldElement::from_hex_be(compiled["compiled_class_hash"].as_str().unwrap()).unwrap(),
+            casm_hash
+        );
+    }
+
+    #[tokio::test]
+    async fn compiled_casm_of_undeclared_class_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp_body = devnet
+            .send_custom_rpc("starknet_getCompiledCasm", json!({ "class_hash": "0x123" }))
+            .await;
+        assert_eq!(resp_body["error"]["code"], 28, "Invalid resp: {resp_body}");
+    }
+
+    #[tokio::test]
+    async fn compiled_casm_of_cairo_0_class_is_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let class_hash = FieldElement::from_hex_be(CAIRO_0_ACCOUNT_CONTRACT_HASH).unwrap();
+        let resp_body = devnet
+            .send_custom_rpc(
+                "starknet_getCompiledCasm",
+                json!({ "class_hash": format!("{class_hash:#x}") }),
+            )
+            .await;
+        let message = resp_body["error"]["message"].as_str().unwrap();
+        assert!(message.contains("Cairo 0 class"), "Invalid resp: {resp_body}");
+    }
+}
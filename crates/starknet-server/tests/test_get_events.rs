@@ -3,6 +3,7 @@ pub mod common;
 mod get_events_integration_tests {
     use std::sync::Arc;
 
+    use serde_json::json;
     use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
     use starknet_rs_contract::ContractFactory;
     use starknet_rs_core::chain_id;
@@ -121,4 +122,20 @@ mod get_events_integration_tests {
 
         assert_eq!(total_extracted_events, generated_events_count);
     }
+
+    #[tokio::test]
+    async fn invalid_requests_are_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let too_many_keys = vec![vec![format!("{:#x}", FieldElement::ONE); 101]];
+        for (filter, expected_code) in [
+            (json!({ "chunk_size": 1001 }), 31),
+            (json!({ "chunk_size": 10, "continuation_token": "abc" }), 33),
+            (json!({ "chunk_size": 10, "keys": too_many_keys }), 34),
+        ] {
+            let resp =
+                devnet.send_custom_rpc("starknet_getEvents", json!({ "filter": filter })).await;
+            assert_eq!(resp["error"]["code"], expected_code, "{resp}");
+        }
+    }
 }
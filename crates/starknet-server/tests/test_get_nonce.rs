@@ -0,0 +1,94 @@
+pub mod common;
+
+mod get_nonce_tests {
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::{
+        BlockId, BlockTag, FieldElement, MaybePendingTransactionReceipt, StarknetError,
+        TransactionReceipt,
+    };
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::{
+        MaybeUnknownErrorCode, Provider, ProviderError, StarknetErrorWithMessage,
+    };
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+
+    /// Sends a transfer from the first predeployed account; returns the hash of its block
+    async fn send_transfer(devnet: &BackgroundDevnet) -> FieldElement {
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        let result = account
+            .execute(vec![Call {
+                to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                selector: get_selector_from_name("transfer").unwrap(),
+                calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+            }])
+            .max_fee(FieldElement::from(1e18 as u128))
+            .send()
+            .await
+            .unwrap();
+
+        match devnet.json_rpc_client.get_transaction_receipt(result.transaction_hash).await {
+            Ok(MaybePendingTransactionReceipt::Receipt(TransactionReceipt::Invoke(receipt))) => {
+                receipt.block_hash
+            }
+            other => panic!("Invalid receipt: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn nonce_is_read_from_state_of_requested_block() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+
+        let first_block_hash = send_transfer(&devnet).await;
+        send_transfer(&devnet).await;
+
+        for (block_id, expected_nonce) in [
+            (BlockId::Number(0), FieldElement::ZERO),
+            (BlockId::Number(1), FieldElement::ONE),
+            (BlockId::Hash(first_block_hash), FieldElement::ONE),
+            (BlockId::Number(2), FieldElement::TWO),
+            (BlockId::Tag(BlockTag::Latest), FieldElement::TWO),
+            (BlockId::Tag(BlockTag::Pending), FieldElement::TWO),
+        ] {
+            let nonce = devnet.json_rpc_client.get_nonce(block_id, account_address).await.unwrap();
+            assert_eq!(nonce, expected_nonce, "Invalid nonce at {block_id:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn nonce_of_unknown_block_or_contract_fails() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+
+        match devnet.json_rpc_client.get_nonce(BlockId::Number(1), account_address).await {
+            Err(ProviderError::StarknetError(StarknetErrorWithMessage {
+                code: MaybeUnknownErrorCode::Known(StarknetError::BlockNotFound),
+                ..
+            })) => (),
+            other => panic!("Invalid result: {other:?}"),
+        }
+
+        match devnet
+            .json_rpc_client
+            .get_nonce(BlockId::Tag(BlockTag::Latest), FieldElement::ONE)
+            .await
+        {
+            Err(ProviderError::StarknetError(StarknetErrorWithMessage {
+                code: MaybeUnknownErrorCode::Known(StarknetError::ContractNotFound),
+                ..
+            })) => (),
+            other => panic!("Invalid result: {other:?}"),
+        }
+    }
+}
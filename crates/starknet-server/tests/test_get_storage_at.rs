@@ -0,0 +1,66 @@
+pub mod common;
+
+mod get_storage_at_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement};
+    use starknet_rs_core::utils::get_storage_var_address;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1000;
+
+    #[tokio::test]
+    async fn storage_is_read_at_latest_and_pending() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let balance_key =
+            get_storage_var_address("ERC20_balances", &[FieldElement::from(DUMMY_ADDRESS)])
+                .unwrap();
+        for block_tag in [BlockTag::Latest, BlockTag::Pending] {
+            let balance = devnet
+                .json_rpc_client
+                .get_storage_at(
+                    FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                    balance_key,
+                    BlockId::Tag(block_tag),
+                )
+                .await
+                .unwrap();
+            assert_eq!(balance, FieldElement::from(DUMMY_AMOUNT));
+        }
+    }
+
+    #[tokio::test]
+    async fn storage_of_undeployed_contract_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "starknet_getStorageAt",
+                json!({ "contract_address": "0x1234", "key": "0x1", "block_id": "latest" }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 20);
+    }
+
+    #[tokio::test]
+    async fn storage_at_unknown_block_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "starknet_getStorageAt",
+                json!({
+                    "contract_address": ERC20_CONTRACT_ADDRESS,
+                    "key": "0x1",
+                    "block_id": { "block_number": 5 }
+                }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 24);
+    }
+}
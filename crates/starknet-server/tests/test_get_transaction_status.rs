@@ -0,0 +1,63 @@
+pub mod common;
+
+mod get_transaction_status_tests {
+    use hyper::Body;
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    async fn get_status(
+        devnet: &BackgroundDevnet,
+        transaction_hash: FieldElement,
+    ) -> serde_json::Value {
+        devnet
+            .send_custom_rpc(
+                "starknet_getTransactionStatus",
+                json!({ "transaction_hash": format!("{transaction_hash:#x}") }),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn status_of_mined_transaction() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        assert_eq!(
+            get_status(&devnet, mint_hash).await["result"],
+            json!({ "finality_status": "ACCEPTED_ON_L2", "execution_status": "SUCCEEDED" })
+        );
+    }
+
+    #[tokio::test]
+    async fn transaction_in_pending_block_is_received() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--block-generation-on", "demand"])
+                .await
+                .expect("Could not start Devnet");
+        let mint_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        assert_eq!(
+            get_status(&devnet, mint_hash).await["result"],
+            json!({ "finality_status": "RECEIVED", "execution_status": "SUCCEEDED" })
+        );
+
+        devnet.post_json("/create_block".into(), Body::empty()).await.unwrap();
+        assert_eq!(
+            get_status(&devnet, mint_hash).await["result"]["finality_status"],
+            "ACCEPTED_ON_L2"
+        );
+    }
+
+    #[tokio::test]
+    async fn status_of_unknown_transaction_fails() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp_body = get_status(&devnet, FieldElement::ONE).await;
+        assert_eq!(resp_body["error"]["code"], 29, "Invalid resp: {resp_body}");
+    }
+}
@@ -0,0 +1,90 @@
+pub mod common;
+
+mod journal_tests {
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    const MINT_AMOUNT: u128 = 1000;
+
+    /// Mints to the first predeployed account in block 1 and replaces its public key with a
+    /// cheatcode in block 2; returns the address of the account and the hash of the mint
+    async fn setup(devnet: &BackgroundDevnet) -> (FieldElement, FieldElement) {
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+        let mint_hash = devnet.mint(account_address, MINT_AMOUNT).await;
+
+        let resp_body = devnet
+            .send_custom_rpc(
+                "devnet_setAccountPublicKey",
+                json!({ "address": format!("{account_address:#x}"), "new_public_key": "0x42" }),
+            )
+            .await;
+        assert!(resp_body.get("result").is_some(), "Invalid resp: {resp_body}");
+
+        (account_address, mint_hash)
+    }
+
+    fn parse_felt(value: &serde_json::Value) -> FieldElement {
+        FieldElement::from_hex_be(value.as_str().unwrap()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn changes_are_journaled_with_their_causes() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (account_address, mint_hash) = setup(&devnet).await;
+
+        let journal = &devnet.send_custom_rpc("devnet_getJournal", json!({})).await["result"];
+        let entries = journal.as_array().unwrap();
+        assert_eq!(entries.len(), 2, "Invalid journal: {journal}");
+
+        let mint_entry = &entries[0];
+        assert_eq!(mint_entry["block_number"], 1);
+        assert_eq!(mint_entry["cause"]["type"], "MINT");
+        assert_eq!(parse_felt(&mint_entry["cause"]["transaction_hash"]), mint_hash);
+        assert_eq!(parse_felt(&mint_entry["cause"]["address"]), account_address);
+        assert_eq!(mint_entry["cause"]["amount"], json!(MINT_AMOUNT));
+        assert!(!mint_entry["state_diff"]["storage_diffs"].as_array().unwrap().is_empty());
+
+        let cheatcode_entry = &entries[1];
+        assert_eq!(cheatcode_entry["block_number"], 2);
+        assert_eq!(
+            cheatcode_entry["cause"],
+            json!({ "type": "CHEATCODE", "method": "devnet_setAccountPublicKey" })
+        );
+        let storage_diffs = cheatcode_entry["state_diff"]["storage_diffs"].as_array().unwrap();
+        assert_eq!(storage_diffs.len(), 1);
+        assert_eq!(parse_felt(&storage_diffs[0]["address"]), account_address);
+    }
+
+    #[tokio::test]
+    async fn journal_is_filtered_by_block_range() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        setup(&devnet).await;
+
+        let journal = &devnet
+            .send_custom_rpc(
+                "devnet_getJournal",
+                json!({ "from_block": { "block_number": 1 }, "to_block": { "block_number": 1 } }),
+            )
+            .await["result"];
+        assert_eq!(journal.as_array().unwrap().len(), 1, "Invalid journal: {journal}");
+        assert_eq!(journal[0]["cause"]["type"], "MINT");
+
+        let journal = &devnet
+            .send_custom_rpc("devnet_getJournal", json!({ "from_block": { "block_number": 2 } }))
+            .await["result"];
+        assert_eq!(journal.as_array().unwrap().len(), 1, "Invalid journal: {journal}");
+        assert_eq!(journal[0]["cause"]["type"], "CHEATCODE");
+    }
+
+    #[tokio::test]
+    async fn journal_of_unknown_block_fails() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp_body = devnet
+            .send_custom_rpc("devnet_getJournal", json!({ "from_block": { "block_number": 1 } }))
+            .await;
+        assert_eq!(resp_body["error"]["code"], 24, "Invalid resp: {resp_body}");
+    }
+}
@@ -0,0 +1,89 @@
+pub mod common;
+
+mod log_format_tests {
+    use std::io::{BufRead, BufReader};
+    use std::sync::mpsc;
+    use std::time::Duration;
+
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    /// Forwards the JSON records printed by `devnet` to the returned receiver
+    fn receive_json_records(devnet: &mut BackgroundDevnet) -> mpsc::Receiver<serde_json::Value> {
+        // the startup summary is printed as plain text, so lines that are not JSON are skipped
+        let stdout = devnet.process.stdout.take().unwrap();
+        let (sender, receiver) = mpsc::channel();
+        std::thread::spawn(move || {
+            for line in BufReader::new(stdout).lines() {
+                let line = line.unwrap();
+                if let Ok(record) = serde_json::from_str::<serde_json::Value>(&line) {
+                    if sender.send(record).is_err() {
+                        break;
+                    }
+                }
+            }
+        });
+        receiver
+    }
+
+    #[tokio::test]
+    async fn json_records_contain_method_and_transaction_hash() {
+        let mut devnet = BackgroundDevnet::spawn_with_additional_args(&["--log-format", "json"])
+            .await
+            .expect("Could not start Devnet");
+        let receiver = receive_json_records(&mut devnet);
+
+        let mint_tx_hash = devnet.mint(0x1, 1).await;
+        devnet.json_rpc_client.chain_id().await.unwrap();
+
+        let mut handled_method = None;
+        let mut accepted_transaction = None;
+        while handled_method.is_none() || accepted_transaction.is_none() {
+            let record = receiver.recv_timeout(Duration::from_secs(5)).expect("No record received");
+            assert!(record["timestamp"].is_string());
+            assert!(record["level"].is_string());
+
+            if record["message"] == "handled method" {
+                handled_method = Some(record);
+            } else if record["message"] == "Transaction accepted" {
+                accepted_transaction = Some(record);
+            }
+        }
+
+        let handled_method = handled_method.unwrap();
+        assert!(handled_method["rpc_method"].is_string());
+        assert!(handled_method["duration_ms"].is_u64());
+        let accepted_transaction_hash =
+            accepted_transaction.unwrap()["transaction_hash"].as_str().unwrap().to_string();
+        assert_eq!(FieldElement::from_hex_be(&accepted_transaction_hash).unwrap(), mint_tx_hash);
+    }
+
+    #[tokio::test]
+    async fn slow_methods_are_logged_with_lock_wait_and_execution_time() {
+        let mut devnet = BackgroundDevnet::spawn_with_additional_args(&[
+            "--log-format",
+            "json",
+            "--slow-query-threshold",
+            "0",
+        ])
+        .await
+        .expect("Could not start Devnet");
+        let receiver = receive_json_records(&mut devnet);
+
+        devnet.json_rpc_client.chain_id().await.unwrap();
+
+        let slow_method = loop {
+            let record = receiver.recv_timeout(Duration::from_secs(5)).expect("No record received");
+            if record["message"] == "slow method" {
+                break record;
+            }
+        };
+        assert_eq!(slow_method["level"], "WARN");
+        assert_eq!(slow_method["rpc_method"], "starknet_chainId");
+        assert!(slow_method["params_digest"].is_string());
+        assert!(slow_method["lock_wait_ms"].is_u64());
+        assert!(slow_method["execution_ms"].is_u64());
+    }
+}
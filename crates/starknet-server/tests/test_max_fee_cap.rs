@@ -0,0 +1,78 @@
+pub mod common;
+
+mod max_fee_cap_tests {
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{
+        Account, AccountError, Call, ExecutionEncoding, SingleOwnerAccount,
+    };
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::{MaybeUnknownErrorCode, ProviderError, StarknetErrorWithMessage};
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+    use crate::common::utils::get_json_body;
+
+    const MAX_FEE_CAP: u128 = 1e16 as u128;
+
+    #[tokio::test]
+    async fn transaction_with_max_fee_above_cap_is_rejected() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&[
+            "--max-fee-cap",
+            &MAX_FEE_CAP.to_string(),
+        ])
+        .await
+        .expect("Could not start Devnet");
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(config["max_fee_cap"], MAX_FEE_CAP.to_string());
+
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        let initial_balance = devnet.get_balance(&account_address).await.unwrap();
+        let transfer_result = account
+            .execute(vec![Call {
+                to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                selector: get_selector_from_name("transfer").unwrap(),
+                calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+            }])
+            .max_fee(FieldElement::from(MAX_FEE_CAP + 1))
+            .send()
+            .await;
+
+        match transfer_result {
+            Err(AccountError::Provider(ProviderError::StarknetError(
+                StarknetErrorWithMessage { code: MaybeUnknownErrorCode::Unknown(-1), message },
+            ))) => assert_eq!(
+                message,
+                format!(
+                    "invoke transaction: max_fee {} exceeds the max fee cap {MAX_FEE_CAP}",
+                    MAX_FEE_CAP + 1
+                )
+            ),
+            other => panic!("Unexpected result: {other:?}"),
+        }
+
+        // nothing was charged
+        assert_eq!(devnet.get_balance(&account_address).await.unwrap(), initial_balance);
+    }
+
+    #[tokio::test]
+    async fn minting_is_not_capped() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--max-fee-cap", "1"])
+            .await
+            .expect("Could not start Devnet");
+
+        let address = FieldElement::ONE;
+        devnet.mint(address, 1000).await;
+
+        assert_eq!(devnet.get_balance(&address).await.unwrap(), FieldElement::from(1000_u32));
+    }
+}
@@ -0,0 +1,47 @@
+pub mod common;
+
+mod metrics_tests {
+    use hyper::header::CONTENT_TYPE;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    /// Returns the value of the sample of `name` with the specified `kind` label
+    fn get_sample(metrics: &str, name: &str, kind: &str) -> f64 {
+        let prefix = format!("{name}{{kind=\"{kind}\"}} ");
+        metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(&prefix))
+            .unwrap_or_else(|| panic!("No sample of {name} for {kind}"))
+            .parse()
+            .unwrap()
+    }
+
+    async fn get_metrics(devnet: &BackgroundDevnet) -> String {
+        let resp = devnet.get("/metrics", None).await.unwrap();
+        assert_eq!(resp.status(), 200);
+        assert!(resp.headers()[CONTENT_TYPE].to_str().unwrap().starts_with("text/plain"));
+        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
+        String::from_utf8(body.to_vec()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn lock_acquisitions_are_counted() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let initial_metrics = get_metrics(&devnet).await;
+        devnet.json_rpc_client.chain_id().await.unwrap();
+        devnet.mint(0x1, 1).await;
+        let metrics = get_metrics(&devnet).await;
+
+        for kind in ["read", "write"] {
+            let acquisitions = "devnet_state_lock_acquisitions_total";
+            assert!(
+                get_sample(&metrics, acquisitions, kind)
+                    > get_sample(&initial_metrics, acquisitions, kind)
+            );
+            assert!(get_sample(&metrics, "devnet_state_lock_wait_seconds_total", kind) >= 0.0);
+            assert_eq!(get_sample(&metrics, "devnet_state_lock_waiting", kind), 0.0);
+        }
+    }
+}
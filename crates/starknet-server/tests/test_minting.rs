@@ -57,6 +57,29 @@ mod minting_tests {
         .await
     }
 
+    #[tokio::test]
+    async fn increase_balance_via_json_rpc() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_mint",
+                json!({ "address": DUMMY_ADDRESS, "amount": DUMMY_AMOUNT }),
+            )
+            .await;
+        let result = &resp["result"];
+        assert_eq!(result["new_balance"], DUMMY_AMOUNT.to_string(), "Invalid resp: {resp}");
+        assert_eq!(result["unit"], "WEI");
+
+        let receipt = devnet
+            .send_custom_rpc(
+                "starknet_getTransactionReceipt",
+                json!({ "transaction_hash": result["tx_hash"] }),
+            )
+            .await;
+        assert_eq!(receipt["result"]["execution_status"], "SUCCEEDED", "Invalid resp: {receipt}");
+    }
+
     async fn reject_bad_request(
         devnet: &BackgroundDevnet,
         json_body: serde_json::Value,
@@ -102,4 +125,20 @@ mod minting_tests {
         )
         .await;
     }
+
+    #[tokio::test]
+    async fn rpc_mint_requires_impersonation_api_key() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--impersonation-api-key", "secret"])
+                .await
+                .expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_mint",
+                json!({ "address": DUMMY_ADDRESS, "amount": DUMMY_AMOUNT }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -32600, "Invalid resp: {resp}");
+    }
 }
@@ -0,0 +1,109 @@
+pub mod common;
+
+mod multicall_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_core::utils::get_selector_from_name;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::PREDEPLOYED_ACCOUNT_ADDRESS;
+
+    static DUMMY_ADDRESS: u128 = 0x42;
+    static DUMMY_AMOUNT: u128 = 42;
+
+    fn balance_of_call(address: &str) -> serde_json::Value {
+        json!({
+            "contract_address": ERC20_CONTRACT_ADDRESS,
+            "entry_point_selector": format!("{:#x}", get_selector_from_name("balanceOf").unwrap()),
+            "calldata": [address]
+        })
+    }
+
+    #[tokio::test]
+    async fn results_are_returned_in_order_of_calls() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let results = &devnet
+            .send_custom_rpc(
+                "devnet_multicall",
+                json!({
+                    "calls": [
+                        balance_of_call(PREDEPLOYED_ACCOUNT_ADDRESS),
+                        balance_of_call(&format!("{DUMMY_ADDRESS:#x}")),
+                    ],
+                    "block_id": "latest"
+                }),
+            )
+            .await["result"];
+
+        let predeployed_balance = devnet
+            .get_balance(&FieldElement::from_hex_be(PREDEPLOYED_ACCOUNT_ADDRESS).unwrap())
+            .await
+            .unwrap();
+        let to_felt =
+            |value: &serde_json::Value| FieldElement::from_hex_be(value.as_str().unwrap()).unwrap();
+        assert_eq!(results.as_array().unwrap().len(), 2);
+        assert_eq!(to_felt(&results[0][0]), predeployed_balance);
+        assert_eq!(to_felt(&results[1][0]), FieldElement::from(DUMMY_AMOUNT));
+    }
+
+    #[tokio::test]
+    async fn failing_call_fails_whole_request() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_multicall",
+                json!({
+                    "calls": [
+                        balance_of_call(PREDEPLOYED_ACCOUNT_ADDRESS),
+                        {
+                            "contract_address": "0x1234",
+                            "entry_point_selector": "0x1",
+                            "calldata": []
+                        },
+                    ],
+                    "block_id": "latest"
+                }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 20);
+        assert!(resp.get("result").is_none());
+    }
+
+    #[tokio::test]
+    async fn unknown_block_is_reported() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_multicall",
+                json!({
+                    "calls": [balance_of_call(PREDEPLOYED_ACCOUNT_ADDRESS)],
+                    "block_id": { "block_number": 1000 }
+                }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 24);
+    }
+
+    #[tokio::test]
+    async fn block_number_can_be_a_string() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        for block_number in ["0", "0x0"] {
+            let resp = devnet
+                .send_custom_rpc(
+                    "devnet_multicall",
+                    json!({
+                        "calls": [balance_of_call(PREDEPLOYED_ACCOUNT_ADDRESS)],
+                        "block_id": { "block_number": block_number }
+                    }),
+                )
+                .await;
+            assert_eq!(resp["result"].as_array().unwrap().len(), 1, "{resp}");
+        }
+    }
+}
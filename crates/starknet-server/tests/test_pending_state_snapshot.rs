@@ -0,0 +1,78 @@
+pub mod common;
+
+mod pending_state_snapshot_tests {
+    use serde_json::json;
+    use starknet_core::constants::{CAIRO_0_ACCOUNT_CONTRACT_HASH, ERC20_CONTRACT_ADDRESS};
+    use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::{CHAIN_ID, PREDEPLOYED_ACCOUNT_INITIAL_BALANCE};
+
+    #[tokio::test]
+    async fn snapshot_includes_transactions_of_pending_block() {
+        // the transaction is kept in the pending block
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--block-max-txs", "10"])
+            .await
+            .expect("Could not start Devnet");
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        account
+            .execute(vec![Call {
+                to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                selector: get_selector_from_name("transfer").unwrap(),
+                calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+            }])
+            .max_fee(FieldElement::from(1e18 as u128))
+            .send()
+            .await
+            .unwrap();
+
+        let snapshot = &devnet
+            .send_custom_rpc(
+                "devnet_getPendingStateSnapshot",
+                json!({ "address": format!("{account_address:#x}") }),
+            )
+            .await["result"];
+
+        let to_felt =
+            |value: &serde_json::Value| FieldElement::from_hex_be(value.as_str().unwrap()).unwrap();
+        assert_eq!(to_felt(&snapshot["address"]), account_address);
+        assert_eq!(snapshot["block_number"], 1);
+        assert_eq!(
+            to_felt(&snapshot["class_hash"]),
+            FieldElement::from_hex_be(CAIRO_0_ACCOUNT_CONTRACT_HASH).unwrap()
+        );
+        assert_eq!(to_felt(&snapshot["nonce"]), FieldElement::ONE);
+        assert_eq!(
+            to_felt(&snapshot["nonce"]),
+            devnet
+                .json_rpc_client
+                .get_nonce(BlockId::Tag(BlockTag::Pending), account_address)
+                .await
+                .unwrap()
+        );
+
+        let balance: u128 = snapshot["balance"].as_str().unwrap().parse().unwrap();
+        assert!(balance < PREDEPLOYED_ACCOUNT_INITIAL_BALANCE);
+    }
+
+    #[tokio::test]
+    async fn snapshot_of_undeployed_contract_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc("devnet_getPendingStateSnapshot", json!({ "address": "0x1234" }))
+            .await;
+        assert_eq!(resp["error"]["code"], 20);
+    }
+}
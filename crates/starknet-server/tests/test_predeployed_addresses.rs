@@ -0,0 +1,109 @@
+pub mod common;
+
+mod predeployed_addresses_tests {
+    use starknet_core::constants::{ERC20_CONTRACT_ADDRESS, UDC_CONTRACT_ADDRESS};
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement, FunctionCall, StarknetError};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::{
+        MaybeUnknownErrorCode, Provider, ProviderError, StarknetErrorWithMessage,
+    };
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::utils::get_json_body;
+
+    const CUSTOM_FEE_TOKEN_ADDRESS: &str = "0x1234";
+    const CUSTOM_UDC_ADDRESS: &str = "0x5678";
+
+    async fn spawn_with_custom_addresses() -> BackgroundDevnet {
+        BackgroundDevnet::spawn_with_additional_args(&[
+            "--fee-token-address",
+            CUSTOM_FEE_TOKEN_ADDRESS,
+            "--udc-address",
+            CUSTOM_UDC_ADDRESS,
+        ])
+        .await
+        .expect("Could not start Devnet")
+    }
+
+    /// Returns the low part of the balance
+    async fn get_custom_fee_token_balance(
+        devnet: &BackgroundDevnet,
+        address: FieldElement,
+    ) -> FieldElement {
+        let balance = devnet
+            .json_rpc_client
+            .call(
+                FunctionCall {
+                    contract_address: FieldElement::from_hex_be(CUSTOM_FEE_TOKEN_ADDRESS).unwrap(),
+                    entry_point_selector: get_selector_from_name("balanceOf").unwrap(),
+                    calldata: vec![address],
+                },
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await
+            .unwrap();
+        balance[0]
+    }
+
+    #[tokio::test]
+    async fn custom_addresses_are_reported_by_config() {
+        let devnet = spawn_with_custom_addresses().await;
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        for (property, expected_address) in
+            [("fee_token_address", CUSTOM_FEE_TOKEN_ADDRESS), ("udc_address", CUSTOM_UDC_ADDRESS)]
+        {
+            assert_eq!(
+                FieldElement::from_hex_be(config[property].as_str().unwrap()).unwrap(),
+                FieldElement::from_hex_be(expected_address).unwrap()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn system_contracts_are_deployed_only_at_custom_addresses() {
+        let devnet = spawn_with_custom_addresses().await;
+
+        for (custom_address, default_address) in [
+            (CUSTOM_FEE_TOKEN_ADDRESS, ERC20_CONTRACT_ADDRESS),
+            (CUSTOM_UDC_ADDRESS, UDC_CONTRACT_ADDRESS),
+        ] {
+            devnet
+                .json_rpc_client
+                .get_class_hash_at(
+                    BlockId::Tag(BlockTag::Latest),
+                    FieldElement::from_hex_be(custom_address).unwrap(),
+                )
+                .await
+                .unwrap();
+
+            let err = devnet
+                .json_rpc_client
+                .get_class_hash_at(
+                    BlockId::Tag(BlockTag::Latest),
+                    FieldElement::from_hex_be(default_address).unwrap(),
+                )
+                .await
+                .expect_err("Should have failed");
+            match err {
+                ProviderError::StarknetError(StarknetErrorWithMessage {
+                    code: MaybeUnknownErrorCode::Known(StarknetError::ContractNotFound),
+                    ..
+                }) => (),
+                _ => panic!("Invalid error: {err:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn minting_uses_custom_fee_token() {
+        let devnet = spawn_with_custom_addresses().await;
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+
+        let initial_balance = get_custom_fee_token_balance(&devnet, account_address).await;
+        devnet.mint(account_address, 1000).await;
+        let new_balance = get_custom_fee_token_balance(&devnet, account_address).await;
+
+        assert_eq!(new_balance - initial_balance, FieldElement::from(1000_u32));
+    }
+}
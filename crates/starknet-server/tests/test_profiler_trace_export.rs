@@ -0,0 +1,64 @@
+pub mod common;
+
+mod profiler_trace_export_tests {
+    use hyper::StatusCode;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::utils::get_json_body;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    #[tokio::test]
+    async fn execute_call_is_exported_with_nested_calls() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let resp = devnet
+            .get("/export/profiler_trace", Some(format!("transactionHash={mint_tx_hash:#x}")))
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK, "Checking status of {resp:?}");
+        let trace = get_json_body(resp).await;
+
+        assert_eq!(trace["entry_point"]["entry_point_type"], "External");
+        assert_eq!(trace["entry_point"]["call_type"], "Call");
+        assert!(trace["vm_trace"].is_null());
+
+        let n_steps = trace["cumulative_resources"]["vm_resources"]["n_steps"].as_u64().unwrap();
+        let nested_calls = trace["nested_calls"].as_array().unwrap();
+        assert!(!nested_calls.is_empty());
+        // resources are cumulative, so each nested call is included in the resources of its caller
+        for nested_call in nested_calls {
+            let nested_n_steps =
+                nested_call["cumulative_resources"]["vm_resources"]["n_steps"].as_u64().unwrap();
+            assert!(nested_n_steps <= n_steps);
+        }
+    }
+
+    #[tokio::test]
+    async fn validate_call_is_exported_on_request() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let resp = devnet
+            .get(
+                "/export/profiler_trace",
+                Some(format!("transactionHash={mint_tx_hash:#x}&call=validate")),
+            )
+            .await
+            .unwrap();
+        assert_eq!(resp.status(), StatusCode::OK, "Checking status of {resp:?}");
+        let trace = get_json_body(resp).await;
+        assert!(trace["nested_calls"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn unknown_transaction_is_reported() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp =
+            devnet.get("/export/profiler_trace", Some("transactionHash=0x1".into())).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
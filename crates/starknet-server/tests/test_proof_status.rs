@@ -0,0 +1,50 @@
+pub mod common;
+
+mod proof_status_tests {
+    use std::time::Duration;
+
+    use hyper::Body;
+    use serde_json::json;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    async fn get_proof_status(
+        devnet: &BackgroundDevnet,
+        block_id: serde_json::Value,
+    ) -> serde_json::Value {
+        devnet.send_custom_rpc("devnet_getProofStatus", json!({ "block_id": block_id })).await
+    }
+
+    #[tokio::test]
+    async fn proof_status_progresses_over_time() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&[
+            "--proof-proving-time",
+            "2",
+            "--proof-verification-time",
+            "2",
+        ])
+        .await
+        .expect("Could not start Devnet");
+        devnet.post_json("/create_block".into(), Body::from(json!({}).to_string())).await.unwrap();
+
+        let resp = get_proof_status(&devnet, json!("latest")).await;
+        assert_eq!(resp["result"]["block_number"], 1);
+        assert_eq!(resp["result"]["status"], "RECEIVED");
+
+        tokio::time::sleep(Duration::from_millis(2500)).await;
+        let resp = get_proof_status(&devnet, json!({ "block_number": 1 })).await;
+        assert_eq!(resp["result"]["status"], "PROVEN");
+
+        tokio::time::sleep(Duration::from_millis(2000)).await;
+        let resp = get_proof_status(&devnet, json!({ "block_number": 1 })).await;
+        assert_eq!(resp["result"]["status"], "VERIFIED_ON_L1");
+    }
+
+    #[tokio::test]
+    async fn proof_status_of_unknown_block_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = get_proof_status(&devnet, json!({ "block_number": 7 })).await;
+        assert_eq!(resp["error"]["code"], 24);
+    }
+}
@@ -0,0 +1,85 @@
+pub mod common;
+
+mod reject_on_revert_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{
+        Account, AccountError, Call, ExecutionEncoding, SingleOwnerAccount,
+    };
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement, StarknetError};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::{
+        MaybeUnknownErrorCode, Provider, ProviderError, StarknetErrorWithMessage,
+    };
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+
+    /// Sends a transfer with a max fee lower than estimated, so that its execution reverts
+    async fn send_reverting_transfer(devnet: &BackgroundDevnet) -> Result<FieldElement, String> {
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        let transfer_execution = account.execute(vec![Call {
+            to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+            selector: get_selector_from_name("transfer").unwrap(),
+            calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+        }]);
+        let fee = transfer_execution.estimate_fee().await.unwrap();
+
+        match transfer_execution.max_fee(FieldElement::from(fee.overall_fee - 1)).send().await {
+            Ok(result) => Ok(result.transaction_hash),
+            Err(AccountError::Provider(ProviderError::StarknetError(
+                StarknetErrorWithMessage {
+                    code: MaybeUnknownErrorCode::Known(StarknetError::ContractError),
+                    message,
+                },
+            ))) => Err(message),
+            Err(other) => panic!("Invalid error: {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn reverting_invoke_is_rejected_without_changes() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--reject-on-revert"])
+            .await
+            .expect("Could not start Devnet");
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+        let latest = BlockId::Tag(BlockTag::Latest);
+        // so that there is a latest block
+        devnet.mint(account_address, 1).await;
+
+        let block_number_before = devnet.json_rpc_client.block_number().await.unwrap();
+        let balance_before = devnet.get_balance(&account_address).await.unwrap();
+
+        send_reverting_transfer(&devnet).await.expect_err("Should have been rejected");
+
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), block_number_before);
+        assert_eq!(devnet.get_balance(&account_address).await.unwrap(), balance_before);
+        assert_eq!(
+            devnet.json_rpc_client.get_nonce(latest, account_address).await.unwrap(),
+            FieldElement::ZERO
+        );
+    }
+
+    #[tokio::test]
+    async fn rejection_can_be_switched_at_runtime() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        send_reverting_transfer(&devnet).await.expect("Should have been included as reverted");
+
+        let resp =
+            devnet.send_custom_rpc("devnet_setRejectOnRevert", json!({ "enabled": true })).await;
+        assert_eq!(resp["result"], json!({ "enabled": true }));
+        send_reverting_transfer(&devnet).await.expect_err("Should have been rejected");
+
+        devnet.send_custom_rpc("devnet_setRejectOnRevert", json!({ "enabled": false })).await;
+        send_reverting_transfer(&devnet).await.expect("Should have been included as reverted");
+    }
+}
@@ -0,0 +1,54 @@
+pub mod common;
+
+mod request_body_size_tests {
+    use hyper::{Body, StatusCode};
+    use serde_json::json;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::utils::get_json_body;
+
+    const MAX_REQUEST_BODY_SIZE: &str = "1000";
+
+    async fn spawn_devnet() -> BackgroundDevnet {
+        BackgroundDevnet::spawn_with_additional_args(&[
+            "--max-request-body-size",
+            MAX_REQUEST_BODY_SIZE,
+        ])
+        .await
+        .expect("Could not start Devnet")
+    }
+
+    #[tokio::test]
+    async fn request_within_limit_is_handled() {
+        let devnet = spawn_devnet().await;
+
+        let resp = devnet.send_custom_rpc("starknet_chainId", json!([])).await;
+        assert!(resp["result"].is_string(), "Unexpected response: {resp}");
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(config["max_request_body_size"], 1000);
+    }
+
+    #[tokio::test]
+    async fn request_over_limit_is_rejected() {
+        let devnet = spawn_devnet().await;
+
+        let calldata = vec!["0x1"; 500];
+        let req_body = json!({
+            "jsonrpc": "2.0",
+            "id": 0,
+            "method": "starknet_call",
+            "params": {
+                "request": {
+                    "contract_address": "0x1",
+                    "entry_point_selector": "0x1",
+                    "calldata": calldata,
+                },
+                "block_id": "latest",
+            }
+        });
+
+        let resp = devnet.post_json("/rpc".into(), Body::from(req_body.to_string())).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::PAYLOAD_TOO_LARGE);
+    }
+}
@@ -49,13 +49,9 @@ mod test_restart {
             other => panic!("Unexpected result: {other:?}"),
         }
 
-        match devnet.json_rpc_client.get_block_with_txs(BlockId::Tag(BlockTag::Latest)).await {
-            Err(ProviderError::StarknetError(StarknetErrorWithMessage {
-                code: MaybeUnknownErrorCode::Known(StarknetError::BlockNotFound),
-                ..
-            })) => (),
-            other => panic!("Unexpected result: {other:?}"),
-        }
+        // only the genesis block is left
+        let latest_block = devnet.json_rpc_client.block_hash_and_number().await.unwrap();
+        assert_eq!(latest_block.block_number, 0);
     }
 
     #[tokio::test]
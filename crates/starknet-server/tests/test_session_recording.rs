@@ -0,0 +1,49 @@
+pub mod common;
+
+mod session_recording_tests {
+    use hyper::StatusCode;
+    use serde_json::json;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::utils::get_json_body;
+
+    #[tokio::test]
+    async fn recorded_calls_are_exported_in_order() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--record-session"])
+            .await
+            .expect("Could not start Devnet");
+
+        let chain_id = devnet.send_custom_rpc("starknet_chainId", json!([])).await;
+        let unknown_transaction = devnet
+            .send_custom_rpc("starknet_getTransactionByHash", json!({ "transaction_hash": "0x1" }))
+            .await;
+
+        let resp = devnet.get("/export/session_fixture", None).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let fixture = get_json_body(resp).await;
+        assert_eq!(
+            fixture["calls"],
+            json!([
+                {
+                    "method": "starknet_chainId",
+                    "params": [],
+                    "response": { "result": chain_id["result"] }
+                },
+                {
+                    "method": "starknet_getTransactionByHash",
+                    "params": { "transaction_hash": "0x1" },
+                    "response": { "error": unknown_transaction["error"] }
+                }
+            ])
+        );
+    }
+
+    #[tokio::test]
+    async fn export_is_rejected_if_not_recording() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet.get("/export/session_fixture", None).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        assert_eq!(get_json_body(resp).await["devnet_code"], 1004);
+    }
+}
@@ -0,0 +1,117 @@
+pub mod common;
+
+mod set_account_public_key_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement, FunctionCall};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::Provider;
+    use starknet_rs_signers::{LocalWallet, Signer};
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+    use crate::common::utils::{assert_tx_successful, get_deployable_account_signer};
+
+    async fn transfer_from(
+        devnet: &BackgroundDevnet,
+        signer: LocalWallet,
+        account_address: FieldElement,
+    ) -> Result<FieldElement, String> {
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        account
+            .execute(vec![Call {
+                to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                selector: get_selector_from_name("transfer").unwrap(),
+                calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+            }])
+            .max_fee(FieldElement::from(1e18 as u128))
+            .send()
+            .await
+            .map(|result| result.transaction_hash)
+            .map_err(|err| format!("{err:?}"))
+    }
+
+    #[tokio::test]
+    async fn account_is_controlled_by_new_key() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (old_signer, account_address) = devnet.get_first_predeployed_account().await;
+        let new_signer = get_deployable_account_signer();
+        let new_public_key = new_signer.get_public_key().await.unwrap().scalar();
+
+        let created_block = &devnet
+            .send_custom_rpc(
+                "devnet_setAccountPublicKey",
+                json!({
+                    "address": format!("{account_address:#x}"),
+                    "new_public_key": format!("{new_public_key:#x}")
+                }),
+            )
+            .await["result"];
+        assert_eq!(created_block["block_number"], 1);
+
+        let public_key = devnet
+            .json_rpc_client
+            .call(
+                FunctionCall {
+                    contract_address: account_address,
+                    entry_point_selector: get_selector_from_name("getPublicKey").unwrap(),
+                    calldata: vec![],
+                },
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await
+            .unwrap();
+        assert_eq!(public_key, vec![new_public_key]);
+
+        assert!(transfer_from(&devnet, old_signer, account_address).await.is_err());
+        let transaction_hash = transfer_from(&devnet, new_signer, account_address).await.unwrap();
+        assert_tx_successful(&transaction_hash, &devnet.json_rpc_client).await;
+    }
+
+    #[tokio::test]
+    async fn contract_without_public_key_is_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_setAccountPublicKey",
+                json!({ "address": ERC20_CONTRACT_ADDRESS, "new_public_key": "0x1" }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -1);
+        assert_eq!(resp["error"]["message"], "The contract has no public key to replace");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_setAccountPublicKey",
+                json!({ "address": "0x1234", "new_public_key": "0x1" }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 20);
+    }
+
+    #[tokio::test]
+    async fn public_key_is_not_set_without_impersonation_api_key() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--impersonation-api-key", "secret"])
+                .await
+                .expect("Could not start Devnet");
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_setAccountPublicKey",
+                json!({ "address": format!("{account_address:#x}"), "new_public_key": "0x42" }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -32600, "Invalid resp: {resp}");
+    }
+}
@@ -0,0 +1,55 @@
+pub mod common;
+
+mod shutdown_tests {
+    use hyper::StatusCode;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static SHUTDOWN_TOKEN: &str = "secret";
+
+    #[tokio::test]
+    async fn shutdown_is_disabled_without_token() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet.shutdown(Some(SHUTDOWN_TOKEN)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        devnet.json_rpc_client.chain_id().await.expect("Devnet should still be alive");
+    }
+
+    #[tokio::test]
+    async fn shutdown_with_invalid_token_is_rejected() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--shutdown-token", SHUTDOWN_TOKEN])
+                .await
+                .expect("Could not start Devnet");
+
+        let resp = devnet.shutdown(None).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        let resp = devnet.shutdown(Some("invalid")).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+
+        devnet.json_rpc_client.chain_id().await.expect("Devnet should still be alive");
+    }
+
+    #[tokio::test]
+    async fn shutdown_with_valid_token_stops_devnet() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--shutdown-token", SHUTDOWN_TOKEN])
+                .await
+                .expect("Could not start Devnet");
+
+        let resp = devnet.shutdown(Some(SHUTDOWN_TOKEN)).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // the server stops after responding
+        for _ in 0..10 {
+            if devnet.json_rpc_client.chain_id().await.is_err() {
+                return;
+            }
+            tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+        }
+        panic!("Devnet still alive after shutdown");
+    }
+}
@@ -0,0 +1,116 @@
+pub mod common;
+
+mod simulate_upgrade_tests {
+    use hyper::Body;
+    use serde_json::json;
+    use starknet_core::constants::{
+        CAIRO_0_ACCOUNT_CONTRACT_HASH, ERC20_CONTRACT_ADDRESS, ERC20_CONTRACT_CLASS_HASH,
+    };
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 0x42;
+    static DUMMY_AMOUNT: u128 = 42;
+
+    /// The mint is replayable, as its block follows the genesis block
+    async fn spawn_with_replayable_mint() -> (BackgroundDevnet, String) {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        (devnet, format!("{mint_tx_hash:#x}"))
+    }
+
+    #[tokio::test]
+    async fn same_class_causes_no_differences() {
+        let (devnet, mint_tx_hash) = spawn_with_replayable_mint().await;
+
+        let simulation = &devnet
+            .send_custom_rpc(
+                "devnet_simulateUpgrade",
+                json!({
+                    "contract_address": ERC20_CONTRACT_ADDRESS,
+                    "class_hash": ERC20_CONTRACT_CLASS_HASH,
+                    "transaction_hashes": [mint_tx_hash]
+                }),
+            )
+            .await["result"];
+
+        let replayed_transaction = &simulation["transactions"][0];
+        assert_eq!(replayed_transaction["differences"], json!([]));
+        assert_eq!(replayed_transaction["original"]["execution_status"], "SUCCEEDED");
+        assert_eq!(replayed_transaction["original"], replayed_transaction["replayed"]);
+    }
+
+    #[tokio::test]
+    async fn incompatible_class_causes_differences() {
+        let (devnet, mint_tx_hash) = spawn_with_replayable_mint().await;
+
+        let simulation = &devnet
+            .send_custom_rpc(
+                "devnet_simulateUpgrade",
+                json!({
+                    "contract_address": ERC20_CONTRACT_ADDRESS,
+                    "class_hash": CAIRO_0_ACCOUNT_CONTRACT_HASH,
+                    "transaction_hashes": [mint_tx_hash]
+                }),
+            )
+            .await["result"];
+
+        let replayed_transaction = &simulation["transactions"][0];
+        assert_eq!(replayed_transaction["replayed"]["execution_status"], "REVERTED");
+        assert!(replayed_transaction["differences"]
+            .as_array()
+            .unwrap()
+            .contains(&json!("execution_status")));
+
+        // the simulation must not affect the actual state
+        let balance = devnet.get_balance(&FieldElement::from(DUMMY_ADDRESS)).await.unwrap();
+        assert_eq!(balance, FieldElement::from(2 * DUMMY_AMOUNT));
+    }
+
+    #[tokio::test]
+    async fn transaction_is_replayed_after_the_preceding_ones_of_its_block() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--block-generation-on", "demand"])
+                .await
+                .expect("Could not start Devnet");
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        // depends on the first mint, which increments the nonce of the minting account
+        let second_mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        devnet.post_json("/create_block".into(), Body::empty()).await.unwrap();
+
+        let simulation = &devnet
+            .send_custom_rpc(
+                "devnet_simulateUpgrade",
+                json!({
+                    "contract_address": ERC20_CONTRACT_ADDRESS,
+                    "class_hash": ERC20_CONTRACT_CLASS_HASH,
+                    "transaction_hashes": [format!("{second_mint_tx_hash:#x}")]
+                }),
+            )
+            .await["result"];
+
+        let replayed_transaction = &simulation["transactions"][0];
+        assert_eq!(replayed_transaction["differences"], json!([]), "Invalid resp: {simulation}");
+        assert_eq!(replayed_transaction["replayed"]["execution_status"], "SUCCEEDED");
+    }
+
+    #[tokio::test]
+    async fn unknown_transaction_is_reported() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_simulateUpgrade",
+                json!({
+                    "contract_address": ERC20_CONTRACT_ADDRESS,
+                    "class_hash": ERC20_CONTRACT_CLASS_HASH,
+                    "transaction_hashes": ["0x123"]
+                }),
+            )
+            .await;
+
+        assert_eq!(resp["error"]["code"], 29);
+    }
+}
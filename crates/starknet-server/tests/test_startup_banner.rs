@@ -0,0 +1,46 @@
+pub mod common;
+
+mod startup_banner_tests {
+    use std::io::{BufRead, BufReader};
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::{ACCOUNTS, PREDEPLOYED_ACCOUNT_INITIAL_BALANCE, SEED};
+    use crate::common::utils::get_json_body;
+
+    #[tokio::test]
+    async fn json_banner_matches_predeployed_accounts() {
+        let mut devnet = BackgroundDevnet::spawn_with_additional_args(&["--json"])
+            .await
+            .expect("Could not start Devnet");
+
+        // the banner is printed before Devnet starts serving, so it is already in the pipe
+        let stdout = devnet.process.stdout.take().unwrap();
+        let banner = BufReader::new(stdout)
+            .lines()
+            .map(|line| line.unwrap())
+            .find_map(|line| {
+                serde_json::from_str::<serde_json::Value>(&line)
+                    .ok()
+                    .filter(|record| record.get("rpc_url").is_some())
+            })
+            .expect("No banner printed");
+
+        assert!(banner["rpc_url"].as_str().unwrap().ends_with("/rpc"));
+        assert_eq!(banner["seed"], SEED);
+        assert_eq!(banner["initial_balance"], PREDEPLOYED_ACCOUNT_INITIAL_BALANCE.to_string());
+        assert_eq!(banner["predeployed_contracts"][0]["name"], "FeeToken");
+        assert_eq!(banner["predeployed_contracts"][1]["name"], "UDC");
+
+        let predeployed_accounts =
+            get_json_body(devnet.get("/predeployed_accounts", None).await.unwrap()).await;
+        let banner_accounts = banner["predeployed_accounts"].as_array().unwrap();
+        assert_eq!(banner_accounts.len(), ACCOUNTS);
+        for (banner_account, predeployed_account) in
+            banner_accounts.iter().zip(predeployed_accounts.as_array().unwrap())
+        {
+            assert_eq!(banner_account["address"], predeployed_account["address"]);
+            assert_eq!(banner_account["private_key"], predeployed_account["private_key"]);
+            assert_eq!(banner_account["public_key"], predeployed_account["public_key"]);
+        }
+    }
+}
@@ -0,0 +1,54 @@
+pub mod common;
+
+mod state_diffs_export_tests {
+    use hyper::{header, StatusCode};
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    async fn get_exported_lines(devnet: &BackgroundDevnet, query: Option<String>) -> Vec<String> {
+        let resp = devnet.get("/export/state_diffs", query).await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK, "Checking status of {resp:?}");
+        assert_eq!(resp.headers()[header::CONTENT_TYPE], "application/x-ndjson");
+
+        let body = hyper::body::to_bytes(resp.into_body()).await.unwrap();
+        String::from_utf8(body.to_vec()).unwrap().lines().map(String::from).collect()
+    }
+
+    #[tokio::test]
+    async fn state_diffs_of_all_blocks_are_exported() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        devnet.post_json("/create_block".into(), hyper::Body::empty()).await.unwrap();
+
+        let lines = get_exported_lines(&devnet, None).await;
+        // the genesis block, followed by the block of the mint and the created block
+        assert_eq!(lines.len(), 3);
+
+        let first: serde_json::Value = serde_json::from_str(&lines[1]).unwrap();
+        assert_eq!(first["block_number"], 1);
+        let storage_diffs = first["state_diff"]["storage_diffs"].as_array().unwrap();
+        assert!(!storage_diffs.is_empty(), "Minting {mint_tx_hash:#x} should change storage");
+
+        let second: serde_json::Value = serde_json::from_str(&lines[2]).unwrap();
+        assert_eq!(second["block_number"], 2);
+        assert_eq!(second["state_diff"]["storage_diffs"], serde_json::json!([]));
+    }
+
+    #[tokio::test]
+    async fn export_starts_from_requested_block() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let lines = get_exported_lines(&devnet, Some("from=2".into())).await;
+        assert_eq!(lines.len(), 1);
+        let exported: serde_json::Value = serde_json::from_str(&lines[0]).unwrap();
+        assert_eq!(exported["block_number"], 2);
+
+        let lines = get_exported_lines(&devnet, Some("from=3".into())).await;
+        assert!(lines.is_empty());
+    }
+}
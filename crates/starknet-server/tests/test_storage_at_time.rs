@@ -0,0 +1,67 @@
+pub mod common;
+
+mod storage_at_time_tests {
+    use hyper::Body;
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_core::utils::get_storage_var_address;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1000;
+
+    async fn set_time(devnet: &BackgroundDevnet, time: u64) {
+        let body = Body::from(json!({ "time": time }).to_string());
+        devnet.post_json("/set_time".into(), body).await.unwrap();
+    }
+
+    async fn get_balance_at_time(devnet: &BackgroundDevnet, timestamp: u64) -> serde_json::Value {
+        let balance_key =
+            get_storage_var_address("ERC20_balances", &[FieldElement::from(DUMMY_ADDRESS)])
+                .unwrap();
+        devnet
+            .send_custom_rpc(
+                "devnet_getStorageAtTime",
+                json!({
+                    "contract_address": ERC20_CONTRACT_ADDRESS,
+                    "key": format!("{balance_key:#x}"),
+                    "timestamp": timestamp
+                }),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn storage_is_read_in_state_of_block_at_time() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        set_time(&devnet, 1000).await;
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        set_time(&devnet, 2000).await;
+        devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let result = &get_balance_at_time(&devnet, 1500).await["result"];
+        assert_eq!(result["block_number"], 2);
+        assert_eq!(
+            FieldElement::from_hex_be(result["value"].as_str().unwrap()).unwrap(),
+            FieldElement::from(DUMMY_AMOUNT)
+        );
+
+        let result = &get_balance_at_time(&devnet, 2500).await["result"];
+        assert_eq!(result["block_number"], 4);
+        assert_eq!(
+            FieldElement::from_hex_be(result["value"].as_str().unwrap()).unwrap(),
+            FieldElement::from(2 * DUMMY_AMOUNT)
+        );
+    }
+
+    #[tokio::test]
+    async fn time_before_first_block_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        set_time(&devnet, 1000).await;
+
+        let resp = get_balance_at_time(&devnet, 999).await;
+        assert_eq!(resp["error"]["code"], 24);
+    }
+}
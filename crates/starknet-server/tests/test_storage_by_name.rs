@@ -0,0 +1,73 @@
+pub mod common;
+
+mod storage_by_name_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement};
+    use starknet_rs_core::utils::get_storage_var_address;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::PREDEPLOYED_ACCOUNT_INITIAL_BALANCE;
+
+    #[tokio::test]
+    async fn balance_is_read_by_variable_name() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+
+        let result = &devnet
+            .send_custom_rpc(
+                "devnet_getStorageByName",
+                json!({
+                    "contract_address": ERC20_CONTRACT_ADDRESS,
+                    "variable_name": "ERC20_balances",
+                    "keys": [format!("{account_address:#x}")],
+                    "length": 2
+                }),
+            )
+            .await["result"];
+
+        let storage_address =
+            FieldElement::from_hex_be(result["storage_address"].as_str().unwrap()).unwrap();
+        assert_eq!(
+            storage_address,
+            get_storage_var_address("ERC20_balances", &[account_address]).unwrap()
+        );
+
+        let values: Vec<FieldElement> = result["values"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|value| FieldElement::from_hex_be(value.as_str().unwrap()).unwrap())
+            .collect();
+        assert_eq!(
+            values,
+            vec![FieldElement::from(PREDEPLOYED_ACCOUNT_INITIAL_BALANCE), FieldElement::ZERO]
+        );
+
+        // the same as reading by the computed address
+        let low = devnet
+            .json_rpc_client
+            .get_storage_at(
+                FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                storage_address,
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await
+            .unwrap();
+        assert_eq!(low, values[0]);
+    }
+
+    #[tokio::test]
+    async fn storage_of_undeployed_contract_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_getStorageByName",
+                json!({ "contract_address": "0x1234", "variable_name": "balance" }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 20);
+    }
+}
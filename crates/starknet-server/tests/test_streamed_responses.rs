@@ -0,0 +1,105 @@
+pub mod common;
+
+mod streamed_responses_tests {
+    use hyper::header::TRANSFER_ENCODING;
+    use hyper::Body;
+    use serde_json::json;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::RPC_PATH;
+    use crate::common::utils::get_json_body;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+    const N_TRANSACTIONS: usize = 20;
+
+    /// Returns Devnet with all of its transactions in the pending block
+    async fn spawn_devnet_with_pending_transactions() -> BackgroundDevnet {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--block-max-txs", "1000"])
+            .await
+            .expect("Could not start Devnet");
+        for _ in 0..N_TRANSACTIONS {
+            devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+        }
+
+        devnet
+    }
+
+    /// Sends `call` on its own and in a batch, which is not streamed, and asserts that the results
+    /// are the same
+    async fn assert_streamed_as_buffered(
+        devnet: &BackgroundDevnet,
+        call: serde_json::Value,
+    ) -> serde_json::Value {
+        let resp = devnet.post_json(RPC_PATH.into(), Body::from(call.to_string())).await.unwrap();
+        assert_eq!(resp.headers().get(TRANSFER_ENCODING).unwrap(), "chunked");
+        let streamed = get_json_body(resp).await;
+
+        let resp =
+            devnet.post_json(RPC_PATH.into(), Body::from(json!([call]).to_string())).await.unwrap();
+        let buffered = get_json_body(resp).await;
+
+        assert_eq!(streamed, buffered[0]);
+        streamed
+    }
+
+    #[tokio::test]
+    async fn block_with_transactions_is_streamed() {
+        let devnet = spawn_devnet_with_pending_transactions().await;
+        // unlike the pending block, a mined block has a fixed timestamp
+        devnet.post_json("/create_block".into(), Body::empty()).await.unwrap();
+
+        let resp = assert_streamed_as_buffered(
+            &devnet,
+            json!({
+                "jsonrpc": "2.0",
+                "id": 1,
+                "method": "starknet_getBlockWithTxs",
+                "params": { "block_id": "latest" }
+            }),
+        )
+        .await;
+
+        assert_eq!(resp["id"], 1);
+        assert_eq!(resp["result"]["transactions"].as_array().unwrap().len(), N_TRANSACTIONS);
+    }
+
+    #[tokio::test]
+    async fn events_are_streamed() {
+        let devnet = spawn_devnet_with_pending_transactions().await;
+
+        let resp = assert_streamed_as_buffered(
+            &devnet,
+            json!({
+                "jsonrpc": "2.0",
+                "id": "events",
+                "method": "starknet_getEvents",
+                "params": {
+                    "filter": {
+                        "from_block": { "block_number": 0 },
+                        "to_block": "pending",
+                        "chunk_size": 1000
+                    }
+                }
+            }),
+        )
+        .await;
+
+        assert_eq!(resp["id"], "events");
+        assert!(resp["result"]["events"].as_array().unwrap().len() >= N_TRANSACTIONS);
+        assert!(resp["result"]["continuation_token"].is_null());
+    }
+
+    #[tokio::test]
+    async fn error_of_streamable_method_is_returned_as_usual() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "starknet_getBlockWithTxs",
+                json!({ "block_id": { "block_number": 7 } }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 24);
+    }
+}
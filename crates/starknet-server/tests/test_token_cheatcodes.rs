@@ -0,0 +1,149 @@
+pub mod common;
+
+mod token_cheatcodes_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement, FunctionCall};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::{
+        PREDEPLOYED_ACCOUNT_ADDRESS, PREDEPLOYED_ACCOUNT_INITIAL_BALANCE,
+    };
+
+    static DUMMY_ADDRESS: &str = "0x42";
+    static DUMMY_AMOUNT: u128 = 42;
+
+    #[tokio::test]
+    async fn set_allowance_without_signature() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let created_block = &devnet
+            .send_custom_rpc(
+                "devnet_setAllowance",
+                json!({
+                    "token": ERC20_CONTRACT_ADDRESS,
+                    "owner": PREDEPLOYED_ACCOUNT_ADDRESS,
+                    "spender": DUMMY_ADDRESS,
+                    "amount": DUMMY_AMOUNT
+                }),
+            )
+            .await["result"];
+        assert_eq!(created_block["block_number"], 1);
+
+        let allowance = devnet
+            .json_rpc_client
+            .call(
+                FunctionCall {
+                    contract_address: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                    entry_point_selector: get_selector_from_name("allowance").unwrap(),
+                    calldata: vec![
+                        FieldElement::from_hex_be(PREDEPLOYED_ACCOUNT_ADDRESS).unwrap(),
+                        FieldElement::from_hex_be(DUMMY_ADDRESS).unwrap(),
+                    ],
+                },
+                BlockId::Tag(BlockTag::Latest),
+            )
+            .await
+            .unwrap();
+        assert_eq!(allowance, vec![FieldElement::from(DUMMY_AMOUNT), FieldElement::ZERO]);
+    }
+
+    #[tokio::test]
+    async fn transfer_token_without_signature() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let created_block = &devnet
+            .send_custom_rpc(
+                "devnet_transferToken",
+                json!({
+                    "token": ERC20_CONTRACT_ADDRESS,
+                    "from": PREDEPLOYED_ACCOUNT_ADDRESS,
+                    "to": DUMMY_ADDRESS,
+                    "amount": DUMMY_AMOUNT
+                }),
+            )
+            .await["result"];
+        assert!(created_block["block_hash"].as_str().unwrap().starts_with("0x"));
+
+        let sender_balance = devnet
+            .get_balance(&FieldElement::from_hex_be(PREDEPLOYED_ACCOUNT_ADDRESS).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(
+            sender_balance,
+            FieldElement::from(PREDEPLOYED_ACCOUNT_INITIAL_BALANCE - DUMMY_AMOUNT)
+        );
+
+        let recipient_balance =
+            devnet.get_balance(&FieldElement::from_hex_be(DUMMY_ADDRESS).unwrap()).await.unwrap();
+        assert_eq!(recipient_balance, FieldElement::from(DUMMY_AMOUNT));
+    }
+
+    #[tokio::test]
+    async fn transfer_exceeding_balance_is_rejected() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_transferToken",
+                json!({
+                    "token": ERC20_CONTRACT_ADDRESS,
+                    "from": DUMMY_ADDRESS,
+                    "to": PREDEPLOYED_ACCOUNT_ADDRESS,
+                    "amount": DUMMY_AMOUNT
+                }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 40);
+
+        let recipient_balance = devnet
+            .get_balance(&FieldElement::from_hex_be(PREDEPLOYED_ACCOUNT_ADDRESS).unwrap())
+            .await
+            .unwrap();
+        assert_eq!(recipient_balance, FieldElement::from(PREDEPLOYED_ACCOUNT_INITIAL_BALANCE));
+    }
+
+    #[tokio::test]
+    async fn set_allowance_requires_impersonation_api_key() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--impersonation-api-key", "secret"])
+                .await
+                .expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_setAllowance",
+                json!({
+                    "token": ERC20_CONTRACT_ADDRESS,
+                    "owner": PREDEPLOYED_ACCOUNT_ADDRESS,
+                    "spender": DUMMY_ADDRESS,
+                    "amount": DUMMY_AMOUNT
+                }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -32600, "Invalid resp: {resp}");
+    }
+
+    #[tokio::test]
+    async fn transfer_token_requires_impersonation_api_key() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--impersonation-api-key", "secret"])
+                .await
+                .expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_transferToken",
+                json!({
+                    "token": ERC20_CONTRACT_ADDRESS,
+                    "from": PREDEPLOYED_ACCOUNT_ADDRESS,
+                    "to": DUMMY_ADDRESS,
+                    "amount": DUMMY_AMOUNT
+                }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], -32600, "Invalid resp: {resp}");
+    }
+}
@@ -0,0 +1,99 @@
+pub mod common;
+
+mod trace_transaction_tests {
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    static DUMMY_ADDRESS: u128 = 1;
+    static DUMMY_AMOUNT: u128 = 1;
+
+    #[tokio::test]
+    async fn invoke_trace_contains_call_tree() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let resp = devnet
+            .send_custom_rpc(
+                "starknet_traceTransaction",
+                json!({ "transaction_hash": format!("{mint_tx_hash:#x}") }),
+            )
+            .await;
+        let trace = &resp["result"];
+        assert_eq!(trace["type"], "INVOKE", "Invalid resp: {resp}");
+        assert!(trace["validate_invocation"].is_object());
+
+        // the account calls the token, which emits the transfer event
+        let execute_invocation = &trace["execute_invocation"];
+        assert_eq!(execute_invocation["call_type"], "CALL");
+        let inner_calls = execute_invocation["calls"].as_array().unwrap();
+        assert!(!inner_calls.is_empty());
+        assert_eq!(inner_calls[0]["caller_address"], execute_invocation["contract_address"]);
+        assert!(!inner_calls[0]["events"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn invoke_trace_contains_state_diff() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mint_tx_hash = devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await;
+
+        let resp = devnet
+            .send_custom_rpc(
+                "starknet_traceTransaction",
+                json!({ "transaction_hash": format!("{mint_tx_hash:#x}") }),
+            )
+            .await;
+        let state_diff = &resp["result"]["state_diff"];
+
+        // the sender's nonce is bumped and the balances of the token are written
+        assert_eq!(state_diff["nonces"].as_array().unwrap().len(), 1, "Invalid resp: {resp}");
+        assert!(!state_diff["storage_diffs"].as_array().unwrap().is_empty());
+        assert!(state_diff["deployed_contracts"].as_array().unwrap().is_empty());
+        assert!(state_diff["declared_classes"].as_array().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn trace_of_unknown_transaction_is_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc("starknet_traceTransaction", json!({ "transaction_hash": "0x1" }))
+            .await;
+        assert_eq!(resp["error"]["code"], 29, "Invalid resp: {resp}");
+    }
+
+    #[tokio::test]
+    async fn block_traces_are_in_order_of_transactions() {
+        let devnet =
+            BackgroundDevnet::spawn_with_additional_args(&["--block-generation-on", "demand"])
+                .await
+                .expect("Could not start Devnet");
+        let tx_hashes =
+            [devnet.mint(DUMMY_ADDRESS, DUMMY_AMOUNT).await, devnet.mint(DUMMY_ADDRESS, 2).await];
+
+        let resp = devnet
+            .send_custom_rpc("starknet_traceBlockTransactions", json!({ "block_id": "pending" }))
+            .await;
+        let traces = resp["result"].as_array().unwrap();
+        assert_eq!(traces.len(), 2, "Invalid resp: {resp}");
+        for (trace, tx_hash) in traces.iter().zip(tx_hashes) {
+            let trace_tx_hash = trace["transaction_hash"].as_str().unwrap();
+            assert_eq!(FieldElement::from_hex_be(trace_tx_hash).unwrap(), tx_hash);
+            assert_eq!(trace["trace_root"]["type"], "INVOKE");
+        }
+    }
+
+    #[tokio::test]
+    async fn traces_of_unknown_block_are_not_found() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "starknet_traceBlockTransactions",
+                json!({ "block_id": { "block_number": 100 } }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 24, "Invalid resp: {resp}");
+    }
+}
@@ -0,0 +1,88 @@
+pub mod common;
+
+mod transaction_batch_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    /// Transfers 1 WEI from the always accepting `sender`, so the signature is arbitrary
+    fn transfer(sender: FieldElement, nonce: u32) -> serde_json::Value {
+        let selector = get_selector_from_name("transfer").unwrap();
+        json!({
+            "type": "INVOKE",
+            "max_fee": format!("{:#x}", 1e18 as u128),
+            "version": "0x1",
+            "signature": [],
+            "nonce": format!("{nonce:#x}"),
+            "sender_address": format!("{sender:#x}"),
+            // legacy encoding of one call with 3 elements of calldata
+            "calldata": [
+                "0x1", ERC20_CONTRACT_ADDRESS, format!("{selector:#x}"), "0x0", "0x3",
+                "0x3", "0x1", "0x1", "0x0"
+            ]
+        })
+    }
+
+    async fn setup() -> (BackgroundDevnet, FieldElement) {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+        devnet
+            .send_custom_rpc(
+                "devnet_setAccountTemplate",
+                json!({
+                    "address": format!("{account_address:#x}"),
+                    "template": { "name": "always_accept" }
+                }),
+            )
+            .await;
+
+        (devnet, account_address)
+    }
+
+    #[tokio::test]
+    async fn batch_is_sealed_in_one_block_in_order() {
+        let (devnet, sender) = setup().await;
+        let block_number = devnet.json_rpc_client.block_number().await.unwrap();
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_sendTransactionBatch",
+                json!({ "transactions": [transfer(sender, 0), transfer(sender, 1)] }),
+            )
+            .await;
+        let transaction_hashes = &resp["result"]["transaction_hashes"];
+        assert_eq!(transaction_hashes.as_array().unwrap().len(), 2, "Invalid resp: {resp}");
+
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), block_number + 1);
+        let block = devnet
+            .send_custom_rpc("starknet_getBlockWithTxHashes", json!({ "block_id": "latest" }))
+            .await;
+        assert_eq!(&block["result"]["transactions"], transaction_hashes);
+    }
+
+    #[tokio::test]
+    async fn batch_with_failing_transaction_is_rejected_as_a_whole() {
+        let (devnet, sender) = setup().await;
+        let block_number = devnet.json_rpc_client.block_number().await.unwrap();
+
+        // the nonce of the second transfer is ahead of the account nonce
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_sendTransactionBatch",
+                json!({ "transactions": [transfer(sender, 0), transfer(sender, 5)] }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 40, "Invalid resp: {resp}");
+        let revert_error = resp["error"]["data"]["revert_error"].as_str().unwrap();
+        assert!(revert_error.contains("index 1"), "Invalid resp: {resp}");
+
+        assert_eq!(devnet.json_rpc_client.block_number().await.unwrap(), block_number);
+        let nonce =
+            devnet.json_rpc_client.get_nonce(BlockId::Tag(BlockTag::Latest), sender).await.unwrap();
+        assert_eq!(nonce, FieldElement::ZERO);
+    }
+}
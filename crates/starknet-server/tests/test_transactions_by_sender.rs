@@ -0,0 +1,116 @@
+pub mod common;
+
+mod transactions_by_sender_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::FieldElement;
+    use starknet_rs_core::utils::get_selector_from_name;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+
+    /// Sends `count` transfers from the first predeployed account; returns its address and the
+    /// hashes of the transfers
+    async fn send_transfers(
+        devnet: &BackgroundDevnet,
+        count: usize,
+    ) -> (FieldElement, Vec<FieldElement>) {
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        let mut transaction_hashes = vec![];
+        for _ in 0..count {
+            let result = account
+                .execute(vec![Call {
+                    to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                    selector: get_selector_from_name("transfer").unwrap(),
+                    calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+                }])
+                .max_fee(FieldElement::from(1e18 as u128))
+                .send()
+                .await
+                .unwrap();
+            transaction_hashes.push(result.transaction_hash);
+        }
+
+        (account_address, transaction_hashes)
+    }
+
+    fn get_transaction_hashes(result: &serde_json::Value) -> Vec<FieldElement> {
+        result["transactions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|transaction| {
+                FieldElement::from_hex_be(transaction["transaction_hash"].as_str().unwrap())
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn transactions_of_sender_are_paginated() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        // minting is done by another account, so it is not listed
+        devnet.mint(0x1, 1).await;
+        let (account_address, transaction_hashes) = send_transfers(&devnet, 3).await;
+
+        let params = json!({ "address": format!("{account_address:#x}"), "chunk_size": 2 });
+        let first_page = &devnet
+            .send_custom_rpc("devnet_getTransactionsBySender", params.clone())
+            .await["result"];
+        assert_eq!(get_transaction_hashes(first_page), transaction_hashes[..2]);
+
+        let mut params = params;
+        params["continuation_token"] = first_page["continuation_token"].clone();
+        let second_page =
+            &devnet.send_custom_rpc("devnet_getTransactionsBySender", params).await["result"];
+        assert_eq!(get_transaction_hashes(second_page), transaction_hashes[2..]);
+        assert!(second_page.get("continuation_token").is_none());
+    }
+
+    #[tokio::test]
+    async fn transactions_of_sender_are_filtered_by_block_range() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.mint(0x1, 1).await;
+        // the transfers are in blocks 2, 3 and 4
+        let (account_address, transaction_hashes) = send_transfers(&devnet, 3).await;
+
+        let result = &devnet
+            .send_custom_rpc(
+                "devnet_getTransactionsBySender",
+                json!({
+                    "address": format!("{account_address:#x}"),
+                    "from_block": { "block_number": 3 },
+                    "to_block": "latest",
+                    "chunk_size": 10
+                }),
+            )
+            .await["result"];
+        assert_eq!(get_transaction_hashes(result), transaction_hashes[1..]);
+    }
+
+    #[tokio::test]
+    async fn unknown_block_is_reported() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+
+        let resp = devnet
+            .send_custom_rpc(
+                "devnet_getTransactionsBySender",
+                json!({
+                    "address": "0x1",
+                    "from_block": { "block_number": 1000 },
+                    "chunk_size": 10
+                }),
+            )
+            .await;
+        assert_eq!(resp["error"]["code"], 24);
+    }
+}
@@ -0,0 +1,111 @@
+pub mod common;
+
+mod validate_transaction_tests {
+    use serde_json::json;
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+    use crate::common::utils::{iter_to_hex_felt, to_hex_felt, to_num_as_hex};
+
+    /// Returns a signed transfer of the first predeployed account, as JSON
+    async fn signed_transfer(devnet: &BackgroundDevnet, nonce: FieldElement) -> serde_json::Value {
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        let max_fee = FieldElement::from(1e18 as u128);
+        let invoke_request = account
+            .execute(vec![Call {
+                to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                selector: get_selector_from_name("transfer").unwrap(),
+                calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+            }])
+            .max_fee(max_fee)
+            .nonce(nonce)
+            .prepared()
+            .unwrap()
+            .get_invoke_request(false)
+            .await
+            .unwrap();
+
+        json!({
+            "type": "INVOKE",
+            "max_fee": to_hex_felt(&max_fee),
+            "version": "0x1",
+            "signature": iter_to_hex_felt(&invoke_request.signature),
+            "nonce": to_num_as_hex(&nonce),
+            "calldata": iter_to_hex_felt(&invoke_request.calldata),
+            "sender_address": to_hex_felt(&account_address),
+        })
+    }
+
+    async fn validate(
+        devnet: &BackgroundDevnet,
+        transaction: serde_json::Value,
+    ) -> serde_json::Value {
+        devnet
+            .send_custom_rpc(
+                "devnet_validateTransaction",
+                json!({ "transaction": transaction, "block_id": "pending" }),
+            )
+            .await
+    }
+
+    #[tokio::test]
+    async fn signed_transaction_is_valid_and_not_added() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let transaction = signed_transfer(&devnet, FieldElement::ZERO).await;
+
+        let resp = validate(&devnet, transaction).await;
+        assert_eq!(resp["result"], json!({ "is_valid": true }));
+
+        let (_, account_address) = devnet.get_first_predeployed_account().await;
+        let nonce = devnet
+            .json_rpc_client
+            .get_nonce(BlockId::Tag(BlockTag::Pending), account_address)
+            .await
+            .unwrap();
+        assert_eq!(nonce, FieldElement::ZERO);
+    }
+
+    #[tokio::test]
+    async fn transaction_with_wrong_signature_is_invalid() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let mut transaction = signed_transfer(&devnet, FieldElement::ZERO).await;
+        transaction["signature"] = json!(["0x1", "0x2"]);
+
+        let resp = validate(&devnet, transaction).await;
+        assert_eq!(resp["result"]["is_valid"], false);
+        let failure_reason = resp["result"]["failure_reason"].as_str().unwrap();
+        assert!(failure_reason.starts_with("Account validation failed."), "{failure_reason}");
+    }
+
+    #[tokio::test]
+    async fn transaction_with_used_nonce_is_invalid() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        let transaction = signed_transfer(&devnet, FieldElement::ZERO).await;
+        let resp = devnet
+            .send_custom_rpc(
+                "starknet_addInvokeTransaction",
+                json!({ "invoke_transaction": transaction }),
+            )
+            .await;
+        assert!(resp["result"]["transaction_hash"].is_string(), "{resp}");
+
+        let resp = validate(&devnet, transaction).await;
+        assert_eq!(
+            resp["result"],
+            json!({ "is_valid": false, "failure_reason": "Account transaction nonce is invalid." })
+        );
+    }
+}
@@ -0,0 +1,57 @@
+pub mod common;
+
+mod verify_execution_tests {
+    use starknet_core::constants::ERC20_CONTRACT_ADDRESS;
+    use starknet_rs_accounts::{Account, Call, ExecutionEncoding, SingleOwnerAccount};
+    use starknet_rs_core::types::{BlockId, BlockTag, FieldElement};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_providers::Provider;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::constants::CHAIN_ID;
+    use crate::common::utils::get_json_body;
+
+    #[tokio::test]
+    async fn transactions_are_mined_with_verification_enabled() {
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--verify-execution"])
+            .await
+            .expect("Could not start Devnet");
+
+        let config = get_json_body(devnet.get("/config", None).await.unwrap()).await;
+        assert_eq!(config["verify_execution"], true);
+
+        let (signer, account_address) = devnet.get_first_predeployed_account().await;
+        let account = SingleOwnerAccount::new(
+            devnet.clone_provider(),
+            signer,
+            account_address,
+            CHAIN_ID,
+            ExecutionEncoding::Legacy,
+        );
+
+        for _ in 0..2 {
+            account
+                .execute(vec![Call {
+                    to: FieldElement::from_hex_be(ERC20_CONTRACT_ADDRESS).unwrap(),
+                    selector: get_selector_from_name("transfer").unwrap(),
+                    calldata: vec![FieldElement::ONE, FieldElement::ONE, FieldElement::ZERO],
+                }])
+                .max_fee(FieldElement::from(1e18 as u128))
+                .send()
+                .await
+                .unwrap();
+        }
+        devnet.mint(account_address, 1000).await;
+
+        let latest_block_number = devnet.json_rpc_client.block_number().await.unwrap();
+        assert_eq!(latest_block_number, 3);
+        assert_eq!(
+            devnet
+                .json_rpc_client
+                .get_nonce(BlockId::Tag(BlockTag::Latest), account_address)
+                .await
+                .unwrap(),
+            FieldElement::TWO
+        );
+    }
+}
@@ -0,0 +1,43 @@
+pub mod common;
+
+mod verify_integrity_tests {
+    use serde_json::json;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+    use crate::common::utils::UniqueAutoDeletableFile;
+
+    #[tokio::test]
+    async fn blocks_of_minted_transactions_are_valid() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        devnet.mint(0x1, 1).await;
+        devnet.mint(0x2, 1).await;
+
+        let report = &devnet.send_custom_rpc("devnet_verifyIntegrity", json!([])).await["result"];
+        assert_eq!(report["valid"], true, "{report}");
+        assert_eq!(report["checked_blocks"], 3);
+        assert_eq!(report["issues"], json!([]));
+    }
+
+    #[tokio::test]
+    async fn blocks_of_loaded_dump_are_valid() {
+        let dump_file = UniqueAutoDeletableFile::new("dump_verify_integrity");
+        let devnet_dump =
+            BackgroundDevnet::spawn_with_additional_args(&["--dump-path", &dump_file.path])
+                .await
+                .expect("Could not start Devnet");
+        devnet_dump.mint(0x1, 1).await;
+        devnet_dump
+            .post_json("/dump".into(), hyper::Body::from(json!({}).to_string()))
+            .await
+            .unwrap();
+
+        let devnet_load =
+            BackgroundDevnet::spawn_with_additional_args(&["--dump-path", &dump_file.path])
+                .await
+                .expect("Could not start Devnet");
+        let report =
+            &devnet_load.send_custom_rpc("devnet_verifyIntegrity", json!([])).await["result"];
+        assert_eq!(report["valid"], true, "{report}");
+        assert_eq!(report["checked_blocks"], 2);
+    }
+}
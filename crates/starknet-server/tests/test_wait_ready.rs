@@ -0,0 +1,27 @@
+pub mod common;
+
+mod wait_ready_tests {
+    use std::process::Command;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    fn wait_ready(url: &str, timeout: &str) -> bool {
+        Command::new("cargo")
+            .args(["run", "--release", "--"])
+            .args(["wait-ready", "--url", url, "--timeout", timeout, "--interval", "100"])
+            .status()
+            .expect("Could not run wait-ready")
+            .success()
+    }
+
+    #[tokio::test]
+    async fn succeeds_for_running_instance() {
+        let devnet = BackgroundDevnet::spawn().await.expect("Could not start Devnet");
+        assert!(wait_ready(&devnet.url, "10"));
+    }
+
+    #[tokio::test]
+    async fn fails_for_unreachable_instance() {
+        assert!(!wait_ready("http://127.0.0.1:1", "1"));
+    }
+}
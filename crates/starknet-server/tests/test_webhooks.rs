@@ -0,0 +1,89 @@
+pub mod common;
+
+mod webhooks_tests {
+    use std::net::SocketAddr;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    use axum::routing::post;
+    use axum::{Extension, Json, Router};
+    use hyper::Body;
+    use serde_json::json;
+    use starknet_rs_core::types::FieldElement;
+
+    use crate::common::background_devnet::BackgroundDevnet;
+
+    type Received = Arc<Mutex<Vec<serde_json::Value>>>;
+
+    async fn receive(
+        Extension(received): Extension<Received>,
+        Json(notification): Json<serde_json::Value>,
+    ) {
+        received.lock().unwrap().push(notification);
+    }
+
+    /// Serves a webhook on a free port; returns its URL and the notifications it receives
+    fn spawn_webhook() -> (String, Received) {
+        let received = Received::default();
+        let app = Router::new().route("/webhook", post(receive)).layer(Extension(received.clone()));
+        let server = axum::Server::bind(&SocketAddr::from(([127, 0, 0, 1], 0)))
+            .serve(app.into_make_service());
+        let url = format!("http://{}/webhook", server.local_addr());
+        tokio::spawn(server);
+
+        (url, received)
+    }
+
+    async fn wait_for_notifications(received: &Received, count: usize) -> Vec<serde_json::Value> {
+        for _ in 0..50 {
+            if received.lock().unwrap().len() >= count {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(100)).await;
+        }
+
+        received.lock().unwrap().clone()
+    }
+
+    #[tokio::test]
+    async fn included_transaction_and_sealed_block_are_posted() {
+        let (url, received) = spawn_webhook();
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--webhook-url", &url])
+            .await
+            .expect("Could not start Devnet");
+
+        let mint_hash = devnet.mint(0x1, 1).await;
+
+        let notifications = wait_for_notifications(&received, 2).await;
+        assert_eq!(notifications.len(), 2);
+
+        let to_felt =
+            |value: &serde_json::Value| FieldElement::from_hex_be(value.as_str().unwrap()).unwrap();
+        let included = &notifications[0];
+        assert_eq!(included["event"], "transaction_included");
+        assert_eq!(to_felt(&included["transaction_hash"]), mint_hash);
+        assert_eq!(included["transaction_type"], "INVOKE");
+        assert_eq!(included["execution_status"], "SUCCEEDED");
+        assert_eq!(included["block_number"], 1);
+
+        let sealed = &notifications[1];
+        assert_eq!(sealed["event"], "block_sealed");
+        assert_eq!(sealed["block_number"], 1);
+        assert_eq!(to_felt(&sealed["transaction_hashes"][0]), mint_hash);
+    }
+
+    #[tokio::test]
+    async fn empty_block_is_posted() {
+        let (url, received) = spawn_webhook();
+        let devnet = BackgroundDevnet::spawn_with_additional_args(&["--webhook-url", &url])
+            .await
+            .expect("Could not start Devnet");
+
+        devnet.post_json("/create_block".into(), Body::from(json!({}).to_string())).await.unwrap();
+
+        let notifications = wait_for_notifications(&received, 1).await;
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(notifications[0]["event"], "block_sealed");
+        assert_eq!(notifications[0]["transaction_hashes"], json!([]));
+    }
+}
@@ -84,6 +84,15 @@ impl StarknetBlocks {
         }
     }
 
+    /// Returns the last block with a timestamp not later than `timestamp`, i.e. the block whose
+    /// state is the state as of that time
+    pub fn get_by_timestamp(&self, timestamp: BlockTimestamp) -> Option<&StarknetBlock> {
+        self.num_to_block
+            .values()
+            .filter(|block| block.timestamp() <= timestamp)
+            .max_by_key(|block| block.block_number())
+    }
+
     /// Returns the block number from a block id, by finding the block by the block id
     fn block_number_from_block_id(&self, block_id: BlockId) -> Option<BlockNumber> {
         self.get_by_block_id(block_id).map(|block| block.block_number())
@@ -243,7 +252,7 @@ impl HashProducer for StarknetBlock {
 
 #[cfg(test)]
 mod tests {
-    use starknet_api::block::{BlockHash, BlockHeader, BlockNumber, BlockStatus};
+    use starknet_api::block::{BlockHash, BlockHeader, BlockNumber, BlockStatus, BlockTimestamp};
     use starknet_rs_core::types::{BlockId, BlockTag};
     use starknet_types::felt::Felt;
     use starknet_types::traits::HashProducer;
@@ -286,6 +295,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn block_is_found_by_timestamp() {
+        let mut blocks = StarknetBlocks::default();
+        for (block_number, timestamp) in [(0, 100), (1, 200), (2, 200), (3, 300)] {
+            let mut block = StarknetBlock::create_pending_block();
+            block.header.block_number = BlockNumber(block_number);
+            block.set_timestamp(BlockTimestamp(timestamp));
+            block.header.block_hash = block.generate_hash().unwrap().into();
+            blocks.insert(block, StateDiff::default());
+        }
+
+        let block_number_at = |timestamp| {
+            blocks.get_by_timestamp(BlockTimestamp(timestamp)).map(|block| block.block_number().0)
+        };
+        assert_eq!(block_number_at(99), None);
+        assert_eq!(block_number_at(100), Some(0));
+        assert_eq!(block_number_at(250), Some(2));
+        assert_eq!(block_number_at(1000), Some(3));
+    }
+
     #[test]
     fn block_number_from_block_id_should_return_correct_result() {
         let mut blocks = StarknetBlocks::default();
@@ -35,6 +35,10 @@ pub const UDC_CONTRACT_CLASS_HASH: &str =
 pub const UDC_CONTRACT_ADDRESS: &str =
     "0x41A78E741E5AF2FEC34B695679BC6891742439F7AFB8484ECD7766661AD02BF";
 
+/// Class declared and deployed when running the startup self-test or the compatibility check and
+/// when generating fixtures. Embedded, so that the binary does not depend on the source tree.
+pub const SELF_TEST_CONTRACT_JSON: &str = include_str!("../test_artifacts/cairo_0_test.json");
+
 pub const DEVNET_DEFAULT_SEED: u32 = 123;
 pub const DEVNET_DEFAULT_TEST_SEED: u32 = 123;
 pub const DEVNET_DEFAULT_TOTAL_ACCOUNTS: u8 = 10;
@@ -43,6 +47,9 @@ pub const DEVNET_DEFAULT_GAS_PRICE: u64 = 100_000_000_000;
 pub const DEVNET_DEFAULT_HOST: IpAddr = IpAddr::V4(Ipv4Addr::LOCALHOST);
 pub const DEVNET_DEFAULT_PORT: u16 = 5050;
 pub const DEVNET_DEFAULT_TIMEOUT: u16 = 120;
+pub const DEVNET_DEFAULT_MAX_REQUEST_BODY_SIZE: usize = 20_000_000;
+pub const DEVNET_DEFAULT_PROOF_PROVING_TIME: u64 = 10;
+pub const DEVNET_DEFAULT_PROOF_VERIFICATION_TIME: u64 = 20;
 pub const DEVNET_DEFAULT_CHAIN_ID: ChainId = ChainId::Testnet;
 
 pub const SUPPORTED_TX_VERSION: u32 = 1;
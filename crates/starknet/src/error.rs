@@ -32,6 +32,11 @@ pub enum Error {
     InvalidMintingTransaction { msg: String },
     #[error("No block found")]
     NoBlock,
+    #[error(
+        "Events of blocks before block {first_retained_block} are pruned due to the event \
+         retention"
+    )]
+    EventsPruned { first_retained_block: u64 },
     #[error("No state at block {block_number}")]
     NoStateAtBlock { block_number: u64 },
     #[error("Format error")]
@@ -56,6 +61,12 @@ pub enum Error {
     SerializationNotSupported,
     #[error("{tx_type}: max_fee cannot be zero")]
     MaxFeeZeroError { tx_type: String },
+    #[error("{tx_type}: max_fee {max_fee} exceeds the max fee cap {max_fee_cap}")]
+    MaxFeeAboveCap { tx_type: String, max_fee: u128, max_fee_cap: u128 },
+    #[error("{tx_type}: sender {sender:x} is not allowed to submit transactions")]
+    SenderNotAllowed { tx_type: String, sender: ContractAddress },
+    #[error("Transaction at index {index} of the batch failed, so none was added: {reason}")]
+    TransactionBatchError { index: usize, reason: String },
     #[error(transparent)]
     TransactionValidationError(#[from] TransactionValidationError),
     #[error(transparent)]
@@ -82,10 +93,25 @@ pub enum TransactionValidationError {
     InsufficientMaxFee,
     #[error("Account transaction nonce is invalid.")]
     InvalidTransactionNonce,
+    #[error(
+        "Account transaction nonce {transaction_nonce:x} is ahead of the account nonce \
+         {account_nonce:x}."
+    )]
+    NonceAheadOfAccount { account_nonce: Felt, transaction_nonce: Felt },
     #[error("Account balance is not enough to cover the transaction cost.")]
     InsufficientAccountBalance,
     #[error("Account validation failed.")]
     ValidationFailure,
+    #[error(
+        "Account validation failed. The transaction is signed for chain ID {matching_chain_id}, \
+         but Devnet uses chain ID {chain_id}."
+    )]
+    ChainIdMismatch { chain_id: String, matching_chain_id: String },
+    #[error(
+        "Compiled class hash {compiled_class_hash:x} of the transaction does not match the hash \
+         {expected_compiled_class_hash:x} of the class compiled by Devnet."
+    )]
+    CompiledClassHashMismatch { compiled_class_hash: Felt, expected_compiled_class_hash: Felt },
 }
 
 pub type DevnetResult<T, E = Error> = Result<T, E>;
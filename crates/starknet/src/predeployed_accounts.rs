@@ -48,6 +48,11 @@ impl PredeployedAccounts {
     pub fn get_accounts(&self) -> &Vec<Account> {
         &self.accounts
     }
+
+    /// Lists `accounts`, deployed by another instance, before the generated accounts
+    pub(crate) fn prepend_accounts(&mut self, accounts: Vec<Account>) {
+        self.accounts.splice(0..0, accounts);
+    }
 }
 
 impl AccountGenerator for PredeployedAccounts {
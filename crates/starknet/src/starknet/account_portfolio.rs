@@ -0,0 +1,110 @@
+use serde::{Deserialize, Serialize};
+use starknet_api::block::BlockNumber;
+use starknet_rs_core::types::{BlockId, BlockTag};
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::{ClassHash, Felt, Nonce, TransactionHash};
+use starknet_types::num_bigint::BigUint;
+
+use super::sender::get_selector;
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+
+/// What wallets and dashboards usually need to know about an account, as of the latest block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AccountPortfolio {
+    pub address: ContractAddress,
+    pub class_hash: ClassHash,
+    pub nonce: Nonce,
+    pub balances: Vec<TokenBalance>,
+    /// Hashes of the transactions sent by the account, the most recent first
+    pub recent_transactions: Vec<TransactionHash>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenBalance {
+    pub token_address: ContractAddress,
+    /// Decimal representation of the u256 amount
+    pub amount: String,
+    pub unit: String,
+}
+
+/// Collects the portfolio of the contract at `address`, with at most `max_transactions` of the
+/// transactions it sent
+pub fn get_account_portfolio(
+    starknet: &Starknet,
+    address: ContractAddress,
+    max_transactions: usize,
+) -> DevnetResult<AccountPortfolio> {
+    let block_id = BlockId::Tag(BlockTag::Latest);
+    let class_hash = starknet.get_class_hash_at(block_id, address)?;
+    let nonce = starknet.contract_nonce_at_block(block_id, address)?;
+
+    let fee_token_address = starknet.config.fee_token_address;
+    let fee_token_balance = TokenBalance {
+        token_address: fee_token_address,
+        amount: get_balance(starknet, block_id, fee_token_address, address)?.to_str_radix(10),
+        unit: "WEI".to_string(),
+    };
+
+    let recent_transactions =
+        starknet.sender_index.get(&address).iter().rev().take(max_transactions).copied().collect();
+
+    Ok(AccountPortfolio {
+        address,
+        class_hash,
+        nonce,
+        balances: vec![fee_token_balance],
+        recent_transactions,
+    })
+}
+
+/// The state of an account as seen by its next transaction, i.e. including the transactions of
+/// the pending block
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingStateSnapshot {
+    pub address: ContractAddress,
+    /// Number of the pending block, whose state the snapshot is of
+    pub block_number: BlockNumber,
+    pub class_hash: ClassHash,
+    pub nonce: Nonce,
+    /// Decimal representation of the u256 fee token balance in WEI
+    pub balance: String,
+}
+
+/// Reads all values from the same state, so that they are consistent with each other
+pub fn get_pending_state_snapshot(
+    starknet: &Starknet,
+    address: ContractAddress,
+) -> DevnetResult<PendingStateSnapshot> {
+    let block_id = BlockId::Tag(BlockTag::Pending);
+    let fee_token_address = starknet.config.fee_token_address;
+
+    Ok(PendingStateSnapshot {
+        address,
+        block_number: starknet.blocks.pending_block.header.block_number,
+        class_hash: starknet.get_class_hash_at(block_id, address)?,
+        nonce: starknet.contract_nonce_at_block(block_id, address)?,
+        balance: get_balance(starknet, block_id, fee_token_address, address)?.to_str_radix(10),
+    })
+}
+
+fn get_balance(
+    starknet: &Starknet,
+    block_id: BlockId,
+    token_address: ContractAddress,
+    address: ContractAddress,
+) -> DevnetResult<BigUint> {
+    let balance = starknet.call(
+        block_id,
+        token_address.into(),
+        get_selector("balanceOf")?.into(),
+        vec![address.into()],
+    )?;
+
+    match balance.as_slice() {
+        [low, high] => Ok((BigUint::from(*high) << 128) + BigUint::from(*low)),
+        _ => Err(Error::UnexpectedInternalError {
+            msg: format!("Token contract expected to return 2 values; got: {balance:?}"),
+        }),
+    }
+}
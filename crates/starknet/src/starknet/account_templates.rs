@@ -0,0 +1,225 @@
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::rpc::transactions::{BroadcastedDeclareTransaction, BroadcastedTransaction};
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error, TransactionValidationError};
+
+/// Validation logic applied by Devnet instead of `__validate__` of a predeployed account, to test
+/// how dapps handle misbehaving or exotic accounts. The class of the account stays the same.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "name", rename_all = "snake_case")]
+pub enum AccountTemplate {
+    /// `__validate__` of the account class is executed, as without a template
+    Standard,
+    /// Every transaction is accepted, regardless of its signature
+    AlwaysAccept,
+    /// Every transaction is rejected, as if `__validate__` failed
+    AlwaysReject,
+    /// Transactions are accepted regardless of their signature while `remaining` is positive;
+    /// each accepted transaction decrements it
+    CounterGated { remaining: u64 },
+}
+
+/// Templates of the predeployed accounts which do not use [`AccountTemplate::Standard`]
+#[derive(Debug, Default, Clone)]
+pub(crate) struct AccountTemplates(HashMap<ContractAddress, AccountTemplate>);
+
+thread_local! {
+    /// Cleared within [`without_account_templates`]
+    static TEMPLATES_APPLIED: Cell<bool> = Cell::new(true);
+}
+
+/// Restores whether templates are applied when dropped, also if the execution panics
+struct TemplatesAppliedGuard(bool);
+
+impl Drop for TemplatesAppliedGuard {
+    fn drop(&mut self) {
+        TEMPLATES_APPLIED.with(|applied| applied.set(self.0));
+    }
+}
+
+/// Runs `execute` as if no account had a template, so that `__validate__` of every sender is
+/// executed, e.g. for requests which are not allowed to impersonate accounts. Templates are applied
+/// again afterwards; the templates of the accounts are not changed.
+pub fn without_account_templates<T>(execute: impl FnOnce() -> T) -> T {
+    let _guard = TemplatesAppliedGuard(TEMPLATES_APPLIED.with(|applied| applied.replace(false)));
+    execute()
+}
+
+fn templates_applied() -> bool {
+    TEMPLATES_APPLIED.with(Cell::get)
+}
+
+pub(crate) fn set_account_template(
+    starknet: &mut Starknet,
+    address: ContractAddress,
+    template: AccountTemplate,
+) -> DevnetResult<()> {
+    let is_predeployed = starknet
+        .predeployed_accounts
+        .get_accounts()
+        .iter()
+        .any(|account| account.account_address == address);
+    if !is_predeployed {
+        return Err(Error::UnsupportedAction {
+            msg: "Templates can only be set for predeployed accounts".to_string(),
+        });
+    }
+
+    match template {
+        AccountTemplate::Standard => starknet.account_templates.0.remove(&address),
+        _ => starknet.account_templates.0.insert(address, template),
+    };
+
+    Ok(())
+}
+
+pub(crate) fn get_account_template(
+    starknet: &Starknet,
+    address: &ContractAddress,
+) -> AccountTemplate {
+    starknet.account_templates.0.get(address).copied().unwrap_or(AccountTemplate::Standard)
+}
+
+/// Returns whether `__validate__` of `sender_address` has to be executed, or the validation
+/// failure if the template of the sender rejects the transaction
+pub(crate) fn check_validation(
+    starknet: &Starknet,
+    sender_address: &ContractAddress,
+) -> DevnetResult<bool> {
+    if !templates_applied() {
+        return Ok(true);
+    }
+
+    match get_account_template(starknet, sender_address) {
+        AccountTemplate::Standard => Ok(true),
+        AccountTemplate::AlwaysAccept => Ok(false),
+        AccountTemplate::AlwaysReject | AccountTemplate::CounterGated { remaining: 0 } => {
+            Err(TransactionValidationError::ValidationFailure.into())
+        }
+        AccountTemplate::CounterGated { .. } => Ok(false),
+    }
+}
+
+/// Same as [`check_validation`], for the sender of `transaction`. Senders of deploy account
+/// transactions are not predeployed, so their validation is always executed.
+pub(crate) fn check_broadcasted_validation(
+    starknet: &Starknet,
+    transaction: &BroadcastedTransaction,
+) -> DevnetResult<bool> {
+    let sender_address = match transaction {
+        BroadcastedTransaction::Invoke(invoke) => &invoke.sender_address,
+        BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V1(declare)) => {
+            &declare.sender_address
+        }
+        BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V2(declare)) => {
+            &declare.sender_address
+        }
+        BroadcastedTransaction::DeployAccount(_) => return Ok(true),
+    };
+
+    check_validation(starknet, sender_address)
+}
+
+/// Counts a transaction of `sender_address`, accepted as allowed by [`check_validation`]
+pub(crate) fn record_accepted_transaction(
+    starknet: &mut Starknet,
+    sender_address: &ContractAddress,
+) {
+    if !templates_applied() {
+        return;
+    }
+
+    if let Some(AccountTemplate::CounterGated { remaining }) =
+        starknet.account_templates.0.get_mut(sender_address)
+    {
+        *remaining = remaining.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_types::contract_address::ContractAddress;
+
+    use super::{
+        check_validation, record_accepted_transaction, set_account_template,
+        without_account_templates, AccountTemplate,
+    };
+    use crate::error::{Error, TransactionValidationError};
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::utils::test_utils::dummy_contract_address;
+
+    fn setup() -> (Starknet, ContractAddress) {
+        let starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let account_address = starknet.get_predeployed_accounts()[0].account_address;
+        (starknet, account_address)
+    }
+
+    #[test]
+    fn counter_gated_account_rejects_once_counter_is_exhausted() {
+        let (mut starknet, account_address) = setup();
+        set_account_template(
+            &mut starknet,
+            account_address,
+            AccountTemplate::CounterGated { remaining: 2 },
+        )
+        .unwrap();
+
+        for _ in 0..2 {
+            assert!(!check_validation(&starknet, &account_address).unwrap());
+            record_accepted_transaction(&mut starknet, &account_address);
+        }
+
+        assert!(matches!(
+            check_validation(&starknet, &account_address).unwrap_err(),
+            Error::TransactionValidationError(TransactionValidationError::ValidationFailure)
+        ));
+    }
+
+    #[test]
+    fn standard_template_restores_validation() {
+        let (mut starknet, account_address) = setup();
+        set_account_template(&mut starknet, account_address, AccountTemplate::AlwaysReject)
+            .unwrap();
+        assert!(check_validation(&starknet, &account_address).is_err());
+
+        set_account_template(&mut starknet, account_address, AccountTemplate::Standard).unwrap();
+        assert!(check_validation(&starknet, &account_address).unwrap());
+    }
+
+    #[test]
+    fn templates_are_not_applied_without_account_templates() {
+        let (mut starknet, account_address) = setup();
+        set_account_template(
+            &mut starknet,
+            account_address,
+            AccountTemplate::CounterGated { remaining: 1 },
+        )
+        .unwrap();
+
+        without_account_templates(|| {
+            assert!(check_validation(&starknet, &account_address).unwrap());
+            record_accepted_transaction(&mut starknet, &account_address);
+        });
+
+        assert!(!check_validation(&starknet, &account_address).unwrap());
+    }
+
+    #[test]
+    fn template_is_not_set_for_other_contracts() {
+        let (mut starknet, _) = setup();
+
+        let err = set_account_template(
+            &mut starknet,
+            dummy_contract_address(),
+            AccountTemplate::AlwaysAccept,
+        )
+        .unwrap_err();
+        assert!(matches!(err, Error::UnsupportedAction { .. }));
+    }
+}
@@ -1,10 +1,15 @@
 use blockifier::transaction::transactions::ExecutableTransaction;
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use starknet_types::contract_class::{compute_casm_class_hash, ContractClass};
 use starknet_types::felt::{ClassHash, TransactionHash};
 use starknet_types::rpc::transactions::broadcasted_declare_transaction_v1::BroadcastedDeclareTransactionV1;
 use starknet_types::rpc::transactions::broadcasted_declare_transaction_v2::BroadcastedDeclareTransactionV2;
-use starknet_types::rpc::transactions::{DeclareTransaction, Transaction};
+use starknet_types::rpc::transactions::{
+    BroadcastedDeclareTransaction, BroadcastedTransaction, DeclareTransaction, Transaction,
+};
 
-use crate::error::{DevnetResult, Error};
+use super::{account_templates, chain_id_diagnostics, execution_verification};
+use crate::error::{DevnetResult, Error, TransactionValidationError};
 use crate::starknet::Starknet;
 
 pub fn add_declare_transaction_v2(
@@ -15,6 +20,23 @@ pub fn add_declare_transaction_v2(
         return Err(Error::MaxFeeZeroError { tx_type: "declare transaction v2".into() });
     }
 
+    // the sequencer compiles the class itself, so the declared CASM cannot differ from it
+    let casm_contract_class = CasmContractClass::try_from(ContractClass::Cairo1(
+        broadcasted_declare_transaction.contract_class.clone(),
+    ))?;
+    let expected_compiled_class_hash = compute_casm_class_hash(&casm_contract_class)?;
+    if broadcasted_declare_transaction.compiled_class_hash != expected_compiled_class_hash {
+        return Err(Error::TransactionValidationError(
+            TransactionValidationError::CompiledClassHashMismatch {
+                compiled_class_hash: broadcasted_declare_transaction.compiled_class_hash,
+                expected_compiled_class_hash,
+            },
+        ));
+    }
+
+    let sender_address = broadcasted_declare_transaction.sender_address;
+    let validate = account_templates::check_validation(starknet, &sender_address)?;
+
     let blockifier_declare_transaction =
         broadcasted_declare_transaction.create_blockifier_declare(starknet.chain_id().to_felt())?;
 
@@ -29,9 +51,21 @@ pub fn add_declare_transaction_v2(
         blockifier::transaction::account_transaction::AccountTransaction::Declare(
             blockifier_declare_transaction,
         )
-        .execute(&mut starknet.state.state, &starknet.block_context, true, true);
-
-    starknet.handle_transaction_result(transaction, blockifier_execution_result)?;
+        .execute(&mut starknet.state.state, &starknet.block_context, true, validate);
+    execution_verification::record_validation(starknet, transaction_hash, validate);
+
+    starknet.handle_transaction_result(transaction, blockifier_execution_result).map_err(
+        |err| {
+            chain_id_diagnostics::explain_validation_failure(
+                starknet,
+                &BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V2(Box::new(
+                    broadcasted_declare_transaction,
+                ))),
+                err,
+            )
+        },
+    )?;
+    account_templates::record_accepted_transaction(starknet, &sender_address);
 
     Ok((transaction_hash, class_hash))
 }
@@ -44,6 +78,9 @@ pub fn add_declare_transaction_v1(
         return Err(Error::MaxFeeZeroError { tx_type: "declare transaction v1".into() });
     }
 
+    let sender_address = broadcasted_declare_transaction.sender_address;
+    let validate = account_templates::check_validation(starknet, &sender_address)?;
+
     let class_hash = broadcasted_declare_transaction.generate_class_hash()?;
     let transaction_hash = broadcasted_declare_transaction
         .calculate_transaction_hash(&starknet.config.chain_id.to_felt(), &class_hash)?;
@@ -59,9 +96,21 @@ pub fn add_declare_transaction_v1(
         blockifier::transaction::account_transaction::AccountTransaction::Declare(
             blockifier_declare_transaction,
         )
-        .execute(&mut starknet.state.state, &starknet.block_context, true, true);
-
-    starknet.handle_transaction_result(transaction, blockifier_execution_result)?;
+        .execute(&mut starknet.state.state, &starknet.block_context, true, validate);
+    execution_verification::record_validation(starknet, transaction_hash, validate);
+
+    starknet.handle_transaction_result(transaction, blockifier_execution_result).map_err(
+        |err| {
+            chain_id_diagnostics::explain_validation_failure(
+                starknet,
+                &BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V1(Box::new(
+                    broadcasted_declare_transaction,
+                ))),
+                err,
+            )
+        },
+    )?;
+    account_templates::record_accepted_transaction(starknet, &sender_address);
 
     Ok((transaction_hash, class_hash))
 }
@@ -126,6 +175,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn declare_transaction_v2_with_mismatching_compiled_class_hash_is_rejected() {
+        let (mut starknet, sender) = setup(Some(100000000));
+        let mut declare_txn = dummy_broadcasted_declare_transaction_v2(&sender);
+        let expected_compiled_class_hash = declare_txn.compiled_class_hash;
+        declare_txn.compiled_class_hash = dummy_felt();
+
+        match starknet.add_declare_transaction_v2(declare_txn.clone()).unwrap_err() {
+            crate::error::Error::TransactionValidationError(
+                crate::error::TransactionValidationError::CompiledClassHashMismatch {
+                    compiled_class_hash,
+                    expected_compiled_class_hash: expected,
+                },
+            ) => {
+                assert_eq!(compiled_class_hash, dummy_felt());
+                assert_eq!(expected, expected_compiled_class_hash);
+            }
+            err => panic!("Wrong error type received {:?}", err),
+        }
+
+        let class_hash = ContractClass::Cairo1(declare_txn.contract_class).generate_hash().unwrap();
+        assert!(!starknet.state.is_contract_declared(&class_hash));
+    }
+
     #[test]
     fn add_declare_v2_transaction_should_return_rejected_txn_and_not_be_part_of_pending_state() {
         let (mut starknet, sender) = setup(Some(1));
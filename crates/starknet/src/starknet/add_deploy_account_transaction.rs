@@ -2,15 +2,24 @@ use blockifier::transaction::transactions::ExecutableTransaction;
 use starknet_types::contract_address::ContractAddress;
 use starknet_types::felt::TransactionHash;
 use starknet_types::rpc::transactions::broadcasted_deploy_account_transaction::BroadcastedDeployAccountTransaction;
-use starknet_types::rpc::transactions::Transaction;
+use starknet_types::rpc::transactions::{BroadcastedTransaction, Transaction};
 
-use super::Starknet;
+use super::{chain_id_diagnostics, execution_verification, Starknet};
 use crate::error::{DevnetResult, Error};
 use crate::traits::StateExtractor;
 
 pub fn add_deploy_account_transaction(
     starknet: &mut Starknet,
     broadcasted_deploy_account_transaction: BroadcastedDeployAccountTransaction,
+) -> DevnetResult<(TransactionHash, ContractAddress)> {
+    execute_deploy_account_transaction(starknet, broadcasted_deploy_account_transaction, true)
+}
+
+/// Adds the transaction, executing `__validate_deploy__` of the account only if `validate` is set
+pub(crate) fn execute_deploy_account_transaction(
+    starknet: &mut Starknet,
+    broadcasted_deploy_account_transaction: BroadcastedDeployAccountTransaction,
+    validate: bool,
 ) -> DevnetResult<(TransactionHash, ContractAddress)> {
     if broadcasted_deploy_account_transaction.common.max_fee.0 == 0 {
         return Err(Error::MaxFeeZeroError { tx_type: "deploy account transaction".into() });
@@ -36,9 +45,18 @@ pub fn add_deploy_account_transaction(
         blockifier::transaction::account_transaction::AccountTransaction::DeployAccount(
             blockifier_deploy_account_transaction,
         )
-        .execute(&mut starknet.state.state, &starknet.block_context, true, true);
-
-    starknet.handle_transaction_result(transaction, blockifier_execution_result)?;
+        .execute(&mut starknet.state.state, &starknet.block_context, true, validate);
+    execution_verification::record_validation(starknet, transaction_hash, validate);
+
+    starknet.handle_transaction_result(transaction, blockifier_execution_result).map_err(
+        |err| {
+            chain_id_diagnostics::explain_validation_failure(
+                starknet,
+                &BroadcastedTransaction::DeployAccount(broadcasted_deploy_account_transaction),
+                err,
+            )
+        },
+    )?;
 
     Ok((transaction_hash, address))
 }
@@ -1,9 +1,12 @@
 use blockifier::transaction::transactions::ExecutableTransaction;
 use starknet_types::felt::TransactionHash;
 use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
-use starknet_types::rpc::transactions::{InvokeTransaction, Transaction};
+use starknet_types::rpc::transactions::{BroadcastedTransaction, InvokeTransaction, Transaction};
 
-use super::Starknet;
+use super::{
+    account_templates, chain_id_diagnostics, counterfactual_accounts, execution_verification,
+    step_limits, Starknet,
+};
 use crate::error::{DevnetResult, Error};
 
 pub fn add_invoke_transaction(
@@ -14,6 +17,26 @@ pub fn add_invoke_transaction(
         return Err(Error::MaxFeeZeroError { tx_type: "invoke transaction".into() });
     }
 
+    let sender_address = broadcasted_invoke_transaction.sender_address;
+    if let Some(account) = counterfactual_accounts::undeployed_account(starknet, &sender_address) {
+        return counterfactual_accounts::deploy_and_invoke(
+            starknet,
+            account,
+            broadcasted_invoke_transaction,
+        );
+    }
+
+    execute_invoke_transaction(starknet, broadcasted_invoke_transaction)
+}
+
+/// Adds the transaction of a sender which is deployed already
+pub(crate) fn execute_invoke_transaction(
+    starknet: &mut Starknet,
+    broadcasted_invoke_transaction: BroadcastedInvokeTransaction,
+) -> DevnetResult<TransactionHash> {
+    let sender_address = broadcasted_invoke_transaction.sender_address;
+    let validate = account_templates::check_validation(starknet, &sender_address)?;
+
     let blockifier_invoke_transaction = broadcasted_invoke_transaction
         .create_blockifier_invoke_transaction(starknet.chain_id().to_felt(), false)?;
     let transaction_hash = blockifier_invoke_transaction.tx_hash.0.into();
@@ -26,9 +49,36 @@ pub fn add_invoke_transaction(
         blockifier::transaction::account_transaction::AccountTransaction::Invoke(
             blockifier_invoke_transaction,
         )
-        .execute(&mut starknet.state.state, &starknet.block_context, true, true);
+        .execute(&mut starknet.state.state, &starknet.block_context, true, validate);
+    execution_verification::record_validation(starknet, transaction_hash, validate);
+
+    if starknet.config.reject_on_revert {
+        if let Ok(tx_info) = &blockifier_execution_result {
+            if let Some(revert_error) = tx_info.revert_error.clone() {
+                // the execution served as a dry run; nothing of it is kept
+                starknet.state.clear_dirty_state();
+                return Err(Error::ExecutionError { revert_error });
+            }
+        }
+    }
 
-    starknet.handle_transaction_result(transaction, blockifier_execution_result)?;
+    if let Ok(tx_info) = &blockifier_execution_result {
+        if let Err(err) = step_limits::check_transaction(starknet, tx_info) {
+            starknet.state.clear_dirty_state();
+            return Err(err);
+        }
+    }
+
+    starknet.handle_transaction_result(transaction, blockifier_execution_result).map_err(
+        |err| {
+            chain_id_diagnostics::explain_validation_failure(
+                starknet,
+                &BroadcastedTransaction::Invoke(broadcasted_invoke_transaction),
+                err,
+            )
+        },
+    )?;
+    account_templates::record_accepted_transaction(starknet, &sender_address);
 
     Ok(transaction_hash)
 }
@@ -173,6 +223,56 @@ mod tests {
         }
     }
 
+    #[test]
+    fn invoke_transaction_with_max_fee_above_cap_is_rejected() {
+        let (mut starknet, account_address, contract_address, increase_balance_selector, _) =
+            setup();
+        starknet.config.max_fee_cap = Some(4999);
+
+        let invoke_transaction = test_invoke_transaction_v1(
+            account_address,
+            contract_address,
+            increase_balance_selector,
+            Felt::from(10),
+            0,
+        );
+
+        match starknet.add_invoke_transaction(invoke_transaction).unwrap_err() {
+            err @ crate::error::Error::MaxFeeAboveCap { .. } => assert_eq!(
+                err.to_string(),
+                "invoke transaction: max_fee 5000 exceeds the max fee cap 4999"
+            ),
+            err => panic!("Wrong error type: {:?}", err),
+        }
+        assert!(starknet.transactions.iter().next().is_none());
+    }
+
+    #[test]
+    fn invoke_transaction_of_sender_not_allowed_is_rejected() {
+        let (mut starknet, account_address, contract_address, increase_balance_selector, _) =
+            setup();
+        starknet.config.allowed_senders = vec![ContractAddress::new(Felt::from(1)).unwrap()];
+
+        let invoke_transaction = test_invoke_transaction_v1(
+            account_address,
+            contract_address,
+            increase_balance_selector,
+            Felt::from(10),
+            0,
+        );
+
+        match starknet.add_invoke_transaction(invoke_transaction.clone()).unwrap_err() {
+            crate::error::Error::SenderNotAllowed { sender, .. } => {
+                assert_eq!(sender, account_address)
+            }
+            err => panic!("Wrong error type: {:?}", err),
+        }
+        assert!(starknet.transactions.iter().next().is_none());
+
+        starknet.config.allowed_senders.push(account_address);
+        starknet.add_invoke_transaction(invoke_transaction).unwrap();
+    }
+
     #[test]
     fn invoke_transaction_should_return_an_error_if_same_nonce_supplied() {
         let (mut starknet, account_address, contract_address, increase_balance_selector, _) =
@@ -202,6 +302,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn invoke_transaction_with_nonce_ahead_of_account_nonce_is_rejected_with_details() {
+        let (mut starknet, account_address, contract_address, increase_balance_selector, _) =
+            setup();
+
+        let invoke_transaction = test_invoke_transaction_v1(
+            account_address,
+            contract_address,
+            increase_balance_selector,
+            Felt::from(10),
+            3,
+        );
+
+        match starknet.add_invoke_transaction(invoke_transaction).unwrap_err() {
+            crate::error::Error::TransactionValidationError(
+                crate::error::TransactionValidationError::NonceAheadOfAccount {
+                    account_nonce,
+                    transaction_nonce,
+                },
+            ) => {
+                assert_eq!(account_nonce, Felt::from(0));
+                assert_eq!(transaction_nonce, Felt::from(3));
+            }
+            err => {
+                panic!("Wrong error type: {:?}", err);
+            }
+        }
+        assert!(starknet.transactions.iter().next().is_none());
+    }
+
     #[test]
     fn nonce_should_be_incremented_if_invoke_reverted() {
         let (mut starknet, account_address, contract_address, increase_balance_selector, _) =
@@ -0,0 +1,172 @@
+use starknet_types::chain_id::ChainId;
+use starknet_types::contract_class::ContractClass;
+use starknet_types::felt::Felt;
+
+use super::starknet_config::{BlockGeneration, StarknetConfig};
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+use crate::predeployed_accounts::PredeployedAccounts;
+use crate::state::StarknetState;
+use crate::traits::{AccountGenerator, Accounted, Deployed};
+
+/// A dump whose head state is the genesis state of a new instance, on top of which the accounts of
+/// the new instance are predeployed. The other properties are the predeployment options of the
+/// instance that produced the dump, which are needed to re-execute its transactions.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BaseDump {
+    pub path: String,
+    /// Seed of the instance whose predeployed accounts sent the transactions of the dump; differs
+    /// from the seed of the new instance, whose accounts would otherwise be the same
+    pub seed: u32,
+    pub total_accounts: u8,
+    pub account_contract_class: ContractClass,
+    pub account_contract_class_hash: Felt,
+    pub predeployed_accounts_initial_balance: Felt,
+    pub chain_id: ChainId,
+}
+
+impl BaseDump {
+    /// A base dump produced by an instance with `seed` and the default predeployment options
+    pub fn with_default_options(path: String, seed: u32) -> Self {
+        let defaults = StarknetConfig::default();
+        Self {
+            path,
+            seed,
+            total_accounts: defaults.total_accounts,
+            account_contract_class: defaults.account_contract_class,
+            account_contract_class_hash: defaults.account_contract_class_hash,
+            predeployed_accounts_initial_balance: defaults.predeployed_accounts_initial_balance,
+            chain_id: defaults.chain_id,
+        }
+    }
+}
+
+/// Re-executes the transactions of `base_dump` in an instance with the predeployments of the
+/// instance that produced it, and moves its head state into `state`. The accounts of `config` are
+/// then predeployed, after the accounts of the base instance.
+pub(crate) fn import_base_state(
+    config: &StarknetConfig,
+    base_dump: &BaseDump,
+    state: &mut StarknetState,
+) -> DevnetResult<PredeployedAccounts> {
+    if base_dump.seed == config.seed {
+        return Err(Error::UnsupportedAction {
+            msg: format!(
+                "The seed {} of the base dump is also the seed of the new instance, whose \
+                 accounts would be the accounts of the base instance",
+                config.seed
+            ),
+        });
+    }
+
+    let mut base = Starknet::new(&StarknetConfig {
+        seed: base_dump.seed,
+        total_accounts: base_dump.total_accounts,
+        account_contract_class: base_dump.account_contract_class.clone(),
+        account_contract_class_hash: base_dump.account_contract_class_hash,
+        predeployed_accounts_initial_balance: base_dump.predeployed_accounts_initial_balance,
+        chain_id: base_dump.chain_id,
+        base_dump: None,
+        dump_on: None,
+        dump_path: None,
+        re_execute_on_init: false,
+        start_time: None,
+        block_max_steps: None,
+        block_max_txs: None,
+        block_generation: BlockGeneration::Transaction,
+        verify_execution: false,
+        webhook_urls: vec![],
+        allowed_senders: vec![],
+        ..config.clone()
+    })?;
+    let transactions = base.load_transactions_custom_path(Some(base_dump.path.clone()))?;
+    base.re_execute(transactions)?;
+    *state = std::mem::take(&mut base.state);
+
+    let mut predeployed_accounts = PredeployedAccounts::new(
+        config.seed,
+        config.predeployed_accounts_initial_balance,
+        config.fee_token_address,
+    );
+    let accounts = predeployed_accounts.generate_accounts(
+        config.total_accounts,
+        config.account_contract_class_hash,
+        config.account_contract_class.clone(),
+    )?;
+    for account in accounts {
+        account.deploy(state)?;
+        account.set_initial_balance(state)?;
+    }
+    predeployed_accounts.prepend_accounts(base.predeployed_accounts.get_accounts().clone());
+
+    Ok(predeployed_accounts)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BaseDump;
+    use crate::error::Error;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+
+    #[test]
+    fn accounts_of_the_new_instance_are_predeployed_after_the_base_accounts() {
+        let dump_path = std::env::temp_dir().join("devnet_base_dump_accounts.json");
+        std::fs::write(&dump_path, "[]").unwrap();
+        let dump_path = dump_path.to_str().unwrap().to_string();
+
+        let base_config = StarknetConfig { seed: 1, ..StarknetConfig::default() };
+        let base_accounts = Starknet::new(&base_config).unwrap().get_predeployed_accounts();
+        // the base instance keeps its own predeployment options
+        let config = StarknetConfig {
+            seed: 2,
+            total_accounts: 2,
+            base_dump: Some(BaseDump::with_default_options(dump_path.clone(), base_config.seed)),
+            ..StarknetConfig::default()
+        };
+        let fresh_accounts = Starknet::new(&StarknetConfig { base_dump: None, ..config.clone() })
+            .unwrap()
+            .get_predeployed_accounts();
+
+        let starknet = Starknet::new(&config).unwrap();
+        std::fs::remove_file(dump_path).unwrap();
+
+        let addresses = |accounts: Vec<crate::account::Account>| {
+            accounts.into_iter().map(|account| account.account_address).collect::<Vec<_>>()
+        };
+        assert_eq!(
+            addresses(starknet.get_predeployed_accounts()),
+            [addresses(base_accounts), addresses(fresh_accounts)].concat()
+        );
+        assert_eq!(starknet.get_latest_block().unwrap().block_number().0, 0);
+    }
+
+    #[test]
+    fn seed_of_the_base_dump_has_to_differ() {
+        let config = StarknetConfig {
+            seed: 1,
+            base_dump: Some(BaseDump::with_default_options("[]".to_string(), 1)),
+            ..StarknetConfig::default()
+        };
+
+        match Starknet::new(&config) {
+            Err(Error::UnsupportedAction { msg }) => assert!(msg.contains("seed 1"), "{msg}"),
+            Err(err) => panic!("Unexpected error: {err}"),
+            Ok(_) => panic!("Should have failed"),
+        }
+    }
+
+    #[test]
+    fn missing_base_dump_is_an_error() {
+        let config = StarknetConfig {
+            seed: 2,
+            base_dump: Some(BaseDump::with_default_options(
+                "nonexistent_base_dump.json".to_string(),
+                1,
+            )),
+            ..StarknetConfig::default()
+        };
+
+        assert!(Starknet::new(&config).is_err());
+    }
+}
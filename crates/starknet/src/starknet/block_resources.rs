@@ -0,0 +1,106 @@
+use std::collections::BTreeMap;
+
+use blockifier::abi::constants::L1_GAS_USAGE;
+use blockifier::execution::call_info::CallInfo;
+use blockifier::transaction::objects::TransactionExecutionInfo;
+use serde::{Deserialize, Serialize};
+use starknet_api::block::BlockNumber;
+use starknet_rs_core::types::BlockId;
+use starknet_types::constants::N_STEPS;
+use starknet_types::felt::BlockHash;
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+
+/// Execution resources of all transactions of a block, summed up
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BlockExecutionResources {
+    pub block_hash: BlockHash,
+    pub block_number: BlockNumber,
+    pub transaction_count: u64,
+    pub steps: u64,
+    pub memory_holes: u64,
+    pub l1_gas_usage: u64,
+    /// Number of applications of each builtin, keyed by builtin name (e.g. `range_check_builtin`)
+    pub builtin_instance_counter: BTreeMap<String, u64>,
+}
+
+impl BlockExecutionResources {
+    fn add_transaction(&mut self, execution_info: &TransactionExecutionInfo) {
+        fn memory_holes(call_info: &Option<CallInfo>) -> u64 {
+            call_info.as_ref().map(|call| call.vm_resources.n_memory_holes as u64).unwrap_or(0)
+        }
+
+        self.transaction_count += 1;
+        self.memory_holes += memory_holes(&execution_info.validate_call_info)
+            + memory_holes(&execution_info.execute_call_info)
+            + memory_holes(&execution_info.fee_transfer_call_info);
+
+        for (resource_name, amount) in execution_info.actual_resources.0.iter() {
+            let amount = *amount as u64;
+            match resource_name.as_str() {
+                N_STEPS => self.steps += amount,
+                L1_GAS_USAGE => self.l1_gas_usage += amount,
+                builtin_name => {
+                    *self.builtin_instance_counter.entry(builtin_name.to_string()).or_default() +=
+                        amount
+                }
+            }
+        }
+    }
+}
+
+pub fn get_block_execution_resources(
+    starknet: &Starknet,
+    block_id: BlockId,
+) -> DevnetResult<BlockExecutionResources> {
+    let block = starknet.blocks.get_by_block_id(block_id).ok_or(Error::NoBlock)?;
+
+    let mut resources = BlockExecutionResources {
+        block_hash: block.block_hash(),
+        block_number: block.block_number(),
+        ..Default::default()
+    };
+    for transaction_hash in block.get_transactions() {
+        let transaction =
+            starknet.transactions.get(transaction_hash).ok_or(Error::NoTransaction)?;
+        resources.add_transaction(&transaction.execution_info);
+    }
+
+    Ok(resources)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use blockifier::abi::constants::L1_GAS_USAGE;
+    use blockifier::transaction::objects::{ResourcesMapping, TransactionExecutionInfo};
+    use starknet_types::constants::{N_STEPS, RANGE_CHECK_BUILTIN_NAME};
+
+    use super::BlockExecutionResources;
+
+    fn execution_info(steps: usize, range_checks: usize) -> TransactionExecutionInfo {
+        TransactionExecutionInfo {
+            actual_resources: ResourcesMapping(HashMap::from([
+                (N_STEPS.to_string(), steps),
+                (RANGE_CHECK_BUILTIN_NAME.to_string(), range_checks),
+                (L1_GAS_USAGE.to_string(), 1),
+            ])),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn resources_of_transactions_are_summed_up() {
+        let mut resources = BlockExecutionResources::default();
+        resources.add_transaction(&execution_info(100, 3));
+        resources.add_transaction(&execution_info(50, 2));
+
+        assert_eq!(resources.transaction_count, 2);
+        assert_eq!(resources.steps, 150);
+        assert_eq!(resources.l1_gas_usage, 2);
+        assert_eq!(resources.builtin_instance_counter[RANGE_CHECK_BUILTIN_NAME], 5);
+        assert!(!resources.builtin_instance_counter.contains_key(N_STEPS));
+    }
+}
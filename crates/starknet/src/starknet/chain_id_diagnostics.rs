@@ -0,0 +1,62 @@
+use blockifier::transaction::transactions::ExecutableTransaction;
+use starknet_rs_core::utils::cairo_short_string_to_felt;
+use starknet_types::felt::Felt;
+use starknet_types::rpc::transactions::BroadcastedTransaction;
+
+use super::Starknet;
+use crate::error::{Error, TransactionValidationError};
+
+/// Chain IDs commonly used by wallets and SDKs
+const COMMON_CHAIN_IDS: [&str; 4] = ["SN_MAIN", "SN_GOERLI", "SN_GOERLI2", "SN_SEPOLIA"];
+
+/// A transaction failing account validation is often signed for another chain than the one of
+/// Devnet. If the transaction would have passed validation under one of the common chain IDs, the
+/// error is replaced with one naming that chain ID; otherwise the error is returned unchanged.
+pub(crate) fn explain_validation_failure(
+    starknet: &Starknet,
+    transaction: &BroadcastedTransaction,
+    error: Error,
+) -> Error {
+    if !matches!(
+        error,
+        Error::TransactionValidationError(TransactionValidationError::ValidationFailure)
+    ) {
+        return error;
+    }
+
+    match find_matching_chain_id(starknet, transaction) {
+        Some(matching_chain_id) => TransactionValidationError::ChainIdMismatch {
+            chain_id: starknet.chain_id().to_string(),
+            matching_chain_id: matching_chain_id.to_string(),
+        }
+        .into(),
+        None => error,
+    }
+}
+
+fn find_matching_chain_id(
+    starknet: &Starknet,
+    transaction: &BroadcastedTransaction,
+) -> Option<&'static str> {
+    let devnet_chain_id = starknet.chain_id().to_felt();
+
+    COMMON_CHAIN_IDS.into_iter().find(|chain_id| {
+        let Ok(chain_id) = cairo_short_string_to_felt(chain_id).map(Felt::from) else {
+            return false;
+        };
+        if chain_id == devnet_chain_id {
+            return false;
+        }
+        let Ok(blockifier_transaction) =
+            transaction.to_blockifier_account_transaction(chain_id, false)
+        else {
+            return false;
+        };
+
+        // executed on a copy of the state, so that nothing of it is kept
+        let mut state = starknet.state.clone();
+        blockifier_transaction
+            .execute(&mut state.state, &starknet.block_context, false, true)
+            .is_ok()
+    })
+}
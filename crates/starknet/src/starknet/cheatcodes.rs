@@ -0,0 +1,300 @@
+use blockifier::execution::entry_point::CallEntryPoint;
+use blockifier::state::state_api::State;
+use starknet_rs_core::utils::get_selector_from_name;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::contract_storage_key::ContractStorageKey;
+use starknet_types::felt::Felt;
+use starknet_types::rpc::state::ThinStateDiff;
+
+use super::journal::ChangeCause;
+use super::{execution_verification, Starknet};
+use crate::error::{DevnetResult, Error, StateError};
+use crate::state::state_diff::StateDiff;
+use crate::traits::{StateChanger, StateExtractor};
+use crate::utils::get_storage_var_address;
+
+/// Sets the allowance of `spender` over the tokens of `owner` by invoking `approve` of `token`
+/// on behalf of `owner`
+pub fn set_allowance(
+    starknet: &mut Starknet,
+    token: ContractAddress,
+    owner: ContractAddress,
+    spender: ContractAddress,
+    amount: u128,
+) -> DevnetResult<()> {
+    execute_as(
+        starknet,
+        "devnet_setAllowance",
+        owner,
+        token,
+        "approve",
+        vec![Felt::from(spender), Felt::from(amount), Felt::from(0)],
+    )
+}
+
+/// Moves `amount` of `token` from `from` to `to` by invoking `transfer` of `token` on behalf of
+/// `from`
+pub fn transfer_token(
+    starknet: &mut Starknet,
+    token: ContractAddress,
+    from: ContractAddress,
+    to: ContractAddress,
+    amount: u128,
+) -> DevnetResult<()> {
+    execute_as(
+        starknet,
+        "devnet_transferToken",
+        from,
+        token,
+        "transfer",
+        vec![Felt::from(to), Felt::from(amount), Felt::from(0)],
+    )
+}
+
+/// Replaces the public key of the account at `address` by writing its `Account_public_key` storage
+/// variable directly, so none of the entry points of the account is invoked. The resulting state
+/// changes are stored in a new block.
+pub fn set_account_public_key(
+    starknet: &mut Starknet,
+    address: ContractAddress,
+    public_key: Felt,
+) -> DevnetResult<()> {
+    if !starknet.state.is_contract_deployed(&address) {
+        return Err(Error::ContractNotFound);
+    }
+
+    let public_key_storage_var = get_storage_var_address("Account_public_key", &[])?;
+    let current_public_key =
+        starknet.state.get_storage(ContractStorageKey::new(address, public_key_storage_var))?;
+    // any contract has storage, so an unset key is the only sign of a contract not being an account
+    if current_public_key == Felt::default() {
+        return Err(Error::UnsupportedAction {
+            msg: "The contract has no public key to replace".to_string(),
+        });
+    }
+
+    starknet.state.state.set_storage_at(
+        address.try_into()?,
+        starknet_api::state::StorageKey(public_key_storage_var.try_into()?),
+        public_key.into(),
+    );
+
+    store_state_changes(starknet, "devnet_setAccountPublicKey")
+}
+
+/// Executes an entry point with `caller` as the caller address, without a transaction, so neither
+/// a signature nor a fee is needed. The resulting state changes are stored in a new block and
+/// journaled as caused by `method`.
+fn execute_as(
+    starknet: &mut Starknet,
+    method: &str,
+    caller: ContractAddress,
+    contract_address: ContractAddress,
+    entry_point_name: &str,
+    calldata: Vec<Felt>,
+) -> DevnetResult<()> {
+    if !starknet.state.is_contract_deployed(&contract_address) {
+        return Err(Error::ContractNotFound);
+    }
+
+    let entry_point_selector: Felt =
+        get_selector_from_name(entry_point_name).map_err(|_| Error::FormatError)?.into();
+    let call = CallEntryPoint {
+        calldata: starknet_api::transaction::Calldata(std::sync::Arc::new(
+            calldata.iter().map(|f| f.into()).collect(),
+        )),
+        storage_address: contract_address.try_into()?,
+        caller_address: caller.try_into()?,
+        entry_point_selector: starknet_api::core::EntryPointSelector(entry_point_selector.into()),
+        initial_gas: blockifier::transaction::transaction_execution::Transaction::initial_gas(),
+        ..Default::default()
+    };
+
+    let mut execution_resources = blockifier::execution::entry_point::ExecutionResources::default();
+    let mut execution_context =
+        blockifier::execution::entry_point::EntryPointExecutionContext::new(
+            &starknet.block_context,
+            &blockifier::transaction::objects::AccountTransactionContext::Deprecated(
+                blockifier::transaction::objects::DeprecatedAccountTransactionContext::default(),
+            ),
+            blockifier::execution::common_hints::ExecutionMode::Execute,
+            true,
+        )?;
+
+    let execution_result =
+        call.execute(&mut starknet.state.state, &mut execution_resources, &mut execution_context);
+    let revert_error = match execution_result {
+        Ok(call_info) if !call_info.execution.failed => None,
+        Ok(_) => Some(format!("Execution of {entry_point_name} failed")),
+        Err(err) => Some(err.to_string()),
+    };
+    if let Some(revert_error) = revert_error {
+        // discard changes of the failed execution
+        starknet.state.clear_dirty_state();
+        return Err(Error::ExecutionError { revert_error });
+    }
+
+    store_state_changes(starknet, method)
+}
+
+/// Writes the storage values, nonces and classes of contracts of `state_diff` directly, e.g. to
+/// apply the changes exported from another instance. A class cannot be transferred by its hash, so
+/// every class referenced by the diff has to be declared already. The changes are stored in a new
+/// block.
+pub fn apply_state_diff(starknet: &mut Starknet, state_diff: ThinStateDiff) -> DevnetResult<()> {
+    let referenced_classes = state_diff
+        .declared_classes
+        .iter()
+        .map(|declared_class| declared_class.class_hash)
+        .chain(state_diff.deprecated_declared_classes.iter().copied())
+        .chain(state_diff.deployed_contracts.iter().map(|contract| contract.class_hash))
+        .chain(state_diff.replaced_classes.iter().map(|replaced| replaced.class_hash));
+    for class_hash in referenced_classes {
+        if !starknet.state.is_contract_declared(&class_hash) {
+            return Err(Error::StateError(StateError::NoneClassHash(class_hash)));
+        }
+    }
+
+    let mut state_difference = StateDiff::default();
+    for contract in state_diff.deployed_contracts {
+        state_difference.address_to_class_hash.insert(contract.address, contract.class_hash);
+    }
+
+    let is_deployed = |address: &ContractAddress| {
+        starknet.state.is_contract_deployed(address)
+            || state_difference.address_to_class_hash.contains_key(address)
+    };
+    for replaced in &state_diff.replaced_classes {
+        if !starknet.state.is_contract_deployed(&replaced.contract_address) {
+            return Err(Error::ContractNotFound);
+        }
+    }
+    for storage_diff in &state_diff.storage_diffs {
+        if !is_deployed(&storage_diff.address) {
+            return Err(Error::ContractNotFound);
+        }
+    }
+    for contract_nonce in &state_diff.nonces {
+        if !is_deployed(&contract_nonce.contract_address) {
+            return Err(Error::ContractNotFound);
+        }
+    }
+
+    for replaced in state_diff.replaced_classes {
+        state_difference
+            .address_to_class_hash
+            .insert(replaced.contract_address, replaced.class_hash);
+    }
+    for storage_diff in state_diff.storage_diffs {
+        state_difference
+            .storage_updates
+            .entry(storage_diff.address)
+            .or_default()
+            .extend(storage_diff.storage_entries.into_iter().map(|entry| (entry.key, entry.value)));
+    }
+    for contract_nonce in state_diff.nonces {
+        state_difference
+            .address_to_nonce
+            .insert(contract_nonce.contract_address, contract_nonce.nonce);
+    }
+
+    store_state_difference(starknet, "devnet_applyStateDiff", state_difference)
+}
+
+/// Moves the changes of the pending state to the persistent state and into a new block
+fn store_state_changes(starknet: &mut Starknet, method: &str) -> DevnetResult<()> {
+    let state_difference = starknet.state.extract_state_diff_from_pending_state()?;
+    store_state_difference(starknet, method, state_difference)
+}
+
+/// Applies `state_difference` to the persistent state and stores it in a new block, journaled as
+/// caused by `method`
+fn store_state_difference(
+    starknet: &mut Starknet,
+    method: &str,
+    state_difference: StateDiff,
+) -> DevnetResult<()> {
+    starknet.state.apply_state_difference(state_difference.clone())?;
+    // values read before the changes are cached
+    starknet.state.clear_dirty_state();
+    starknet.journal.record(
+        starknet.blocks.pending_block.block_number().0,
+        ChangeCause::Cheatcode { method: method.to_string() },
+        state_difference.clone(),
+    );
+    starknet.pending_state_diff.extend(state_difference);
+    execution_verification::record_direct_changes(starknet);
+    starknet.create_block(None)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_types::contract_storage_key::ContractStorageKey;
+    use starknet_types::felt::Felt;
+    use starknet_types::patricia_key::PatriciaKey;
+    use starknet_types::rpc::state::{
+        ContractNonce, DeployedContract, StorageDiff, StorageEntry, ThinStateDiff,
+    };
+
+    use super::apply_state_diff;
+    use crate::error::{Error, StateError};
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::traits::StateExtractor;
+    use crate::utils::test_utils::dummy_contract_address;
+
+    #[test]
+    fn storage_and_nonce_of_deployed_contract_are_applied() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let account = starknet.get_predeployed_accounts()[0].clone();
+        let (address, class_hash) = (account.account_address, account.class_hash);
+        let new_address = dummy_contract_address();
+        let key = PatriciaKey::new(Felt::from(42)).unwrap();
+
+        apply_state_diff(
+            &mut starknet,
+            ThinStateDiff {
+                deployed_contracts: vec![DeployedContract { address: new_address, class_hash }],
+                storage_diffs: vec![StorageDiff {
+                    address: new_address,
+                    storage_entries: vec![StorageEntry { key, value: Felt::from(7) }],
+                }],
+                nonces: vec![ContractNonce { contract_address: address, nonce: Felt::from(5) }],
+                ..Default::default()
+            },
+        )
+        .unwrap();
+
+        assert_eq!(
+            starknet.state.get_storage(ContractStorageKey::new(new_address, key)).unwrap(),
+            Felt::from(7)
+        );
+        assert_eq!(starknet.state.get_nonce(&address).unwrap(), Felt::from(5));
+        assert_eq!(starknet.get_latest_block().unwrap().block_number().0, 1);
+    }
+
+    #[test]
+    fn diff_with_undeclared_class_is_not_applied() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let address = starknet.get_predeployed_accounts()[0].account_address;
+
+        let err = apply_state_diff(
+            &mut starknet,
+            ThinStateDiff {
+                deployed_contracts: vec![DeployedContract {
+                    address: dummy_contract_address(),
+                    class_hash: Felt::from(1234),
+                }],
+                nonces: vec![ContractNonce { contract_address: address, nonce: Felt::from(5) }],
+                ..Default::default()
+            },
+        )
+        .unwrap_err();
+
+        assert!(matches!(err, Error::StateError(StateError::NoneClassHash(_))));
+        assert_eq!(starknet.state.get_nonce(&address).unwrap(), Felt::from(0));
+        assert_eq!(starknet.get_latest_block().unwrap().block_number().0, 0);
+    }
+}
@@ -0,0 +1,142 @@
+use super::account_templates::AccountTemplates;
+use super::counterfactual_accounts::CounterfactualAccounts;
+use super::dump::DumpRecord;
+use super::execution_verification::ExecutionVerification;
+use super::notifications::DevnetNotification;
+use super::transactions_by_sender::SenderIndex;
+use super::Starknet;
+use crate::blocks::StarknetBlock;
+use crate::error::DevnetResult;
+use crate::state::state_diff::StateDiff;
+use crate::state::StarknetState;
+
+/// What the acceptance of a transaction causes outside of the instance. Deferred within
+/// [`add_atomically`], so that nothing is notified or dumped of transactions which are rolled
+/// back.
+pub(crate) struct DeferredEffects {
+    pub(crate) notification: DevnetNotification,
+    /// Set if transactions are dumped on every transaction
+    pub(crate) transaction_to_dump: Option<DumpRecord>,
+}
+
+/// Everything that accepting transactions into the pending block changes. Transactions and
+/// journal entries are only appended, so only their number is kept.
+struct Checkpoint {
+    state: StarknetState,
+    pending_block: StarknetBlock,
+    pending_state_diff: StateDiff,
+    transactions_len: usize,
+    sender_index: SenderIndex,
+    journal_len: usize,
+    account_templates: AccountTemplates,
+    counterfactual_accounts: CounterfactualAccounts,
+    execution_verification: ExecutionVerification,
+}
+
+impl Checkpoint {
+    fn new(starknet: &Starknet) -> Self {
+        Self {
+            state: starknet.state.clone(),
+            pending_block: starknet.blocks.pending_block.clone(),
+            pending_state_diff: starknet.pending_state_diff.clone(),
+            transactions_len: starknet.transactions.len(),
+            sender_index: starknet.sender_index.clone(),
+            journal_len: starknet.journal.len(),
+            account_templates: starknet.account_templates.clone(),
+            counterfactual_accounts: starknet.counterfactual_accounts.clone(),
+            execution_verification: starknet.execution_verification.clone(),
+        }
+    }
+
+    fn restore(self, starknet: &mut Starknet) {
+        starknet.state = self.state;
+        starknet.blocks.pending_block = self.pending_block;
+        starknet.pending_state_diff = self.pending_state_diff;
+        starknet.transactions.truncate(self.transactions_len);
+        starknet.sender_index = self.sender_index;
+        starknet.journal.truncate(self.journal_len);
+        starknet.account_templates = self.account_templates;
+        starknet.counterfactual_accounts = self.counterfactual_accounts;
+        starknet.execution_verification = self.execution_verification;
+    }
+}
+
+/// Runs `add`, which accepts transactions into the pending block, so that either all of them are
+/// kept or, if `add` fails, none of them. Meanwhile the pending block is not sealed, so the caller
+/// decides whether to seal it afterwards. The message to L1 hook is still called for transactions
+/// which are rolled back.
+pub(crate) fn add_atomically<T>(
+    starknet: &mut Starknet,
+    add: impl FnOnce(&mut Starknet) -> DevnetResult<T>,
+) -> DevnetResult<T> {
+    let checkpoint = Checkpoint::new(starknet);
+    let was_paused = std::mem::replace(&mut starknet.mining_paused, true);
+    let outer_effects = starknet.deferred_effects.replace(vec![]);
+
+    let result = add(starknet);
+
+    starknet.mining_paused = was_paused;
+    let effects =
+        std::mem::replace(&mut starknet.deferred_effects, outer_effects).unwrap_or_default();
+    if result.is_err() {
+        checkpoint.restore(starknet);
+        return result;
+    }
+
+    match &mut starknet.deferred_effects {
+        // nested in another atomic addition, which may still be rolled back
+        Some(outer_effects) => outer_effects.extend(effects),
+        None => {
+            for effects in effects {
+                starknet.notify(effects.notification);
+                if let Some(record) = effects.transaction_to_dump {
+                    starknet.dump_transaction(&record)?;
+                }
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_api::transaction::Fee;
+    use starknet_types::traits::HashProducer;
+
+    use super::add_atomically;
+    use crate::error::Error;
+    use crate::starknet::account_templates::AccountTemplate;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::traits::StateExtractor;
+    use crate::utils::test_utils::dummy_broadcasted_declare_transaction_v2;
+
+    #[test]
+    fn failed_addition_is_rolled_back() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let sender = starknet.get_predeployed_accounts()[0].account_address;
+        starknet.set_account_template(sender, AccountTemplate::AlwaysAccept).unwrap();
+        let mut declare_transaction = dummy_broadcasted_declare_transaction_v2(&sender);
+        declare_transaction.common.max_fee = Fee(1e18 as u128);
+        let class_hash = starknet_types::contract_class::ContractClass::Cairo1(
+            declare_transaction.contract_class.clone(),
+        )
+        .generate_hash()
+        .unwrap();
+        let latest_block_number = starknet.get_latest_block().unwrap().block_number();
+        let transactions_len = starknet.transactions.len();
+
+        let result = add_atomically(&mut starknet, |starknet| {
+            starknet.add_declare_transaction_v2(declare_transaction)?;
+            Err::<(), _>(Error::UnsupportedAction { msg: "Rolled back".into() })
+        });
+
+        assert!(matches!(result, Err(Error::UnsupportedAction { .. })));
+        assert_eq!(starknet.get_latest_block().unwrap().block_number(), latest_block_number);
+        assert!(starknet.pending_block().get_transactions().is_empty());
+        assert_eq!(starknet.transactions.len(), transactions_len);
+        assert!(!starknet.state.is_contract_declared(&class_hash));
+        assert!(!starknet.is_mining_paused());
+    }
+}
@@ -0,0 +1,307 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use starknet_rs_core::types::{BlockId, BlockTag};
+use starknet_types::contract_class::ContractClass;
+use starknet_types::felt::{ClassHash, Felt};
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+
+const ENTRY_POINT_TYPES: [&str; 3] = ["CONSTRUCTOR", "EXTERNAL", "L1_HANDLER"];
+
+/// Structural difference between two declared classes. Everything is reported from the
+/// perspective of replacing class A with class B.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ClassDiff {
+    pub class_hash_a: ClassHash,
+    pub class_hash_b: ClassHash,
+    pub identical: bool,
+    pub abi: AbiDiff,
+    pub entry_points_by_type: BTreeMap<String, EntryPointsDiff>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AbiDiff {
+    pub added: Vec<Value>,
+    pub removed: Vec<Value>,
+    pub changed: Vec<ChangedAbiEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChangedAbiEntry {
+    pub name: String,
+    pub r#type: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+/// Entry points are identified by their selectors; offsets and function indices are ignored, as
+/// they change with every recompilation.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntryPointsDiff {
+    pub added: Vec<Felt>,
+    pub removed: Vec<Felt>,
+}
+
+impl AbiDiff {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl EntryPointsDiff {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+pub fn compare_classes(
+    starknet: &Starknet,
+    class_hash_a: ClassHash,
+    class_hash_b: ClassHash,
+) -> DevnetResult<ClassDiff> {
+    let block_id = BlockId::Tag(BlockTag::Latest);
+    let class_a = to_json(starknet.get_class(block_id, class_hash_a)?)?;
+    let class_b = to_json(starknet.get_class(block_id, class_hash_b)?)?;
+
+    let abi = diff_abi(&get_abi_entries(&class_a)?, &get_abi_entries(&class_b)?);
+
+    let mut entry_points_by_type = BTreeMap::new();
+    for entry_point_type in ENTRY_POINT_TYPES {
+        let entry_points_diff = diff_selectors(
+            &get_selectors(&class_a, entry_point_type)?,
+            &get_selectors(&class_b, entry_point_type)?,
+        );
+        entry_points_by_type.insert(entry_point_type.to_string(), entry_points_diff);
+    }
+
+    let identical = class_hash_a == class_hash_b
+        || (abi.is_empty() && entry_points_by_type.values().all(EntryPointsDiff::is_empty));
+
+    Ok(ClassDiff { class_hash_a, class_hash_b, identical, abi, entry_points_by_type })
+}
+
+pub(crate) fn to_json(contract_class: ContractClass) -> DevnetResult<Value> {
+    serde_json::to_value(contract_class)
+        .map_err(|_| Error::SerializationError { obj_name: "ContractClass".to_string() })
+}
+
+/// Returns the items of the ABI of `contract_class`. In Sierra artifacts the ABI may be stored as a
+/// JSON string, so it is parsed if needed.
+pub(crate) fn get_abi_items(contract_class: &Value) -> DevnetResult<Vec<Value>> {
+    let abi = match contract_class.get("abi") {
+        Some(Value::String(abi_str)) => serde_json::from_str(abi_str)
+            .map_err(|_| Error::DeserializationError { obj_name: "ABI".to_string() })?,
+        Some(abi) => abi.clone(),
+        None => Value::Null,
+    };
+    // Sierra ABI may be wrapped in an object with an `items` property
+    let items = match abi {
+        Value::Array(items) => items,
+        Value::Object(mut abi_object) => match abi_object.remove("items") {
+            Some(Value::Array(items)) => items,
+            _ => vec![],
+        },
+        _ => vec![],
+    };
+
+    Ok(items)
+}
+
+/// Returns ABI entries keyed by their type and name. Entries without a name are keyed by their
+/// signature, so they are matched regardless of their position and a modified one is reported as
+/// removed and added.
+fn get_abi_entries(contract_class: &Value) -> DevnetResult<BTreeMap<(String, String), Value>> {
+    let mut entries = BTreeMap::new();
+    for item in get_abi_items(contract_class)? {
+        let entry_type = item.get("type").and_then(Value::as_str).unwrap_or_default().to_string();
+        let name = match item.get("name").and_then(Value::as_str) {
+            Some(name) => name.to_string(),
+            None => signature_of(&item),
+        };
+        entries.insert((entry_type, name), item);
+    }
+
+    Ok(entries)
+}
+
+/// Serializes an ABI entry with its object keys sorted, so that the same entry has the same
+/// signature regardless of the order of its properties
+fn signature_of(entry: &Value) -> String {
+    match entry {
+        Value::Object(properties) => {
+            let mut sorted_properties: Vec<_> = properties.iter().collect();
+            sorted_properties.sort_by(|(key_a, _), (key_b, _)| key_a.cmp(key_b));
+            let properties: Vec<String> = sorted_properties
+                .into_iter()
+                .map(|(key, value)| {
+                    format!("{}:{}", Value::String(key.clone()), signature_of(value))
+                })
+                .collect();
+            format!("{{{}}}", properties.join(","))
+        }
+        Value::Array(items) => {
+            let items: Vec<String> = items.iter().map(signature_of).collect();
+            format!("[{}]", items.join(","))
+        }
+        _ => entry.to_string(),
+    }
+}
+
+fn diff_abi(
+    entries_a: &BTreeMap<(String, String), Value>,
+    entries_b: &BTreeMap<(String, String), Value>,
+) -> AbiDiff {
+    let mut diff = AbiDiff::default();
+
+    for (key, entry_a) in entries_a {
+        match entries_b.get(key) {
+            None => diff.removed.push(entry_a.clone()),
+            Some(entry_b) if entry_a != entry_b => diff.changed.push(ChangedAbiEntry {
+                name: key.1.clone(),
+                r#type: key.0.clone(),
+                before: entry_a.clone(),
+                after: entry_b.clone(),
+            }),
+            Some(_) => {}
+        }
+    }
+
+    diff.added = entries_b
+        .iter()
+        .filter(|(key, _)| !entries_a.contains_key(key))
+        .map(|(_, entry)| entry.clone())
+        .collect();
+
+    diff
+}
+
+fn get_selectors(contract_class: &Value, entry_point_type: &str) -> DevnetResult<Vec<Felt>> {
+    let entry_points = match contract_class
+        .get("entry_points_by_type")
+        .and_then(|entry_points_by_type| entry_points_by_type.get(entry_point_type))
+    {
+        Some(Value::Array(entry_points)) => entry_points,
+        _ => return Ok(vec![]),
+    };
+
+    entry_points
+        .iter()
+        .map(|entry_point| {
+            let selector = entry_point.get("selector").and_then(Value::as_str).ok_or(
+                Error::DeserializationError { obj_name: "entry point selector".to_string() },
+            )?;
+            Ok(Felt::from_prefixed_hex_str(selector)?)
+        })
+        .collect()
+}
+
+fn diff_selectors(selectors_a: &[Felt], selectors_b: &[Felt]) -> EntryPointsDiff {
+    EntryPointsDiff {
+        added: selectors_b.iter().filter(|s| !selectors_a.contains(s)).cloned().collect(),
+        removed: selectors_a.iter().filter(|s| !selectors_b.contains(s)).cloned().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+    use starknet_types::felt::Felt;
+
+    use super::{diff_abi, diff_selectors, get_abi_entries, get_selectors};
+
+    #[test]
+    fn abi_diff_detects_added_removed_and_changed_entries() {
+        let class_a = json!({
+            "abi": [
+                { "type": "function", "name": "get_balance", "inputs": [], "outputs": [] },
+                { "type": "function", "name": "withdraw", "inputs": [] },
+            ]
+        });
+        let class_b = json!({
+            "abi": [
+                {
+                    "type": "function",
+                    "name": "get_balance",
+                    "inputs": [{ "name": "user", "type": "felt" }],
+                    "outputs": []
+                },
+                { "type": "event", "name": "Deposited", "keys": [], "data": [] },
+            ]
+        });
+
+        let diff =
+            diff_abi(&get_abi_entries(&class_a).unwrap(), &get_abi_entries(&class_b).unwrap());
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0]["name"], "Deposited");
+        assert_eq!(diff.removed.len(), 1);
+        assert_eq!(diff.removed[0]["name"], "withdraw");
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].name, "get_balance");
+    }
+
+    #[test]
+    fn unnamed_abi_entries_are_matched_by_content() {
+        let class_a = json!({
+            "abi": [
+                { "type": "constructor", "inputs": [{ "name": "owner", "type": "felt" }] },
+                { "type": "l1_handler", "inputs": [] },
+            ]
+        });
+        let class_b = json!({
+            "abi": [
+                { "type": "function", "name": "get_owner", "inputs": [], "outputs": [] },
+                { "inputs": [], "type": "l1_handler" },
+                { "inputs": [{ "type": "felt", "name": "owner" }], "type": "constructor" },
+            ]
+        });
+
+        let diff =
+            diff_abi(&get_abi_entries(&class_a).unwrap(), &get_abi_entries(&class_b).unwrap());
+
+        assert_eq!(diff.added.len(), 1);
+        assert_eq!(diff.added[0]["name"], "get_owner");
+        assert!(diff.removed.is_empty());
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn abi_stored_as_string_is_parsed() {
+        let class = json!({ "abi": r#"[{ "type": "function", "name": "foo" }]"# });
+        let entries = get_abi_entries(&class).unwrap();
+
+        assert!(entries.contains_key(&("function".to_string(), "foo".to_string())));
+    }
+
+    #[test]
+    fn selectors_are_compared_regardless_of_padding() {
+        let class_a = json!({
+            "entry_points_by_type": {
+                "EXTERNAL": [
+                    { "selector": "0x0000000000000000000000000000000000000000000000000000000000000a", "offset": "0x1" },
+                    { "selector": "0xb", "offset": "0x2" },
+                ]
+            }
+        });
+        let class_b = json!({
+            "entry_points_by_type": {
+                "EXTERNAL": [
+                    { "selector": "0xa", "function_idx": 0 },
+                    { "selector": "0xc", "function_idx": 1 },
+                ]
+            }
+        });
+
+        let diff = diff_selectors(
+            &get_selectors(&class_a, "EXTERNAL").unwrap(),
+            &get_selectors(&class_b, "EXTERNAL").unwrap(),
+        );
+
+        assert_eq!(diff.added, vec![Felt::from(0xc)]);
+        assert_eq!(diff.removed, vec![Felt::from(0xb)]);
+        assert!(get_selectors(&class_a, "L1_HANDLER").unwrap().is_empty());
+    }
+}
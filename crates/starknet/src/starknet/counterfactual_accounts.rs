@@ -0,0 +1,376 @@
+use std::collections::HashMap;
+
+use blockifier::transaction::account_transaction::AccountTransaction;
+use blockifier::transaction::objects::TransactionExecutionInfo;
+use blockifier::transaction::transactions::ExecutableTransaction;
+use serde::{Deserialize, Serialize};
+use starknet_api::transaction::Fee;
+use starknet_rs_core::types::ExecutionResult;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::{Calldata, ClassHash, ContractAddressSalt, Felt, TransactionHash};
+use starknet_types::rpc::transactions::broadcasted_deploy_account_transaction::BroadcastedDeployAccountTransaction;
+use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
+use starknet_types::rpc::transactions::BroadcastedTransaction;
+use starknet_types::traits::ToHexString;
+
+use super::{
+    add_deploy_account_transaction, add_invoke_transaction, checkpoint, execution_verification,
+    Starknet,
+};
+use crate::error::{DevnetResult, Error, StateError, TransactionValidationError};
+use crate::state::StarknetState;
+use crate::traits::{StateChanger, StateExtractor};
+
+/// The deploy account transaction of an account, without its fee and signature, which Devnet
+/// sends on behalf of the account before its first invoke transaction
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct CounterfactualAccount {
+    pub class_hash: ClassHash,
+    pub contract_address_salt: ContractAddressSalt,
+    pub constructor_calldata: Calldata,
+}
+
+impl CounterfactualAccount {
+    fn to_deploy_account_transaction(&self, max_fee: Fee) -> BroadcastedDeployAccountTransaction {
+        BroadcastedDeployAccountTransaction::new(
+            &self.constructor_calldata,
+            max_fee,
+            &vec![],
+            Felt::from(0),
+            self.class_hash,
+            self.contract_address_salt,
+            Felt::from(1),
+        )
+    }
+}
+
+/// Registered accounts which are not deployed yet, by their address
+#[derive(Debug, Default, Clone)]
+pub(crate) struct CounterfactualAccounts(HashMap<ContractAddress, CounterfactualAccount>);
+
+impl CounterfactualAccounts {
+    pub(crate) fn is_registered(&self, address: &ContractAddress) -> bool {
+        self.0.contains_key(address)
+    }
+}
+
+/// Registers `account` to be deployed on the first invoke transaction sent from its address,
+/// which is returned
+pub(crate) fn register_counterfactual_account(
+    starknet: &mut Starknet,
+    account: CounterfactualAccount,
+) -> DevnetResult<ContractAddress> {
+    if !starknet.state.is_contract_declared(&account.class_hash) {
+        return Err(Error::StateError(StateError::NoneClassHash(account.class_hash)));
+    }
+
+    let address: ContractAddress = account
+        .to_deploy_account_transaction(Fee(0))
+        .create_blockifier_deploy_account(starknet.chain_id().to_felt(), false)?
+        .contract_address
+        .into();
+    if starknet.state.is_contract_deployed(&address) {
+        return Err(Error::UnsupportedAction {
+            msg: format!("Account at {} is already deployed", address.to_prefixed_hex_str()),
+        });
+    }
+
+    starknet.counterfactual_accounts.0.insert(address, account);
+    Ok(address)
+}
+
+/// The registration of `address`, if the account is not deployed yet. Registrations of accounts
+/// which were deployed by a deploy account transaction of their own are dropped.
+pub(crate) fn undeployed_account(
+    starknet: &mut Starknet,
+    address: &ContractAddress,
+) -> Option<CounterfactualAccount> {
+    let account = starknet.counterfactual_accounts.0.get(address)?.clone();
+    if starknet.state.is_contract_deployed(address) {
+        starknet.counterfactual_accounts.0.remove(address);
+        return None;
+    }
+
+    Some(account)
+}
+
+/// Adds `invoke_transaction`, the first one of the registered `account`, after a deploy account
+/// transaction, which Devnet sends on behalf of the account. The deployment is not validated,
+/// because Devnet cannot sign it. Both transactions are paid from the max fee of the invoke
+/// transaction, and if either of them fails, reverts or the max fee does not cover both, neither
+/// of them is added.
+pub(crate) fn deploy_and_invoke(
+    starknet: &mut Starknet,
+    account: CounterfactualAccount,
+    invoke_transaction: BroadcastedInvokeTransaction,
+) -> DevnetResult<TransactionHash> {
+    let sender_address = invoke_transaction.sender_address;
+    let max_fee = invoke_transaction.common.max_fee;
+
+    let transaction_hash = checkpoint::add_atomically(starknet, |starknet| {
+        let deploy_transaction = account.to_deploy_account_transaction(max_fee);
+        starknet.counterfactual_deployment = Some((sender_address, account));
+        let deployment = add_deploy_account_transaction::execute_deploy_account_transaction(
+            starknet,
+            deploy_transaction,
+            false,
+        );
+        starknet.counterfactual_deployment = None;
+        let (deploy_transaction_hash, _) = deployment?;
+        // re-executing the deployment would use up the nonce
+        execution_verification::record_direct_changes(starknet);
+        starknet.counterfactual_accounts.0.remove(&sender_address);
+
+        let invoke_transaction_hash =
+            add_invoke_transaction::execute_invoke_transaction(starknet, invoke_transaction)?;
+
+        let mut actual_fee = 0;
+        for transaction_hash in [deploy_transaction_hash, invoke_transaction_hash] {
+            let transaction =
+                starknet.transactions.get(&transaction_hash).ok_or(Error::NoTransaction)?;
+            if let ExecutionResult::Reverted { reason } = &transaction.execution_result {
+                return Err(Error::ExecutionError { revert_error: reason.clone() });
+            }
+            actual_fee += transaction.execution_info.actual_fee.0;
+        }
+        if actual_fee > max_fee.0 {
+            return Err(TransactionValidationError::InsufficientMaxFee.into());
+        }
+
+        Ok(invoke_transaction_hash)
+    })?;
+
+    if starknet.is_pending_block_full() {
+        starknet.create_block(None)?;
+    }
+
+    Ok(transaction_hash)
+}
+
+/// Deploys the sender of `transaction` on `state`, a copy of the state on which transactions are
+/// estimated or simulated, as it is deployed before its first invoke transaction is added.
+/// Returns the execution of the deployment, if there is one.
+pub(crate) fn deploy_on_copy(
+    starknet: &Starknet,
+    state: &mut StarknetState,
+    transaction: &BroadcastedTransaction,
+    charge_fee: bool,
+) -> DevnetResult<Option<TransactionExecutionInfo>> {
+    let BroadcastedTransaction::Invoke(invoke_transaction) = transaction else {
+        return Ok(None);
+    };
+    let sender_address = invoke_transaction.sender_address;
+    let Some(account) = starknet.counterfactual_accounts.0.get(&sender_address) else {
+        return Ok(None);
+    };
+    if state.is_contract_deployed(&sender_address) {
+        return Ok(None);
+    }
+
+    let deploy_account_transaction = account
+        .to_deploy_account_transaction(invoke_transaction.common.max_fee)
+        .create_blockifier_deploy_account(starknet.chain_id().to_felt(), true)?;
+    let execution_info = AccountTransaction::DeployAccount(deploy_account_transaction).execute(
+        &mut state.state,
+        &starknet.block_context,
+        charge_fee,
+        false,
+    )?;
+    if let Some(revert_error) = &execution_info.revert_error {
+        return Err(Error::ExecutionError { revert_error: revert_error.clone() });
+    }
+    // as when the deployment is added, it does not use up a nonce of the account
+    let mut state_difference = state.extract_state_diff_from_pending_state()?;
+    state_difference.address_to_nonce.remove(&sender_address);
+    state.apply_state_difference(state_difference)?;
+    state.clear_dirty_state();
+
+    Ok(Some(execution_info))
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_api::transaction::Fee;
+    use starknet_rs_core::types::{BlockId, BlockTag};
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_rs_signers::SigningKey;
+    use starknet_types::contract_address::ContractAddress;
+    use starknet_types::contract_storage_key::ContractStorageKey;
+    use starknet_types::felt::Felt;
+    use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
+    use starknet_types::rpc::transactions::BroadcastedTransaction;
+
+    use super::{register_counterfactual_account, undeployed_account, CounterfactualAccount};
+    use crate::constants::ERC20_CONTRACT_ADDRESS;
+    use crate::error::{Error, StateError};
+    use crate::starknet::estimations::estimate_fee;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::traits::{StateChanger, StateExtractor};
+    use crate::utils::get_storage_var_address;
+
+    const MAX_FEE: Fee = Fee(1_000_000_000_000_000_000);
+    const FUNDED_BALANCE: u128 = 1_000_000_000_000_000_000_000;
+    const PRIVATE_KEY: u128 = 0x123;
+
+    fn signing_key() -> SigningKey {
+        SigningKey::from_secret_scalar(Felt::from(PRIVATE_KEY).into())
+    }
+
+    /// Returns an instance with a registered and funded account, and its address
+    fn setup() -> (Starknet, ContractAddress) {
+        let config = StarknetConfig::default();
+        let mut starknet = Starknet::new(&config).unwrap();
+        let account = CounterfactualAccount {
+            class_hash: config.account_contract_class_hash,
+            contract_address_salt: Felt::from(13),
+            constructor_calldata: vec![signing_key().verifying_key().scalar().into()],
+        };
+        let address = register_counterfactual_account(&mut starknet, account).unwrap();
+        fund(&mut starknet, address);
+        (starknet, address)
+    }
+
+    fn fund(starknet: &mut Starknet, address: ContractAddress) {
+        let fee_token_address =
+            ContractAddress::new(Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap())
+                .unwrap();
+        let balance_storage_key = ContractStorageKey::new(
+            fee_token_address,
+            get_storage_var_address("ERC20_balances", &[address.into()]).unwrap(),
+        );
+        starknet.state.change_storage(balance_storage_key, Felt::from(FUNDED_BALANCE)).unwrap();
+        starknet.state.clear_dirty_state();
+    }
+
+    /// A transfer of 1 WEI from `sender`, with nonce 0 and signed if `signed` is set
+    fn transfer(
+        starknet: &Starknet,
+        sender: ContractAddress,
+        signed: bool,
+    ) -> BroadcastedInvokeTransaction {
+        let calldata = vec![
+            Felt::from(1), // number of calls
+            Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap(),
+            Felt::from(get_selector_from_name("transfer").unwrap()),
+            Felt::from(0), // data offset
+            Felt::from(3), // data length
+            Felt::from(3), // calldata length
+            Felt::from(1), // recipient
+            Felt::from(1), // amount low
+            Felt::from(0), // amount high
+        ];
+        let unsigned_transfer = BroadcastedInvokeTransaction::new(
+            sender,
+            MAX_FEE,
+            &vec![],
+            Felt::from(0),
+            &calldata,
+            Felt::from(1),
+        );
+        if !signed {
+            return unsigned_transfer;
+        }
+
+        let transaction_hash: Felt = unsigned_transfer
+            .create_blockifier_invoke_transaction(starknet.chain_id().to_felt(), false)
+            .unwrap()
+            .tx_hash
+            .0
+            .into();
+        let signature = signing_key().sign(&transaction_hash.into()).unwrap();
+        BroadcastedInvokeTransaction::new(
+            sender,
+            MAX_FEE,
+            &vec![signature.r.into(), signature.s.into()],
+            Felt::from(0),
+            &calldata,
+            Felt::from(1),
+        )
+    }
+
+    #[test]
+    fn registered_account_is_deployed_with_its_first_invoke_at_nonce_0() {
+        let (mut starknet, address) = setup();
+        let pending = BlockId::Tag(BlockTag::Pending);
+        assert_eq!(starknet.contract_nonce_at_block(pending, address).unwrap(), Felt::from(0));
+
+        let transaction_hash =
+            starknet.add_invoke_transaction(transfer(&starknet, address, true)).unwrap();
+
+        assert!(starknet.state.is_contract_deployed(&address));
+        assert_eq!(starknet.contract_nonce_at_block(pending, address).unwrap(), Felt::from(1));
+        let latest_block = starknet.get_latest_block().unwrap();
+        assert_eq!(latest_block.get_transactions().len(), 2);
+        assert_eq!(latest_block.get_transactions()[1], transaction_hash);
+        assert!(undeployed_account(&mut starknet, &address).is_none());
+        // the deployment does not use up a nonce
+        let deployment = starknet.transactions.get(&latest_block.get_transactions()[0]).unwrap();
+        assert!(deployment.state_diff.nonces.is_empty());
+    }
+
+    #[test]
+    fn deployment_is_replayed_from_dump_with_the_first_invoke() {
+        let (mut starknet, address) = setup();
+        starknet.add_invoke_transaction(transfer(&starknet, address, true)).unwrap();
+        let dump_path = std::env::temp_dir().join("devnet_counterfactual_deployment.json");
+        let dump_path = dump_path.to_str().unwrap().to_string();
+        starknet.dump_transactions_custom_path(Some(dump_path.clone())).unwrap();
+
+        let mut loaded_starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        fund(&mut loaded_starknet, address);
+        let transactions =
+            loaded_starknet.load_transactions_custom_path(Some(dump_path.clone())).unwrap();
+        std::fs::remove_file(dump_path).unwrap();
+        loaded_starknet.re_execute(transactions).unwrap();
+
+        let transaction_hashes = |starknet: &Starknet| {
+            starknet.transactions.iter().map(|(hash, _)| *hash).collect::<Vec<_>>()
+        };
+        assert_eq!(transaction_hashes(&loaded_starknet), transaction_hashes(&starknet));
+        let pending = BlockId::Tag(BlockTag::Pending);
+        assert_eq!(
+            loaded_starknet.contract_nonce_at_block(pending, address).unwrap(),
+            Felt::from(1)
+        );
+    }
+
+    #[test]
+    fn rejected_invoke_is_not_preceded_by_deployment() {
+        let (mut starknet, address) = setup();
+        let transactions_len = starknet.transactions.len();
+
+        starknet.add_invoke_transaction(transfer(&starknet, address, false)).unwrap_err();
+
+        assert!(!starknet.state.is_contract_deployed(&address));
+        assert_eq!(starknet.transactions.len(), transactions_len);
+        assert!(undeployed_account(&mut starknet, &address).is_some());
+    }
+
+    #[test]
+    fn invoke_of_undeployed_account_is_estimated_with_deployment() {
+        let (starknet, address) = setup();
+        let pending = BlockId::Tag(BlockTag::Pending);
+        let transfer = BroadcastedTransaction::Invoke(transfer(&starknet, address, false));
+
+        // the estimation is not signed
+        let estimates = estimate_fee(&starknet, pending, &[transfer], None, Some(false)).unwrap();
+
+        assert_eq!(estimates.len(), 1);
+        assert!(!starknet.state.is_contract_deployed(&address));
+    }
+
+    #[test]
+    fn account_of_undeclared_class_is_not_registered() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let account = CounterfactualAccount {
+            class_hash: Felt::from(1),
+            contract_address_salt: Felt::from(13),
+            constructor_calldata: vec![Felt::from(1)],
+        };
+
+        let err = register_counterfactual_account(&mut starknet, account).unwrap_err();
+        assert!(matches!(err, Error::StateError(StateError::NoneClassHash(_))));
+    }
+}
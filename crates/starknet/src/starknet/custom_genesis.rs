@@ -0,0 +1,169 @@
+use std::path::Path;
+
+use serde::Deserialize;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::contract_class::{Cairo0Json, ContractClass};
+use starknet_types::contract_storage_key::ContractStorageKey;
+use starknet_types::felt::{ClassHash, Nonce};
+use starknet_types::rpc::state::StorageEntry;
+use starknet_types::traits::HashProducer;
+
+use crate::error::{DevnetResult, Error, StateError};
+use crate::state::state_diff::StateDiff;
+use crate::state::StarknetState;
+use crate::traits::{StateChanger, StateExtractor};
+
+/// What a bare-bones instance starts with instead of the canonical predeployments (fee token, UDC,
+/// chargeable and predeployed accounts)
+#[derive(Debug, Clone, Default)]
+pub struct CustomGenesis {
+    pub classes: Vec<(ClassHash, ContractClass)>,
+    pub contracts: Vec<GenesisContract>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct GenesisContract {
+    /// Shown among the predeployed contracts on startup
+    #[serde(default)]
+    pub name: String,
+    pub address: ContractAddress,
+    pub class_hash: ClassHash,
+    #[serde(default)]
+    pub storage: Vec<StorageEntry>,
+    #[serde(default)]
+    pub nonce: Nonce,
+}
+
+/// The format of a genesis file; classes are given as paths of Cairo 0 or Sierra artifacts,
+/// relative to the genesis file
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GenesisFile {
+    #[serde(default)]
+    classes: Vec<String>,
+    #[serde(default)]
+    contracts: Vec<GenesisContract>,
+}
+
+impl CustomGenesis {
+    /// Loads the genesis file at `path` and the artifacts it references. Fails if a contract is of
+    /// a class that is not declared by the file.
+    pub fn from_path(path: &str) -> DevnetResult<Self> {
+        let genesis_file: GenesisFile = serde_json::from_str(&read_file(Path::new(path))?)
+            .map_err(|_| Error::DeserializationError { obj_name: "genesis file".to_string() })?;
+
+        let base_dir = Path::new(path).parent().unwrap_or_else(|| Path::new(""));
+        let mut classes = vec![];
+        for class_path in genesis_file.classes {
+            let contract_class = load_contract_class(&base_dir.join(class_path))?;
+            classes.push((contract_class.generate_hash()?, contract_class));
+        }
+
+        for contract in &genesis_file.contracts {
+            if !classes.iter().any(|(class_hash, _)| *class_hash == contract.class_hash) {
+                return Err(Error::StateError(StateError::NoneClassHash(contract.class_hash)));
+            }
+        }
+
+        Ok(Self { classes, contracts: genesis_file.contracts })
+    }
+
+    pub(crate) fn deploy(&self, state: &mut StarknetState) -> DevnetResult<()> {
+        for (class_hash, contract_class) in &self.classes {
+            if !state.is_contract_declared(class_hash) {
+                state.declare_contract_class(*class_hash, contract_class.clone())?;
+            }
+        }
+
+        for contract in &self.contracts {
+            state.deploy_contract(contract.address, contract.class_hash)?;
+            for storage_entry in &contract.storage {
+                let storage_key = ContractStorageKey::new(contract.address, storage_entry.key);
+                state.change_storage(storage_key, storage_entry.value)?;
+            }
+        }
+
+        // deploying sets the nonce to zero, so nonces are only set once all are deployed
+        state.apply_state_difference(StateDiff {
+            address_to_nonce: self
+                .contracts
+                .iter()
+                .map(|contract| (contract.address, contract.nonce))
+                .collect(),
+            ..Default::default()
+        })?;
+
+        Ok(())
+    }
+}
+
+fn read_file(path: &Path) -> DevnetResult<String> {
+    std::fs::read_to_string(path)
+        .map_err(|err| Error::ReadFileError { source: err, path: path.display().to_string() })
+}
+
+fn load_contract_class(path: &Path) -> DevnetResult<ContractClass> {
+    let json_str = read_file(path)?;
+    let is_sierra = serde_json::from_str::<serde_json::Value>(&json_str)
+        .map_err(|_| Error::ContractClassLoadError)?
+        .get("sierra_program")
+        .is_some();
+
+    Ok(if is_sierra {
+        ContractClass::Cairo1(ContractClass::cairo_1_from_sierra_json_str(&json_str)?)
+    } else {
+        Cairo0Json::raw_json_from_json_str(&json_str)?.into()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_types::contract_address::ContractAddress;
+    use starknet_types::contract_class::{Cairo0Json, ContractClass};
+    use starknet_types::contract_storage_key::ContractStorageKey;
+    use starknet_types::felt::Felt;
+    use starknet_types::patricia_key::PatriciaKey;
+    use starknet_types::rpc::state::StorageEntry;
+    use starknet_types::traits::HashProducer;
+
+    use super::{CustomGenesis, GenesisContract};
+    use crate::constants::CAIRO_0_ACCOUNT_CONTRACT_PATH;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::traits::StateExtractor;
+
+    #[test]
+    fn only_genesis_contracts_are_deployed() {
+        let contract_class: ContractClass =
+            Cairo0Json::raw_json_from_path(CAIRO_0_ACCOUNT_CONTRACT_PATH).unwrap().into();
+        let class_hash = contract_class.generate_hash().unwrap();
+        let address = ContractAddress::new(Felt::from(0x1234)).unwrap();
+        let storage_key = PatriciaKey::new(Felt::from(5)).unwrap();
+        let custom_genesis = CustomGenesis {
+            classes: vec![(class_hash, contract_class)],
+            contracts: vec![GenesisContract {
+                name: "Custom".to_string(),
+                address,
+                class_hash,
+                storage: vec![StorageEntry { key: storage_key, value: Felt::from(7) }],
+                nonce: Felt::from(2),
+            }],
+        };
+
+        let config = StarknetConfig { custom_genesis: Some(custom_genesis), ..Default::default() };
+        let mut starknet = Starknet::new(&config).unwrap();
+
+        assert_eq!(
+            starknet.state.get_class_hash_at_contract_address(&address).unwrap(),
+            class_hash
+        );
+        assert_eq!(starknet.state.get_nonce(&address).unwrap(), Felt::from(2));
+        assert_eq!(
+            starknet.state.get_storage(ContractStorageKey::new(address, storage_key)).unwrap(),
+            Felt::from(7)
+        );
+        assert!(!starknet.state.is_contract_deployed(&config.fee_token_address));
+        assert!(!starknet.state.is_contract_deployed(&config.udc_address));
+        assert!(starknet.get_predeployed_accounts().is_empty());
+    }
+}
@@ -1,83 +1,120 @@
-use std::fs::{self, File, OpenOptions};
+use std::fs::{self, OpenOptions};
 use std::io::{Read, Seek, SeekFrom, Write};
 use std::path::Path;
 
-use starknet_types::rpc::transactions::broadcasted_declare_transaction_v1::BroadcastedDeclareTransactionV1;
-use starknet_types::rpc::transactions::broadcasted_declare_transaction_v2::BroadcastedDeclareTransactionV2;
-use starknet_types::rpc::transactions::broadcasted_deploy_account_transaction::BroadcastedDeployAccountTransaction;
-use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
-use starknet_types::rpc::transactions::{DeclareTransaction, InvokeTransaction, Transaction};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use starknet_types::rpc::transactions::{
+    BroadcastedDeclareTransaction, BroadcastedTransaction, Transaction,
+};
+use tracing::warn;
 
+use super::counterfactual_accounts::CounterfactualAccount;
+use super::dump_format::{
+    dump_deserialization_error, dump_serialization_error, encode_binary_dump, encode_binary_record,
+    read_dump, BINARY_DUMP_MAGIC,
+};
+use super::journal::ChangeCause;
+use super::starknet_config::DumpFormat;
 use super::{DumpOn, Starknet};
 use crate::error::{DevnetResult, Error};
+use crate::transactions::{to_broadcasted_transaction, StarknetTransaction};
+
+/// Properties of broadcastable transactions, in addition to the ones specific to a transaction type
+const BROADCASTED_COMMON_PROPERTIES: [&str; 5] =
+    ["type", "max_fee", "version", "signature", "nonce"];
+
+/// Dumped record of a transaction. The deployment of a counterfactual account, which Devnet sends
+/// on behalf of the account, is dumped as the registration of the account, so that on load the
+/// account is deployed again with its first invoke transaction, as it was originally.
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum DumpRecord {
+    Transaction(Transaction),
+    CounterfactualAccount { counterfactual_account: CounterfactualAccount },
+}
+
+impl From<&StarknetTransaction> for DumpRecord {
+    fn from(transaction: &StarknetTransaction) -> Self {
+        match &transaction.counterfactual_account {
+            Some(account) => {
+                Self::CounterfactualAccount { counterfactual_account: account.clone() }
+            }
+            None => Self::Transaction(transaction.inner.clone()),
+        }
+    }
+}
+
+/// A loaded record of a dump, in the form in which it is re-executed
+#[derive(Debug)]
+pub enum DumpedTransaction {
+    Broadcasted(BroadcastedTransaction),
+    /// The registration of a counterfactual account, which is deployed with the first invoke
+    /// transaction of the account that follows it
+    CounterfactualAccount(CounterfactualAccount),
+}
 
 impl Starknet {
-    pub fn re_execute(&mut self, transactions: Vec<Transaction>) -> DevnetResult<()> {
-        for transaction in transactions.iter() {
-            match transaction {
-                Transaction::Declare(DeclareTransaction::Version0(_)) => {
-                    return Err(Error::SerializationNotSupported);
+    pub fn re_execute(&mut self, transactions: Vec<DumpedTransaction>) -> DevnetResult<()> {
+        for transaction in transactions {
+            let transaction = match transaction {
+                DumpedTransaction::Broadcasted(transaction) => transaction,
+                DumpedTransaction::CounterfactualAccount(account) => {
+                    self.register_counterfactual_account(account)?;
+                    continue;
                 }
-                Transaction::Declare(DeclareTransaction::Version1(tx)) => {
-                    let declare_tx = BroadcastedDeclareTransactionV1::new(
-                        tx.sender_address,
-                        tx.max_fee,
-                        &tx.signature,
-                        tx.nonce,
-                        &tx.contract_class,
-                        tx.version,
-                    );
-                    self.add_declare_transaction_v1(declare_tx)?;
+            };
+
+            // the first invoke transaction of a counterfactual account adds its deployment as well
+            let transactions_len = self.transactions.len();
+            match transaction {
+                BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V1(declare_tx)) => {
+                    self.add_declare_transaction_v1(*declare_tx)?;
                 }
-                Transaction::Declare(DeclareTransaction::Version2(tx)) => {
-                    let declare_tx = BroadcastedDeclareTransactionV2::new(
-                        &tx.contract_class,
-                        tx.compiled_class_hash,
-                        tx.sender_address,
-                        tx.max_fee,
-                        &tx.signature,
-                        tx.nonce,
-                        tx.version,
-                    );
-                    self.add_declare_transaction_v2(declare_tx)?;
+                BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V2(declare_tx)) => {
+                    self.add_declare_transaction_v2(*declare_tx)?;
                 }
-                Transaction::DeployAccount(tx) => {
-                    let deploy_account_tx = BroadcastedDeployAccountTransaction::new(
-                        &tx.constructor_calldata,
-                        tx.max_fee,
-                        &tx.signature,
-                        tx.nonce,
-                        tx.class_hash,
-                        tx.contract_address_salt,
-                        tx.version,
-                    );
+                BroadcastedTransaction::DeployAccount(deploy_account_tx) => {
                     self.add_deploy_account_transaction(deploy_account_tx)?;
                 }
-                Transaction::Deploy(_) => return Err(Error::SerializationNotSupported),
-                Transaction::Invoke(InvokeTransaction::Version0(_)) => {
-                    return Err(Error::SerializationNotSupported);
-                }
-                Transaction::Invoke(InvokeTransaction::Version1(tx)) => {
-                    let invoke_tx = BroadcastedInvokeTransaction::new(
-                        tx.sender_address,
-                        tx.max_fee,
-                        &tx.signature,
-                        tx.nonce,
-                        &tx.calldata,
-                        tx.version,
-                    );
+                BroadcastedTransaction::Invoke(invoke_tx) => {
                     self.add_invoke_transaction(invoke_tx)?;
                 }
-                Transaction::L1Handler(_) => return Err(Error::SerializationNotSupported),
-            };
+            }
+            let added_transaction_hashes = self
+                .transactions
+                .iter()
+                .skip(transactions_len)
+                .map(|(transaction_hash, _)| *transaction_hash)
+                .collect::<Vec<_>>();
+            for transaction_hash in added_transaction_hashes {
+                self.journal.set_cause_of_transaction(
+                    transaction_hash,
+                    ChangeCause::Load { transaction_hash },
+                );
+            }
         }
 
         Ok(())
     }
 
     /// attach starknet transaction to end of existing file
-    pub fn dump_transaction(&self, transaction: &Transaction) -> DevnetResult<()> {
+    pub(crate) fn dump_transaction(&self, transaction: &DumpRecord) -> DevnetResult<()> {
         match &self.config.dump_path {
+            Some(path) if self.config.dump_format == DumpFormat::Binary => {
+                let file_path = Path::new(path);
+                let mut file = OpenOptions::new()
+                    .append(true)
+                    .create(true)
+                    .open(file_path)
+                    .map_err(Error::IoError)?;
+                if file.metadata().map_err(Error::IoError)?.len() == 0 {
+                    file.write_all(BINARY_DUMP_MAGIC).map_err(Error::IoError)?;
+                }
+                file.write_all(&encode_binary_record(transaction)?).map_err(Error::IoError)?;
+
+                Ok(())
+            }
             Some(path) => {
                 let file_path = Path::new(path);
                 if file_path.exists() {
@@ -131,14 +168,16 @@ impl Starknet {
                 let transactions = &self
                     .transactions
                     .iter()
-                    .map(|x| x.1.inner.clone())
-                    .collect::<Vec<Transaction>>();
+                    .map(|x| DumpRecord::from(x.1))
+                    .collect::<Vec<DumpRecord>>();
 
                 // dump only if there are transactions to dump
                 if !transactions.is_empty() {
-                    let transactions_dump = serde_json::to_string(transactions).map_err(|_| {
-                        Error::SerializationError { obj_name: "Vec<Transaction>".to_string() }
-                    })?;
+                    let transactions_dump = match self.config.dump_format {
+                        DumpFormat::Json => serde_json::to_vec(transactions)
+                            .map_err(|_| dump_serialization_error())?,
+                        DumpFormat::Binary => encode_binary_dump(transactions)?,
+                    };
                     fs::write(Path::new(&path), transactions_dump)?;
                 }
 
@@ -148,15 +187,15 @@ impl Starknet {
         }
     }
 
-    pub fn load_transactions(&self) -> DevnetResult<Vec<Transaction>> {
+    pub fn load_transactions(&self) -> DevnetResult<Vec<DumpedTransaction>> {
         self.load_transactions_custom_path(None)
     }
 
-    // load starknet transactions from file
+    // load starknet transactions from file, in the form in which they are re-executed
     pub fn load_transactions_custom_path(
         &self,
         custom_path: Option<String>,
-    ) -> DevnetResult<Vec<Transaction>> {
+    ) -> DevnetResult<Vec<DumpedTransaction>> {
         let dump_path = if custom_path.is_some() { &custom_path } else { &self.config.dump_path };
         match dump_path {
             Some(path) => {
@@ -166,11 +205,8 @@ impl Starknet {
                 // exist it means that it's first execution and in that case return an empty vector,
                 // in case of load from HTTP endpoint return FileNotFound error
                 if file_path.exists() {
-                    let file = File::open(file_path).map_err(Error::IoError)?;
-                    let transactions: Vec<Transaction> =
-                        serde_json::from_reader(file).map_err(|_| Error::DeserializationError {
-                            obj_name: "Vec<Transaction>".to_string(),
-                        })?;
+                    let dump = read_dump(&fs::read(file_path).map_err(Error::IoError)?)?;
+                    let transactions = parse_dump(dump)?;
 
                     // to avoid doublets in transaction mode during load, we need to remove the file
                     // because they will be re-executed and saved again
@@ -187,3 +223,61 @@ impl Starknet {
         }
     }
 }
+
+/// Parses the transactions of a dump. If the dump was created by an incompatible version of Devnet,
+/// so that its transactions cannot be parsed, only their broadcastable properties are parsed and
+/// replayed against the current version.
+fn parse_dump(dump: Value) -> DevnetResult<Vec<DumpedTransaction>> {
+    match Vec::<DumpRecord>::deserialize(&dump) {
+        Ok(records) => records
+            .into_iter()
+            .map(|record| match record {
+                DumpRecord::Transaction(transaction) => {
+                    to_broadcasted_transaction(&transaction).map(DumpedTransaction::Broadcasted)
+                }
+                DumpRecord::CounterfactualAccount { counterfactual_account } => {
+                    Ok(DumpedTransaction::CounterfactualAccount(counterfactual_account))
+                }
+            })
+            .collect(),
+        Err(err) => {
+            let Value::Array(dumped_transactions) = dump else {
+                return Err(dump_deserialization_error());
+            };
+            warn!(
+                "The dump was not created by this version of Devnet ({err}); replaying its \
+                 transactions, whose hashes may differ from the original ones"
+            );
+            dumped_transactions
+                .into_iter()
+                .map(|dumped_transaction| {
+                    parse_broadcastable_properties(dumped_transaction)
+                        .ok_or_else(dump_deserialization_error)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Parses a dumped transaction without the properties that are not needed to broadcast it again,
+/// e.g. the ones added by a different version of Devnet
+fn parse_broadcastable_properties(dumped_transaction: Value) -> Option<DumpedTransaction> {
+    let Value::Object(mut properties) = dumped_transaction else {
+        return None;
+    };
+    if let Some(account) = properties.remove("counterfactual_account") {
+        return serde_json::from_value(account).ok().map(DumpedTransaction::CounterfactualAccount);
+    }
+    let type_properties: &[&str] = match properties.get("type")?.as_str()? {
+        "INVOKE" => &["sender_address", "calldata"],
+        "DECLARE" => &["contract_class", "sender_address", "compiled_class_hash"],
+        "DEPLOY_ACCOUNT" => &["contract_address_salt", "constructor_calldata", "class_hash"],
+        _ => return None,
+    };
+    properties.retain(|property, _| {
+        BROADCASTED_COMMON_PROPERTIES.contains(&property.as_str())
+            || type_properties.contains(&property.as_str())
+    });
+
+    serde_json::from_value(Value::Object(properties)).ok().map(DumpedTransaction::Broadcasted)
+}
@@ -0,0 +1,171 @@
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use serde_json::{Number, Value};
+
+use super::starknet_config::DumpFormat;
+use crate::error::{DevnetResult, Error};
+
+/// Start of binary dumps, which JSON dumps cannot start with, so that the format is detected. It is
+/// followed by the records of the transactions.
+pub(crate) const BINARY_DUMP_MAGIC: &[u8] = b"\0DEVNET-DUMP-V1\0";
+
+/// A JSON value in a form that can be decoded from a format which is not self-describing. Records
+/// hold the JSON representation of transactions, so that dumps of older versions can still be
+/// replayed by their broadcastable properties; encoding them this way costs an extra copy of each
+/// transaction compared to a JSON dump.
+#[derive(Debug, Serialize, Deserialize)]
+enum BinaryValue {
+    Null,
+    Bool(bool),
+    PositiveInteger(u64),
+    NegativeInteger(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<BinaryValue>),
+    Object(Vec<(String, BinaryValue)>),
+}
+
+impl From<Value> for BinaryValue {
+    fn from(value: Value) -> Self {
+        match value {
+            Value::Null => Self::Null,
+            Value::Bool(boolean) => Self::Bool(boolean),
+            Value::Number(number) => match (number.as_u64(), number.as_i64()) {
+                (Some(positive), _) => Self::PositiveInteger(positive),
+                (None, Some(negative)) => Self::NegativeInteger(negative),
+                (None, None) => Self::Float(number.as_f64().unwrap_or_default()),
+            },
+            Value::String(string) => Self::String(string),
+            Value::Array(elements) => Self::Array(elements.into_iter().map(Self::from).collect()),
+            Value::Object(properties) => Self::Object(
+                properties.into_iter().map(|(key, value)| (key, Self::from(value))).collect(),
+            ),
+        }
+    }
+}
+
+impl From<BinaryValue> for Value {
+    fn from(value: BinaryValue) -> Self {
+        match value {
+            BinaryValue::Null => Value::Null,
+            BinaryValue::Bool(boolean) => Value::Bool(boolean),
+            BinaryValue::PositiveInteger(positive) => Value::from(positive),
+            BinaryValue::NegativeInteger(negative) => Value::from(negative),
+            BinaryValue::Float(float) => Number::from_f64(float).map_or(Value::Null, Value::Number),
+            BinaryValue::String(string) => Value::String(string),
+            BinaryValue::Array(elements) => {
+                Value::Array(elements.into_iter().map(Value::from).collect())
+            }
+            BinaryValue::Object(properties) => Value::Object(
+                properties.into_iter().map(|(key, value)| (key, Value::from(value))).collect(),
+            ),
+        }
+    }
+}
+
+pub(crate) fn dump_serialization_error() -> Error {
+    Error::SerializationError { obj_name: "Vec<Transaction>".to_string() }
+}
+
+pub(crate) fn dump_deserialization_error() -> Error {
+    Error::DeserializationError { obj_name: "Vec<Transaction>".to_string() }
+}
+
+/// Encodes a dumped transaction as a record of a binary dump. Records are self-delimiting, so a
+/// transaction is dumped by appending its record.
+pub(crate) fn encode_binary_record(transaction: impl Serialize) -> DevnetResult<Vec<u8>> {
+    let value = serde_json::to_value(transaction).map_err(|_| dump_serialization_error())?;
+
+    bincode::serde::encode_to_vec(BinaryValue::from(value), bincode::config::standard())
+        .map_err(|_| dump_serialization_error())
+}
+
+pub(crate) fn encode_binary_dump<T: Serialize>(
+    transactions: impl IntoIterator<Item = T>,
+) -> DevnetResult<Vec<u8>> {
+    let mut dump = BINARY_DUMP_MAGIC.to_vec();
+    for transaction in transactions {
+        dump.extend(encode_binary_record(transaction)?);
+    }
+
+    Ok(dump)
+}
+
+/// Parses a dump in the JSON or binary format into the array of its transactions
+pub(crate) fn read_dump(bytes: &[u8]) -> DevnetResult<Value> {
+    let Some(mut records) = bytes.strip_prefix(BINARY_DUMP_MAGIC) else {
+        return serde_json::from_slice(bytes).map_err(|_| dump_deserialization_error());
+    };
+
+    let mut transactions = vec![];
+    while !records.is_empty() {
+        let (record, length): (BinaryValue, usize) =
+            bincode::serde::decode_from_slice(records, bincode::config::standard())
+                .map_err(|_| dump_deserialization_error())?;
+        transactions.push(Value::from(record));
+        records = &records[length..];
+    }
+
+    Ok(Value::Array(transactions))
+}
+
+/// Rewrites the dump at `input_path` in `format` to `output_path`, without executing its
+/// transactions
+pub fn convert_dump(input_path: &str, output_path: &str, format: DumpFormat) -> DevnetResult<()> {
+    let input_path = Path::new(input_path);
+    if !input_path.exists() {
+        return Err(Error::FileNotFound);
+    }
+    let Value::Array(transactions) = read_dump(&fs::read(input_path)?)? else {
+        return Err(dump_deserialization_error());
+    };
+
+    let output = match format {
+        DumpFormat::Json => {
+            serde_json::to_vec(&transactions).map_err(|_| dump_serialization_error())?
+        }
+        DumpFormat::Binary => encode_binary_dump(transactions)?,
+    };
+    fs::write(output_path, output)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{encode_binary_dump, encode_binary_record, read_dump, BINARY_DUMP_MAGIC};
+
+    #[test]
+    fn binary_records_are_decoded_in_order() {
+        let transactions = [
+            json!({ "type": "INVOKE", "nonce": "0x1", "calldata": ["0x2", "0x3"] }),
+            json!({ "type": "DECLARE", "max_fee": 5, "offset": -1, "ratio": 0.5, "abi": null }),
+        ];
+
+        let dump = encode_binary_dump(&transactions).unwrap();
+
+        assert_eq!(read_dump(&dump).unwrap(), json!(transactions));
+        assert_eq!(read_dump(BINARY_DUMP_MAGIC).unwrap(), json!([]));
+    }
+
+    #[test]
+    fn json_dump_is_detected() {
+        let dump = json!([{ "type": "INVOKE", "nonce": "0x1" }]);
+
+        assert_eq!(read_dump(dump.to_string().as_bytes()).unwrap(), dump);
+        assert!(read_dump(b"[{").is_err());
+    }
+
+    #[test]
+    fn truncated_binary_dump_is_rejected() {
+        let mut dump = BINARY_DUMP_MAGIC.to_vec();
+        dump.extend(encode_binary_record(json!({ "type": "INVOKE" })).unwrap());
+        dump.pop();
+
+        assert!(read_dump(&dump).is_err());
+    }
+}
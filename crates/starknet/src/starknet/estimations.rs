@@ -1,5 +1,6 @@
 use blockifier::fee::fee_utils::{calculate_l1_gas_by_vm_usage, extract_l1_gas_and_vm_usage};
 use blockifier::transaction::account_transaction::AccountTransaction;
+use blockifier::transaction::objects::TransactionExecutionInfo;
 use blockifier::transaction::transactions::ExecutableTransaction;
 use starknet_rs_core::types::{BlockId, MsgFromL1};
 use starknet_types::contract_address::ContractAddress;
@@ -8,6 +9,7 @@ use starknet_types::rpc::estimate_message_fee::{
 };
 use starknet_types::rpc::transactions::BroadcastedTransaction;
 
+use super::{account_templates, counterfactual_accounts};
 use crate::error::{DevnetResult, Error};
 use crate::starknet::Starknet;
 use crate::state::StarknetState;
@@ -22,14 +24,26 @@ pub fn estimate_fee(
     let mut state = starknet.get_state_at(&block_id)?.clone();
     let chain_id = starknet.chain_id().to_felt();
 
-    let transactions = transactions
+    // validation is estimated as it would be executed, according to the template of the sender
+    let blockifier_transactions = transactions
         .iter()
-        .map(|txn| Ok(txn.to_blockifier_account_transaction(chain_id, true)?))
-        .collect::<DevnetResult<Vec<AccountTransaction>>>()?;
+        .map(|txn| {
+            let validate_txn = validate.unwrap_or(true)
+                && account_templates::check_broadcasted_validation(starknet, txn)?;
+            Ok((txn.to_blockifier_account_transaction(chain_id, true)?, validate_txn))
+        })
+        .collect::<DevnetResult<Vec<(AccountTransaction, bool)>>>()?;
 
-    transactions
+    blockifier_transactions
         .into_iter()
-        .map(|transaction| {
+        .zip(transactions)
+        .map(|((transaction, validate_txn), broadcasted_transaction)| {
+            let deployment = counterfactual_accounts::deploy_on_copy(
+                starknet,
+                &mut state,
+                broadcasted_transaction,
+                charge_fee.unwrap_or(false),
+            )?;
             estimate_transaction_fee(
                 &mut state,
                 &starknet.block_context,
@@ -37,7 +51,8 @@ pub fn estimate_fee(
                     transaction,
                 ),
                 charge_fee,
-                validate,
+                Some(validate_txn),
+                deployment,
             )
         })
         .collect()
@@ -68,6 +83,7 @@ pub fn estimate_message_fee(
         ),
         None,
         None,
+        None,
     )
 }
 
@@ -77,6 +93,7 @@ fn estimate_transaction_fee(
     transaction: blockifier::transaction::transaction_execution::Transaction,
     charge_fee: Option<bool>,
     validate: Option<bool>,
+    deployment: Option<TransactionExecutionInfo>,
 ) -> DevnetResult<FeeEstimateWrapper> {
     let transaction_execution_info = transaction.execute(
         &mut state.state,
@@ -89,13 +106,26 @@ fn estimate_transaction_fee(
         return Err(Error::ExecutionError { revert_error });
     }
 
+    // the deployment of a counterfactual account is paid together with its first transaction
+    let total_l1_gas_usage = deployment
+        .iter()
+        .chain([&transaction_execution_info])
+        .map(|execution_info| total_l1_gas_usage(block_context, execution_info))
+        .sum::<DevnetResult<u64>>()?;
+
+    let gas_price = block_context.gas_prices.eth_l1_gas_price as u64;
+
+    Ok(FeeEstimateWrapper::new(total_l1_gas_usage, gas_price, total_l1_gas_usage * gas_price))
+}
+
+fn total_l1_gas_usage(
+    block_context: &blockifier::block_context::BlockContext,
+    transaction_execution_info: &TransactionExecutionInfo,
+) -> DevnetResult<u64> {
     let (l1_gas_usage, vm_resources) =
         extract_l1_gas_and_vm_usage(&transaction_execution_info.actual_resources);
     let l1_gas_by_vm_usage = calculate_l1_gas_by_vm_usage(block_context, &vm_resources)?;
     let total_l1_gas_usage = l1_gas_usage as f64 + l1_gas_by_vm_usage;
-    let total_l1_gas_usage = total_l1_gas_usage.ceil() as u64;
 
-    let gas_price = block_context.gas_prices.eth_l1_gas_price as u64;
-
-    Ok(FeeEstimateWrapper::new(total_l1_gas_usage, gas_price, total_l1_gas_usage * gas_price))
+    Ok(total_l1_gas_usage.ceil() as u64)
 }
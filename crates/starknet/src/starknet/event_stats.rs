@@ -0,0 +1,153 @@
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use starknet_rs_core::types::BlockId;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::Felt;
+
+use super::{events, Starknet};
+use crate::error::DevnetResult;
+
+/// Counts of the events emitted in a range of blocks
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventStats {
+    pub total_events: u64,
+    /// Ordered by count, from the most frequent emitter
+    pub contracts: Vec<ContractEventCount>,
+    /// Ordered by count, from the most frequent first key
+    pub first_keys: Vec<KeyEventCount>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContractEventCount {
+    pub from_address: ContractAddress,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyEventCount {
+    /// The first key of the events, usually the selector of the event name; `None` for events
+    /// without keys
+    pub key: Option<Felt>,
+    pub count: u64,
+}
+
+/// Counts the events of blocks from `from_block` to `to_block` per emitting contract and per first
+/// key. The range is resolved as in `starknet_getEvents`, so pruned events are not counted.
+pub fn get_event_stats(
+    starknet: &Starknet,
+    from_block: Option<BlockId>,
+    to_block: Option<BlockId>,
+) -> DevnetResult<EventStats> {
+    let (events, _) = events::get_events(starknet, from_block, to_block, None, None, 0, None)?;
+
+    // counts are kept in the order of first occurrence, which the stable sort preserves for ties
+    let mut contract_counts: IndexMap<ContractAddress, u64> = IndexMap::new();
+    let mut first_key_counts: IndexMap<Option<Felt>, u64> = IndexMap::new();
+    for event in &events {
+        *contract_counts.entry(event.from_address).or_default() += 1;
+        *first_key_counts.entry(event.keys.first().copied()).or_default() += 1;
+    }
+
+    let mut contracts: Vec<ContractEventCount> = contract_counts
+        .into_iter()
+        .map(|(from_address, count)| ContractEventCount { from_address, count })
+        .collect();
+    contracts.sort_by(|a, b| b.count.cmp(&a.count));
+
+    let mut first_keys: Vec<KeyEventCount> =
+        first_key_counts.into_iter().map(|(key, count)| KeyEventCount { key, count }).collect();
+    first_keys.sort_by(|a, b| b.count.cmp(&a.count));
+
+    Ok(EventStats { total_events: events.len() as u64, contracts, first_keys })
+}
+
+#[cfg(test)]
+mod tests {
+    use blockifier::execution::call_info::{CallInfo, OrderedEvent};
+    use blockifier::transaction::objects::TransactionExecutionInfo;
+    use starknet_api::hash::StarkFelt;
+    use starknet_api::transaction::{EventContent, EventData, EventKey};
+    use starknet_rs_core::types::BlockId;
+    use starknet_types::contract_address::ContractAddress;
+    use starknet_types::felt::Felt;
+    use starknet_types::rpc::transactions::{DeclareTransaction, Transaction};
+
+    use super::{get_event_stats, ContractEventCount, KeyEventCount};
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::utils::test_utils::dummy_declare_transaction_v1;
+
+    /// Adds a transaction, in a block of its own, in which `emitter` emits an event with each of
+    /// `first_keys`
+    fn add_transaction(
+        starknet: &mut Starknet,
+        transaction_hash: u128,
+        emitter: u128,
+        first_keys: &[Option<u128>],
+    ) {
+        let mut call_info = CallInfo::default();
+        call_info.call.storage_address =
+            starknet_api::core::ContractAddress::try_from(StarkFelt::from(emitter)).unwrap();
+        for (order, first_key) in first_keys.iter().enumerate() {
+            call_info.execution.events.push(OrderedEvent {
+                order,
+                event: EventContent {
+                    keys: first_key.map(|key| EventKey(StarkFelt::from(key))).into_iter().collect(),
+                    data: EventData(vec![]),
+                },
+            });
+        }
+
+        let transaction =
+            Transaction::Declare(DeclareTransaction::Version1(dummy_declare_transaction_v1()));
+        let execution_info =
+            TransactionExecutionInfo { execute_call_info: Some(call_info), ..Default::default() };
+        starknet
+            .handle_accepted_transaction(
+                &Felt::from(transaction_hash),
+                &transaction,
+                execution_info,
+            )
+            .unwrap();
+    }
+
+    fn contract_event_count(address: u128, count: u64) -> ContractEventCount {
+        ContractEventCount {
+            from_address: ContractAddress::new(Felt::from(address)).unwrap(),
+            count,
+        }
+    }
+
+    #[test]
+    fn events_are_counted_per_contract_and_first_key() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        add_transaction(&mut starknet, 100, 0x1, &[Some(0xa)]);
+        add_transaction(&mut starknet, 101, 0x2, &[Some(0xb), Some(0xa), None]);
+        add_transaction(&mut starknet, 102, 0x2, &[Some(0xb)]);
+
+        let stats = get_event_stats(&starknet, None, None).unwrap();
+        assert_eq!(stats.total_events, 5);
+        assert_eq!(
+            stats.contracts,
+            vec![contract_event_count(0x2, 4), contract_event_count(0x1, 1)]
+        );
+        assert_eq!(
+            stats.first_keys,
+            vec![
+                // ties are ordered by first occurrence
+                KeyEventCount { key: Some(Felt::from(0xa)), count: 2 },
+                KeyEventCount { key: Some(Felt::from(0xb)), count: 2 },
+                KeyEventCount { key: None, count: 1 },
+            ]
+        );
+
+        // the blocks of the first two transactions
+        let stats =
+            get_event_stats(&starknet, Some(BlockId::Number(1)), Some(BlockId::Number(2))).unwrap();
+        assert_eq!(stats.total_events, 4);
+        assert_eq!(
+            stats.contracts,
+            vec![contract_event_count(0x2, 3), contract_event_count(0x1, 1)]
+        );
+    }
+}
@@ -1,14 +1,22 @@
+use starknet_api::block::BlockNumber;
 use starknet_rs_core::types::BlockId;
 use starknet_types::contract_address::ContractAddress;
 use starknet_types::emitted_event::{EmittedEvent, Event};
 use starknet_types::felt::Felt;
 
 use super::Starknet;
+use crate::blocks::StarknetBlock;
 use crate::error::{DevnetResult, Error};
-use crate::traits::HashIdentified;
+use crate::traits::{HashIdentified, HashIdentifiedMut};
+use crate::transactions::StarknetTransactions;
+
+/// Ranges of blocks are split into shards of at least this many blocks, so that spawning a thread
+/// for a shard costs less than scanning it
+const MIN_BLOCKS_PER_SHARD: usize = 64;
 
 /// The method returns transaction events, based on query and if there are more results to be
-/// fetched in the form of a tuple (events, has_more).
+/// fetched in the form of a tuple (events, has_more). Wide ranges of blocks are scanned in
+/// parallel, up to one thread per available CPU.
 ///
 /// # Arguments
 ///
@@ -24,61 +32,145 @@ pub(crate) fn get_events(
     to_block: Option<BlockId>,
     contract_address: Option<ContractAddress>,
     keys_filter: Option<Vec<Vec<Felt>>>,
-    mut skip: usize,
+    skip: usize,
     limit: Option<usize>,
 ) -> DevnetResult<(Vec<EmittedEvent>, bool)> {
     let blocks = starknet.blocks.get_blocks(from_block, to_block)?;
+    // an explicit range must not start in pruned blocks; an open one starts after them
+    let first_retained_block = starknet.events_pruned_before;
+    if from_block.is_some()
+        && blocks.first().map_or(false, |block| block.block_number() < first_retained_block)
+    {
+        return Err(Error::EventsPruned { first_retained_block: first_retained_block.0 });
+    }
+    let blocks: Vec<&StarknetBlock> =
+        blocks.into_iter().filter(|block| block.block_number() >= first_retained_block).collect();
+
+    let filter =
+        |event: &Event| check_if_filter_applies_for_event(&contract_address, &keys_filter, event);
+    // one event more than the requested ones tells whether there are more
+    let max_events = limit.map(|limit| skip.saturating_add(limit).saturating_add(1));
+    let shard_count = std::thread::available_parallelism()
+        .map_or(1, usize::from)
+        .min(blocks.len() / MIN_BLOCKS_PER_SHARD);
+
+    let events =
+        scan_blocks_in_shards(&starknet.transactions, &blocks, &filter, max_events, shard_count)?;
+
+    let mut events = events.into_iter().skip(skip);
+    let page: Vec<EmittedEvent> = match limit {
+        Some(limit) => events.by_ref().take(limit).collect(),
+        None => events.by_ref().collect(),
+    };
+    let has_more = events.next().is_some();
+
+    Ok((page, has_more))
+}
+
+/// Scans `blocks` split into `shard_count` shards of consecutive blocks, each in a thread of its
+/// own, and merges the events of the shards in the order of the blocks. At most `max_events` events
+/// are collected, if specified.
+fn scan_blocks_in_shards(
+    transactions: &StarknetTransactions,
+    blocks: &[&StarknetBlock],
+    filter: &(dyn Fn(&Event) -> bool + Sync),
+    max_events: Option<usize>,
+    shard_count: usize,
+) -> DevnetResult<Vec<EmittedEvent>> {
+    if shard_count <= 1 {
+        return scan_blocks(transactions, blocks, filter, max_events);
+    }
+
+    let shard_size = (blocks.len() + shard_count - 1) / shard_count;
+    let shard_results: Vec<DevnetResult<Vec<EmittedEvent>>> = std::thread::scope(|scope| {
+        let workers: Vec<_> = blocks
+            .chunks(shard_size)
+            .map(|shard| scope.spawn(move || scan_blocks(transactions, shard, filter, max_events)))
+            .collect();
+
+        workers
+            .into_iter()
+            .map(|worker| worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .collect()
+    });
+
+    let mut events = vec![];
+    for shard_events in shard_results {
+        events.extend(shard_events?);
+        if let Some(max_events) = max_events {
+            if events.len() >= max_events {
+                // the events of the following shards would not be returned
+                events.truncate(max_events);
+                break;
+            }
+        }
+    }
+
+    Ok(events)
+}
+
+/// Returns the events of the transactions of `blocks` to which `filter` applies, in the order of
+/// the blocks, but at most `max_events` events, if specified
+fn scan_blocks(
+    transactions: &StarknetTransactions,
+    blocks: &[&StarknetBlock],
+    filter: &(dyn Fn(&Event) -> bool + Sync),
+    max_events: Option<usize>,
+) -> DevnetResult<Vec<EmittedEvent>> {
     let mut events: Vec<EmittedEvent> = Vec::new();
-    let mut elements_added = 0;
 
     // iterate over each block and get the transactions for each one
     // then iterate over each transaction events and filter them
     for block in blocks {
         for transaction_hash in block.get_transactions() {
             let transaction =
-                starknet.transactions.get_by_hash(*transaction_hash).ok_or(Error::NoTransaction)?;
-
-            // filter the events from the transaction
-            let filtered_transaction_events = transaction
-                .get_events()
-                .into_iter()
-                .filter(|event| {
-                    check_if_filter_applies_for_event(&contract_address, &keys_filter, event)
-                })
-                .skip_while(|_| {
-                    if skip > 0 {
-                        skip -= 1;
-                        true
-                    } else {
-                        false
-                    }
-                });
+                transactions.get_by_hash(*transaction_hash).ok_or(Error::NoTransaction)?;
 
             // produce an emitted event for each filtered transaction event
-            for transaction_event in filtered_transaction_events {
-                // check if there are more elements to fetch
-                if let Some(limit) = limit {
-                    if elements_added == limit {
-                        return Ok((events, true));
-                    }
+            for transaction_event in
+                transaction.get_events().into_iter().filter(|event| filter(event))
+            {
+                if max_events.map_or(false, |max_events| events.len() == max_events) {
+                    return Ok(events);
                 }
 
-                let emitted_event = EmittedEvent {
+                events.push(EmittedEvent {
                     transaction_hash: *transaction_hash,
                     block_hash: block.block_hash(),
                     block_number: block.block_number(),
                     keys: transaction_event.keys,
                     from_address: transaction_event.from_address,
                     data: transaction_event.data,
-                };
+                });
+            }
+        }
+    }
 
-                events.push(emitted_event);
-                elements_added += 1;
+    Ok(events)
+}
+
+/// Drops the events of the blocks preceding the latest `retained_blocks` blocks, of which the
+/// latest one has `latest_block_number`
+pub(crate) fn prune_events(
+    starknet: &mut Starknet,
+    retained_blocks: u64,
+    latest_block_number: BlockNumber,
+) {
+    let first_retained_block = (latest_block_number.0 + 1).saturating_sub(retained_blocks);
+    for block_number in starknet.events_pruned_before.0..first_retained_block {
+        let Some(block) = starknet.blocks.num_to_block.get(&BlockNumber(block_number)) else {
+            continue;
+        };
+        for transaction_hash in block.get_transactions() {
+            if let Some(transaction) = starknet.transactions.get_by_hash_mut(transaction_hash) {
+                transaction.prune_events();
             }
         }
     }
 
-    Ok((events, false))
+    if first_retained_block > starknet.events_pruned_before.0 {
+        starknet.events_pruned_before = BlockNumber(first_retained_block);
+    }
 }
 
 /// This method checks if the event applies to the provided filters and returns true or false
@@ -132,9 +224,10 @@ mod tests {
     use starknet_types::felt::Felt;
     use starknet_types::rpc::transactions::{DeclareTransaction, Transaction};
 
-    use super::{check_if_filter_applies_for_event, get_events};
+    use super::{check_if_filter_applies_for_event, get_events, scan_blocks_in_shards};
+    use crate::error::Error;
     use crate::starknet::events::check_if_filter_applies_for_event_keys;
-    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::starknet_config::{EventRetention, StarknetConfig};
     use crate::starknet::Starknet;
     use crate::traits::HashIdentified;
     use crate::utils::test_utils::{dummy_contract_address, dummy_declare_transaction_v1};
@@ -345,20 +438,45 @@ mod tests {
         assert_eq!(events.len(), 10);
         assert!(has_more);
 
-        // returns all events from the first 2 blocks, skip 3, but limit the result to 1
+        // returns all events up to the 2nd transaction, skip 3, but limit the result to 1
         let (events, has_more) =
-            get_events(&starknet, None, Some(BlockId::Number(1)), None, None, 3, Some(1)).unwrap();
+            get_events(&starknet, None, Some(BlockId::Number(2)), None, None, 3, Some(1)).unwrap();
         assert_eq!(events.len(), 0);
         assert!(!has_more);
 
-        // returns all events from the first 2 blocks, skip 1, but limit the result to 1, it should
-        // return 1 event and should be more
+        // returns all events up to the 2nd transaction, skip 1, but limit the result to 1, it
+        // should return 1 event and should be more
         let (events, has_more) =
-            get_events(&starknet, None, Some(BlockId::Number(1)), None, None, 1, Some(1)).unwrap();
+            get_events(&starknet, None, Some(BlockId::Number(2)), None, None, 1, Some(1)).unwrap();
         assert_eq!(events.len(), 1);
         assert!(has_more);
     }
 
+    #[test]
+    fn events_of_blocks_outside_retention_are_pruned() {
+        let starknet = setup_with_config(&StarknetConfig {
+            event_retention: EventRetention::Blocks(2),
+            ..StarknetConfig::default()
+        });
+
+        // only the events of the 4th and 5th transaction are retained
+        let (events, _) = get_events(&starknet, None, None, None, None, 0, None).unwrap();
+        assert_eq!(events.len(), 9);
+        let (events, _) =
+            get_events(&starknet, Some(BlockId::Number(4)), None, None, None, 0, None).unwrap();
+        assert_eq!(events.len(), 9);
+
+        match get_events(&starknet, Some(BlockId::Number(0)), None, None, None, 0, None) {
+            Err(Error::EventsPruned { first_retained_block }) => {
+                assert_eq!(first_retained_block, 4)
+            }
+            other => panic!("Unexpected result: {other:?}"),
+        }
+
+        let pruned_transaction = starknet.transactions.get_by_hash(Felt::from(100)).unwrap();
+        assert!(pruned_transaction.get_events().is_empty());
+    }
+
     #[test]
     fn check_correct_events_being_returned() {
         let starknet = setup();
@@ -389,10 +507,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn events_of_shards_are_merged_in_order_of_blocks() {
+        let starknet = setup();
+        let blocks = starknet.blocks.get_blocks(None, None).unwrap();
+        let filter = |_: &Event| true;
+        let scan = |max_events: Option<usize>, shard_count: usize| {
+            scan_blocks_in_shards(&starknet.transactions, &blocks, &filter, max_events, shard_count)
+                .unwrap()
+                .into_iter()
+                .map(|event| (event.transaction_hash, event.keys))
+                .collect::<Vec<_>>()
+        };
+
+        let sequential_events = scan(None, 1);
+        assert_eq!(sequential_events.len(), 15);
+        for shard_count in 2..=blocks.len() {
+            assert_eq!(scan(None, shard_count), sequential_events);
+            assert_eq!(scan(Some(4), shard_count), sequential_events[..4]);
+        }
+    }
+
     fn setup() -> Starknet {
+        setup_with_config(&StarknetConfig::default())
+    }
+
+    fn setup_with_config(config: &StarknetConfig) -> Starknet {
         // generate 5 transactions
         // each transaction should have events count equal to the order of the transaction
-        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let mut starknet = Starknet::new(config).unwrap();
 
         for idx in 0..5 {
             let transaction =
@@ -409,7 +552,8 @@ mod tests {
                 .unwrap();
         }
 
-        assert_eq!(starknet.blocks.get_blocks(None, None).unwrap().len(), 5);
+        // the transactions are in the blocks after the genesis block
+        assert_eq!(starknet.blocks.get_blocks(None, None).unwrap().len(), 6);
         for idx in 0..5 {
             starknet.transactions.get_by_hash(Felt::from(idx as u128 + 100)).unwrap();
         }
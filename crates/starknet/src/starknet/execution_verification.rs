@@ -0,0 +1,285 @@
+use std::collections::HashMap;
+use std::thread;
+
+use blockifier::block_context::BlockContext;
+use blockifier::transaction::transactions::ExecutableTransaction;
+use starknet_api::block::BlockNumber;
+use starknet_types::felt::{Felt, TransactionHash};
+use starknet_types::rpc::transactions::BroadcastedTransaction;
+use tracing::{error, warn};
+
+use super::Starknet;
+use crate::blocks::StarknetBlock;
+use crate::error::{DevnetResult, Error};
+use crate::state::state_diff::StateDiff;
+use crate::state::StarknetState;
+use crate::traits::{HashIdentified, StateChanger, StateExtractor};
+use crate::transactions::to_broadcasted_transaction;
+
+/// What is needed, besides the archived states, to re-execute the pending block once sealed. Only
+/// recorded with `config.verify_execution`.
+#[derive(Default, Clone)]
+pub(crate) struct ExecutionVerification {
+    /// Whether `__validate__` was executed, by hash of the transactions executed since the last
+    /// block was sealed; not executing it depends on e.g. account templates at submission
+    validations: HashMap<TransactionHash, bool>,
+    /// Set if the state was changed without a transaction (e.g. by a cheatcode) since the last
+    /// block was sealed
+    has_direct_changes: bool,
+}
+
+pub(crate) fn record_validation(
+    starknet: &mut Starknet,
+    transaction_hash: TransactionHash,
+    validate: bool,
+) {
+    if starknet.config.verify_execution {
+        starknet.execution_verification.validations.insert(transaction_hash, validate);
+    }
+}
+
+pub(crate) fn record_direct_changes(starknet: &mut Starknet) {
+    if starknet.config.verify_execution {
+        starknet.execution_verification.has_direct_changes = true;
+    }
+}
+
+/// Re-executes the transactions of `block`, which is being sealed with `state_diff`, from the state
+/// of its parent in a background thread, and logs where the resulting state diff differs. Has to
+/// be called before the block context is updated for the next block, because the transactions
+/// were executed in it.
+pub(crate) fn verify_sealed_block(
+    starknet: &mut Starknet,
+    block: &StarknetBlock,
+    state_diff: &StateDiff,
+) {
+    if !starknet.config.verify_execution {
+        return;
+    }
+    let validations = std::mem::take(&mut starknet.execution_verification.validations);
+    let has_direct_changes =
+        std::mem::replace(&mut starknet.execution_verification.has_direct_changes, false);
+
+    let block_number = block.block_number();
+    if block.get_transactions().is_empty() {
+        return;
+    }
+    if has_direct_changes {
+        warn!(
+            "Execution of block {block_number} is not verified, because its state was also \
+             changed without transactions"
+        );
+        return;
+    }
+
+    let job = match VerificationJob::new(starknet, block, state_diff.clone(), &validations) {
+        Ok(job) => job,
+        Err(err) => {
+            error!("Execution verification of block {block_number} could not start: {err}");
+            return;
+        }
+    };
+    thread::spawn(move || {
+        for difference in job.run() {
+            error!("Execution verification of block {block_number} failed: {difference}");
+        }
+    });
+}
+
+struct VerificationJob {
+    parent_state: StarknetState,
+    block_context: BlockContext,
+    chain_id: Felt,
+    /// Transactions in the order of execution, with whether `__validate__` was executed
+    transactions: Vec<(TransactionHash, BroadcastedTransaction, bool)>,
+    expected_state_diff: StateDiff,
+}
+
+impl VerificationJob {
+    fn new(
+        starknet: &Starknet,
+        block: &StarknetBlock,
+        expected_state_diff: StateDiff,
+        validations: &HashMap<TransactionHash, bool>,
+    ) -> DevnetResult<Self> {
+        // only the genesis block has no parent, and it holds no transactions
+        let parent_number =
+            block.block_number().0.checked_sub(1).ok_or(Error::UnsupportedAction {
+                msg: "The genesis block has no parent state".to_string(),
+            })?;
+        let parent_state = starknet
+            .blocks
+            .num_to_state
+            .get(&BlockNumber(parent_number))
+            .ok_or(Error::NoStateAtBlock { block_number: parent_number })?;
+
+        let mut transactions = vec![];
+        for transaction_hash in block.get_transactions() {
+            let transaction =
+                starknet.transactions.get_by_hash(*transaction_hash).ok_or(Error::NoTransaction)?;
+            transactions.push((
+                *transaction_hash,
+                to_broadcasted_transaction(&transaction.inner)?,
+                validations.get(transaction_hash).copied().unwrap_or(true),
+            ));
+        }
+
+        Ok(Self {
+            parent_state: parent_state.clone(),
+            block_context: starknet.block_context.clone(),
+            chain_id: starknet.chain_id().to_felt(),
+            transactions,
+            expected_state_diff,
+        })
+    }
+
+    /// Returns the differences from the original execution; empty if there are none
+    fn run(mut self) -> Vec<String> {
+        let mut replayed_state_diff = StateDiff::default();
+        for (transaction_hash, transaction, validate) in std::mem::take(&mut self.transactions) {
+            match self.replay_transaction(transaction, validate) {
+                Ok(state_diff) => replayed_state_diff.extend(state_diff),
+                Err(err) => {
+                    return vec![format!(
+                        "The transaction {transaction_hash:#x} could not be re-executed: {err}"
+                    )];
+                }
+            }
+        }
+
+        describe_differences(&self.expected_state_diff, &replayed_state_diff)
+    }
+
+    fn replay_transaction(
+        &mut self,
+        transaction: BroadcastedTransaction,
+        validate: bool,
+    ) -> DevnetResult<StateDiff> {
+        transaction.to_blockifier_account_transaction(self.chain_id, false)?.execute(
+            &mut self.parent_state.state,
+            &self.block_context,
+            true,
+            validate,
+        )?;
+
+        let state_diff = self.parent_state.extract_state_diff_from_pending_state()?;
+        self.parent_state.apply_state_difference(state_diff.clone())?;
+        self.parent_state.clear_dirty_state();
+
+        Ok(state_diff)
+    }
+}
+
+fn describe_differences(expected: &StateDiff, replayed: &StateDiff) -> Vec<String> {
+    let compared = [
+        ("storage updates", expected.storage_updates == replayed.storage_updates),
+        ("nonces", expected.address_to_nonce == replayed.address_to_nonce),
+        ("deployed contracts", expected.address_to_class_hash == replayed.address_to_class_hash),
+        (
+            "declared classes",
+            expected.class_hash_to_compiled_class_hash
+                == replayed.class_hash_to_compiled_class_hash
+                && expected.declared_contracts == replayed.declared_contracts
+                && expected.cairo_0_declared_contracts == replayed.cairo_0_declared_contracts,
+        ),
+    ];
+
+    compared
+        .into_iter()
+        .filter(|(_, equal)| !equal)
+        .map(|(property, _)| {
+            format!("The {property} differ from the ones of the original execution")
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use starknet_api::block::BlockNumber;
+    use starknet_api::transaction::Fee;
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_types::contract_address::ContractAddress;
+    use starknet_types::felt::Felt;
+    use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
+
+    use super::VerificationJob;
+    use crate::constants::ERC20_CONTRACT_ADDRESS;
+    use crate::starknet::account_templates::{set_account_template, AccountTemplate};
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+
+    /// Returns an instance in which a transfer, sent without validation, is mined in block 1
+    fn setup() -> Starknet {
+        let mut starknet =
+            Starknet::new(&StarknetConfig { verify_execution: true, ..Default::default() })
+                .unwrap();
+        let accounts = starknet.get_predeployed_accounts();
+        let (sender, recipient) = (accounts[0].account_address, accounts[1].account_address);
+        set_account_template(&mut starknet, sender, AccountTemplate::AlwaysAccept).unwrap();
+
+        let calldata = vec![
+            Felt::from(1), // number of calls
+            Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap(),
+            Felt::from(get_selector_from_name("transfer").unwrap()),
+            Felt::from(0), // data offset
+            Felt::from(3), // data length
+            Felt::from(3), // calldata length
+            Felt::from(recipient),
+            Felt::from(1), // amount low
+            Felt::from(0), // amount high
+        ];
+        starknet
+            .add_invoke_transaction(BroadcastedInvokeTransaction::new(
+                sender,
+                Fee(1_000_000_000_000_000_000),
+                &vec![],
+                Felt::from(0),
+                &calldata,
+                Felt::from(1),
+            ))
+            .unwrap();
+
+        starknet
+    }
+
+    fn job_of_transfer_block(starknet: &Starknet) -> VerificationJob {
+        let block = starknet.blocks.num_to_block.get(&BlockNumber(1)).unwrap();
+        let state_diff = starknet.blocks.num_to_state_diff.get(&BlockNumber(1)).unwrap().clone();
+        let validations = block.get_transactions().iter().map(|hash| (*hash, false)).collect();
+
+        VerificationJob::new(starknet, block, state_diff, &validations).unwrap()
+    }
+
+    #[test]
+    fn re_execution_of_block_matches_original_execution() {
+        let starknet = setup();
+
+        assert_eq!(job_of_transfer_block(&starknet).run(), Vec::<String>::new());
+    }
+
+    #[test]
+    fn differing_state_diff_is_reported() {
+        let starknet = setup();
+        let mut job = job_of_transfer_block(&starknet);
+        job.expected_state_diff
+            .address_to_nonce
+            .insert(ContractAddress::new(Felt::from(1)).unwrap(), Felt::from(7));
+
+        assert_eq!(job.run(), vec!["The nonces differ from the ones of the original execution"]);
+    }
+
+    #[test]
+    fn validation_is_executed_if_not_recorded_otherwise() {
+        let starknet = setup();
+        let block = starknet.blocks.num_to_block.get(&BlockNumber(1)).unwrap();
+        let state_diff = starknet.blocks.num_to_state_diff.get(&BlockNumber(1)).unwrap().clone();
+
+        // the transfer is not signed, so its validation fails when executed
+        let job = VerificationJob::new(&starknet, block, state_diff, &HashMap::new()).unwrap();
+        let differences = job.run();
+        assert_eq!(differences.len(), 1);
+        assert!(differences[0].contains("could not be re-executed"));
+    }
+}
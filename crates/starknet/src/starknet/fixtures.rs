@@ -0,0 +1,210 @@
+use serde::Serialize;
+use starknet_rs_core::types::{CompressedLegacyContractClass, TransactionExecutionStatus};
+use starknet_rs_ff::FieldElement;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::contract_class::{Cairo0ContractClass, Cairo0Json, DeprecatedContractClass};
+use starknet_types::felt::{ClassHash, Felt, TransactionHash};
+use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
+use starknet_types::rpc::transactions::{BroadcastedDeclareTransaction, BroadcastedTransaction};
+
+use super::sender::{get_selector, TransactionSender};
+use super::starknet_config::{BlockGeneration, StarknetConfig};
+use super::Starknet;
+use crate::constants::{CAIRO_0_ACCOUNT_CONTRACT_HASH, SELF_TEST_CONTRACT_JSON};
+use crate::error::{DevnetResult, Error};
+use crate::raw_execution::Call;
+
+/// Signed transactions that succeed if broadcast in the given order to a fresh Devnet started with
+/// the same configuration (e.g. the same seed and chain ID)
+#[derive(Debug, Clone, Serialize)]
+pub struct Fixtures {
+    pub chain_id: Felt,
+    pub seed: u32,
+    pub sender_address: ContractAddress,
+    pub transactions: Vec<Fixture>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Fixture {
+    /// What the transaction does, e.g. `transfer`
+    pub name: String,
+    pub transaction_hash: TransactionHash,
+    pub transaction: BroadcastedTransaction,
+}
+
+/// Generates fixtures (a transfer, a declaration, a deployment and an invocation of the deployed
+/// contract) sent from the first predeployed account. Each transaction is executed against a
+/// throwaway instance created from `config`, to obtain the nonce of the following one and to
+/// verify that it succeeds.
+pub async fn generate_fixtures(config: &StarknetConfig) -> DevnetResult<Fixtures> {
+    let mut starknet = Starknet::new(&StarknetConfig {
+        dump_on: None,
+        dump_path: None,
+        re_execute_on_init: false,
+        block_max_steps: None,
+        block_max_txs: None,
+        block_generation: BlockGeneration::Transaction,
+        ..config.clone()
+    })?;
+
+    // calldata of transactions is encoded as expected by Cairo 0 accounts
+    if config.account_contract_class_hash
+        != Felt::from_prefixed_hex_str(CAIRO_0_ACCOUNT_CONTRACT_HASH)?
+    {
+        return Err(Error::UnsupportedAction {
+            msg: "Fixtures can only be generated for the default Cairo 0 account class".into(),
+        });
+    }
+    let accounts = starknet.get_predeployed_accounts();
+    let (sender_account, recipient_account) = match accounts.as_slice() {
+        [] => {
+            return Err(Error::UnsupportedAction {
+                msg: "Fixtures require at least one predeployed account".into(),
+            });
+        }
+        [sender] => (sender, sender),
+        [sender, recipient, ..] => (sender, recipient),
+    };
+    let sender = TransactionSender::new(
+        &starknet,
+        sender_account.account_address,
+        sender_account.private_key,
+    )?;
+
+    let mut transactions = vec![];
+
+    // transfer
+    let transfer_tx = sender
+        .invoke(
+            &starknet,
+            Call {
+                to: Felt::from(starknet.config.fee_token_address).into(),
+                selector: get_selector("transfer")?,
+                calldata: vec![
+                    Felt::from(recipient_account.account_address).into(),
+                    FieldElement::ONE,  // amount low
+                    FieldElement::ZERO, // amount high
+                ],
+            },
+        )
+        .await?;
+    let transaction_hash = starknet.add_invoke_transaction(transfer_tx.clone())?;
+    transactions.push(to_fixture(
+        &starknet,
+        "transfer",
+        transaction_hash,
+        BroadcastedTransaction::Invoke(transfer_tx),
+    )?);
+
+    // declare
+    let contract_class = load_broadcastable_class(SELF_TEST_CONTRACT_JSON)?;
+    let declare_tx = sender.declare(&starknet, &contract_class).await?;
+    let (transaction_hash, class_hash) = starknet.add_declare_transaction_v1(declare_tx.clone())?;
+    transactions.push(to_fixture(
+        &starknet,
+        "declare",
+        transaction_hash,
+        BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V1(Box::new(declare_tx))),
+    )?);
+
+    // deploy through UDC
+    let (deploy_tx, contract_address) = deploy(&starknet, &sender, class_hash).await?;
+    let transaction_hash = starknet.add_invoke_transaction(deploy_tx.clone())?;
+    transactions.push(to_fixture(
+        &starknet,
+        "deploy",
+        transaction_hash,
+        BroadcastedTransaction::Invoke(deploy_tx),
+    )?);
+
+    // invoke the deployed contract
+    let invoke_tx = sender
+        .invoke(
+            &starknet,
+            Call {
+                to: contract_address.into(),
+                selector: get_selector("increase_balance")?,
+                calldata: vec![FieldElement::from(10u32)],
+            },
+        )
+        .await?;
+    let transaction_hash = starknet.add_invoke_transaction(invoke_tx.clone())?;
+    transactions.push(to_fixture(
+        &starknet,
+        "invoke",
+        transaction_hash,
+        BroadcastedTransaction::Invoke(invoke_tx),
+    )?);
+
+    Ok(Fixtures {
+        chain_id: starknet.chain_id().to_felt(),
+        seed: config.seed,
+        sender_address: sender.address,
+        transactions,
+    })
+}
+
+/// Deploys an instance of `class_hash` without constructor calldata. Returns the transaction and
+/// the address of the contract to be deployed.
+async fn deploy(
+    starknet: &Starknet,
+    sender: &TransactionSender,
+    class_hash: ClassHash,
+) -> DevnetResult<(BroadcastedInvokeTransaction, Felt)> {
+    let salt = FieldElement::ZERO;
+    let deploy_tx = sender
+        .invoke(
+            starknet,
+            Call {
+                to: Felt::from(starknet.config.udc_address).into(),
+                selector: get_selector("deployContract")?,
+                calldata: vec![
+                    class_hash.into(),
+                    salt,
+                    FieldElement::ZERO, // unique
+                    FieldElement::ZERO, // constructor calldata length
+                ],
+            },
+        )
+        .await?;
+
+    // not unique, so the deployer address is not part of the computation
+    let contract_address = starknet_rs_core::utils::get_udc_deployed_address(
+        salt,
+        class_hash.into(),
+        &starknet_rs_core::utils::UdcUniqueness::NotUnique,
+        &[],
+    );
+
+    Ok((deploy_tx, contract_address.into()))
+}
+
+/// Loads a compiled Cairo 0 class in the RPC format, so that its program is serialized as
+/// base64-encoded gzipped JSON, which is what `starknet_addDeclareTransaction` expects
+fn load_broadcastable_class(json_str: &str) -> DevnetResult<Cairo0ContractClass> {
+    let compressed_class: CompressedLegacyContractClass =
+        Cairo0Json::raw_json_from_json_str(json_str)?.try_into()?;
+    let serialized_class = serde_json::to_string(&compressed_class).map_err(|_| {
+        Error::SerializationError { obj_name: "CompressedLegacyContractClass".to_string() }
+    })?;
+
+    Ok(Cairo0ContractClass::Rpc(DeprecatedContractClass::rpc_from_json_str(&serialized_class)?))
+}
+
+fn to_fixture(
+    starknet: &Starknet,
+    name: &str,
+    transaction_hash: TransactionHash,
+    transaction: BroadcastedTransaction,
+) -> DevnetResult<Fixture> {
+    let (execution_status, _) =
+        starknet.get_transaction_execution_and_finality_status(transaction_hash)?;
+    match execution_status {
+        TransactionExecutionStatus::Succeeded => {
+            Ok(Fixture { name: name.to_string(), transaction_hash, transaction })
+        }
+        TransactionExecutionStatus::Reverted => {
+            Err(Error::UnexpectedInternalError { msg: format!("Fixture {name} reverted") })
+        }
+    }
+}
@@ -0,0 +1,121 @@
+use serde::{Deserialize, Serialize};
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::{ClassHash, Felt, Key};
+use starknet_types::traits::ToDecimalString;
+
+use super::Starknet;
+use crate::constants::{ERC20_CONTRACT_CLASS_HASH, UDC_CONTRACT_CLASS_HASH};
+use crate::error::DevnetResult;
+
+/// What Devnet sets up before any transaction is received
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GenesisSummary {
+    pub seed: u32,
+    pub chain_id: String,
+    pub predeployed_contracts: Vec<PredeployedContract>,
+    pub account_class_hash: ClassHash,
+    /// Decimal representation of the balance in WEI each account starts with
+    pub initial_balance: String,
+    pub predeployed_accounts: Vec<PredeployedAccount>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PredeployedContract {
+    pub name: String,
+    pub address: ContractAddress,
+    pub class_hash: ClassHash,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PredeployedAccount {
+    pub address: ContractAddress,
+    pub private_key: Key,
+    pub public_key: Key,
+}
+
+pub fn get_genesis_summary(starknet: &Starknet) -> DevnetResult<GenesisSummary> {
+    let predeployed_contracts = match &starknet.config.custom_genesis {
+        Some(custom_genesis) => custom_genesis
+            .contracts
+            .iter()
+            .map(|contract| PredeployedContract {
+                name: contract.name.clone(),
+                address: contract.address,
+                class_hash: contract.class_hash,
+            })
+            .collect(),
+        None => vec![
+            predeployed_contract(
+                "FeeToken",
+                starknet.config.fee_token_address,
+                ERC20_CONTRACT_CLASS_HASH,
+            )?,
+            predeployed_contract("UDC", starknet.config.udc_address, UDC_CONTRACT_CLASS_HASH)?,
+        ],
+    };
+
+    let predeployed_accounts = starknet
+        .get_predeployed_accounts()
+        .into_iter()
+        .map(|account| PredeployedAccount {
+            address: account.account_address,
+            private_key: account.private_key,
+            public_key: account.public_key,
+        })
+        .collect();
+
+    Ok(GenesisSummary {
+        seed: starknet.config.seed,
+        chain_id: starknet.config.chain_id.to_string(),
+        predeployed_contracts,
+        account_class_hash: starknet.config.account_contract_class_hash,
+        initial_balance: starknet.config.predeployed_accounts_initial_balance.to_decimal_string(),
+        predeployed_accounts,
+    })
+}
+
+fn predeployed_contract(
+    name: &str,
+    address: ContractAddress,
+    class_hash: &str,
+) -> DevnetResult<PredeployedContract> {
+    Ok(PredeployedContract {
+        name: name.to_string(),
+        address,
+        class_hash: Felt::from_prefixed_hex_str(class_hash)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_types::felt::Felt;
+
+    use super::get_genesis_summary;
+    use crate::constants::ERC20_CONTRACT_ADDRESS;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+
+    #[test]
+    fn summary_lists_predeployed_contracts_and_accounts() {
+        let config = StarknetConfig { total_accounts: 3, ..Default::default() };
+        let starknet = Starknet::new(&config).unwrap();
+
+        let summary = get_genesis_summary(&starknet).unwrap();
+        assert_eq!(summary.seed, config.seed);
+        assert_eq!(summary.chain_id, "SN_GOERLI");
+        assert_eq!(summary.account_class_hash, config.account_contract_class_hash);
+        assert_eq!(summary.predeployed_accounts.len(), 3);
+        assert_eq!(
+            summary.predeployed_accounts[0].address,
+            starknet.get_predeployed_accounts()[0].account_address
+        );
+
+        let contract_names: Vec<_> =
+            summary.predeployed_contracts.iter().map(|contract| contract.name.as_str()).collect();
+        assert_eq!(contract_names, vec!["FeeToken", "UDC"]);
+        assert_eq!(
+            Felt::from(summary.predeployed_contracts[0].address),
+            Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap()
+        );
+    }
+}
@@ -1,4 +1,5 @@
-use starknet_rs_core::types::BlockId;
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
+use starknet_rs_core::types::{BlockId, BlockTag};
 use starknet_types::contract_address::ContractAddress;
 use starknet_types::contract_class::ContractClass;
 use starknet_types::felt::{ClassHash, Felt};
@@ -31,6 +32,21 @@ pub fn get_class_impl(
     state.state.state.contract_class_at(&class_hash)
 }
 
+/// Compiles the Sierra class with `class_hash` the same way as the classes of declare transactions
+pub fn get_compiled_casm_impl(
+    starknet: &Starknet,
+    class_hash: ClassHash,
+) -> DevnetResult<CasmContractClass> {
+    match starknet.get_class(BlockId::Tag(BlockTag::Pending), class_hash)? {
+        contract_class @ ContractClass::Cairo1(_) => {
+            Ok(CasmContractClass::try_from(contract_class)?)
+        }
+        ContractClass::Cairo0(_) => Err(Error::UnsupportedAction {
+            msg: format!("Class {class_hash:x} is a Cairo 0 class, which has no compiled CASM"),
+        }),
+    }
+}
+
 pub fn get_class_at_impl(
     starknet: &Starknet,
     block_id: BlockId,
@@ -44,7 +60,7 @@ pub fn get_class_at_impl(
 mod tests {
 
     use starknet_rs_core::types::BlockId;
-    use starknet_types::contract_class::{Cairo0Json, ContractClass};
+    use starknet_types::contract_class::{compute_casm_class_hash, Cairo0Json, ContractClass};
     use starknet_types::felt::Felt;
     use starknet_types::traits::HashProducer;
 
@@ -107,6 +123,20 @@ mod tests {
         assert_eq!(contract_class, expected)
     }
 
+    #[test]
+    fn get_compiled_casm_of_sierra_class() {
+        let (mut starknet, account) = setup(Some(100000000));
+
+        let declare_txn = dummy_broadcasted_declare_transaction_v2(&account.account_address);
+        let compiled_class_hash = declare_txn.compiled_class_hash;
+        let (_, class_hash) = starknet.add_declare_transaction_v2(declare_txn).unwrap();
+
+        let casm = starknet.get_compiled_casm(class_hash).unwrap();
+        assert_eq!(compute_casm_class_hash(&casm).unwrap(), compiled_class_hash);
+
+        assert!(starknet.get_compiled_casm(account.class_hash).is_err());
+    }
+
     #[test]
     fn get_class_hash_at_generated_accounts() {
         let (mut starknet, account) = setup(Some(100000000));
@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use starknet_api::block::BlockNumber;
+use starknet_types::felt::Felt;
+
+use super::Starknet;
+
+/// Result of walking the blocks of Devnet, from the first to the latest one
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityReport {
+    pub valid: bool,
+    pub checked_blocks: u64,
+    pub issues: Vec<IntegrityIssue>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IntegrityIssue {
+    pub block_number: u64,
+    pub description: String,
+}
+
+/// Checks that blocks are numbered without gaps and chained by their parent hashes, that each of
+/// them has a state diff and a state snapshot, and that transactions and blocks reference each
+/// other consistently. Devnet does not compute state roots, so the continuity of the state is
+/// checked by the presence of the state of each block.
+pub fn verify_integrity(starknet: &Starknet) -> IntegrityReport {
+    let blocks = &starknet.blocks;
+    let checked_blocks = blocks.num_to_block.len() as u64;
+
+    let mut issues = vec![];
+    for block_number in 0..checked_blocks {
+        issues.extend(verify_block(starknet, BlockNumber(block_number)));
+    }
+
+    // each transaction of a block has to be listed in it
+    let mut block_transaction_counts = HashMap::<BlockNumber, usize>::new();
+    for (_, transaction) in starknet.transactions.iter() {
+        if let Some(block_number) = transaction.block_number {
+            *block_transaction_counts.entry(block_number).or_default() += 1;
+        }
+    }
+    for (block_number, transaction_count) in block_transaction_counts {
+        let listed_count = blocks
+            .num_to_block
+            .get(&block_number)
+            .map_or(0, |block| block.get_transactions().len());
+        if listed_count != transaction_count {
+            issues.push(IntegrityIssue {
+                block_number: block_number.0,
+                description: format!(
+                    "{transaction_count} transactions belong to the block, but it lists \
+                     {listed_count}"
+                ),
+            });
+        }
+    }
+
+    let latest_block_hash = checked_blocks
+        .checked_sub(1)
+        .and_then(|latest_block_number| blocks.num_to_block.get(&BlockNumber(latest_block_number)));
+    if blocks.last_block_hash != latest_block_hash.map(|block| block.block_hash()) {
+        issues.push(IntegrityIssue {
+            block_number: checked_blocks.saturating_sub(1),
+            description: "The hash of the latest block does not match the last block hash"
+                .to_string(),
+        });
+    }
+
+    issues.sort_by_key(|issue| issue.block_number);
+    IntegrityReport { valid: issues.is_empty(), checked_blocks, issues }
+}
+
+/// Checks the block with `block_number` against its parent, its state and its transactions
+pub(crate) fn verify_block(starknet: &Starknet, block_number: BlockNumber) -> Vec<IntegrityIssue> {
+    let blocks = &starknet.blocks;
+    let issue = |description: String| IntegrityIssue { block_number: block_number.0, description };
+
+    let Some(block) = blocks.num_to_block.get(&block_number) else {
+        return vec![issue("The block is missing".to_string())];
+    };
+
+    let mut issues = vec![];
+    let block_hash = block.block_hash();
+    if blocks.hash_to_num.get(&block_hash) != Some(&block_number) {
+        issues.push(issue(format!("The block hash {block_hash:#x} is not indexed")));
+    }
+
+    let expected_parent_hash = match block_number.0.checked_sub(1) {
+        Some(parent_number) => {
+            blocks.num_to_block.get(&BlockNumber(parent_number)).map(|parent| parent.block_hash())
+        }
+        None => Some(Felt::default()),
+    };
+    if expected_parent_hash != Some(block.parent_hash()) {
+        issues.push(issue(format!(
+            "The parent hash {:#x} does not match the hash of the previous block",
+            block.parent_hash()
+        )));
+    }
+
+    if !blocks.num_to_state_diff.contains_key(&block_number) {
+        issues.push(issue("The state diff of the block is missing".to_string()));
+    }
+    if !blocks.num_to_state.contains_key(&block_number) {
+        issues.push(issue("The state of the block is missing".to_string()));
+    }
+
+    for transaction_hash in block.get_transactions() {
+        match starknet.transactions.get(transaction_hash) {
+            Some(transaction)
+                if transaction.block_number == Some(block_number)
+                    && transaction.block_hash == Some(block_hash) => {}
+            Some(_) => issues.push(issue(format!(
+                "The transaction {transaction_hash:#x} does not reference the block"
+            ))),
+            None => issues.push(issue(format!("The transaction {transaction_hash:#x} is missing"))),
+        }
+    }
+
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_api::block::BlockNumber;
+    use starknet_types::felt::Felt;
+
+    use super::verify_integrity;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+
+    fn starknet_with_blocks(count: usize) -> Starknet {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        for _ in 0..count {
+            starknet.create_block(None).unwrap();
+        }
+        starknet
+    }
+
+    #[test]
+    fn chain_of_created_blocks_is_valid() {
+        let starknet = starknet_with_blocks(3);
+
+        let report = verify_integrity(&starknet);
+        assert!(report.valid, "{report:?}");
+        // the created blocks follow the genesis block
+        assert_eq!(report.checked_blocks, 4);
+    }
+
+    #[test]
+    fn broken_parent_hash_is_reported() {
+        let mut starknet = starknet_with_blocks(3);
+        starknet.blocks.num_to_block.get_mut(&BlockNumber(2)).unwrap().header.parent_hash =
+            Felt::from(1).into();
+
+        let report = verify_integrity(&starknet);
+        assert!(!report.valid);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].block_number, 2);
+        assert!(report.issues[0].description.contains("parent hash"));
+    }
+
+    #[test]
+    fn missing_state_is_reported() {
+        let mut starknet = starknet_with_blocks(2);
+        starknet.blocks.num_to_state.remove(&BlockNumber(0));
+
+        let report = verify_integrity(&starknet);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].block_number, 0);
+    }
+}
@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use starknet_rs_core::types::BlockId;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::TransactionHash;
+use starknet_types::rpc::state::ThinStateDiff;
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+use crate::state::state_diff::StateDiff;
+
+/// State changes of a transaction or of an action of the user, with the block they belong to
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JournalEntry {
+    pub block_number: u64,
+    pub cause: ChangeCause,
+    pub state_diff: ThinStateDiff,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ChangeCause {
+    /// A transaction sent by a client, or the deployment of a counterfactual account
+    Transaction { transaction_hash: TransactionHash },
+    /// The transaction minting `amount` of the fee token to `address`
+    Mint { transaction_hash: TransactionHash, address: ContractAddress, amount: u128 },
+    /// A method writing the state without a transaction, e.g. `devnet_setAllowance`
+    Cheatcode { method: String },
+    /// A transaction re-executed from a dump
+    Load { transaction_hash: TransactionHash },
+}
+
+/// Every state change since the start, in the order of the changes
+#[derive(Debug, Default)]
+pub(crate) struct Journal(Vec<JournalEntry>);
+
+impl Journal {
+    pub(crate) fn record(&mut self, block_number: u64, cause: ChangeCause, state_diff: StateDiff) {
+        self.0.push(JournalEntry { block_number, cause, state_diff: state_diff.into() });
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Removes the entries recorded after the first `len` ones
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
+
+    /// Attributes the changes of the transaction with `transaction_hash` to `cause`, for
+    /// transactions sent by Devnet on behalf of the user
+    pub(crate) fn set_cause_of_transaction(
+        &mut self,
+        transaction_hash: TransactionHash,
+        cause: ChangeCause,
+    ) {
+        let transaction_cause = ChangeCause::Transaction { transaction_hash };
+        if let Some(entry) = self.0.iter_mut().rev().find(|entry| entry.cause == transaction_cause)
+        {
+            entry.cause = cause;
+        }
+    }
+}
+
+/// Returns the entries of blocks from `from_block` to `to_block`. Entries of the pending block are
+/// included only if `to_block` is not specified.
+pub(crate) fn get_journal(
+    starknet: &Starknet,
+    from_block: Option<BlockId>,
+    to_block: Option<BlockId>,
+) -> DevnetResult<Vec<JournalEntry>> {
+    let resolve_block_number = |block_id: BlockId| {
+        starknet
+            .blocks
+            .get_by_block_id(block_id)
+            .map(|block| block.block_number().0)
+            .ok_or(Error::NoBlock)
+    };
+    let from_block_number = from_block.map(resolve_block_number).transpose()?;
+    let to_block_number = to_block.map(resolve_block_number).transpose()?;
+    let pending_block_number = starknet.blocks.pending_block.block_number().0;
+
+    let is_in_range = |block_number: u64| {
+        if block_number >= pending_block_number {
+            return to_block_number.is_none();
+        }
+
+        from_block_number.map_or(true, |from| block_number >= from)
+            && to_block_number.map_or(true, |to| block_number <= to)
+    };
+
+    Ok(starknet.journal.0.iter().filter(|entry| is_in_range(entry.block_number)).cloned().collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_rs_core::types::BlockId;
+    use starknet_types::felt::Felt;
+    use starknet_types::rpc::transactions::{DeclareTransaction, Transaction};
+
+    use super::{get_journal, ChangeCause};
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::utils::test_utils::dummy_declare_transaction_v1;
+
+    /// Adds a transaction with `transaction_hash` in block 1, then replaces the public key of a
+    /// predeployed account in block 2
+    fn setup(transaction_hash: Felt) -> Starknet {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+
+        let mut declare_transaction = dummy_declare_transaction_v1();
+        declare_transaction.transaction_hash = transaction_hash;
+        let transaction = Transaction::Declare(DeclareTransaction::Version1(declare_transaction));
+        starknet
+            .handle_accepted_transaction(&transaction_hash, &transaction, Default::default())
+            .unwrap();
+
+        let account_address = starknet.get_predeployed_accounts()[0].account_address;
+        starknet.set_account_public_key(account_address, Felt::from(42)).unwrap();
+
+        starknet
+    }
+
+    #[test]
+    fn changes_are_journaled_with_their_causes() {
+        let transaction_hash = Felt::from(100);
+        let starknet = setup(transaction_hash);
+
+        let journal = get_journal(&starknet, None, None).unwrap();
+        let entries: Vec<(u64, ChangeCause)> =
+            journal.iter().map(|entry| (entry.block_number, entry.cause.clone())).collect();
+        assert_eq!(
+            entries,
+            vec![
+                (1, ChangeCause::Transaction { transaction_hash }),
+                (2, ChangeCause::Cheatcode { method: "devnet_setAccountPublicKey".to_string() }),
+            ]
+        );
+        assert_eq!(journal[1].state_diff.storage_diffs.len(), 1);
+    }
+
+    #[test]
+    fn journal_is_filtered_by_block_range() {
+        let transaction_hash = Felt::from(100);
+        let mut starknet = setup(transaction_hash);
+        starknet
+            .journal
+            .set_cause_of_transaction(transaction_hash, ChangeCause::Load { transaction_hash });
+
+        let journal =
+            get_journal(&starknet, Some(BlockId::Number(1)), Some(BlockId::Number(1))).unwrap();
+        assert_eq!(journal.len(), 1);
+        assert_eq!(journal[0].cause, ChangeCause::Load { transaction_hash });
+
+        assert_eq!(get_journal(&starknet, Some(BlockId::Number(2)), None).unwrap().len(), 1);
+        assert!(get_journal(&starknet, Some(BlockId::Number(3)), None).is_err());
+    }
+}
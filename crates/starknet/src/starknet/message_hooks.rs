@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use blockifier::execution::call_info::{CallInfo, OrderedL2ToL1Message};
+use blockifier::transaction::objects::TransactionExecutionInfo;
+use starknet_api::hash::StarkFelt;
+use starknet_api::transaction::L2ToL1Payload;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::TransactionHash;
+use starknet_types::rpc::transaction_receipt::{MessageToL1, OrderedMessageToL1};
+
+/// Called for every message sent to L1 by an accepted transaction, in the order in which the
+/// messages were sent, before the transaction is added to the pending block. Changes of the
+/// `to_address` and the `payload` of the message are kept, e.g. in the receipt of the
+/// transaction; `from_address`, the address of the sending contract, cannot be changed.
+pub type MessageToL1Hook = Arc<dyn Fn(&TransactionHash, &mut MessageToL1) + Send + Sync>;
+
+/// Passes the messages sent by the execution of the transaction with `transaction_hash` to `hook`
+pub(crate) fn apply_message_to_l1_hook(
+    hook: &MessageToL1Hook,
+    transaction_hash: &TransactionHash,
+    execution_info: &mut TransactionExecutionInfo,
+) {
+    let mut messages = vec![];
+    if let Some(call_info) = execution_info.execute_call_info.as_mut() {
+        collect_messages(call_info, &mut messages);
+    }
+    // messages are ordered within the whole transaction, not within the call sending them
+    messages.sort_by_key(|(_, message)| message.order);
+
+    for (from_address, sent_message) in messages {
+        let mut message = OrderedMessageToL1::new(sent_message.clone(), from_address).message;
+        hook(transaction_hash, &mut message);
+
+        sent_message.message.to_address = message.to_address;
+        sent_message.message.payload =
+            L2ToL1Payload(message.payload.into_iter().map(StarkFelt::from).collect());
+    }
+}
+
+fn collect_messages<'a>(
+    call_info: &'a mut CallInfo,
+    messages: &mut Vec<(ContractAddress, &'a mut OrderedL2ToL1Message)>,
+) {
+    let from_address = ContractAddress::from(call_info.call.storage_address);
+    messages.extend(
+        call_info.execution.l2_to_l1_messages.iter_mut().map(|message| (from_address, message)),
+    );
+
+    for inner_call in call_info.inner_calls.iter_mut() {
+        collect_messages(inner_call, messages);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use blockifier::execution::call_info::{CallInfo, OrderedL2ToL1Message};
+    use blockifier::transaction::objects::TransactionExecutionInfo;
+    use starknet_api::hash::StarkFelt;
+    use starknet_api::transaction::L2ToL1Payload;
+    use starknet_types::felt::Felt;
+    use starknet_types::rpc::transaction_receipt::MessageToL1;
+
+    use super::{apply_message_to_l1_hook, MessageToL1Hook};
+
+    fn sent_message(order: usize, payload: u64) -> OrderedL2ToL1Message {
+        let mut message = OrderedL2ToL1Message { order, ..Default::default() };
+        message.message.payload = L2ToL1Payload(vec![StarkFelt::from(payload)]);
+        message
+    }
+
+    #[test]
+    fn messages_of_nested_calls_are_passed_in_order_and_modified() {
+        let mut inner_call = CallInfo::default();
+        inner_call.execution.l2_to_l1_messages = vec![sent_message(1, 20)];
+        let mut outer_call = CallInfo::default();
+        outer_call.execution.l2_to_l1_messages = vec![sent_message(0, 10), sent_message(2, 30)];
+        outer_call.inner_calls = vec![inner_call];
+        let mut execution_info =
+            TransactionExecutionInfo { execute_call_info: Some(outer_call), ..Default::default() };
+
+        let received_payloads = Arc::new(Mutex::new(vec![]));
+        let hook: MessageToL1Hook = {
+            let received_payloads = received_payloads.clone();
+            Arc::new(move |_: &Felt, message: &mut MessageToL1| {
+                received_payloads.lock().unwrap().push(message.payload.clone());
+                message.payload.push(Felt::from(1));
+            })
+        };
+        apply_message_to_l1_hook(&hook, &Felt::from(100), &mut execution_info);
+
+        assert_eq!(
+            *received_payloads.lock().unwrap(),
+            vec![vec![Felt::from(10)], vec![Felt::from(20)], vec![Felt::from(30)]]
+        );
+        let outer_call = execution_info.execute_call_info.unwrap();
+        assert_eq!(
+            outer_call.inner_calls[0].execution.l2_to_l1_messages[0].message.payload,
+            L2ToL1Payload(vec![StarkFelt::from(20_u64), StarkFelt::from(1_u64)])
+        );
+    }
+}
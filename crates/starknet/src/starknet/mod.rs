@@ -6,10 +6,11 @@ use blockifier::state::state_api::StateReader;
 use blockifier::transaction::errors::TransactionPreValidationError;
 use blockifier::transaction::objects::TransactionExecutionInfo;
 use blockifier::transaction::transactions::ExecutableTransaction;
+use cairo_lang_starknet::casm_contract_class::CasmContractClass;
 use starknet_api::block::{BlockNumber, BlockStatus, BlockTimestamp, GasPrice};
 use starknet_api::transaction::Fee;
 use starknet_rs_core::types::{
-    BlockId, MsgFromL1, TransactionExecutionStatus, TransactionFinalityStatus,
+    BlockId, BlockTag, MsgFromL1, TransactionExecutionStatus, TransactionFinalityStatus,
 };
 use starknet_rs_core::utils::get_selector_from_name;
 use starknet_rs_ff::FieldElement;
@@ -25,8 +26,9 @@ use starknet_types::contract_class::ContractClass;
 use starknet_types::contract_storage_key::ContractStorageKey;
 use starknet_types::emitted_event::EmittedEvent;
 use starknet_types::felt::{ClassHash, Felt, TransactionHash};
+use starknet_types::num_bigint::BigUint;
 use starknet_types::patricia_key::PatriciaKey;
-use starknet_types::rpc::block::{Block, BlockHeader};
+use starknet_types::rpc::block::{Block, BlockHeader, BlockWithReceipts, TransactionWithReceipt};
 use starknet_types::rpc::estimate_message_fee::FeeEstimateWrapper;
 use starknet_types::rpc::state::ThinStateDiff;
 use starknet_types::rpc::transaction_receipt::TransactionReceipt;
@@ -35,16 +37,35 @@ use starknet_types::rpc::transactions::broadcasted_declare_transaction_v2::Broad
 use starknet_types::rpc::transactions::broadcasted_deploy_account_transaction::BroadcastedDeployAccountTransaction;
 use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
 use starknet_types::rpc::transactions::{
-    BroadcastedTransaction, BroadcastedTransactionCommon, DeclareTransaction,
-    DeclareTransactionTrace, DeployAccountTransactionTrace, ExecutionInvocation,
-    FunctionInvocation, InvokeTransactionTrace, SimulatedTransaction, SimulationFlag, Transaction,
-    TransactionTrace, Transactions,
+    BlockTransactionTrace, BroadcastedTransaction, BroadcastedTransactionCommon,
+    DeclareTransaction, SimulatedTransaction, SimulationFlag, Transaction, TransactionTrace,
+    Transactions,
 };
-use starknet_types::traits::HashProducer;
-use tracing::{error, warn};
-
+use starknet_types::traits::{HashProducer, ToHexString};
+use tracing::{error, info, warn};
+
+use self::account_portfolio::{AccountPortfolio, PendingStateSnapshot};
+use self::account_templates::{AccountTemplate, AccountTemplates};
+use self::block_resources::BlockExecutionResources;
+use self::checkpoint::DeferredEffects;
+use self::class_diff::ClassDiff;
+use self::counterfactual_accounts::{CounterfactualAccount, CounterfactualAccounts};
+use self::dump::DumpRecord;
+use self::event_stats::EventStats;
+use self::execution_verification::ExecutionVerification;
+use self::integrity::IntegrityReport;
+use self::journal::{ChangeCause, Journal, JournalEntry};
+use self::message_hooks::{apply_message_to_l1_hook, MessageToL1Hook};
+use self::notifications::{DevnetNotification, NotificationListener};
 use self::predeployed::initialize_erc20;
-use self::starknet_config::{DumpOn, StarknetConfig};
+use self::profiler_trace::{ProfiledCall, ProfilerCallTrace};
+use self::proof_jobs::{ProofJobs, ProofStatus};
+use self::starknet_config::{BlockGeneration, DumpOn, EventRetention, StarknetConfig};
+use self::step_limits::ContractStepLimits;
+use self::transaction_trace::{create_trace, TraceType};
+use self::transaction_validation::TransactionValidation;
+use self::transactions_by_sender::SenderIndex;
+use self::upgrade_simulation::UpgradeSimulation;
 use crate::account::Account;
 use crate::blocks::{StarknetBlock, StarknetBlocks};
 use crate::constants::{
@@ -63,16 +84,45 @@ use crate::traits::{
 };
 use crate::transactions::{StarknetTransaction, StarknetTransactions};
 
+pub mod account_portfolio;
+pub mod account_templates;
 mod add_declare_transaction;
 mod add_deploy_account_transaction;
 mod add_invoke_transaction;
-mod dump;
+pub mod base_dump;
+pub mod block_resources;
+mod chain_id_diagnostics;
+mod cheatcodes;
+mod checkpoint;
+pub mod class_diff;
+pub mod counterfactual_accounts;
+pub mod custom_genesis;
+pub mod dump;
+pub mod dump_format;
 mod estimations;
+pub mod event_stats;
 mod events;
+mod execution_verification;
+pub mod fixtures;
+pub mod genesis;
 mod get_class_impls;
+pub mod integrity;
+pub mod journal;
+pub mod message_hooks;
+pub mod notifications;
 mod predeployed;
+pub mod profiler_trace;
+pub mod proof_jobs;
+pub mod self_test;
+mod sender;
 pub mod starknet_config;
 mod state_update;
+mod step_limits;
+mod transaction_batch;
+mod transaction_trace;
+pub mod transaction_validation;
+mod transactions_by_sender;
+pub mod upgrade_simulation;
 
 pub struct Starknet {
     pub(in crate::starknet) state: StarknetState,
@@ -82,6 +132,36 @@ pub struct Starknet {
     pub transactions: StarknetTransactions,
     pub config: StarknetConfig,
     pub pending_block_timestamp_shift: i64,
+    /// State changes of the transactions in the pending block, stored with the block once mined
+    pending_state_diff: StateDiff,
+    /// Events of blocks before this one are pruned, as configured by `config.event_retention`
+    events_pruned_before: BlockNumber,
+    /// Transactions by sender, so that the activity of an account is listed without scanning
+    /// blocks
+    sender_index: SenderIndex,
+    /// Validation logic applied instead of `__validate__` of predeployed accounts
+    account_templates: AccountTemplates,
+    /// Accounts deployed by Devnet on their first invoke transaction
+    counterfactual_accounts: CounterfactualAccounts,
+    /// Simulated proofs of the sealed blocks
+    proof_jobs: ProofJobs,
+    /// Maximum number of steps of calls into specific contracts
+    contract_step_limits: ContractStepLimits,
+    /// Inputs to re-execute the pending block once sealed, see `config.verify_execution`
+    execution_verification: ExecutionVerification,
+    /// State changes with their causes, see [`Starknet::get_journal`]
+    journal: Journal,
+    /// Notified of included transactions and sealed blocks, see [`Starknet::notify`]
+    notification_listener: Option<NotificationListener>,
+    message_to_l1_hook: Option<MessageToL1Hook>,
+    /// If set, transactions are kept in the pending block until mining is resumed, see
+    /// [`Starknet::pause_mining`]
+    mining_paused: bool,
+    /// Set while transactions are added atomically, see [`checkpoint::add_atomically`]
+    deferred_effects: Option<Vec<DeferredEffects>>,
+    /// Address and registration of the counterfactual account whose deployment is being added, see
+    /// [`counterfactual_accounts::deploy_and_invoke`]
+    counterfactual_deployment: Option<(ContractAddress, CounterfactualAccount)>,
 }
 
 impl Default for Starknet {
@@ -98,6 +178,20 @@ impl Default for Starknet {
             transactions: Default::default(),
             config: Default::default(),
             pending_block_timestamp_shift: 0,
+            pending_state_diff: StateDiff::default(),
+            events_pruned_before: BlockNumber(0),
+            sender_index: SenderIndex::default(),
+            account_templates: AccountTemplates::default(),
+            counterfactual_accounts: CounterfactualAccounts::default(),
+            proof_jobs: ProofJobs::default(),
+            contract_step_limits: ContractStepLimits::default(),
+            execution_verification: ExecutionVerification::default(),
+            journal: Journal::default(),
+            notification_listener: None,
+            message_to_l1_hook: None,
+            mining_paused: false,
+            deferred_effects: None,
+            counterfactual_deployment: None,
         }
     }
 }
@@ -105,34 +199,18 @@ impl Default for Starknet {
 impl Starknet {
     pub fn new(config: &StarknetConfig) -> DevnetResult<Self> {
         let mut state = StarknetState::default();
-        // deploy udc and erc20 contracts
-        let erc20_fee_contract = predeployed::create_erc20()?;
-        let udc_contract = predeployed::create_udc()?;
-
-        erc20_fee_contract.deploy(&mut state)?;
-        initialize_erc20(&mut state)?;
-
-        udc_contract.deploy(&mut state)?;
-
-        let mut predeployed_accounts = PredeployedAccounts::new(
-            config.seed,
-            config.predeployed_accounts_initial_balance,
-            erc20_fee_contract.get_address(),
-        );
-
-        let accounts = predeployed_accounts.generate_accounts(
-            config.total_accounts,
-            config.account_contract_class_hash,
-            config.account_contract_class.clone(),
-        )?;
-        for account in accounts {
-            account.deploy(&mut state)?;
-            account.set_initial_balance(&mut state)?;
-        }
-
-        let chargeable_account = Account::new_chargeable(erc20_fee_contract.get_address())?;
-        chargeable_account.deploy(&mut state)?;
-        chargeable_account.set_initial_balance(&mut state)?;
+        let predeployed_accounts = match (&config.base_dump, &config.custom_genesis) {
+            (Some(base_dump), _) => base_dump::import_base_state(config, base_dump, &mut state)?,
+            (None, Some(custom_genesis)) => {
+                custom_genesis.deploy(&mut state)?;
+                PredeployedAccounts::new(
+                    config.seed,
+                    config.predeployed_accounts_initial_balance,
+                    config.fee_token_address,
+                )
+            }
+            (None, None) => Self::predeploy_canonical_contracts(config, &mut state)?,
+        };
 
         // copy already modified state to cached state
         state.clear_dirty_state();
@@ -142,22 +220,54 @@ impl Starknet {
             predeployed_accounts,
             block_context: Self::init_block_context(
                 config.gas_price,
-                ERC20_CONTRACT_ADDRESS,
+                &config.fee_token_address.to_prefixed_hex_str(),
                 config.chain_id,
             ),
             blocks: StarknetBlocks::default(),
             transactions: StarknetTransactions::default(),
             config: config.clone(),
             pending_block_timestamp_shift: 0,
+            pending_state_diff: StateDiff::default(),
+            events_pruned_before: BlockNumber(0),
+            sender_index: SenderIndex::default(),
+            account_templates: AccountTemplates::default(),
+            counterfactual_accounts: CounterfactualAccounts::default(),
+            proof_jobs: ProofJobs::default(),
+            contract_step_limits: ContractStepLimits::default(),
+            execution_verification: ExecutionVerification::default(),
+            journal: Journal::default(),
+            notification_listener: None,
+            message_to_l1_hook: None,
+            mining_paused: false,
+            deferred_effects: None,
+            counterfactual_deployment: None,
         };
 
         this.restart_pending_block()?;
 
+        // set block timestamp shift before the genesis block, so that it has the start time
+        if let Some(start_time) = config.start_time {
+            this.set_block_timestamp_shift(
+                start_time as i64 - Starknet::get_unix_timestamp_as_seconds() as i64,
+            );
+        }
+        // the genesis block holds no transactions, so that the latest block always exists
+        this.create_block(None)?;
+
         // Load starknet transactions
         if this.config.dump_path.is_some() && this.config.re_execute_on_init {
             // Try to load transactions from dump_path, if there is no file skip this step
             match this.load_transactions() {
-                Ok(txs) => this.re_execute(txs)?,
+                Ok(txs) => {
+                    this.re_execute(txs)?;
+                    let report = integrity::verify_integrity(&this);
+                    for issue in report.issues {
+                        error!(
+                            "Integrity check of loaded block {} failed: {}",
+                            issue.block_number, issue.description
+                        );
+                    }
+                }
                 Err(Error::FileNotFound) => {}
                 Err(err) => return Err(err),
             };
@@ -168,10 +278,33 @@ impl Starknet {
 
     pub fn restart(&mut self) -> DevnetResult<()> {
         self.config.re_execute_on_init = false;
+        let notification_listener = self.notification_listener.take();
+        let message_to_l1_hook = self.message_to_l1_hook.take();
         *self = Starknet::new(&self.config)?;
+        self.notification_listener = notification_listener;
+        self.message_to_l1_hook = message_to_l1_hook;
         Ok(())
     }
 
+    /// Sets the listener notified of every transaction included and every block sealed from now
+    /// on. Transactions loaded on initialization are not notified.
+    pub fn set_notification_listener(&mut self, listener: NotificationListener) {
+        self.notification_listener = Some(listener);
+    }
+
+    /// Sets the hook to which the messages sent to L1 by every transaction accepted from now on
+    /// are passed, to be inspected, recorded or modified. Transactions loaded on initialization
+    /// are not passed.
+    pub fn set_message_to_l1_hook(&mut self, hook: MessageToL1Hook) {
+        self.message_to_l1_hook = Some(hook);
+    }
+
+    fn notify(&self, notification: DevnetNotification) {
+        if let Some(listener) = &self.notification_listener {
+            listener(notification);
+        }
+    }
+
     pub fn get_predeployed_accounts(&self) -> Vec<Account> {
         self.predeployed_accounts.get_accounts().to_vec()
     }
@@ -198,18 +331,23 @@ impl Starknet {
         new_block.set_block_hash(new_block.generate_hash()?);
         new_block.status = BlockStatus::AcceptedOnL2;
 
+        execution_verification::verify_sealed_block(self, &new_block, &state_diff);
+
         // set block timestamp and context block timestamp for contract execution
         let block_timestamp = match timestamp {
             Some(timestamp) => BlockTimestamp(timestamp),
-            None => BlockTimestamp(
-                (Starknet::get_unix_timestamp_as_seconds() as i64
-                    + self.pending_block_timestamp_shift) as u64,
-            ),
+            None => self.current_block_timestamp(),
         };
         new_block.set_timestamp(block_timestamp);
         self.block_context.block_timestamp = block_timestamp;
 
         let new_block_number = new_block.block_number();
+        let notification = DevnetNotification::BlockSealed {
+            block_number: new_block_number,
+            block_hash: new_block.header.block_hash.0.into(),
+            timestamp: block_timestamp,
+            transaction_hashes: new_block.get_transactions().clone(),
+        };
 
         // update txs block hash block number for each transaction in the pending block
         new_block.get_transactions().iter().for_each(|tx_hash| {
@@ -229,6 +367,17 @@ impl Starknet {
         let deep_cloned_state = self.state.clone();
         self.blocks.save_state_at(new_block_number, deep_cloned_state);
 
+        for issue in integrity::verify_block(self, new_block_number) {
+            error!("Integrity check of block {new_block_number} failed: {}", issue.description);
+        }
+
+        if let EventRetention::Blocks(retained_blocks) = self.config.event_retention {
+            events::prune_events(self, retained_blocks, new_block_number);
+        }
+
+        proof_jobs::receive_proof_job(self, new_block_number);
+        self.notify(notification);
+
         Ok(new_block_number)
     }
 
@@ -292,8 +441,12 @@ impl Starknet {
                 // we should not save transactions that failed with one of the following errors
                 match tx_err {
                     blockifier::transaction::errors::TransactionExecutionError::TransactionPreValidationError(
-                        TransactionPreValidationError::InvalidNonce { .. }
-                    ) => Err(TransactionValidationError::InvalidTransactionNonce.into()),
+                        TransactionPreValidationError::InvalidNonce { account_nonce, incoming_tx_nonce, .. }
+                    ) => Err(invalid_nonce_error(
+                        &transaction,
+                        account_nonce.0.into(),
+                        incoming_tx_nonce.0.into(),
+                    )),
                     blockifier::transaction::errors::TransactionExecutionError::FeeCheckError { .. } =>
                         Err(TransactionValidationError::InsufficientMaxFee.into()),
                     blockifier::transaction::errors::TransactionExecutionError::TransactionPreValidationError(
@@ -311,37 +464,174 @@ impl Starknet {
 
     /// Handles suceeded and reverted transactions.
     /// The tx is stored and potentially dumped.
-    /// A new block is generated.
+    /// A new block is generated, unless the pending block is still below the configured limits.
     pub(crate) fn handle_accepted_transaction(
         &mut self,
         transaction_hash: &TransactionHash,
         transaction: &Transaction,
-        tx_info: TransactionExecutionInfo,
+        mut tx_info: TransactionExecutionInfo,
     ) -> DevnetResult<()> {
-        let transaction_to_add = StarknetTransaction::create_accepted(transaction, tx_info);
+        if let Some(hook) = &self.message_to_l1_hook {
+            apply_message_to_l1_hook(hook, transaction_hash, &mut tx_info);
+        }
+        info!(
+            transaction_hash = %transaction_hash.to_prefixed_hex_str(),
+            transaction_type = ?transaction.get_type(),
+            reverted = tx_info.is_reverted(),
+            "Transaction accepted"
+        );
+        let mut state_difference = self.state.extract_state_diff_from_pending_state()?;
+        if self.is_pending_block_full_for(steps_of(&tx_info)) {
+            // the changes are extracted, so the cache is cleared before the block is sealed
+            self.state.clear_dirty_state();
+            self.create_block(None)?;
+        }
+        let mut transaction_to_add = StarknetTransaction::create_accepted(transaction, tx_info);
+        if let Some((address, account)) = self.counterfactual_deployment.take() {
+            // Devnet sends the deployment on behalf of the account, so it does not use up a nonce
+            // of the account, whose first invoke transaction has nonce 0
+            state_difference.address_to_nonce.remove(&address);
+            transaction_to_add.counterfactual_account = Some(account);
+        }
+        transaction_to_add.state_diff = state_difference.clone().into();
+        let transaction_to_dump = (self.config.dump_on == Some(DumpOn::Transaction))
+            .then(|| DumpRecord::from(&transaction_to_add));
+        let notification = DevnetNotification::TransactionIncluded {
+            transaction_hash: *transaction_hash,
+            transaction_type: transaction.get_type(),
+            execution_status: transaction_to_add.execution_result.status(),
+            block_number: self.blocks.pending_block.block_number(),
+        };
 
         // add accepted transaction to pending block
         self.blocks.pending_block.add_transaction(*transaction_hash);
 
         self.transactions.insert(transaction_hash, transaction_to_add);
+        self.sender_index.insert(*transaction_hash, transaction);
 
-        let state_difference = self.state.extract_state_diff_from_pending_state()?;
         // apply state changes from cached state
         self.state.apply_state_difference(state_difference.clone())?;
         // make cached state part of "persistent" state
         self.state.clear_dirty_state();
-        // create new block from pending one
-        self.generate_new_block(state_difference, None)?;
-        // clear pending block information
-        self.generate_pending_block()?;
+        self.journal.record(
+            self.blocks.pending_block.block_number().0,
+            ChangeCause::Transaction { transaction_hash: *transaction_hash },
+            state_difference.clone(),
+        );
+        self.pending_state_diff.extend(state_difference);
 
-        if self.config.dump_on == Some(DumpOn::Transaction) {
-            self.dump_transaction(transaction)?;
+        if let Some(deferred_effects) = &mut self.deferred_effects {
+            deferred_effects.push(DeferredEffects { notification, transaction_to_dump });
+            return Ok(());
+        }
+        self.notify(notification);
+
+        if self.is_pending_block_full() {
+            self.create_block(None)?;
+        }
+
+        if let Some(record) = transaction_to_dump {
+            self.dump_transaction(&record)?;
         }
 
         Ok(())
     }
 
+    /// Without block size limits, every transaction is mined in a block of its own. Otherwise
+    /// transactions are packed into the pending block until it reaches one of the limits, so the
+    /// following transactions are deferred to the next block. If blocks are generated on demand
+    /// or mining is paused, the pending block is never full. See also
+    /// [`Starknet::is_pending_block_full_for`].
+    fn is_pending_block_full(&self) -> bool {
+        if self.config.block_generation == BlockGeneration::Demand || self.mining_paused {
+            return false;
+        }
+
+        let (max_txs, max_steps) = match (self.config.block_max_txs, self.config.block_max_steps) {
+            (None, None) => return true,
+            limits => limits,
+        };
+
+        let pending_transactions = self.blocks.pending_block.get_transactions();
+        if max_txs.map_or(false, |max_txs| pending_transactions.len() as u64 >= max_txs) {
+            return true;
+        }
+
+        max_steps.map_or(false, |max_steps| self.pending_block_steps() >= max_steps)
+    }
+
+    /// Whether a transaction of `steps` steps would push the pending block past one of the limits,
+    /// so that the pending block has to be sealed before the transaction is added, which then
+    /// opens the next block. A transaction exceeding the limits on its own still gets a block.
+    fn is_pending_block_full_for(&self, steps: u64) -> bool {
+        if self.config.block_generation == BlockGeneration::Demand || self.mining_paused {
+            return false;
+        }
+
+        let pending_transactions = self.blocks.pending_block.get_transactions();
+        if pending_transactions.is_empty() {
+            return false;
+        }
+        if self
+            .config
+            .block_max_txs
+            .map_or(false, |max_txs| pending_transactions.len() as u64 >= max_txs)
+        {
+            return true;
+        }
+
+        self.config
+            .block_max_steps
+            .map_or(false, |max_steps| self.pending_block_steps() + steps > max_steps)
+    }
+
+    fn pending_block_steps(&self) -> u64 {
+        self.blocks
+            .pending_block
+            .get_transactions()
+            .iter()
+            .filter_map(|transaction_hash| self.transactions.get(transaction_hash))
+            .map(|transaction| steps_of(&transaction.execution_info))
+            .sum()
+    }
+
+    /// Deploys the fee token, the UDC, the predeployed accounts and the chargeable account
+    fn predeploy_canonical_contracts(
+        config: &StarknetConfig,
+        state: &mut StarknetState,
+    ) -> DevnetResult<PredeployedAccounts> {
+        // deploy udc and erc20 contracts
+        let erc20_fee_contract = predeployed::create_erc20(config.fee_token_address)?;
+        let udc_contract = predeployed::create_udc(config.udc_address)?;
+
+        erc20_fee_contract.deploy(state)?;
+        initialize_erc20(state, config.fee_token_address)?;
+
+        udc_contract.deploy(state)?;
+
+        let mut predeployed_accounts = PredeployedAccounts::new(
+            config.seed,
+            config.predeployed_accounts_initial_balance,
+            erc20_fee_contract.get_address(),
+        );
+
+        let accounts = predeployed_accounts.generate_accounts(
+            config.total_accounts,
+            config.account_contract_class_hash,
+            config.account_contract_class.clone(),
+        )?;
+        for account in accounts {
+            account.deploy(state)?;
+            account.set_initial_balance(state)?;
+        }
+
+        let chargeable_account = Account::new_chargeable(erc20_fee_contract.get_address())?;
+        chargeable_account.deploy(state)?;
+        chargeable_account.set_initial_balance(state)?;
+
+        Ok(predeployed_accounts)
+    }
+
     fn init_block_context(
         gas_price: u64,
         fee_token_address: &str,
@@ -391,6 +681,14 @@ impl Starknet {
         block_context.block_number = block_context.block_number.next();
     }
 
+    /// Timestamp of a block sealed now, shifted by the time set or advanced by the user
+    fn current_block_timestamp(&self) -> BlockTimestamp {
+        BlockTimestamp(
+            (Starknet::get_unix_timestamp_as_seconds() as i64 + self.pending_block_timestamp_shift)
+                as u64,
+        )
+    }
+
     fn pending_block(&self) -> &StarknetBlock {
         &self.blocks.pending_block
     }
@@ -447,6 +745,99 @@ impl Starknet {
         get_class_impls::get_class_at_impl(self, block_id, contract_address)
     }
 
+    /// Returns the CASM of the Sierra class with `class_hash`, declared up to the pending block
+    pub fn get_compiled_casm(&self, class_hash: ClassHash) -> DevnetResult<CasmContractClass> {
+        get_class_impls::get_compiled_casm_impl(self, class_hash)
+    }
+
+    /// Returns only the ABI entries of the class of the contract at `contract_address`
+    pub fn get_abi_at(
+        &self,
+        block_id: BlockId,
+        contract_address: ContractAddress,
+    ) -> DevnetResult<Vec<serde_json::Value>> {
+        let contract_class = self.get_class_at(block_id, contract_address)?;
+        class_diff::get_abi_items(&class_diff::to_json(contract_class)?)
+    }
+
+    /// Sums up execution resources of all transactions of the block identified by `block_id`
+    pub fn get_block_execution_resources(
+        &self,
+        block_id: BlockId,
+    ) -> DevnetResult<BlockExecutionResources> {
+        block_resources::get_block_execution_resources(self, block_id)
+    }
+
+    /// Exports the `profiled_call` of the transaction and its nested calls in the trace format of
+    /// cairo-profiler
+    pub fn get_profiler_trace(
+        &self,
+        transaction_hash: TransactionHash,
+        profiled_call: ProfiledCall,
+    ) -> DevnetResult<ProfilerCallTrace> {
+        profiler_trace::get_profiler_trace(self, transaction_hash, profiled_call)
+    }
+
+    /// Returns the trace of the stored transaction, with the full tree of calls of each invocation
+    pub fn get_transaction_trace(
+        &self,
+        transaction_hash: TransactionHash,
+    ) -> DevnetResult<TransactionTrace> {
+        transaction_trace::get_transaction_trace(self, transaction_hash)
+    }
+
+    /// Returns the traces of all transactions of the block, in the order of the block
+    pub fn get_block_transaction_traces(
+        &self,
+        block_id: BlockId,
+    ) -> DevnetResult<Vec<BlockTransactionTrace>> {
+        transaction_trace::get_block_transaction_traces(self, block_id)
+    }
+
+    /// Aggregates the class hash, the nonce, the fee token balance and the most recent
+    /// transactions of the account at `address`
+    pub fn get_account_portfolio(
+        &self,
+        address: ContractAddress,
+        max_transactions: usize,
+    ) -> DevnetResult<AccountPortfolio> {
+        account_portfolio::get_account_portfolio(self, address, max_transactions)
+    }
+
+    /// Reads the class hash, the nonce and the fee token balance of the account at `address` in
+    /// the pending state, at once
+    pub fn get_pending_state_snapshot(
+        &self,
+        address: ContractAddress,
+    ) -> DevnetResult<PendingStateSnapshot> {
+        account_portfolio::get_pending_state_snapshot(self, address)
+    }
+
+    /// Walks all blocks, validating their numbering, parent hashes, states and transactions
+    pub fn verify_integrity(&self) -> IntegrityReport {
+        integrity::verify_integrity(self)
+    }
+
+    /// Compares ABIs and entry points of two classes declared in the latest state
+    pub fn compare_classes(
+        &self,
+        class_hash_a: ClassHash,
+        class_hash_b: ClassHash,
+    ) -> DevnetResult<ClassDiff> {
+        class_diff::compare_classes(self, class_hash_a, class_hash_b)
+    }
+
+    /// Replays the given transactions on top of the states they were originally executed in, with
+    /// the class of `contract_address` replaced by `class_hash`
+    pub fn simulate_upgrade(
+        &self,
+        contract_address: ContractAddress,
+        class_hash: ClassHash,
+        transaction_hashes: &[TransactionHash],
+    ) -> DevnetResult<UpgradeSimulation> {
+        upgrade_simulation::simulate_upgrade(self, contract_address, class_hash, transaction_hashes)
+    }
+
     pub fn call(
         &self,
         block_id: BlockId,
@@ -456,7 +847,7 @@ impl Starknet {
     ) -> DevnetResult<Vec<Felt>> {
         let state = self.get_state_at(&block_id)?;
 
-        if !self.state.is_contract_deployed(&ContractAddress::new(contract_address)?) {
+        if !state.is_contract_deployed(&ContractAddress::new(contract_address)?) {
             return Err(Error::ContractNotFound);
         }
 
@@ -489,6 +880,7 @@ impl Starknet {
             .map_err(|err| {
                 Error::BlockifierTransactionError(blockifier::transaction::errors::TransactionExecutionError::EntryPointExecutionError(err))
             })?;
+        step_limits::check_call(self, &res)?;
 
         Ok(res.execution.retdata.0.into_iter().map(Felt::from).collect())
     }
@@ -513,6 +905,8 @@ impl Starknet {
         &mut self,
         declare_transaction: BroadcastedDeclareTransactionV1,
     ) -> DevnetResult<(TransactionHash, ClassHash)> {
+        self.check_max_fee_cap(declare_transaction.common.max_fee, "declare transaction v1")?;
+        self.check_allowed_sender(declare_transaction.sender_address, "declare transaction v1")?;
         add_declare_transaction::add_declare_transaction_v1(self, declare_transaction)
     }
 
@@ -520,6 +914,8 @@ impl Starknet {
         &mut self,
         declare_transaction: BroadcastedDeclareTransactionV2,
     ) -> DevnetResult<(TransactionHash, ClassHash)> {
+        self.check_max_fee_cap(declare_transaction.common.max_fee, "declare transaction v2")?;
+        self.check_allowed_sender(declare_transaction.sender_address, "declare transaction v2")?;
         add_declare_transaction::add_declare_transaction_v2(self, declare_transaction)
     }
 
@@ -532,6 +928,14 @@ impl Starknet {
         &mut self,
         deploy_account_transaction: BroadcastedDeployAccountTransaction,
     ) -> DevnetResult<(TransactionHash, ContractAddress)> {
+        self.check_max_fee_cap(
+            deploy_account_transaction.common.max_fee,
+            "deploy account transaction",
+        )?;
+        self.check_allowed_sender(
+            deploy_account_transaction.contract_address()?,
+            "deploy account transaction",
+        )?;
         add_deploy_account_transaction::add_deploy_account_transaction(
             self,
             deploy_account_transaction,
@@ -542,11 +946,53 @@ impl Starknet {
         &mut self,
         invoke_transaction: BroadcastedInvokeTransaction,
     ) -> DevnetResult<TransactionHash> {
+        self.check_max_fee_cap(invoke_transaction.common.max_fee, "invoke transaction")?;
+        self.check_allowed_sender(invoke_transaction.sender_address, "invoke transaction")?;
         add_invoke_transaction::add_invoke_transaction(self, invoke_transaction)
     }
 
+    /// Adds `transactions` together, in order, to the next sealed block, or none of them if any
+    /// of them would fail or revert
+    pub fn add_transaction_batch(
+        &mut self,
+        transactions: Vec<BroadcastedTransaction>,
+    ) -> DevnetResult<Vec<TransactionHash>> {
+        transaction_batch::add_transaction_batch(self, transactions)
+    }
+
+    /// Rejects a transaction whose max fee exceeds `config.max_fee_cap`, as such a fee is most
+    /// likely the result of a misconfigured client which would drain the balance of the sender
+    fn check_max_fee_cap(&self, max_fee: Fee, tx_type: &str) -> DevnetResult<()> {
+        match self.config.max_fee_cap {
+            Some(max_fee_cap) if max_fee.0 > max_fee_cap => Err(Error::MaxFeeAboveCap {
+                tx_type: tx_type.into(),
+                max_fee: max_fee.0,
+                max_fee_cap,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Rejects a transaction whose sender, i.e. the deployed account of a deploy account
+    /// transaction, is not in `config.allowed_senders`, unless the list is empty
+    fn check_allowed_sender(&self, sender: ContractAddress, tx_type: &str) -> DevnetResult<()> {
+        if self.config.allowed_senders.is_empty() || self.config.allowed_senders.contains(&sender) {
+            return Ok(());
+        }
+
+        Err(Error::SenderNotAllowed { tx_type: tx_type.into(), sender })
+    }
+
     /// Creates an invoke tx for minting, using the chargeable account.
     pub async fn mint(&mut self, address: ContractAddress, amount: u128) -> DevnetResult<Felt> {
+        if self.config.custom_genesis.is_some() {
+            return Err(Error::UnsupportedAction {
+                msg: "Minting is not supported with a custom genesis, as the chargeable account \
+                      is not predeployed"
+                    .to_string(),
+            });
+        }
+
         let sufficiently_big_max_fee: u128 = self.config.gas_price as u128 * 1_000_000;
         let chargeable_address_felt = Felt::from_prefixed_hex_str(CHARGEABLE_ACCOUNT_ADDRESS)?;
         let nonce =
@@ -560,7 +1006,7 @@ impl Starknet {
             FieldElement::from(0u32),   // `high` part
         ];
 
-        let erc20_address_felt = Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS)?;
+        let erc20_address_felt = Felt::from(self.config.fee_token_address);
         let raw_execution = RawExecution {
             calls: vec![Call {
                 to: erc20_address_felt.into(),
@@ -595,14 +1041,105 @@ impl Starknet {
             },
         };
 
-        // apply the invoke tx
-        self.add_invoke_transaction(invoke_tx)
+        // apply the invoke tx; it is paid by the chargeable account, so its max fee is not capped
+        let transaction_hash = add_invoke_transaction::add_invoke_transaction(self, invoke_tx)?;
+        self.journal.set_cause_of_transaction(
+            transaction_hash,
+            ChangeCause::Mint { transaction_hash, address, amount },
+        );
+
+        Ok(transaction_hash)
+    }
+
+    /// Sets the allowance of `spender` over the `token` balance of `owner`, without requiring a
+    /// signature of `owner`
+    pub fn set_allowance(
+        &mut self,
+        token: ContractAddress,
+        owner: ContractAddress,
+        spender: ContractAddress,
+        amount: u128,
+    ) -> DevnetResult<()> {
+        cheatcodes::set_allowance(self, token, owner, spender, amount)
+    }
+
+    /// Transfers `amount` of `token` from `from` to `to`, without requiring a signature of `from`
+    pub fn transfer_token(
+        &mut self,
+        token: ContractAddress,
+        from: ContractAddress,
+        to: ContractAddress,
+        amount: u128,
+    ) -> DevnetResult<()> {
+        cheatcodes::transfer_token(self, token, from, to, amount)
+    }
+
+    /// Replaces the public key of the account at `address`, without invoking the account
+    pub fn set_account_public_key(
+        &mut self,
+        address: ContractAddress,
+        public_key: Felt,
+    ) -> DevnetResult<()> {
+        cheatcodes::set_account_public_key(self, address, public_key)
+    }
+
+    /// Writes the changes of `state_diff`, e.g. exported from another instance, without
+    /// executing any transaction
+    pub fn apply_state_diff(&mut self, state_diff: ThinStateDiff) -> DevnetResult<()> {
+        cheatcodes::apply_state_diff(self, state_diff)
+    }
+
+    /// Sets the validation logic applied to transactions of the predeployed account at `address`
+    pub fn set_account_template(
+        &mut self,
+        address: ContractAddress,
+        template: AccountTemplate,
+    ) -> DevnetResult<()> {
+        account_templates::set_account_template(self, address, template)
+    }
+
+    pub fn get_account_template(&self, address: &ContractAddress) -> AccountTemplate {
+        account_templates::get_account_template(self, address)
+    }
+
+    /// Registers an account to be deployed on the first invoke transaction sent from its
+    /// address, which is returned
+    pub fn register_counterfactual_account(
+        &mut self,
+        account: CounterfactualAccount,
+    ) -> DevnetResult<ContractAddress> {
+        counterfactual_accounts::register_counterfactual_account(self, account)
+    }
+
+    /// Limits the number of steps of every call into the contract at `address`, including its
+    /// nested calls. The limit is removed if `steps` is `None`.
+    pub fn set_contract_step_limit(
+        &mut self,
+        address: ContractAddress,
+        steps: Option<u64>,
+    ) -> DevnetResult<()> {
+        step_limits::set_contract_step_limit(self, address, steps)
+    }
+
+    /// Reports whether the account of `transaction` would accept it at `block_id`, without
+    /// adding it
+    pub fn validate_transaction(
+        &self,
+        block_id: BlockId,
+        transaction: &BroadcastedTransaction,
+    ) -> DevnetResult<TransactionValidation> {
+        transaction_validation::validate_transaction(self, block_id, transaction)
     }
 
     pub fn block_state_update(&self, block_id: BlockId) -> DevnetResult<StateUpdate> {
         state_update::state_update_by_block_id(self, block_id)
     }
 
+    /// Returns state updates of blocks with numbers greater than or equal to `from`
+    pub fn state_updates_from_block(&self, from: u64) -> Vec<(BlockNumber, StateUpdate)> {
+        state_update::state_updates_from_block(self, BlockNumber(from))
+    }
+
     pub fn get_block_txs_count(&self, block_id: BlockId) -> DevnetResult<u64> {
         let block = self.blocks.get_by_block_id(block_id).ok_or(Error::NoBlock)?;
 
@@ -615,7 +1152,15 @@ impl Starknet {
         contract_address: ContractAddress,
     ) -> DevnetResult<Felt> {
         let state = self.get_state_at(&block_id)?;
-        state.get_nonce(&contract_address)
+        match state.get_nonce(&contract_address) {
+            // the deployment of a counterfactual account does not use up a nonce of its own
+            Err(Error::ContractNotFound)
+                if self.counterfactual_accounts.is_registered(&contract_address) =>
+            {
+                Ok(Felt::from(0))
+            }
+            result => result,
+        }
     }
 
     pub fn contract_storage_at_block(
@@ -628,14 +1173,123 @@ impl Starknet {
         state.get_storage(ContractStorageKey::new(contract_address, storage_key))
     }
 
+    /// Reads a storage slot as of `timestamp`, in the state of the last block with a timestamp
+    /// not later than it. Returns the block and the value of the slot.
+    pub fn contract_storage_at_time(
+        &self,
+        contract_address: ContractAddress,
+        storage_key: PatriciaKey,
+        timestamp: u64,
+    ) -> DevnetResult<(StarknetBlock, Felt)> {
+        let block =
+            self.blocks.get_by_timestamp(BlockTimestamp(timestamp)).ok_or(Error::NoBlock)?;
+        let value = self.contract_storage_at_block(
+            BlockId::Number(block.block_number().0),
+            contract_address,
+            storage_key,
+        )?;
+
+        Ok((block.clone(), value))
+    }
+
+    /// Reads `length` consecutive storage slots of the storage variable `variable_name`, starting
+    /// with its address as computed by Cairo: `sn_keccak` of the name, hashed with each of `keys`
+    /// (the keys of a mapping) using Pedersen. Returns the address and the values of the slots.
+    pub fn contract_storage_by_name_at_block(
+        &self,
+        block_id: BlockId,
+        contract_address: ContractAddress,
+        variable_name: &str,
+        keys: &[Felt],
+        length: usize,
+    ) -> DevnetResult<(PatriciaKey, Vec<Felt>)> {
+        let storage_address = crate::utils::get_storage_var_address(variable_name, keys)?;
+        let base_address = FieldElement::from(storage_address.to_felt());
+
+        let values = (0..length)
+            .map(|offset| {
+                let slot_address = base_address + FieldElement::from(offset as u64);
+                let storage_key = PatriciaKey::new(slot_address.into())?;
+                self.contract_storage_at_block(block_id, contract_address, storage_key)
+            })
+            .collect::<DevnetResult<Vec<Felt>>>()?;
+
+        Ok((storage_address, values))
+    }
+
     pub fn get_block(&self, block_id: BlockId) -> DevnetResult<StarknetBlock> {
         let block = self.blocks.get_by_block_id(block_id).ok_or(Error::NoBlock)?;
         Ok(block.clone())
     }
 
+    /// Returns the pending block as it would be sealed now: with the transactions added since the
+    /// latest block, which is its parent, and the current timestamp. Its hash is only computed once
+    /// it is sealed, so it is zero.
+    pub fn get_pending_block(&self) -> StarknetBlock {
+        let mut block = self.pending_block().clone();
+        if let Some(last_block_hash) = self.blocks.last_block_hash {
+            block.header.parent_hash = last_block_hash.into();
+        }
+        block.set_timestamp(self.current_block_timestamp());
+
+        block
+    }
+
+    /// Returns the stage of the simulated proof of the block, which depends on the time passed
+    /// since the block was sealed
+    pub fn get_proof_status(&self, block_id: BlockId) -> DevnetResult<ProofStatus> {
+        proof_jobs::get_proof_status(self, block_id)
+    }
+
+    /// Returns the block with its transactions; for the pending tag, the
+    /// [pending block](Self::get_pending_block) with the transactions not yet mined
     pub fn get_block_with_transactions(&self, block_id: BlockId) -> DevnetResult<Block> {
-        let block = self.blocks.get_by_block_id(block_id).ok_or(Error::NoBlock)?;
+        let block = match block_id {
+            BlockId::Tag(BlockTag::Pending) => self.get_pending_block(),
+            _ => self.get_block(block_id)?,
+        };
+
+        Ok(Block {
+            status: *block.status(),
+            header: BlockHeader::from(&block),
+            transactions: Transactions::Full(self.get_transactions_of_block(&block)?),
+        })
+    }
+
+    pub fn get_block_with_receipts(&self, block_id: BlockId) -> DevnetResult<BlockWithReceipts> {
+        let block = match block_id {
+            BlockId::Tag(BlockTag::Pending) => self.get_pending_block(),
+            _ => self.get_block(block_id)?,
+        };
+
         let transactions = block
+            .get_transactions()
+            .iter()
+            .map(|transaction_hash| {
+                let transaction =
+                    self.transactions.get_by_hash(*transaction_hash).ok_or(Error::NoTransaction)?;
+
+                Ok(TransactionWithReceipt {
+                    transaction: transaction.inner.clone(),
+                    receipt: transaction.get_receipt(self.config.udc_address)?,
+                })
+            })
+            .collect::<DevnetResult<Vec<_>>>()?;
+
+        Ok(BlockWithReceipts {
+            status: *block.status(),
+            header: BlockHeader::from(&block),
+            transactions,
+        })
+    }
+
+    /// Returns the transactions of the pending block, in the order in which they were added
+    pub fn get_pending_transactions(&self) -> DevnetResult<Vec<Transaction>> {
+        self.get_transactions_of_block(self.pending_block())
+    }
+
+    fn get_transactions_of_block(&self, block: &StarknetBlock) -> DevnetResult<Vec<Transaction>> {
+        block
             .get_transactions()
             .iter()
             .map(|transaction_hash| {
@@ -644,13 +1298,7 @@ impl Starknet {
                     .ok_or(Error::NoTransaction)
                     .map(|transaction| transaction.inner.clone())
             })
-            .collect::<DevnetResult<Vec<Transaction>>>()?;
-
-        Ok(Block {
-            status: *block.status(),
-            header: BlockHeader::from(block),
-            transactions: Transactions::Full(transactions),
-        })
+            .collect()
     }
 
     pub fn get_transaction_by_block_id_and_index(
@@ -658,7 +1306,10 @@ impl Starknet {
         block_id: BlockId,
         index: u64,
     ) -> DevnetResult<&Transaction> {
-        let block = self.get_block(block_id)?;
+        let block = match block_id {
+            BlockId::Tag(BlockTag::Pending) => self.get_pending_block(),
+            _ => self.get_block(block_id)?,
+        };
         let transaction_hash = block
             .get_transactions()
             .get(index as usize)
@@ -695,6 +1346,43 @@ impl Starknet {
         events::get_events(self, from_block, to_block, address, keys, skip, limit)
     }
 
+    /// Counts the events of the blocks in the range per emitting contract and per first key
+    pub fn get_event_stats(
+        &self,
+        from_block: Option<BlockId>,
+        to_block: Option<BlockId>,
+    ) -> DevnetResult<EventStats> {
+        event_stats::get_event_stats(self, from_block, to_block)
+    }
+
+    /// Returns the state changes of blocks from `from_block` to `to_block`, each with its cause
+    pub fn get_journal(
+        &self,
+        from_block: Option<BlockId>,
+        to_block: Option<BlockId>,
+    ) -> DevnetResult<Vec<JournalEntry>> {
+        journal::get_journal(self, from_block, to_block)
+    }
+
+    /// Returns a page of the transactions sent by `sender_address` and whether there are more
+    pub fn get_transactions_by_sender(
+        &self,
+        sender_address: ContractAddress,
+        from_block: Option<BlockId>,
+        to_block: Option<BlockId>,
+        skip: usize,
+        limit: usize,
+    ) -> DevnetResult<(Vec<Transaction>, bool)> {
+        transactions_by_sender::get_transactions_by_sender(
+            self,
+            sender_address,
+            from_block,
+            to_block,
+            skip,
+            limit,
+        )
+    }
+
     pub fn get_transaction_receipt_by_hash(
         &self,
         transaction_hash: TransactionHash,
@@ -702,7 +1390,7 @@ impl Starknet {
         let transaction_to_map =
             self.transactions.get(&transaction_hash).ok_or(Error::NoTransaction)?;
 
-        transaction_to_map.get_receipt()
+        transaction_to_map.get_receipt(self.config.udc_address)
     }
 
     pub fn get_transaction_execution_and_finality_status(
@@ -714,6 +1402,13 @@ impl Starknet {
         Ok((transaction.execution_result.status(), transaction.finality_status))
     }
 
+    /// Returns whether the transaction is in the pending block, i.e. not yet in a mined block
+    pub fn is_transaction_pending(&self, transaction_hash: TransactionHash) -> DevnetResult<bool> {
+        let transaction = self.transactions.get(&transaction_hash).ok_or(Error::NoTransaction)?;
+
+        Ok(transaction.block_number.is_none())
+    }
+
     pub fn simulate_transactions(
         &self,
         block_id: BlockId,
@@ -727,10 +1422,7 @@ impl Starknet {
         let mut skip_fee_charge = false;
         for flag in simulation_flags.iter() {
             match flag {
-                SimulationFlag::SkipValidate => {
-                    skip_validate = true;
-                    warn!("SKIP_VALIDATE chosen in simulation, but does not affect fee estimation");
-                }
+                SimulationFlag::SkipValidate => skip_validate = true,
                 SimulationFlag::SkipFeeCharge => skip_fee_charge = true,
             }
         }
@@ -738,103 +1430,38 @@ impl Starknet {
         let mut transactions_traces: Vec<TransactionTrace> = vec![];
 
         for broadcasted_transaction in transactions.iter() {
+            counterfactual_accounts::deploy_on_copy(
+                self,
+                &mut state,
+                broadcasted_transaction,
+                !skip_fee_charge,
+            )?;
+            let validate = !skip_validate
+                && account_templates::check_broadcasted_validation(self, broadcasted_transaction)?;
             let blockifier_transaction =
                 broadcasted_transaction.to_blockifier_account_transaction(chain_id, true)?;
             let tx_execution_info = blockifier_transaction.execute(
                 &mut state.state,
                 &self.block_context,
                 !skip_fee_charge,
-                !skip_validate,
+                validate,
             )?;
 
             let state_diff: ThinStateDiff = state.extract_state_diff_from_pending_state()?.into();
             let state_diff =
                 if state_diff == ThinStateDiff::default() { None } else { Some(state_diff) };
 
-            let address_to_class_hash_map = &state.state.state.address_to_class_hash;
-
-            let validate_invocation =
-                if let Some(validate_info) = tx_execution_info.validate_call_info {
-                    Some(FunctionInvocation::try_from_call_info(
-                        validate_info,
-                        address_to_class_hash_map,
-                    )?)
-                } else {
-                    None
-                };
-
-            let fee_transfer_invocation =
-                if let Some(fee_transfer_info) = tx_execution_info.fee_transfer_call_info {
-                    Some(FunctionInvocation::try_from_call_info(
-                        fee_transfer_info,
-                        address_to_class_hash_map,
-                    )?)
-                } else {
-                    None
-                };
-
-            let trace = match broadcasted_transaction {
-                BroadcastedTransaction::Declare(_) => {
-                    TransactionTrace::Declare(DeclareTransactionTrace {
-                        validate_invocation,
-                        fee_transfer_invocation,
-                        state_diff,
-                    })
-                }
-                BroadcastedTransaction::DeployAccount(_) => {
-                    TransactionTrace::DeployAccount(DeployAccountTransactionTrace {
-                        validate_invocation,
-                        constructor_invocation: if let Some(call_info) =
-                            tx_execution_info.execute_call_info
-                        {
-                            Some(FunctionInvocation::try_from_call_info(
-                                call_info,
-                                address_to_class_hash_map,
-                            )?)
-                        } else {
-                            None
-                        },
-                        fee_transfer_invocation,
-                        state_diff,
-                    })
-                }
-                BroadcastedTransaction::Invoke(_) => {
-                    TransactionTrace::Invoke(InvokeTransactionTrace {
-                        fee_transfer_invocation,
-                        validate_invocation,
-                        state_diff,
-                        execute_invocation: match tx_execution_info.execute_call_info {
-                            Some(call_info) => match call_info.execution.failed {
-                                false => ExecutionInvocation::Succeeded(
-                                    FunctionInvocation::try_from_call_info(
-                                        call_info,
-                                        address_to_class_hash_map,
-                                    )?,
-                                ),
-                                true => ExecutionInvocation::Reverted(
-                                    starknet_types::rpc::transactions::Reversion {
-                                        revert_reason: tx_execution_info
-                                            .revert_error
-                                            .unwrap_or("Revert reason not found".into()),
-                                    },
-                                ),
-                            },
-                            None => match tx_execution_info.revert_error {
-                                Some(revert_reason) => ExecutionInvocation::Reverted(
-                                    starknet_types::rpc::transactions::Reversion { revert_reason },
-                                ),
-                                None => {
-                                    return Err(Error::UnexpectedInternalError {
-                                        msg: "Simulation contains neither call_info nor \
-                                              revert_error"
-                                            .into(),
-                                    });
-                                }
-                            },
-                        },
-                    })
-                }
+            let trace_type = match broadcasted_transaction {
+                BroadcastedTransaction::Declare(_) => TraceType::Declare,
+                BroadcastedTransaction::DeployAccount(_) => TraceType::DeployAccount,
+                BroadcastedTransaction::Invoke(_) => TraceType::Invoke,
             };
+            let trace = create_trace(
+                trace_type,
+                &tx_execution_info,
+                &state.state.state.address_to_class_hash,
+                state_diff,
+            )?;
 
             transactions_traces.push(trace);
         }
@@ -872,14 +1499,39 @@ impl Starknet {
     }
 
     pub fn create_block(&mut self, timestamp: Option<u64>) -> DevnetResult<(), Error> {
-        // create new block from pending one
-        self.generate_new_block(StateDiff::default(), timestamp)?;
+        // create new block from pending one, with the changes of its transactions (if any)
+        let state_diff = std::mem::take(&mut self.pending_state_diff);
+        self.generate_new_block(state_diff, timestamp)?;
         // clear pending block information
         self.generate_pending_block()?;
 
         Ok(())
     }
 
+    /// Keeps the following transactions in the pending block, as if blocks were generated on
+    /// demand, until [`Starknet::resume_mining`]. Blocks can still be created meanwhile.
+    pub fn pause_mining(&mut self) {
+        self.mining_paused = true;
+    }
+
+    /// Seals the transactions added while mining was paused into one block, unless blocks are
+    /// generated on demand, and mines the following transactions as configured again
+    pub fn resume_mining(&mut self) -> DevnetResult<()> {
+        let was_paused = std::mem::replace(&mut self.mining_paused, false);
+        if was_paused
+            && self.config.block_generation != BlockGeneration::Demand
+            && !self.blocks.pending_block.get_transactions().is_empty()
+        {
+            self.create_block(None)?;
+        }
+
+        Ok(())
+    }
+
+    pub fn is_mining_paused(&self) -> bool {
+        self.mining_paused
+    }
+
     // Create empty block
     pub fn set_time(&mut self, timestamp: u64) -> DevnetResult<(), Error> {
         self.set_block_timestamp_shift(
@@ -907,26 +1559,62 @@ impl Starknet {
     }
 }
 
+/// A nonce ahead of the account nonce is reported in more detail, because Devnet executes
+/// transactions on submission: there is no mempool in which the transaction could wait for the
+/// transactions with the skipped nonces
+fn invalid_nonce_error(
+    transaction: &Transaction,
+    account_nonce: Felt,
+    transaction_nonce: Felt,
+) -> Error {
+    let (account_nonce_value, transaction_nonce_value) =
+        (BigUint::from(account_nonce), BigUint::from(transaction_nonce));
+    if transaction_nonce_value <= account_nonce_value {
+        return TransactionValidationError::InvalidTransactionNonce.into();
+    }
+
+    let sender_address =
+        transaction.get_sender_address().map(|address| address.to_prefixed_hex_str());
+    warn!(
+        transaction_hash = %transaction.get_transaction_hash().to_prefixed_hex_str(),
+        sender_address = ?sender_address,
+        account_nonce = %account_nonce.to_prefixed_hex_str(),
+        transaction_nonce = %transaction_nonce.to_prefixed_hex_str(),
+        skipped_nonces = %(transaction_nonce_value - account_nonce_value),
+        "Transaction rejected, its nonce is ahead of the account nonce; transactions are not queued"
+    );
+    TransactionValidationError::NonceAheadOfAccount { account_nonce, transaction_nonce }.into()
+}
+
+/// Number of steps of a transaction, which the block limits count
+fn steps_of(execution_info: &TransactionExecutionInfo) -> u64 {
+    execution_info.actual_resources.0.get(N_STEPS).copied().unwrap_or(0) as u64
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Mutex};
     use std::thread;
     use std::time::Duration;
 
     use blockifier::state::state_api::State;
     use blockifier::transaction::errors::TransactionExecutionError;
     use starknet_api::block::{BlockHash, BlockNumber, BlockStatus, BlockTimestamp, GasPrice};
-    use starknet_rs_core::types::{BlockId, BlockTag};
+    use starknet_rs_core::types::{BlockId, BlockTag, TransactionExecutionStatus};
     use starknet_types::contract_address::ContractAddress;
     use starknet_types::felt::Felt;
+    use starknet_types::rpc::transactions::{DeclareTransaction, Transaction, TransactionType};
 
+    use super::notifications::DevnetNotification;
     use super::Starknet;
     use crate::blocks::StarknetBlock;
     use crate::constants::{
         DEVNET_DEFAULT_CHAIN_ID, DEVNET_DEFAULT_INITIAL_BALANCE, ERC20_CONTRACT_ADDRESS,
     };
     use crate::error::{DevnetResult, Error};
-    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::starknet_config::{BlockGeneration, StarknetConfig};
     use crate::state::state_diff::StateDiff;
+    use crate::state::StarknetState;
     use crate::traits::{Accounted, StateChanger, StateExtractor};
     use crate::utils::test_utils::{
         dummy_contract_address, dummy_declare_transaction_v1, dummy_felt,
@@ -969,6 +1657,83 @@ mod tests {
         assert_eq!(starknet.pending_block().header.block_number, initial_block_number.next());
     }
 
+    #[test]
+    fn pending_block_lists_pending_transactions_with_latest_block_as_parent() {
+        let config = StarknetConfig::default();
+        let mut starknet = Starknet::new(&config).unwrap();
+        starknet.generate_new_block(StateDiff::default(), None).unwrap();
+        starknet.generate_pending_block().unwrap();
+
+        let tx = dummy_declare_transaction_v1();
+        starknet.blocks.pending_block.add_transaction(tx.transaction_hash);
+
+        let latest_block = starknet.get_latest_block().unwrap();
+        let pending_block = starknet.get_pending_block();
+        assert_eq!(pending_block.status, BlockStatus::Pending);
+        assert_eq!(pending_block.block_number(), latest_block.block_number().next());
+        assert_eq!(pending_block.parent_hash(), latest_block.block_hash());
+        assert_eq!(pending_block.get_transactions(), &vec![tx.transaction_hash]);
+        assert!(pending_block.header.timestamp >= latest_block.header.timestamp);
+    }
+
+    #[test]
+    fn transactions_added_while_mining_is_paused_are_sealed_together_on_resume() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        starknet.pause_mining();
+
+        let transaction_hashes = vec![Felt::from(100), Felt::from(101)];
+        for transaction_hash in &transaction_hashes {
+            let mut declare_transaction = dummy_declare_transaction_v1();
+            declare_transaction.transaction_hash = *transaction_hash;
+            let transaction =
+                Transaction::Declare(DeclareTransaction::Version1(declare_transaction));
+            starknet
+                .handle_accepted_transaction(transaction_hash, &transaction, Default::default())
+                .unwrap();
+        }
+        assert_eq!(starknet.get_latest_block().unwrap().block_number(), BlockNumber(0));
+
+        starknet.resume_mining().unwrap();
+        let latest_block = starknet.get_latest_block().unwrap();
+        assert_eq!(latest_block.block_number(), BlockNumber(1));
+        assert_eq!(latest_block.get_transactions(), &transaction_hashes);
+
+        // nothing to seal
+        starknet.resume_mining().unwrap();
+        assert_eq!(starknet.get_latest_block().unwrap().block_number(), BlockNumber(1));
+    }
+
+    #[test]
+    fn transactions_are_kept_pending_until_block_is_created_on_demand() {
+        let config =
+            StarknetConfig { block_generation: BlockGeneration::Demand, ..Default::default() };
+        let mut starknet = Starknet::new(&config).unwrap();
+
+        let transaction_hashes = vec![Felt::from(100), Felt::from(101)];
+        for transaction_hash in &transaction_hashes {
+            let mut declare_transaction = dummy_declare_transaction_v1();
+            declare_transaction.transaction_hash = *transaction_hash;
+            let transaction =
+                Transaction::Declare(DeclareTransaction::Version1(declare_transaction));
+            starknet
+                .handle_accepted_transaction(transaction_hash, &transaction, Default::default())
+                .unwrap();
+        }
+
+        let pending_transactions = starknet.get_pending_transactions().unwrap();
+        assert_eq!(
+            pending_transactions.iter().map(|tx| *tx.get_transaction_hash()).collect::<Vec<_>>(),
+            transaction_hashes
+        );
+        assert_eq!(starknet.get_latest_block().unwrap().block_number(), BlockNumber(0));
+
+        starknet.create_block(None).unwrap();
+        let latest_block = starknet.get_latest_block().unwrap();
+        assert_eq!(latest_block.block_number(), BlockNumber(1));
+        assert_eq!(latest_block.get_transactions(), &transaction_hashes);
+        assert!(starknet.get_pending_transactions().unwrap().is_empty());
+    }
+
     #[test]
     fn correct_new_block_creation() {
         let config = StarknetConfig::default();
@@ -981,15 +1746,14 @@ mod tests {
 
         // pending block has some transactions
         assert!(!starknet.pending_block().get_transactions().is_empty());
-        // blocks collection is empty
-        assert!(starknet.blocks.num_to_block.is_empty());
+        // blocks collection only contains the genesis block
+        assert_eq!(starknet.blocks.num_to_block.len(), 1);
 
         starknet.generate_new_block(StateDiff::default(), None).unwrap();
-        // blocks collection should not be empty
-        assert!(!starknet.blocks.num_to_block.is_empty());
+        assert_eq!(starknet.blocks.num_to_block.len(), 2);
 
         // get block by number and check that the transactions in the block are correct
-        let added_block = starknet.blocks.num_to_block.get(&BlockNumber(0)).unwrap();
+        let added_block = starknet.blocks.num_to_block.get(&BlockNumber(1)).unwrap();
 
         assert!(added_block.get_transactions().len() == 1);
         assert_eq!(*added_block.get_transactions().first().unwrap(), tx.transaction_hash);
@@ -1002,7 +1766,6 @@ mod tests {
 
         let initial_block_number = starknet.block_context.block_number;
         let initial_gas_price = starknet.block_context.gas_prices.eth_l1_gas_price;
-        let initial_block_timestamp = starknet.block_context.block_timestamp;
         let initial_sequencer = starknet.block_context.sequencer_address;
 
         // create pending block with some information in it
@@ -1020,7 +1783,10 @@ mod tests {
         assert!(*starknet.pending_block() != pending_block);
         assert_eq!(starknet.pending_block().status, BlockStatus::Pending);
         assert!(starknet.pending_block().get_transactions().is_empty());
-        assert_eq!(starknet.pending_block().header.timestamp, initial_block_timestamp);
+        assert_eq!(
+            starknet.pending_block().header.timestamp,
+            StarknetBlock::create_pending_block().header.timestamp
+        );
         assert_eq!(starknet.pending_block().header.block_number, initial_block_number);
         assert_eq!(starknet.pending_block().header.parent_hash, BlockHash::default());
         assert_eq!(starknet.pending_block().header.gas_price, GasPrice(initial_gas_price));
@@ -1096,6 +1862,39 @@ mod tests {
         }
     }
 
+    #[test]
+    fn calling_contract_at_block_preceding_its_deployment() {
+        let config = StarknetConfig::default();
+        let mut starknet = Starknet::new(&config).unwrap();
+        starknet.generate_new_block(StateDiff::default(), None).unwrap();
+        // as if the token was deployed after block 0
+        starknet.blocks.num_to_state.insert(BlockNumber(0), StarknetState::default());
+
+        let token_address = Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap();
+        let entry_point_selector =
+            starknet_rs_core::utils::get_selector_from_name("balanceOf").unwrap();
+        let calldata =
+            vec![Felt::from(starknet.predeployed_accounts.get_accounts()[0].account_address)];
+        match starknet.call(
+            BlockId::Number(0),
+            token_address,
+            entry_point_selector.into(),
+            calldata.clone(),
+        ) {
+            Err(Error::ContractNotFound) => (),
+            unexpected => panic!("Should have failed; got {unexpected:?}"),
+        }
+
+        starknet
+            .call(
+                BlockId::Tag(BlockTag::Latest),
+                token_address,
+                entry_point_selector.into(),
+                calldata,
+            )
+            .unwrap();
+    }
+
     #[test]
     fn calling_nonexistent_contract_method() {
         let config = StarknetConfig::default();
@@ -1170,13 +1969,14 @@ mod tests {
         let config = StarknetConfig::default();
         let mut starknet = Starknet::new(&config).unwrap();
 
-        starknet.get_latest_block().err().unwrap();
+        // the genesis block is the latest block of a fresh instance
+        assert_eq!(starknet.get_latest_block().unwrap().block_number(), BlockNumber(0));
 
         starknet.generate_new_block(StateDiff::default(), None).unwrap();
         starknet.generate_pending_block().unwrap();
 
-        // last added block number -> 0
-        let added_block = starknet.blocks.num_to_block.get(&BlockNumber(0)).unwrap();
+        // last added block number -> 1
+        let added_block = starknet.blocks.num_to_block.get(&BlockNumber(1)).unwrap();
         // number of the accepted block -> 1
         let block_number = starknet.get_latest_block().unwrap().block_number();
 
@@ -1185,7 +1985,7 @@ mod tests {
         starknet.generate_new_block(StateDiff::default(), None).unwrap();
         starknet.generate_pending_block().unwrap();
 
-        let added_block2 = starknet.blocks.num_to_block.get(&BlockNumber(1)).unwrap();
+        let added_block2 = starknet.blocks.num_to_block.get(&BlockNumber(2)).unwrap();
         let block_number2 = starknet.get_latest_block().unwrap().block_number();
 
         assert_eq!(block_number2.0, added_block2.header.block_number.0);
@@ -1199,7 +1999,7 @@ mod tests {
         starknet.generate_new_block(StateDiff::default(), None).unwrap();
         starknet.generate_pending_block().unwrap();
 
-        let num_no_transactions = starknet.get_block_txs_count(BlockId::Number(0));
+        let num_no_transactions = starknet.get_block_txs_count(BlockId::Number(1));
 
         assert_eq!(num_no_transactions.unwrap(), 0);
 
@@ -1210,7 +2010,7 @@ mod tests {
 
         starknet.generate_new_block(StateDiff::default(), None).unwrap();
 
-        let num_one_transaction = starknet.get_block_txs_count(BlockId::Number(1));
+        let num_one_transaction = starknet.get_block_txs_count(BlockId::Number(2));
 
         assert_eq!(num_one_transaction.unwrap(), 1);
     }
@@ -1294,7 +2094,8 @@ mod tests {
 
         let latest_block = starknet.get_latest_block();
 
-        assert_eq!(latest_block.unwrap().block_number(), BlockNumber(2));
+        // the three generated blocks follow the genesis block
+        assert_eq!(latest_block.unwrap().block_number(), BlockNumber(3));
     }
     #[test]
     fn check_timestamp_of_newly_generated_block() {
@@ -1319,4 +2120,98 @@ mod tests {
         // ----(pending block timestamp)----(sleep)----(new block timestamp)
         assert!(pending_block_timestamp.0 + sleep_duration_secs <= block_timestamp.0);
     }
+
+    #[test]
+    fn storage_variable_is_read_by_name() {
+        let config = StarknetConfig::default();
+        let starknet = Starknet::new(&config).unwrap();
+        let fee_token_address =
+            ContractAddress::new(Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap())
+                .unwrap();
+        let account_address = starknet.predeployed_accounts.get_accounts()[0].account_address;
+
+        // the balance is a Uint256, stored as the low and the high part in consecutive slots
+        let (storage_address, values) = starknet
+            .contract_storage_by_name_at_block(
+                BlockId::Tag(BlockTag::Latest),
+                fee_token_address,
+                "ERC20_balances",
+                &[account_address.into()],
+                2,
+            )
+            .unwrap();
+
+        assert_eq!(
+            storage_address,
+            crate::utils::get_storage_var_address("ERC20_balances", &[account_address.into()])
+                .unwrap()
+        );
+        assert_eq!(values, vec![config.predeployed_accounts_initial_balance, Felt::from(0)]);
+    }
+
+    #[test]
+    fn storage_of_undeployed_contract_is_not_read_by_name() {
+        let starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+
+        let err = starknet
+            .contract_storage_by_name_at_block(
+                BlockId::Tag(BlockTag::Latest),
+                dummy_contract_address(),
+                "balance",
+                &[],
+                1,
+            )
+            .unwrap_err();
+        assert!(matches!(err, Error::ContractNotFound));
+    }
+
+    #[test]
+    fn listener_is_notified_of_included_transaction_and_sealed_block() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let notifications = Arc::new(Mutex::new(vec![]));
+        let received = notifications.clone();
+        starknet.set_notification_listener(Arc::new(move |notification| {
+            received.lock().unwrap().push(notification)
+        }));
+
+        let declare_transaction = dummy_declare_transaction_v1();
+        let transaction_hash = declare_transaction.transaction_hash;
+        starknet
+            .handle_accepted_transaction(
+                &transaction_hash,
+                &Transaction::Declare(DeclareTransaction::Version1(declare_transaction)),
+                Default::default(),
+            )
+            .unwrap();
+
+        let notifications = notifications.lock().unwrap();
+        assert_eq!(notifications.len(), 2);
+        assert_eq!(
+            notifications[0],
+            DevnetNotification::TransactionIncluded {
+                transaction_hash,
+                transaction_type: TransactionType::Declare,
+                execution_status: TransactionExecutionStatus::Succeeded,
+                block_number: BlockNumber(1),
+            }
+        );
+        assert!(matches!(
+            &notifications[1],
+            DevnetNotification::BlockSealed { block_number: BlockNumber(1), transaction_hashes, .. }
+                if transaction_hashes == &vec![transaction_hash]
+        ));
+    }
+
+    #[test]
+    fn listener_is_kept_on_restart() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let notification_count = Arc::new(Mutex::new(0));
+        let counter = notification_count.clone();
+        starknet.set_notification_listener(Arc::new(move |_| *counter.lock().unwrap() += 1));
+
+        starknet.restart().unwrap();
+        starknet.create_block(None).unwrap();
+
+        assert_eq!(*notification_count.lock().unwrap(), 1);
+    }
 }
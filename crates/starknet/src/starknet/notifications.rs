@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use serde::Serialize;
+use starknet_api::block::{BlockNumber, BlockTimestamp};
+use starknet_rs_core::types::TransactionExecutionStatus;
+use starknet_types::felt::{BlockHash, TransactionHash};
+use starknet_types::rpc::transactions::TransactionType;
+
+/// Sent to the listener set via [`Starknet::set_notification_listener`](super::Starknet), e.g.
+/// to post it to webhooks
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum DevnetNotification {
+    /// A transaction was accepted into the pending block
+    TransactionIncluded {
+        transaction_hash: TransactionHash,
+        transaction_type: TransactionType,
+        execution_status: TransactionExecutionStatus,
+        /// The number the pending block will have once sealed
+        block_number: BlockNumber,
+    },
+    /// The pending block was turned into a new block
+    BlockSealed {
+        block_number: BlockNumber,
+        block_hash: BlockHash,
+        timestamp: BlockTimestamp,
+        transaction_hashes: Vec<TransactionHash>,
+    },
+}
+
+/// Called while the state is being modified, so it should only hand the notification over, e.g.
+/// to a channel, instead of processing it
+pub type NotificationListener = Arc<dyn Fn(DevnetNotification) + Send + Sync>;
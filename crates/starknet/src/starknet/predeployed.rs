@@ -4,8 +4,8 @@ use starknet_types::contract_storage_key::ContractStorageKey;
 use starknet_types::felt::Felt;
 
 use crate::constants::{
-    CHARGEABLE_ACCOUNT_ADDRESS, ERC20_CONTRACT_ADDRESS, ERC20_CONTRACT_CLASS_HASH,
-    ERC20_CONTRACT_PATH, UDC_CONTRACT_ADDRESS, UDC_CONTRACT_CLASS_HASH, UDC_CONTRACT_PATH,
+    CHARGEABLE_ACCOUNT_ADDRESS, ERC20_CONTRACT_CLASS_HASH, ERC20_CONTRACT_PATH,
+    UDC_CONTRACT_CLASS_HASH, UDC_CONTRACT_PATH,
 };
 use crate::error::{DevnetResult, Error};
 use crate::state::StarknetState;
@@ -13,26 +13,23 @@ use crate::system_contract::SystemContract;
 use crate::traits::StateChanger;
 use crate::utils::get_storage_var_address;
 
-pub(crate) fn create_erc20() -> DevnetResult<SystemContract> {
+pub(crate) fn create_erc20(address: ContractAddress) -> DevnetResult<SystemContract> {
     let erc20_contract_class_json_str =
         std::fs::read_to_string(ERC20_CONTRACT_PATH).map_err(|err| Error::ReadFileError {
             source: err,
             path: ERC20_CONTRACT_PATH.to_string(),
         })?;
-    let erc20_fee_contract = SystemContract::new(
-        ERC20_CONTRACT_CLASS_HASH,
-        ERC20_CONTRACT_ADDRESS,
-        &erc20_contract_class_json_str,
-    )?;
+    let erc20_fee_contract =
+        SystemContract::new(ERC20_CONTRACT_CLASS_HASH, address, &erc20_contract_class_json_str)?;
 
     Ok(erc20_fee_contract)
 }
 
 /// Set initial values of ERC20 contract storage
-pub(crate) fn initialize_erc20(state: &mut StarknetState) -> DevnetResult<()> {
-    let contract_address =
-        ContractAddress::new(Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS)?)?;
-
+pub(crate) fn initialize_erc20(
+    state: &mut StarknetState,
+    contract_address: ContractAddress,
+) -> DevnetResult<()> {
     for (storage_var_name, storage_value) in [
         ("ERC20_name", get_selector_from_name("ether").unwrap().into()),
         ("ERC20_symbol", get_selector_from_name("ETH").unwrap().into()),
@@ -48,14 +45,11 @@ pub(crate) fn initialize_erc20(state: &mut StarknetState) -> DevnetResult<()> {
     Ok(())
 }
 
-pub(crate) fn create_udc() -> DevnetResult<SystemContract> {
+pub(crate) fn create_udc(address: ContractAddress) -> DevnetResult<SystemContract> {
     let udc_contract_class_json_str = std::fs::read_to_string(UDC_CONTRACT_PATH)
         .map_err(|err| Error::ReadFileError { source: err, path: UDC_CONTRACT_PATH.to_string() })?;
-    let udc_contract = SystemContract::new(
-        UDC_CONTRACT_CLASS_HASH,
-        UDC_CONTRACT_ADDRESS,
-        &udc_contract_class_json_str,
-    )?;
+    let udc_contract =
+        SystemContract::new(UDC_CONTRACT_CLASS_HASH, address, &udc_contract_class_json_str)?;
 
     Ok(udc_contract)
 }
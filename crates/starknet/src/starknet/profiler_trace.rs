@@ -0,0 +1,187 @@
+use std::collections::BTreeMap;
+
+use blockifier::execution::call_info::CallInfo;
+use serde::{Deserialize, Serialize};
+use starknet_api::deprecated_contract_class::EntryPointType as BlockifierEntryPointType;
+use starknet_types::felt::{Felt, TransactionHash};
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+
+/// A call of a transaction, in the trace format consumed by cairo-profiler and cairo-coverage
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProfilerCallTrace {
+    pub entry_point: ProfilerEntryPoint,
+    /// Resources of the call, including the resources of its nested calls
+    pub cumulative_resources: ProfilerExecutionResources,
+    pub used_l1_resources: ProfilerL1Resources,
+    pub nested_calls: Vec<ProfilerCallTrace>,
+    /// Devnet does not record the VM trace, so it is always `null`
+    pub vm_trace: Option<Vec<()>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProfilerEntryPoint {
+    pub class_hash: Option<Felt>,
+    pub code_address: Option<Felt>,
+    pub entry_point_type: ProfilerEntryPointType,
+    pub entry_point_selector: Felt,
+    pub calldata: Vec<Felt>,
+    pub storage_address: Felt,
+    pub caller_address: Felt,
+    pub call_type: ProfilerCallType,
+    pub initial_gas: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ProfilerEntryPointType {
+    Constructor,
+    External,
+    L1Handler,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum ProfilerCallType {
+    Call,
+    Delegate,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProfilerExecutionResources {
+    pub vm_resources: ProfilerVmResources,
+    pub gas_consumed: Option<u64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProfilerVmResources {
+    pub n_steps: usize,
+    pub n_memory_holes: usize,
+    pub builtin_instance_counter: BTreeMap<String, usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProfilerL1Resources {
+    /// Payload length of each message sent to L1 by the call itself
+    pub l2_l1_message_sizes: Vec<usize>,
+}
+
+/// The top-level call of a transaction to export
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ProfiledCall {
+    Validate,
+    #[default]
+    Execute,
+    FeeTransfer,
+}
+
+impl From<&CallInfo> for ProfilerCallTrace {
+    fn from(call_info: &CallInfo) -> Self {
+        let call = &call_info.call;
+        let vm_resources = &call_info.vm_resources;
+
+        Self {
+            entry_point: ProfilerEntryPoint {
+                class_hash: call.class_hash.map(Felt::from),
+                code_address: call.code_address.map(|address| address.0.into()),
+                entry_point_type: match call.entry_point_type {
+                    BlockifierEntryPointType::Constructor => ProfilerEntryPointType::Constructor,
+                    BlockifierEntryPointType::External => ProfilerEntryPointType::External,
+                    BlockifierEntryPointType::L1Handler => ProfilerEntryPointType::L1Handler,
+                },
+                entry_point_selector: call.entry_point_selector.0.into(),
+                calldata: call.calldata.0.iter().map(|felt| Felt::from(*felt)).collect(),
+                storage_address: call.storage_address.0.into(),
+                caller_address: call.caller_address.0.into(),
+                call_type: match call.call_type {
+                    blockifier::execution::entry_point::CallType::Call => ProfilerCallType::Call,
+                    blockifier::execution::entry_point::CallType::Delegate => {
+                        ProfilerCallType::Delegate
+                    }
+                },
+                initial_gas: call.initial_gas,
+            },
+            cumulative_resources: ProfilerExecutionResources {
+                vm_resources: ProfilerVmResources {
+                    n_steps: vm_resources.n_steps,
+                    n_memory_holes: vm_resources.n_memory_holes,
+                    builtin_instance_counter: vm_resources
+                        .builtin_instance_counter
+                        .iter()
+                        .map(|(builtin_name, count)| (builtin_name.clone(), *count))
+                        .collect(),
+                },
+                gas_consumed: Some(call_info.execution.gas_consumed),
+            },
+            used_l1_resources: ProfilerL1Resources {
+                l2_l1_message_sizes: call_info
+                    .execution
+                    .l2_to_l1_messages
+                    .iter()
+                    .map(|message| message.message.payload.0.len())
+                    .collect(),
+            },
+            nested_calls: call_info.inner_calls.iter().map(ProfilerCallTrace::from).collect(),
+            vm_trace: None,
+        }
+    }
+}
+
+pub fn get_profiler_trace(
+    starknet: &Starknet,
+    transaction_hash: TransactionHash,
+    profiled_call: ProfiledCall,
+) -> DevnetResult<ProfilerCallTrace> {
+    let transaction = starknet.transactions.get(&transaction_hash).ok_or(Error::NoTransaction)?;
+    let execution_info = &transaction.execution_info;
+
+    let (call_info, call_name) = match profiled_call {
+        ProfiledCall::Validate => (&execution_info.validate_call_info, "validate"),
+        ProfiledCall::Execute => (&execution_info.execute_call_info, "execute"),
+        ProfiledCall::FeeTransfer => (&execution_info.fee_transfer_call_info, "fee transfer"),
+    };
+
+    call_info.as_ref().map(ProfilerCallTrace::from).ok_or(Error::UnsupportedAction {
+        msg: format!("Transaction has no {call_name} call to export"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use blockifier::execution::call_info::{CallExecution, CallInfo};
+    use blockifier::execution::entry_point::CallEntryPoint;
+
+    use super::{ProfilerCallTrace, ProfilerEntryPointType};
+
+    fn call_info(n_steps: usize, inner_calls: Vec<CallInfo>) -> CallInfo {
+        let mut call_info = CallInfo {
+            call: CallEntryPoint { initial_gas: 1000, ..Default::default() },
+            execution: CallExecution { gas_consumed: 10, ..Default::default() },
+            inner_calls,
+            ..Default::default()
+        };
+        call_info.vm_resources.n_steps = n_steps;
+        call_info
+    }
+
+    #[test]
+    fn nested_calls_are_converted_recursively() {
+        let trace = ProfilerCallTrace::from(&call_info(
+            300,
+            vec![call_info(100, vec![call_info(50, vec![])]), call_info(20, vec![])],
+        ));
+
+        assert_eq!(trace.cumulative_resources.vm_resources.n_steps, 300);
+        assert_eq!(trace.cumulative_resources.gas_consumed, Some(10));
+        assert_eq!(trace.entry_point.entry_point_type, ProfilerEntryPointType::External);
+        assert_eq!(trace.entry_point.initial_gas, 1000);
+        assert_eq!(trace.nested_calls.len(), 2);
+        assert_eq!(trace.nested_calls[0].cumulative_resources.vm_resources.n_steps, 100);
+        assert_eq!(
+            trace.nested_calls[0].nested_calls[0].cumulative_resources.vm_resources.n_steps,
+            50
+        );
+        assert!(trace.nested_calls[1].nested_calls.is_empty());
+        assert!(trace.vm_trace.is_none());
+    }
+}
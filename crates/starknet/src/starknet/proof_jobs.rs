@@ -0,0 +1,111 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use starknet_api::block::BlockNumber;
+use starknet_rs_core::types::BlockId;
+use starknet_types::felt::BlockHash;
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+
+/// Stage of the simulated proof of a block, from its sealing to the verification of the proof on
+/// L1
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ProofJobStatus {
+    Received,
+    Proven,
+    VerifiedOnL1,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProofStatus {
+    pub block_number: BlockNumber,
+    pub block_hash: BlockHash,
+    pub status: ProofJobStatus,
+}
+
+/// The instant at which the proof job of each block was received, i.e. at which the block was
+/// sealed. Devnet does not prove blocks; the status of a job only depends on the time passed.
+#[derive(Debug, Default)]
+pub(crate) struct ProofJobs(HashMap<BlockNumber, Instant>);
+
+pub(crate) fn receive_proof_job(starknet: &mut Starknet, block_number: BlockNumber) {
+    starknet.proof_jobs.0.insert(block_number, Instant::now());
+}
+
+pub(crate) fn get_proof_status(
+    starknet: &Starknet,
+    block_id: BlockId,
+) -> DevnetResult<ProofStatus> {
+    let block = starknet.blocks.get_by_block_id(block_id).ok_or(Error::NoBlock)?;
+    let block_number = block.block_number();
+    let received_at = starknet.proof_jobs.0.get(&block_number).ok_or(Error::UnsupportedAction {
+        msg: format!("Block {block_number} has no proof job"),
+    })?;
+
+    Ok(ProofStatus {
+        block_number,
+        block_hash: block.block_hash(),
+        status: status_after(starknet, received_at.elapsed()),
+    })
+}
+
+fn status_after(starknet: &Starknet, elapsed: Duration) -> ProofJobStatus {
+    let proving_time = Duration::from_secs(starknet.config.proof_proving_time);
+    let verification_time = Duration::from_secs(starknet.config.proof_verification_time);
+
+    if elapsed < proving_time {
+        ProofJobStatus::Received
+    } else if elapsed < proving_time + verification_time {
+        ProofJobStatus::Proven
+    } else {
+        ProofJobStatus::VerifiedOnL1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use starknet_rs_core::types::{BlockId, BlockTag};
+
+    use super::{get_proof_status, status_after, ProofJobStatus};
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+
+    fn setup(proof_proving_time: u64, proof_verification_time: u64) -> Starknet {
+        Starknet::new(&StarknetConfig {
+            proof_proving_time,
+            proof_verification_time,
+            ..StarknetConfig::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn status_progresses_with_time() {
+        let starknet = setup(10, 20);
+
+        assert_eq!(status_after(&starknet, Duration::from_secs(9)), ProofJobStatus::Received);
+        assert_eq!(status_after(&starknet, Duration::from_secs(10)), ProofJobStatus::Proven);
+        assert_eq!(status_after(&starknet, Duration::from_secs(29)), ProofJobStatus::Proven);
+        assert_eq!(status_after(&starknet, Duration::from_secs(30)), ProofJobStatus::VerifiedOnL1);
+    }
+
+    #[test]
+    fn job_is_received_when_block_is_sealed() {
+        let mut starknet = setup(3600, 3600);
+        starknet.create_block(None).unwrap();
+
+        let proof_status = get_proof_status(&starknet, BlockId::Tag(BlockTag::Latest)).unwrap();
+        assert_eq!(proof_status.status, ProofJobStatus::Received);
+
+        let mut starknet = setup(0, 0);
+        starknet.create_block(None).unwrap();
+
+        let proof_status = get_proof_status(&starknet, BlockId::Number(1)).unwrap();
+        assert_eq!(proof_status.status, ProofJobStatus::VerifiedOnL1);
+    }
+}
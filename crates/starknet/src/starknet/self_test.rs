@@ -0,0 +1,144 @@
+use starknet_rs_core::types::{BlockId, BlockTag, TransactionExecutionStatus};
+use starknet_rs_ff::FieldElement;
+use starknet_types::contract_class::{Cairo0ContractClass, Cairo0Json};
+use starknet_types::felt::{Felt, TransactionHash};
+use starknet_types::rpc::transactions::BroadcastedTransaction;
+
+use super::sender::{get_selector, TransactionSender};
+use super::starknet_config::{BlockGeneration, StarknetConfig};
+use super::{estimations, Starknet};
+use crate::constants::SELF_TEST_CONTRACT_JSON;
+use crate::error::{DevnetResult, Error};
+use crate::raw_execution::Call;
+use crate::transactions::StarknetTransaction;
+
+/// Runs a smoke sequence (declare, deploy, invoke, read event, estimate fee) against a throwaway
+/// instance created from `config`. The instance is never dumped, so the state of the devnet that
+/// is about to be served is not affected.
+pub async fn run_self_test(config: &StarknetConfig) -> DevnetResult<()> {
+    let mut starknet = Starknet::new(&StarknetConfig {
+        dump_on: None,
+        dump_path: None,
+        re_execute_on_init: false,
+        block_max_steps: None,
+        block_max_txs: None,
+        block_generation: BlockGeneration::Transaction,
+        ..config.clone()
+    })?;
+    // the chargeable account is used, because its private key is known regardless of the seed
+    // and the class of predeployed accounts
+    let sender = TransactionSender::chargeable(&starknet)?;
+
+    // declare
+    let contract_class: Cairo0ContractClass =
+        Cairo0Json::raw_json_from_json_str(SELF_TEST_CONTRACT_JSON)?.into();
+    let declare_tx = sender.declare(&starknet, &contract_class).await?;
+    let (declare_tx_hash, class_hash) = starknet.add_declare_transaction_v1(declare_tx)?;
+    assert_succeeded(&starknet, declare_tx_hash, "declare")?;
+
+    // deploy through UDC
+    let udc_address = Felt::from(starknet.config.udc_address);
+    let deploy_tx = sender
+        .invoke(
+            &starknet,
+            Call {
+                to: udc_address.into(),
+                selector: get_selector("deployContract")?,
+                calldata: vec![
+                    class_hash.into(),
+                    FieldElement::ZERO, // salt
+                    FieldElement::ZERO, // unique
+                    FieldElement::ZERO, // constructor calldata length
+                ],
+            },
+        )
+        .await?;
+    let deploy_tx_hash = starknet.add_invoke_transaction(deploy_tx)?;
+    assert_succeeded(&starknet, deploy_tx_hash, "deploy")?;
+
+    // read event
+    let deploy_block = BlockId::Number(
+        starknet
+            .transactions
+            .get(&deploy_tx_hash)
+            .and_then(|transaction| transaction.block_number)
+            .ok_or(Error::NoTransaction)?
+            .0,
+    );
+    let (events, _) = starknet.get_events(
+        Some(deploy_block),
+        Some(deploy_block),
+        Some(starknet.config.udc_address),
+        None,
+        0,
+        None,
+    )?;
+    let deployment_events = events
+        .into_iter()
+        .filter(|event| event.transaction_hash == deploy_tx_hash)
+        .map(|event| starknet_types::emitted_event::Event {
+            from_address: event.from_address,
+            keys: event.keys,
+            data: event.data,
+        })
+        .collect::<Vec<_>>();
+    let contract_address = StarknetTransaction::get_deployed_address_from_events(
+        &deployment_events,
+        starknet.config.udc_address,
+    )?
+    .ok_or_else(|| self_test_error("no ContractDeployed event emitted by the deployment"))?;
+
+    // invoke
+    let increase_balance_call = Call {
+        to: Felt::from(contract_address).into(),
+        selector: get_selector("increase_balance")?,
+        calldata: vec![FieldElement::from(10u32)],
+    };
+    let invoke_tx = sender.invoke(&starknet, increase_balance_call.clone()).await?;
+    let invoke_tx_hash = starknet.add_invoke_transaction(invoke_tx)?;
+    assert_succeeded(&starknet, invoke_tx_hash, "invoke")?;
+
+    let balance = starknet.call(
+        BlockId::Tag(BlockTag::Latest),
+        contract_address.into(),
+        get_selector("get_balance")?.into(),
+        vec![],
+    )?;
+    if balance != vec![Felt::from(10)] {
+        return Err(self_test_error("invoke did not change the storage of the deployed contract"));
+    }
+
+    // estimate fee; the signature is not validated as the transaction is a query one
+    let estimation_tx = sender.invoke(&starknet, increase_balance_call).await?;
+    let estimations = estimations::estimate_fee(
+        &starknet,
+        BlockId::Tag(BlockTag::Latest),
+        &[BroadcastedTransaction::Invoke(estimation_tx)],
+        None,
+        Some(false),
+    )?;
+    if estimations.len() != 1 {
+        return Err(self_test_error("fee estimation returned an unexpected number of results"));
+    }
+
+    Ok(())
+}
+
+fn assert_succeeded(
+    starknet: &Starknet,
+    transaction_hash: TransactionHash,
+    step: &str,
+) -> DevnetResult<()> {
+    let (execution_status, _) =
+        starknet.get_transaction_execution_and_finality_status(transaction_hash)?;
+    match execution_status {
+        TransactionExecutionStatus::Succeeded => Ok(()),
+        TransactionExecutionStatus::Reverted => {
+            Err(self_test_error(&format!("{step} transaction reverted")))
+        }
+    }
+}
+
+fn self_test_error(msg: &str) -> Error {
+    Error::UnexpectedInternalError { msg: format!("Self-test failed: {msg}") }
+}
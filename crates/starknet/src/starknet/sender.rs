@@ -0,0 +1,109 @@
+use blockifier::state::state_api::StateReader;
+use starknet_api::transaction::Fee;
+use starknet_rs_ff::FieldElement;
+use starknet_rs_signers::Signer;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::contract_class::Cairo0ContractClass;
+use starknet_types::felt::{Felt, Key, TransactionSignature};
+use starknet_types::rpc::transactions::broadcasted_declare_transaction_v1::BroadcastedDeclareTransactionV1;
+use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
+
+use super::Starknet;
+use crate::constants::{CHARGEABLE_ACCOUNT_ADDRESS, CHARGEABLE_ACCOUNT_PRIVATE_KEY};
+use crate::error::{DevnetResult, Error};
+use crate::raw_execution::{Call, RawExecution};
+
+/// Creates signed transactions on behalf of an account with a known private key. Calldata is
+/// encoded as expected by Cairo 0 accounts.
+pub(crate) struct TransactionSender {
+    pub(crate) address: ContractAddress,
+    private_key: Key,
+    chain_id: Felt,
+    max_fee: u128,
+}
+
+impl TransactionSender {
+    pub(crate) fn new(
+        starknet: &Starknet,
+        address: ContractAddress,
+        private_key: Key,
+    ) -> DevnetResult<Self> {
+        Ok(Self {
+            address,
+            private_key,
+            chain_id: starknet.chain_id().to_felt(),
+            max_fee: starknet.config.gas_price as u128 * 1_000_000,
+        })
+    }
+
+    /// The chargeable account can be used regardless of the seed and the class of predeployed
+    /// accounts
+    pub(crate) fn chargeable(starknet: &Starknet) -> DevnetResult<Self> {
+        Self::new(
+            starknet,
+            ContractAddress::new(Felt::from_prefixed_hex_str(CHARGEABLE_ACCOUNT_ADDRESS)?)?,
+            Felt::from_prefixed_hex_str(CHARGEABLE_ACCOUNT_PRIVATE_KEY)?,
+        )
+    }
+
+    pub(crate) fn nonce(&self, starknet: &Starknet) -> DevnetResult<Felt> {
+        let nonce = starknet.state.state.get_nonce_at(self.address.try_into()?)?;
+        Ok(nonce.0.into())
+    }
+
+    pub(crate) async fn sign(&self, msg_hash: Felt) -> DevnetResult<TransactionSignature> {
+        let signer = starknet_rs_signers::LocalWallet::from(
+            starknet_rs_signers::SigningKey::from_secret_scalar(self.private_key.into()),
+        );
+        let signature = signer.sign_hash(&msg_hash.into()).await?;
+
+        Ok(vec![signature.r.into(), signature.s.into()])
+    }
+
+    pub(crate) async fn invoke(
+        &self,
+        starknet: &Starknet,
+        call: Call,
+    ) -> DevnetResult<BroadcastedInvokeTransaction> {
+        let nonce = self.nonce(starknet)?;
+        let raw_execution = RawExecution {
+            calls: vec![call],
+            nonce: nonce.into(),
+            max_fee: FieldElement::from(self.max_fee),
+        };
+        let msg_hash = raw_execution.transaction_hash(self.chain_id.into(), self.address.into());
+
+        Ok(BroadcastedInvokeTransaction::new(
+            self.address,
+            Fee(self.max_fee),
+            &self.sign(msg_hash.into()).await?,
+            nonce,
+            &raw_execution.raw_calldata().into_iter().map(|c| c.into()).collect(),
+            Felt::from(1),
+        ))
+    }
+
+    pub(crate) async fn declare(
+        &self,
+        starknet: &Starknet,
+        contract_class: &Cairo0ContractClass,
+    ) -> DevnetResult<BroadcastedDeclareTransactionV1> {
+        let mut declare_tx = BroadcastedDeclareTransactionV1::new(
+            self.address,
+            Fee(self.max_fee),
+            &vec![],
+            self.nonce(starknet)?,
+            contract_class,
+            Felt::from(1),
+        );
+        let class_hash = declare_tx.generate_class_hash()?;
+        let declare_tx_hash = declare_tx.calculate_transaction_hash(&self.chain_id, &class_hash)?;
+        declare_tx.common.signature = self.sign(declare_tx_hash).await?;
+
+        Ok(declare_tx)
+    }
+}
+
+pub(crate) fn get_selector(name: &str) -> DevnetResult<FieldElement> {
+    starknet_rs_core::utils::get_selector_from_name(name).map_err(|_| Error::FormatError)
+}
@@ -1,14 +1,21 @@
+use std::fmt::Display;
 use std::net::IpAddr;
+use std::str::FromStr;
 
 use starknet_types::chain_id::ChainId;
+use starknet_types::contract_address::ContractAddress;
 use starknet_types::contract_class::{Cairo0ContractClass, Cairo0Json, ContractClass};
 use starknet_types::felt::Felt;
 use starknet_types::traits::HashProducer;
 
+use super::base_dump::BaseDump;
+use super::custom_genesis::CustomGenesis;
 use crate::constants::{
     CAIRO_0_ACCOUNT_CONTRACT_PATH, DEVNET_DEFAULT_CHAIN_ID, DEVNET_DEFAULT_GAS_PRICE,
-    DEVNET_DEFAULT_HOST, DEVNET_DEFAULT_INITIAL_BALANCE, DEVNET_DEFAULT_PORT,
+    DEVNET_DEFAULT_HOST, DEVNET_DEFAULT_INITIAL_BALANCE, DEVNET_DEFAULT_MAX_REQUEST_BODY_SIZE,
+    DEVNET_DEFAULT_PORT, DEVNET_DEFAULT_PROOF_PROVING_TIME, DEVNET_DEFAULT_PROOF_VERIFICATION_TIME,
     DEVNET_DEFAULT_TEST_SEED, DEVNET_DEFAULT_TIMEOUT, DEVNET_DEFAULT_TOTAL_ACCOUNTS,
+    ERC20_CONTRACT_ADDRESS, UDC_CONTRACT_ADDRESS,
 };
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq, clap::ValueEnum)]
@@ -17,6 +24,57 @@ pub enum DumpOn {
     Transaction,
 }
 
+/// How transactions are encoded in a dump; loading detects the format of the file
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum DumpFormat {
+    #[default]
+    Json,
+    /// Length-delimited records, appended without rewriting the file; not human-readable
+    Binary,
+}
+
+/// When the pending block is mined
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, clap::ValueEnum)]
+pub enum BlockGeneration {
+    /// As soon as a transaction is added, unless limited by `block_max_txs` or `block_max_steps`
+    #[default]
+    Transaction,
+    /// Only when a block is created, e.g. via `POST /create_block`
+    Demand,
+}
+
+/// How long events are kept queryable
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum EventRetention {
+    #[default]
+    Unlimited,
+    /// Only the events of this many of the latest blocks are kept
+    Blocks(u64),
+}
+
+impl FromStr for EventRetention {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "unlimited" => Ok(EventRetention::Unlimited),
+            _ => match s.parse::<u64>() {
+                Ok(blocks) if blocks > 0 => Ok(EventRetention::Blocks(blocks)),
+                _ => Err(format!("expected a positive number of blocks or unlimited; got: {s}")),
+            },
+        }
+    }
+}
+
+impl Display for EventRetention {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventRetention::Unlimited => write!(f, "unlimited"),
+            EventRetention::Blocks(blocks) => write!(f, "{blocks}"),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct StarknetConfig {
     pub seed: u32,
@@ -28,12 +86,60 @@ pub struct StarknetConfig {
     pub port: u16,
     pub start_time: Option<u64>,
     pub timeout: u16,
+    /// Requests with a larger body, in bytes, are rejected by the server
+    pub max_request_body_size: usize,
+    /// If set, responses larger than it, in bytes, are compressed for clients accepting gzip or
+    /// deflate
+    pub compression_min_size: Option<u16>,
+    /// If set, JSON-RPC calls and their responses are recorded, to be exported as a fixture
+    pub record_session: bool,
+    /// JSON-RPC methods which are rejected as not supported
+    pub disabled_methods: Vec<String>,
     pub gas_price: u64,
     pub chain_id: ChainId,
+    /// Address at which the fee token is predeployed
+    pub fee_token_address: ContractAddress,
+    /// Address at which the Universal Deployer Contract is predeployed
+    pub udc_address: ContractAddress,
+    /// If set, it is deployed instead of the fee token, the UDC and any account
+    pub custom_genesis: Option<CustomGenesis>,
+    /// If set, the head state of the dump is the genesis state, on top of which the accounts are
+    /// predeployed
+    pub base_dump: Option<BaseDump>,
     pub dump_on: Option<DumpOn>,
     pub dump_path: Option<String>,
+    pub dump_format: DumpFormat,
     /// on initialization, re-execute loaded txs (if any)
     pub re_execute_on_init: bool,
+    /// If set, a block is mined only when the sum of steps of its transactions reaches this limit
+    pub block_max_steps: Option<u64>,
+    /// If set, a block is mined only when the number of its transactions reaches this limit
+    pub block_max_txs: Option<u64>,
+    pub block_generation: BlockGeneration,
+    /// If set, `POST /shutdown` stops the server when sent with this token as bearer token
+    pub shutdown_token: Option<String>,
+    /// If set, only requests sending this key in the `x-devnet-api-key` header may set account
+    /// templates, and the templates only apply to their transactions
+    pub impersonation_api_key: Option<String>,
+    /// If set, JSON-RPC methods taking at least this many milliseconds are logged as slow
+    pub slow_query_threshold_ms: Option<u64>,
+    pub event_retention: EventRetention,
+    /// If set, invoke transactions that would revert are rejected instead of being included
+    pub reject_on_revert: bool,
+    /// If set, transactions with a larger max fee, in WEI, are rejected
+    pub max_fee_cap: Option<u128>,
+    /// If not empty, only transactions sent by these accounts are accepted; minting is not
+    /// affected
+    pub allowed_senders: Vec<ContractAddress>,
+    /// If set, each sealed block is re-executed from the state of its parent in the background,
+    /// and differences of the resulting state diff are logged
+    pub verify_execution: bool,
+    /// Included transactions and sealed blocks are posted to each of these URLs
+    pub webhook_urls: Vec<String>,
+    /// Seconds after which the simulated proof of a sealed block is proven
+    pub proof_proving_time: u64,
+    /// Seconds after which a proven block is verified on L1
+    pub proof_verification_time: u64,
 }
 
 impl Default for StarknetConfig {
@@ -52,11 +158,40 @@ impl Default for StarknetConfig {
             port: DEVNET_DEFAULT_PORT,
             start_time: None,
             timeout: DEVNET_DEFAULT_TIMEOUT,
+            max_request_body_size: DEVNET_DEFAULT_MAX_REQUEST_BODY_SIZE,
+            compression_min_size: None,
+            record_session: false,
+            disabled_methods: vec![],
             gas_price: DEVNET_DEFAULT_GAS_PRICE,
             chain_id: DEVNET_DEFAULT_CHAIN_ID,
+            fee_token_address: ContractAddress::new(
+                Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap(),
+            )
+            .unwrap(),
+            udc_address: ContractAddress::new(
+                Felt::from_prefixed_hex_str(UDC_CONTRACT_ADDRESS).unwrap(),
+            )
+            .unwrap(),
+            custom_genesis: None,
+            base_dump: None,
             dump_on: None,
             dump_path: None,
+            dump_format: DumpFormat::default(),
             re_execute_on_init: true,
+            block_max_steps: None,
+            block_max_txs: None,
+            block_generation: BlockGeneration::default(),
+            shutdown_token: None,
+            impersonation_api_key: None,
+            slow_query_threshold_ms: None,
+            event_retention: EventRetention::Unlimited,
+            reject_on_revert: false,
+            max_fee_cap: None,
+            allowed_senders: vec![],
+            verify_execution: false,
+            webhook_urls: vec![],
+            proof_proving_time: DEVNET_DEFAULT_PROOF_PROVING_TIME,
+            proof_verification_time: DEVNET_DEFAULT_PROOF_VERIFICATION_TIME,
         }
     }
 }
@@ -1,3 +1,4 @@
+use starknet_api::block::BlockNumber;
 use starknet_rs_core::types::BlockId;
 
 use super::Starknet;
@@ -15,6 +16,32 @@ pub fn state_update_by_block_id(
     Ok(StateUpdate::new(block.block_hash(), state_diff))
 }
 
+/// Returns state updates of all blocks starting with `from`, in ascending order of block numbers.
+/// If `from` is beyond the latest block, the result is empty.
+pub fn state_updates_from_block(
+    starknet: &Starknet,
+    from: BlockNumber,
+) -> Vec<(BlockNumber, StateUpdate)> {
+    let mut block_numbers: Vec<BlockNumber> = starknet
+        .blocks
+        .num_to_block
+        .keys()
+        .filter(|block_number| **block_number >= from)
+        .cloned()
+        .collect();
+    block_numbers.sort();
+
+    block_numbers
+        .into_iter()
+        .filter_map(|block_number| {
+            let block = starknet.blocks.num_to_block.get(&block_number)?;
+            let state_diff =
+                starknet.blocks.num_to_state_diff.get(&block_number).cloned().unwrap_or_default();
+            Some((block_number, StateUpdate::new(block.block_hash(), state_diff)))
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use cairo_lang_starknet::casm_contract_class::CasmContractClass;
@@ -89,6 +116,23 @@ mod tests {
         assert_eq!(state_diff.declared_classes, expected_state_diff.declared_classes);
     }
 
+    #[test]
+    fn state_updates_are_returned_from_the_requested_block() {
+        let (mut starknet, _) = setup();
+        for _ in 0..3 {
+            starknet.create_block(None).unwrap();
+        }
+
+        let block_numbers: Vec<u64> = starknet
+            .state_updates_from_block(1)
+            .into_iter()
+            .map(|(block_number, _)| block_number.0)
+            .collect();
+        assert_eq!(block_numbers, vec![1, 2]);
+
+        assert!(starknet.state_updates_from_block(3).is_empty());
+    }
+
     /// Initializes starknet with account_without_validations
     /// deploys ERC20 contract
     fn setup() -> (Starknet, ContractAddress) {
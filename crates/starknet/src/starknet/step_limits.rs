@@ -0,0 +1,123 @@
+use std::collections::HashMap;
+
+use blockifier::execution::call_info::CallInfo;
+use blockifier::transaction::objects::TransactionExecutionInfo;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::traits::ToHexString;
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+use crate::traits::StateExtractor;
+
+/// Maximum number of steps of a call into a contract, including its nested calls. Blockifier
+/// only limits the steps of a whole transaction, so the limits are checked once a call or a
+/// transaction has been executed.
+#[derive(Debug, Default)]
+pub(crate) struct ContractStepLimits(HashMap<ContractAddress, u64>);
+
+/// Sets the step limit of the contract at `address`, or removes it if `steps` is `None`
+pub(crate) fn set_contract_step_limit(
+    starknet: &mut Starknet,
+    address: ContractAddress,
+    steps: Option<u64>,
+) -> DevnetResult<()> {
+    if !starknet.state.is_contract_deployed(&address) {
+        return Err(Error::ContractNotFound);
+    }
+
+    match steps {
+        Some(steps) => starknet.contract_step_limits.0.insert(address, steps),
+        None => starknet.contract_step_limits.0.remove(&address),
+    };
+
+    Ok(())
+}
+
+/// Fails if `call_info`, or any of its nested calls, is a call into a contract which took more
+/// steps than the limit of the contract
+pub(crate) fn check_call(starknet: &Starknet, call_info: &CallInfo) -> DevnetResult<()> {
+    if starknet.contract_step_limits.0.is_empty() {
+        return Ok(());
+    }
+
+    let address = ContractAddress::from(call_info.call.storage_address);
+    let steps = call_info.vm_resources.n_steps as u64;
+    if let Some(&limit) = starknet.contract_step_limits.0.get(&address) {
+        if steps > limit {
+            return Err(Error::ExecutionError {
+                revert_error: format!(
+                    "Call into contract {} took {steps} steps, exceeding its step limit of {limit}",
+                    address.to_prefixed_hex_str()
+                ),
+            });
+        }
+    }
+
+    call_info.inner_calls.iter().try_for_each(|inner_call| check_call(starknet, inner_call))
+}
+
+/// Same as [`check_call`], for the validation and the execution of a transaction. The fee
+/// transfer is not limited.
+pub(crate) fn check_transaction(
+    starknet: &Starknet,
+    execution_info: &TransactionExecutionInfo,
+) -> DevnetResult<()> {
+    [&execution_info.validate_call_info, &execution_info.execute_call_info]
+        .into_iter()
+        .flatten()
+        .try_for_each(|call_info| check_call(starknet, call_info))
+}
+
+#[cfg(test)]
+mod tests {
+    use blockifier::execution::call_info::CallInfo;
+    use blockifier::execution::entry_point::CallEntryPoint;
+    use starknet_types::contract_address::ContractAddress;
+
+    use super::{check_call, set_contract_step_limit};
+    use crate::error::Error;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::utils::test_utils::dummy_contract_address;
+
+    fn call_info(address: ContractAddress, n_steps: usize, inner_calls: Vec<CallInfo>) -> CallInfo {
+        let mut call_info = CallInfo {
+            call: CallEntryPoint {
+                storage_address: address.try_into().unwrap(),
+                ..Default::default()
+            },
+            inner_calls,
+            ..Default::default()
+        };
+        call_info.vm_resources.n_steps = n_steps;
+        call_info
+    }
+
+    #[test]
+    fn nested_call_exceeding_limit_fails() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let account_address = starknet.get_predeployed_accounts()[0].account_address;
+        let limited_address = starknet.get_predeployed_accounts()[1].account_address;
+        set_contract_step_limit(&mut starknet, limited_address, Some(100)).unwrap();
+
+        let within_limit =
+            call_info(account_address, 1000, vec![call_info(limited_address, 100, vec![])]);
+        assert!(check_call(&starknet, &within_limit).is_ok());
+
+        let over_limit =
+            call_info(account_address, 1000, vec![call_info(limited_address, 101, vec![])]);
+        assert!(matches!(check_call(&starknet, &over_limit), Err(Error::ExecutionError { .. })));
+
+        set_contract_step_limit(&mut starknet, limited_address, None).unwrap();
+        assert!(check_call(&starknet, &over_limit).is_ok());
+    }
+
+    #[test]
+    fn limit_is_not_set_for_undeployed_contract() {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+
+        let err = set_contract_step_limit(&mut starknet, dummy_contract_address(), Some(100))
+            .unwrap_err();
+        assert!(matches!(err, Error::ContractNotFound));
+    }
+}
@@ -0,0 +1,216 @@
+use starknet_rs_core::types::ExecutionResult;
+use starknet_types::felt::TransactionHash;
+use starknet_types::rpc::transactions::{BroadcastedDeclareTransaction, BroadcastedTransaction};
+
+use super::starknet_config::BlockGeneration;
+use super::{checkpoint, Starknet};
+use crate::error::{DevnetResult, Error};
+use crate::transactions::StarknetTransaction;
+
+/// Adds `transactions` in order, so that they end up together in the next sealed block. If any
+/// of them fails the checks of a single submission or reverts, none of them is added.
+pub(crate) fn add_transaction_batch(
+    starknet: &mut Starknet,
+    transactions: Vec<BroadcastedTransaction>,
+) -> DevnetResult<Vec<TransactionHash>> {
+    if transactions.is_empty() {
+        return Err(Error::UnsupportedAction { msg: "The batch has no transactions".into() });
+    }
+
+    let transaction_hashes = checkpoint::add_atomically(starknet, |starknet| {
+        transactions
+            .into_iter()
+            .enumerate()
+            .map(|(index, transaction)| {
+                add_unreverted_transaction(starknet, transaction)
+                    .map_err(|err| Error::TransactionBatchError { index, reason: err.to_string() })
+            })
+            .collect::<DevnetResult<Vec<_>>>()
+    })?;
+
+    // the block is sealed once the whole batch is added, regardless of the block size limits
+    if !starknet.mining_paused && starknet.config.block_generation != BlockGeneration::Demand {
+        starknet.create_block(None)?;
+    }
+
+    Ok(transaction_hashes)
+}
+
+fn add_unreverted_transaction(
+    starknet: &mut Starknet,
+    transaction: BroadcastedTransaction,
+) -> DevnetResult<TransactionHash> {
+    let transaction_hash = add_transaction(starknet, transaction)?;
+    if let Some(StarknetTransaction {
+        execution_result: ExecutionResult::Reverted { reason },
+        ..
+    }) = starknet.transactions.get(&transaction_hash)
+    {
+        return Err(Error::ExecutionError { revert_error: reason.clone() });
+    }
+
+    Ok(transaction_hash)
+}
+
+fn add_transaction(
+    starknet: &mut Starknet,
+    transaction: BroadcastedTransaction,
+) -> DevnetResult<TransactionHash> {
+    Ok(match transaction {
+        BroadcastedTransaction::Invoke(invoke_tx) => starknet.add_invoke_transaction(invoke_tx)?,
+        BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V1(declare_tx)) => {
+            starknet.add_declare_transaction_v1(*declare_tx)?.0
+        }
+        BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V2(declare_tx)) => {
+            starknet.add_declare_transaction_v2(*declare_tx)?.0
+        }
+        BroadcastedTransaction::DeployAccount(deploy_account_tx) => {
+            starknet.add_deploy_account_transaction(deploy_account_tx)?.0
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_api::transaction::Fee;
+    use starknet_rs_core::utils::get_selector_from_name;
+    use starknet_types::contract_address::ContractAddress;
+    use starknet_types::felt::Felt;
+    use starknet_types::rpc::transactions::broadcasted_declare_transaction_v1::BroadcastedDeclareTransactionV1;
+    use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
+    use starknet_types::rpc::transactions::{
+        BroadcastedDeclareTransaction, BroadcastedTransaction,
+    };
+    use starknet_types::traits::HashProducer;
+
+    use crate::constants::ERC20_CONTRACT_ADDRESS;
+    use crate::error::Error;
+    use crate::starknet::account_templates::AccountTemplate;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::traits::StateExtractor;
+    use crate::utils::exported_test_utils::dummy_cairo_0_contract_class;
+    use crate::utils::test_utils::dummy_broadcasted_declare_transaction_v2;
+
+    const MAX_FEE: u128 = 1e18 as u128;
+
+    /// Declares of the predeployed account, which accepts them regardless of their signature
+    fn setup() -> (Starknet, ContractAddress) {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let sender = starknet.get_predeployed_accounts()[0].account_address;
+        starknet.set_account_template(sender, AccountTemplate::AlwaysAccept).unwrap();
+        (starknet, sender)
+    }
+
+    fn declare_batch(sender: ContractAddress, second_nonce: u128) -> Vec<BroadcastedTransaction> {
+        let mut declare_v2 = dummy_broadcasted_declare_transaction_v2(&sender);
+        declare_v2.common.max_fee = Fee(MAX_FEE);
+        let declare_v1 = BroadcastedDeclareTransactionV1::new(
+            sender,
+            Fee(MAX_FEE),
+            &vec![],
+            Felt::from(second_nonce),
+            &dummy_cairo_0_contract_class().into(),
+            Felt::from(1),
+        );
+
+        vec![
+            BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V2(Box::new(
+                declare_v2,
+            ))),
+            BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V1(Box::new(
+                declare_v1,
+            ))),
+        ]
+    }
+
+    fn transfer(
+        sender: ContractAddress,
+        recipient: ContractAddress,
+        nonce: u128,
+    ) -> BroadcastedTransaction {
+        let calldata = vec![
+            Felt::from(1), // number of calls
+            Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap(),
+            Felt::from(get_selector_from_name("transfer").unwrap()),
+            Felt::from(0), // data offset
+            Felt::from(3), // data length
+            Felt::from(3), // calldata length
+            Felt::from(recipient),
+            Felt::from(1), // amount low
+            Felt::from(0), // amount high
+        ];
+
+        BroadcastedTransaction::Invoke(BroadcastedInvokeTransaction::new(
+            sender,
+            Fee(MAX_FEE),
+            &vec![],
+            Felt::from(nonce),
+            &calldata,
+            Felt::from(1),
+        ))
+    }
+
+    #[test]
+    fn batch_is_sealed_in_one_block_in_order() {
+        let (mut starknet, sender) = setup();
+        let latest_block_number = starknet.get_latest_block().unwrap().block_number();
+
+        let transaction_hashes = starknet.add_transaction_batch(declare_batch(sender, 1)).unwrap();
+
+        let latest_block = starknet.get_latest_block().unwrap();
+        assert_eq!(latest_block.block_number(), latest_block_number.next());
+        assert_eq!(latest_block.get_transactions(), &transaction_hashes);
+        assert!(starknet.pending_block().get_transactions().is_empty());
+    }
+
+    #[test]
+    fn batch_with_failing_transaction_is_rejected_as_a_whole() {
+        let (mut starknet, sender) = setup();
+        let latest_block_number = starknet.get_latest_block().unwrap().block_number();
+        let transactions = declare_batch(sender, 5);
+        let BroadcastedTransaction::Declare(BroadcastedDeclareTransaction::V2(declare_v2)) =
+            &transactions[0]
+        else {
+            panic!("Expected declare v2");
+        };
+        let class_hash = starknet_types::contract_class::ContractClass::Cairo1(
+            declare_v2.contract_class.clone(),
+        )
+        .generate_hash()
+        .unwrap();
+
+        match starknet.add_transaction_batch(transactions).unwrap_err() {
+            Error::TransactionBatchError { index: 1, .. } => (),
+            err => panic!("Wrong error type received {err:?}"),
+        }
+
+        assert_eq!(starknet.get_latest_block().unwrap().block_number(), latest_block_number);
+        assert!(starknet.pending_block().get_transactions().is_empty());
+        assert!(!starknet.state.is_contract_declared(&class_hash));
+    }
+
+    #[test]
+    fn batch_exceeding_step_limit_of_contract_is_rejected_as_a_whole() {
+        let (mut starknet, sender) = setup();
+        let recipient = starknet.get_predeployed_accounts()[1].account_address;
+        let fee_token_address =
+            ContractAddress::new(Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap())
+                .unwrap();
+        starknet.set_contract_step_limit(fee_token_address, Some(1)).unwrap();
+        let latest_block_number = starknet.get_latest_block().unwrap().block_number();
+        let mut transactions = declare_batch(sender, 1);
+        transactions[1] = transfer(sender, recipient, 1);
+
+        match starknet.add_transaction_batch(transactions).unwrap_err() {
+            Error::TransactionBatchError { index: 1, reason } => {
+                assert!(reason.contains("exceeding its step limit of 1"), "{reason}")
+            }
+            err => panic!("Wrong error type received {err:?}"),
+        }
+
+        assert_eq!(starknet.get_latest_block().unwrap().block_number(), latest_block_number);
+        assert!(starknet.pending_block().get_transactions().is_empty());
+        assert_eq!(starknet.state.get_nonce(&sender).unwrap(), Felt::from(0));
+    }
+}
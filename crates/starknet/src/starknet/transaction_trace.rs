@@ -0,0 +1,238 @@
+use std::collections::HashMap;
+
+use blockifier::execution::call_info::CallInfo;
+use blockifier::transaction::objects::TransactionExecutionInfo;
+use starknet_rs_core::types::{BlockId, BlockTag};
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::{Felt, TransactionHash};
+use starknet_types::rpc::state::ThinStateDiff;
+use starknet_types::rpc::transactions::{
+    BlockTransactionTrace, DeclareTransactionTrace, DeployAccountTransactionTrace,
+    ExecutionInvocation, FunctionInvocation, InvokeTransactionTrace, L1HandlerTransactionTrace,
+    Reversion, Transaction, TransactionTrace,
+};
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+
+/// Type of the traced transaction, which determines the invocations of its trace
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TraceType {
+    Declare,
+    DeployAccount,
+    Invoke,
+    L1Handler,
+}
+
+/// Converts the execution of a transaction into its trace, with the full tree of nested calls of
+/// each invocation
+pub(crate) fn create_trace(
+    trace_type: TraceType,
+    execution_info: &TransactionExecutionInfo,
+    address_to_class_hash: &HashMap<ContractAddress, Felt>,
+    state_diff: Option<ThinStateDiff>,
+) -> DevnetResult<TransactionTrace> {
+    let invocation = |call_info: Option<&CallInfo>| -> DevnetResult<Option<FunctionInvocation>> {
+        match call_info {
+            Some(call_info) => {
+                Ok(Some(FunctionInvocation::try_from_call_info(call_info, address_to_class_hash)?))
+            }
+            None => Ok(None),
+        }
+    };
+
+    let validate_invocation = invocation(execution_info.validate_call_info.as_ref())?;
+    let fee_transfer_invocation = invocation(execution_info.fee_transfer_call_info.as_ref())?;
+
+    Ok(match trace_type {
+        TraceType::Declare => TransactionTrace::Declare(DeclareTransactionTrace {
+            validate_invocation,
+            fee_transfer_invocation,
+            state_diff,
+        }),
+        TraceType::DeployAccount => {
+            TransactionTrace::DeployAccount(DeployAccountTransactionTrace {
+                validate_invocation,
+                constructor_invocation: invocation(execution_info.execute_call_info.as_ref())?,
+                fee_transfer_invocation,
+                state_diff,
+            })
+        }
+        TraceType::Invoke => TransactionTrace::Invoke(InvokeTransactionTrace {
+            fee_transfer_invocation,
+            validate_invocation,
+            state_diff,
+            execute_invocation: match &execution_info.execute_call_info {
+                Some(call_info) => match call_info.execution.failed {
+                    false => ExecutionInvocation::Succeeded(
+                        FunctionInvocation::try_from_call_info(call_info, address_to_class_hash)?,
+                    ),
+                    true => ExecutionInvocation::Reverted(Reversion {
+                        revert_reason: execution_info
+                            .revert_error
+                            .clone()
+                            .unwrap_or("Revert reason not found".into()),
+                    }),
+                },
+                None => match &execution_info.revert_error {
+                    Some(revert_reason) => ExecutionInvocation::Reverted(Reversion {
+                        revert_reason: revert_reason.clone(),
+                    }),
+                    None => {
+                        return Err(Error::UnexpectedInternalError {
+                            msg: "Execution contains neither call_info nor revert_error".into(),
+                        });
+                    }
+                },
+            },
+        }),
+        TraceType::L1Handler => TransactionTrace::L1Handler(L1HandlerTransactionTrace {
+            function_invocation: invocation(execution_info.execute_call_info.as_ref())?.ok_or(
+                Error::UnexpectedInternalError {
+                    msg: "Execution of L1 handler contains no call_info".into(),
+                },
+            )?,
+        }),
+    })
+}
+
+/// Returns the trace of the stored transaction with `transaction_hash`, as recorded when it was
+/// executed, with the state diff the transaction produced
+pub(crate) fn get_transaction_trace(
+    starknet: &Starknet,
+    transaction_hash: TransactionHash,
+) -> DevnetResult<TransactionTrace> {
+    let transaction = starknet.transactions.get(&transaction_hash).ok_or(Error::NoTransaction)?;
+    let trace_type = match &transaction.inner {
+        Transaction::Declare(_) => TraceType::Declare,
+        Transaction::DeployAccount(_) => TraceType::DeployAccount,
+        Transaction::Invoke(_) => TraceType::Invoke,
+        Transaction::L1Handler(_) => TraceType::L1Handler,
+        Transaction::Deploy(_) => {
+            return Err(Error::UnsupportedAction {
+                msg: "Deploy transactions have no trace".into(),
+            });
+        }
+    };
+
+    let state_diff = if transaction.state_diff == ThinStateDiff::default() {
+        None
+    } else {
+        Some(transaction.state_diff.clone())
+    };
+
+    create_trace(
+        trace_type,
+        &transaction.execution_info,
+        &starknet.state.state.state.address_to_class_hash,
+        state_diff,
+    )
+}
+
+/// Returns the traces of the transactions of the block with `block_id`, in the order of the block
+pub(crate) fn get_block_transaction_traces(
+    starknet: &Starknet,
+    block_id: BlockId,
+) -> DevnetResult<Vec<BlockTransactionTrace>> {
+    let transaction_hashes = match block_id {
+        BlockId::Tag(BlockTag::Pending) => starknet.pending_block().get_transactions().clone(),
+        _ => starknet.get_block(block_id)?.get_transactions().clone(),
+    };
+
+    transaction_hashes
+        .into_iter()
+        .map(|transaction_hash| {
+            Ok(BlockTransactionTrace {
+                transaction_hash,
+                trace_root: get_transaction_trace(starknet, transaction_hash)?,
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use blockifier::execution::call_info::{CallExecution, CallInfo};
+    use blockifier::execution::entry_point::CallEntryPoint;
+    use blockifier::transaction::objects::TransactionExecutionInfo;
+    use starknet_api::core::ClassHash;
+    use starknet_api::hash::StarkFelt;
+    use starknet_types::rpc::transactions::{ExecutionInvocation, TransactionTrace};
+
+    use super::{create_trace, TraceType};
+    use crate::error::Error;
+
+    fn call_info(inner_calls: Vec<CallInfo>) -> CallInfo {
+        CallInfo {
+            call: CallEntryPoint {
+                class_hash: Some(ClassHash(StarkFelt::from(1_u64))),
+                ..Default::default()
+            },
+            inner_calls,
+            ..Default::default()
+        }
+    }
+
+    fn serialized_calls(trace: &TransactionTrace, invocation: &str) -> serde_json::Value {
+        serde_json::to_value(trace).unwrap()[invocation]["calls"].clone()
+    }
+
+    #[test]
+    fn invoke_trace_contains_nested_calls() {
+        let execution_info = TransactionExecutionInfo {
+            validate_call_info: Some(call_info(vec![])),
+            execute_call_info: Some(call_info(vec![call_info(vec![call_info(vec![])])])),
+            ..Default::default()
+        };
+
+        let trace =
+            create_trace(TraceType::Invoke, &execution_info, &HashMap::new(), None).unwrap();
+
+        let TransactionTrace::Invoke(invoke_trace) = &trace else {
+            panic!("Expected invoke trace, got {trace:?}");
+        };
+        assert!(invoke_trace.validate_invocation.is_some());
+        assert!(invoke_trace.fee_transfer_invocation.is_none());
+        assert!(matches!(invoke_trace.execute_invocation, ExecutionInvocation::Succeeded(_)));
+        let calls = serialized_calls(&trace, "execute_invocation");
+        assert_eq!(calls.as_array().unwrap().len(), 1);
+        assert_eq!(calls[0]["calls"].as_array().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn reverted_invoke_trace_contains_revert_reason() {
+        let execution_info = TransactionExecutionInfo {
+            execute_call_info: Some(CallInfo {
+                execution: CallExecution { failed: true, ..Default::default() },
+                ..call_info(vec![])
+            }),
+            revert_error: Some("reason".into()),
+            ..Default::default()
+        };
+
+        match create_trace(TraceType::Invoke, &execution_info, &HashMap::new(), None).unwrap() {
+            TransactionTrace::Invoke(invoke_trace) => match invoke_trace.execute_invocation {
+                ExecutionInvocation::Reverted(reversion) => {
+                    assert_eq!(reversion.revert_reason, "reason")
+                }
+                other => panic!("Expected reversion, got {other:?}"),
+            },
+            other => panic!("Expected invoke trace, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn l1_handler_trace_requires_call() {
+        match create_trace(
+            TraceType::L1Handler,
+            &TransactionExecutionInfo::default(),
+            &HashMap::new(),
+            None,
+        ) {
+            Err(Error::UnexpectedInternalError { .. }) => (),
+            other => panic!("Unexpected result: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,141 @@
+use std::error::Error as StdError;
+
+use blockifier::transaction::errors::{TransactionExecutionError, TransactionPreValidationError};
+use blockifier::transaction::transactions::ExecutableTransaction;
+use serde::{Deserialize, Serialize};
+use starknet_rs_core::types::BlockId;
+use starknet_types::rpc::transactions::BroadcastedTransaction;
+
+use super::{account_templates, chain_id_diagnostics, Starknet};
+use crate::error::{DevnetResult, Error, TransactionValidationError};
+
+/// Whether the account of a transaction would accept it, see [`validate_transaction`]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransactionValidation {
+    pub is_valid: bool,
+    /// Why the transaction would be rejected; only present if it is not valid
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub failure_reason: Option<String>,
+}
+
+impl TransactionValidation {
+    fn invalid(failure_reason: String) -> Self {
+        Self { is_valid: false, failure_reason: Some(failure_reason) }
+    }
+}
+
+/// Checks the nonce of `transaction` and runs `__validate__` of its account (or the account
+/// template of the sender) on a copy of the state at `block_id`, without charging the fee.
+/// Blockifier executes an invoke transaction together with its validation, but the outcome of
+/// the execution does not affect the result, and nothing of the state is kept.
+pub(crate) fn validate_transaction(
+    starknet: &Starknet,
+    block_id: BlockId,
+    transaction: &BroadcastedTransaction,
+) -> DevnetResult<TransactionValidation> {
+    let mut state = starknet.get_state_at(&block_id)?.clone();
+
+    let validate = match account_templates::check_broadcasted_validation(starknet, transaction) {
+        Ok(validate) => validate,
+        Err(err) => return Ok(TransactionValidation::invalid(err.to_string())),
+    };
+
+    let blockifier_transaction =
+        transaction.to_blockifier_account_transaction(starknet.chain_id().to_felt(), false)?;
+    match blockifier_transaction.execute(&mut state.state, &starknet.block_context, false, validate)
+    {
+        Ok(_) => Ok(TransactionValidation { is_valid: true, failure_reason: None }),
+        Err(err @ TransactionExecutionError::ValidateTransactionError(..)) => {
+            let failure = chain_id_diagnostics::explain_validation_failure(
+                starknet,
+                transaction,
+                TransactionValidationError::ValidationFailure.into(),
+            );
+            let failure_reason = match (&failure, err.source()) {
+                (
+                    Error::TransactionValidationError(
+                        TransactionValidationError::ValidationFailure,
+                    ),
+                    Some(cause),
+                ) => format!("{failure} {cause}"),
+                _ => failure.to_string(),
+            };
+
+            Ok(TransactionValidation::invalid(failure_reason))
+        }
+        Err(TransactionExecutionError::TransactionPreValidationError(
+            TransactionPreValidationError::InvalidNonce { .. },
+        )) => Ok(TransactionValidation::invalid(
+            TransactionValidationError::InvalidTransactionNonce.to_string(),
+        )),
+        Err(err) => Err(err.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_api::transaction::Fee;
+    use starknet_rs_core::types::{BlockId, BlockTag};
+    use starknet_types::contract_address::ContractAddress;
+    use starknet_types::felt::Felt;
+    use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
+    use starknet_types::rpc::transactions::BroadcastedTransaction;
+
+    use super::validate_transaction;
+    use crate::error::TransactionValidationError;
+    use crate::starknet::account_templates::AccountTemplate;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+
+    fn setup(template: AccountTemplate) -> (Starknet, ContractAddress) {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let account_address = starknet.get_predeployed_accounts()[0].account_address;
+        starknet.set_account_template(account_address, template).unwrap();
+        (starknet, account_address)
+    }
+
+    fn unsigned_invoke(sender_address: ContractAddress, nonce: u128) -> BroadcastedTransaction {
+        BroadcastedTransaction::Invoke(BroadcastedInvokeTransaction::new(
+            sender_address,
+            Fee(5000),
+            &vec![],
+            Felt::from(nonce),
+            &vec![],
+            Felt::from(1),
+        ))
+    }
+
+    #[test]
+    fn transaction_rejected_by_template_is_invalid() {
+        let (starknet, account_address) = setup(AccountTemplate::AlwaysReject);
+
+        let validation = validate_transaction(
+            &starknet,
+            BlockId::Tag(BlockTag::Pending),
+            &unsigned_invoke(account_address, 0),
+        )
+        .unwrap();
+        assert!(!validation.is_valid);
+        assert_eq!(
+            validation.failure_reason.unwrap(),
+            TransactionValidationError::ValidationFailure.to_string()
+        );
+    }
+
+    #[test]
+    fn transaction_with_invalid_nonce_is_invalid() {
+        let (starknet, account_address) = setup(AccountTemplate::AlwaysAccept);
+
+        let validation = validate_transaction(
+            &starknet,
+            BlockId::Tag(BlockTag::Pending),
+            &unsigned_invoke(account_address, 5),
+        )
+        .unwrap();
+        assert!(!validation.is_valid);
+        assert_eq!(
+            validation.failure_reason.unwrap(),
+            TransactionValidationError::InvalidTransactionNonce.to_string()
+        );
+    }
+}
@@ -0,0 +1,175 @@
+use std::collections::HashMap;
+
+use starknet_api::block::BlockNumber;
+use starknet_rs_core::types::BlockId;
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::felt::TransactionHash;
+use starknet_types::rpc::transactions::Transaction;
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error};
+
+/// Hashes of accepted transactions per sender, in the order in which they were accepted
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SenderIndex(HashMap<ContractAddress, Vec<TransactionHash>>);
+
+impl SenderIndex {
+    /// Transactions without a sender (deploy, L1 handler) are not indexed
+    pub(crate) fn insert(&mut self, transaction_hash: TransactionHash, transaction: &Transaction) {
+        if let Some(sender_address) = transaction.get_sender_address() {
+            self.0.entry(*sender_address).or_default().push(transaction_hash);
+        }
+    }
+
+    pub(crate) fn get(&self, sender_address: &ContractAddress) -> &[TransactionHash] {
+        self.0.get(sender_address).map_or(&[], Vec::as_slice)
+    }
+}
+
+/// Returns the transactions sent by `sender_address` in blocks from `from_block` to `to_block`,
+/// in the order in which they were accepted. Transactions of the pending block are included only
+/// if `to_block` is not specified.
+///
+/// # Arguments
+/// * `skip` - The number of matching transactions to skip.
+/// * `limit` - The maximum number of transactions to return.
+pub(crate) fn get_transactions_by_sender(
+    starknet: &Starknet,
+    sender_address: ContractAddress,
+    from_block: Option<BlockId>,
+    to_block: Option<BlockId>,
+    skip: usize,
+    limit: usize,
+) -> DevnetResult<(Vec<Transaction>, bool)> {
+    let resolve_block_number = |block_id: BlockId| {
+        starknet
+            .blocks
+            .get_by_block_id(block_id)
+            .map(|block| block.block_number())
+            .ok_or(Error::NoBlock)
+    };
+    let from_block_number = from_block.map(resolve_block_number).transpose()?;
+    let to_block_number = to_block.map(resolve_block_number).transpose()?;
+
+    let is_in_range = |block_number: Option<BlockNumber>| match block_number {
+        Some(block_number) => {
+            from_block_number.map_or(true, |from| block_number >= from)
+                && to_block_number.map_or(true, |to| block_number <= to)
+        }
+        None => to_block_number.is_none(),
+    };
+
+    let mut transactions = starknet
+        .sender_index
+        .get(&sender_address)
+        .iter()
+        .filter_map(|transaction_hash| starknet.transactions.get(transaction_hash))
+        .filter(|transaction| is_in_range(transaction.block_number))
+        .skip(skip)
+        .map(|transaction| transaction.inner.clone());
+
+    let page: Vec<Transaction> = transactions.by_ref().take(limit).collect();
+    let has_more_transactions = transactions.next().is_some();
+
+    Ok((page, has_more_transactions))
+}
+
+#[cfg(test)]
+mod tests {
+    use starknet_rs_core::types::BlockId;
+    use starknet_types::contract_address::ContractAddress;
+    use starknet_types::felt::Felt;
+    use starknet_types::rpc::transactions::{DeclareTransaction, Transaction};
+
+    use super::get_transactions_by_sender;
+    use crate::error::Error;
+    use crate::starknet::starknet_config::StarknetConfig;
+    use crate::starknet::Starknet;
+    use crate::utils::test_utils::dummy_declare_transaction_v1;
+
+    /// Adds a transaction with `transaction_hash`, sent by `sender_address`, in a block of its own
+    fn add_transaction(
+        starknet: &mut Starknet,
+        sender_address: ContractAddress,
+        transaction_hash: u128,
+    ) {
+        let mut declare_transaction = dummy_declare_transaction_v1();
+        declare_transaction.sender_address = sender_address;
+        declare_transaction.transaction_hash = Felt::from(transaction_hash);
+        let transaction = Transaction::Declare(DeclareTransaction::Version1(declare_transaction));
+
+        starknet
+            .handle_accepted_transaction(
+                &Felt::from(transaction_hash),
+                &transaction,
+                Default::default(),
+            )
+            .unwrap();
+    }
+
+    fn setup() -> (Starknet, ContractAddress) {
+        let mut starknet = Starknet::new(&StarknetConfig::default()).unwrap();
+        let sender = ContractAddress::new(Felt::from(0x100)).unwrap();
+        let other_sender = ContractAddress::new(Felt::from(0x200)).unwrap();
+        // transactions of `sender` are in blocks 1, 3 and 5
+        for idx in 0..3 {
+            add_transaction(&mut starknet, sender, 100 + idx);
+            add_transaction(&mut starknet, other_sender, 200 + idx);
+        }
+
+        (starknet, sender)
+    }
+
+    fn get_transaction_hashes(transactions: &[Transaction]) -> Vec<Felt> {
+        transactions.iter().map(|transaction| *transaction.get_transaction_hash()).collect()
+    }
+
+    #[test]
+    fn transactions_are_filtered_by_sender_and_block_range() {
+        let (starknet, sender) = setup();
+
+        let (transactions, has_more) =
+            get_transactions_by_sender(&starknet, sender, None, None, 0, 10).unwrap();
+        assert_eq!(
+            get_transaction_hashes(&transactions),
+            vec![Felt::from(100), Felt::from(101), Felt::from(102)]
+        );
+        assert!(!has_more);
+
+        let (transactions, _) = get_transactions_by_sender(
+            &starknet,
+            sender,
+            Some(BlockId::Number(2)),
+            Some(BlockId::Number(4)),
+            0,
+            10,
+        )
+        .unwrap();
+        assert_eq!(get_transaction_hashes(&transactions), vec![Felt::from(101)]);
+    }
+
+    #[test]
+    fn transactions_are_paginated() {
+        let (starknet, sender) = setup();
+
+        let (first_page, has_more) =
+            get_transactions_by_sender(&starknet, sender, None, None, 0, 2).unwrap();
+        assert_eq!(get_transaction_hashes(&first_page), vec![Felt::from(100), Felt::from(101)]);
+        assert!(has_more);
+
+        let (second_page, has_more) =
+            get_transactions_by_sender(&starknet, sender, None, None, 2, 2).unwrap();
+        assert_eq!(get_transaction_hashes(&second_page), vec![Felt::from(102)]);
+        assert!(!has_more);
+    }
+
+    #[test]
+    fn unknown_block_is_reported() {
+        let (starknet, sender) = setup();
+
+        let err =
+            get_transactions_by_sender(&starknet, sender, Some(BlockId::Number(10)), None, 0, 10)
+                .unwrap_err();
+        assert!(matches!(err, Error::NoBlock));
+    }
+}
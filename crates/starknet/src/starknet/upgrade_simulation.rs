@@ -0,0 +1,257 @@
+use blockifier::transaction::objects::TransactionExecutionInfo;
+use blockifier::transaction::transactions::ExecutableTransaction;
+use serde::{Deserialize, Serialize};
+use starknet_api::block::BlockNumber;
+use starknet_rs_core::types::{BlockId, BlockTag, TransactionExecutionStatus};
+use starknet_types::contract_address::ContractAddress;
+use starknet_types::emitted_event::Event;
+use starknet_types::felt::{ClassHash, Felt, TransactionHash};
+use starknet_types::rpc::state::{StorageDiff, ThinStateDiff};
+
+use super::Starknet;
+use crate::error::{DevnetResult, Error, StateError};
+use crate::state::StarknetState;
+use crate::traits::{HashIdentified, StateChanger, StateExtractor};
+use crate::transactions::{get_events_from_call_info, to_broadcasted_transaction};
+
+/// Outcome of replaying historical transactions against a contract whose class was replaced
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeSimulation {
+    pub contract_address: ContractAddress,
+    pub class_hash: ClassHash,
+    pub transactions: Vec<ReplayedTransaction>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayedTransaction {
+    pub transaction_hash: TransactionHash,
+    /// Names of the properties in which the replay differs from the original execution; empty
+    /// if the new class behaves the same
+    pub differences: Vec<String>,
+    pub original: ExecutionSummary,
+    pub replayed: ExecutionSummary,
+}
+
+/// Observable effects of a transaction execution. Storage updates of the fee token are left out,
+/// because they only reflect the charged fee, which is reported separately.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExecutionSummary {
+    pub execution_status: TransactionExecutionStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub revert_reason: Option<String>,
+    pub retdata: Vec<Felt>,
+    pub events: Vec<Event>,
+    pub storage_updates: Vec<StorageDiff>,
+    pub actual_fee: Felt,
+}
+
+impl ExecutionSummary {
+    fn new(
+        execution_info: &TransactionExecutionInfo,
+        state_diff: ThinStateDiff,
+        fee_token_address: ContractAddress,
+    ) -> DevnetResult<Self> {
+        let mut storage_updates: Vec<StorageDiff> = state_diff
+            .storage_diffs
+            .into_iter()
+            .filter(|storage_diff| storage_diff.address != fee_token_address)
+            .map(|mut storage_diff| {
+                storage_diff.storage_entries.sort_by_key(|entry| entry.key.to_felt());
+                storage_diff
+            })
+            .collect();
+        storage_updates.sort_by_key(|storage_diff| Felt::from(storage_diff.address));
+
+        let (retdata, events) = match &execution_info.execute_call_info {
+            Some(call_info) => (
+                call_info.execution.retdata.0.iter().map(|felt| Felt::from(*felt)).collect(),
+                get_events_from_call_info(call_info),
+            ),
+            None => (vec![], vec![]),
+        };
+
+        Ok(Self {
+            execution_status: match execution_info.is_reverted() {
+                true => TransactionExecutionStatus::Reverted,
+                false => TransactionExecutionStatus::Succeeded,
+            },
+            revert_reason: execution_info.revert_error.clone(),
+            retdata,
+            events,
+            storage_updates,
+            actual_fee: Felt::from(execution_info.actual_fee.0),
+        })
+    }
+
+    /// Used if the replayed transaction is not accepted at all, e.g. if validation fails
+    fn rejected(reason: String) -> Self {
+        Self {
+            execution_status: TransactionExecutionStatus::Reverted,
+            revert_reason: Some(format!("Transaction rejected: {reason}")),
+            retdata: vec![],
+            events: vec![],
+            storage_updates: vec![],
+            actual_fee: Felt::default(),
+        }
+    }
+
+    /// The fee is not compared, as any change in the implementation is expected to change it
+    fn differences(&self, other: &Self) -> Vec<String> {
+        let mut differences = vec![];
+        if self.execution_status != other.execution_status {
+            differences.push("execution_status".to_string());
+        }
+        if self.revert_reason != other.revert_reason {
+            differences.push("revert_reason".to_string());
+        }
+        if self.retdata != other.retdata {
+            differences.push("retdata".to_string());
+        }
+        if self.events != other.events {
+            differences.push("events".to_string());
+        }
+        if self.storage_updates != other.storage_updates {
+            differences.push("storage_updates".to_string());
+        }
+
+        differences
+    }
+}
+
+pub fn simulate_upgrade(
+    starknet: &Starknet,
+    contract_address: ContractAddress,
+    class_hash: ClassHash,
+    transaction_hashes: &[TransactionHash],
+) -> DevnetResult<UpgradeSimulation> {
+    if !starknet.state.is_contract_deployed(&contract_address) {
+        return Err(Error::ContractNotFound);
+    }
+    // fail early if the new class is not declared
+    starknet.get_class(BlockId::Tag(BlockTag::Latest), class_hash)?;
+
+    let chain_id = starknet.chain_id().to_felt();
+    let mut transactions = vec![];
+
+    for transaction_hash in transaction_hashes {
+        let transaction =
+            starknet.transactions.get_by_hash(*transaction_hash).ok_or(Error::NoTransaction)?;
+        let block_number = transaction.block_number.ok_or(Error::NoTransaction)?;
+        if block_number.0 == 0 {
+            return Err(Error::UnsupportedAction {
+                msg: "Transactions of the first block cannot be replayed".into(),
+            });
+        }
+
+        let mut state = starknet.get_state_at(&BlockId::Number(block_number.0 - 1))?.clone();
+        replay_preceding_transactions(starknet, &mut state, block_number, transaction_hash)?;
+        if !state.is_contract_deployed(&contract_address) {
+            return Err(Error::UnsupportedAction {
+                msg: format!("Contract is not deployed before transaction {transaction_hash:x}"),
+            });
+        }
+        replace_class(starknet, &mut state, contract_address, class_hash)?;
+
+        let original = ExecutionSummary::new(
+            &transaction.execution_info,
+            transaction.state_diff.clone(),
+            starknet.config.fee_token_address,
+        )?;
+
+        let blockifier_transaction = to_broadcasted_transaction(&transaction.inner)?
+            .to_blockifier_account_transaction(chain_id, false)?;
+        let replayed = match blockifier_transaction.execute(
+            &mut state.state,
+            &starknet.block_context,
+            true,
+            true,
+        ) {
+            Ok(execution_info) => ExecutionSummary::new(
+                &execution_info,
+                state.extract_state_diff_from_pending_state()?.into(),
+                starknet.config.fee_token_address,
+            )?,
+            Err(err) => ExecutionSummary::rejected(err.to_string()),
+        };
+
+        transactions.push(ReplayedTransaction {
+            transaction_hash: *transaction_hash,
+            differences: original.differences(&replayed),
+            original,
+            replayed,
+        });
+    }
+
+    Ok(UpgradeSimulation { contract_address, class_hash, transactions })
+}
+
+/// Brings `state`, the state of the parent of the block `block_number`, to the state on which
+/// `transaction_hash` was executed, by executing the transactions of the block which precede it,
+/// with the original class of the contract
+fn replay_preceding_transactions(
+    starknet: &Starknet,
+    state: &mut StarknetState,
+    block_number: BlockNumber,
+    transaction_hash: &TransactionHash,
+) -> DevnetResult<()> {
+    let block = starknet.blocks.num_to_block.get(&block_number).ok_or(Error::NoBlock)?;
+    let chain_id = starknet.chain_id().to_felt();
+
+    for preceding_transaction_hash in
+        block.get_transactions().iter().take_while(|hash| *hash != transaction_hash)
+    {
+        let preceding_transaction = starknet
+            .transactions
+            .get_by_hash(*preceding_transaction_hash)
+            .ok_or(Error::NoTransaction)?;
+        to_broadcasted_transaction(&preceding_transaction.inner)?
+            .to_blockifier_account_transaction(chain_id, false)?
+            .execute(&mut state.state, &starknet.block_context, true, false)?;
+    }
+
+    let state_difference = state.extract_state_diff_from_pending_state()?;
+    state.apply_state_difference(state_difference)?;
+    state.clear_dirty_state();
+
+    Ok(())
+}
+
+/// Points `contract_address` to `class_hash` in `state`. The class may have been declared after
+/// the block of `state`, so its artifacts are copied over from the latest state.
+fn replace_class(
+    starknet: &Starknet,
+    state: &mut StarknetState,
+    contract_address: ContractAddress,
+    class_hash: ClassHash,
+) -> DevnetResult<()> {
+    let latest_state = &starknet.state.state.state;
+    let persistent_state = &mut state.state.state;
+
+    if let Some(compiled_class_hash) =
+        latest_state.class_hash_to_compiled_class_hash.get(&class_hash)
+    {
+        persistent_state.class_hash_to_compiled_class_hash.insert(class_hash, *compiled_class_hash);
+        if let Some(compiled_class) =
+            latest_state.class_hash_to_compiled_class.get(compiled_class_hash)
+        {
+            persistent_state
+                .class_hash_to_compiled_class
+                .insert(*compiled_class_hash, compiled_class.clone());
+        }
+    }
+    if let Some(contract_class) = latest_state.class_hash_to_compiled_class.get(&class_hash) {
+        persistent_state.class_hash_to_compiled_class.insert(class_hash, contract_class.clone());
+    }
+    if let Some(contract_class) = starknet.state.contract_classes.get(&class_hash) {
+        state.contract_classes.insert(class_hash, contract_class.clone());
+    }
+
+    if !state.is_contract_declared(&class_hash) {
+        return Err(Error::StateError(StateError::NoneClassHash(class_hash)));
+    }
+    state.state.state.address_to_class_hash.insert(contract_address, class_hash);
+    // restart the cache so that the replaced class hash is read from the persistent state
+    state.clear_dirty_state();
+
+    Ok(())
+}
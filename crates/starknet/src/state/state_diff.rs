@@ -121,6 +121,20 @@ impl StateDiff {
     }
 }
 
+impl StateDiff {
+    /// Merges a later diff into this one, so that the result covers both
+    pub(crate) fn extend(&mut self, later: StateDiff) {
+        for (address, storage) in later.storage_updates {
+            self.storage_updates.entry(address).or_default().extend(storage);
+        }
+        self.address_to_nonce.extend(later.address_to_nonce);
+        self.address_to_class_hash.extend(later.address_to_class_hash);
+        self.class_hash_to_compiled_class_hash.extend(later.class_hash_to_compiled_class_hash);
+        self.declared_contracts.extend(later.declared_contracts);
+        self.cairo_0_declared_contracts.extend(later.cairo_0_declared_contracts);
+    }
+}
+
 impl From<StateDiff> for ThinStateDiff {
     fn from(value: StateDiff) -> Self {
         let declared_classes: Vec<(Felt, Felt)> =
@@ -191,6 +205,32 @@ mod tests {
         dummy_cairo_1_contract_class, dummy_contract_address, dummy_felt,
     };
 
+    #[test]
+    fn later_diff_overrides_earlier_one() {
+        let address = dummy_contract_address();
+        let key = starknet_types::patricia_key::PatriciaKey::new(dummy_felt()).unwrap();
+        let other_key = starknet_types::patricia_key::PatriciaKey::new(Felt::from(1)).unwrap();
+
+        let mut diff = StateDiff {
+            storage_updates: [(address, [(key, Felt::from(1)), (other_key, Felt::from(2))].into())]
+                .into(),
+            address_to_nonce: [(address, Felt::from(1))].into(),
+            declared_contracts: vec![Felt::from(10)],
+            ..Default::default()
+        };
+        diff.extend(StateDiff {
+            storage_updates: [(address, [(key, Felt::from(3))].into())].into(),
+            address_to_nonce: [(address, Felt::from(2))].into(),
+            declared_contracts: vec![Felt::from(11)],
+            ..Default::default()
+        });
+
+        assert_eq!(diff.storage_updates[&address][&key], Felt::from(3));
+        assert_eq!(diff.storage_updates[&address][&other_key], Felt::from(2));
+        assert_eq!(diff.address_to_nonce[&address], Felt::from(2));
+        assert_eq!(diff.declared_contracts, vec![Felt::from(10), Felt::from(11)]);
+    }
+
     #[test]
     fn correct_no_difference_between_non_modified_states() {
         let (old_state, mut new_state) = setup();
@@ -14,12 +14,12 @@ pub(crate) struct SystemContract {
 impl SystemContract {
     pub(crate) fn new(
         class_hash: &str,
-        address: &str,
+        address: ContractAddress,
         contract_class_json_str: &str,
     ) -> DevnetResult<Self> {
         Ok(Self {
             class_hash: Felt::from_prefixed_hex_str(class_hash)?,
-            address: ContractAddress::new(Felt::from_prefixed_hex_str(address)?)?,
+            address,
             contract_class: Cairo0Json::raw_json_from_json_str(contract_class_json_str)?.into(),
         })
     }
@@ -59,7 +59,9 @@ impl Accounted for SystemContract {
 
 #[cfg(test)]
 mod tests {
+    use starknet_types::contract_address::ContractAddress;
     use starknet_types::contract_class::Cairo0Json;
+    use starknet_types::felt::Felt;
 
     use super::SystemContract;
     use crate::constants::{
@@ -78,7 +80,8 @@ mod tests {
         let mut state = StarknetState::default();
         let sys_contract = SystemContract::new(
             ERC20_CONTRACT_CLASS_HASH,
-            ERC20_CONTRACT_ADDRESS,
+            ContractAddress::new(Felt::from_prefixed_hex_str(ERC20_CONTRACT_ADDRESS).unwrap())
+                .unwrap(),
             std::fs::read_to_string(ERC20_CONTRACT_PATH).unwrap().as_str(),
         )
         .unwrap();
@@ -1,3 +1,4 @@
+use blockifier::execution::call_info::CallInfo;
 use blockifier::transaction::objects::TransactionExecutionInfo;
 use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
@@ -7,11 +8,19 @@ use starknet_rs_core::utils::get_selector_from_name;
 use starknet_types::contract_address::ContractAddress;
 use starknet_types::emitted_event::{Event, OrderedEvent};
 use starknet_types::felt::{BlockHash, Felt, TransactionHash};
+use starknet_types::rpc::state::ThinStateDiff;
 use starknet_types::rpc::transaction_receipt::{DeployTransactionReceipt, TransactionReceipt};
-use starknet_types::rpc::transactions::{Transaction, TransactionType};
+use starknet_types::rpc::transactions::broadcasted_declare_transaction_v1::BroadcastedDeclareTransactionV1;
+use starknet_types::rpc::transactions::broadcasted_declare_transaction_v2::BroadcastedDeclareTransactionV2;
+use starknet_types::rpc::transactions::broadcasted_deploy_account_transaction::BroadcastedDeployAccountTransaction;
+use starknet_types::rpc::transactions::broadcasted_invoke_transaction::BroadcastedInvokeTransaction;
+use starknet_types::rpc::transactions::{
+    BroadcastedDeclareTransaction, BroadcastedTransaction, DeclareTransaction, InvokeTransaction,
+    Transaction, TransactionType,
+};
 
-use crate::constants::UDC_CONTRACT_ADDRESS;
 use crate::error::{DevnetResult, Error};
+use crate::starknet::counterfactual_accounts::CounterfactualAccount;
 use crate::traits::{HashIdentified, HashIdentifiedMut};
 
 #[derive(Debug, Default, Serialize, Deserialize)]
@@ -29,6 +38,15 @@ impl StarknetTransactions {
     pub fn iter(&self) -> indexmap::map::Iter<'_, Felt, StarknetTransaction> {
         self.0.iter()
     }
+
+    pub(crate) fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Removes the transactions inserted after the first `len` ones
+    pub(crate) fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
+    }
 }
 
 impl HashIdentifiedMut for StarknetTransactions {
@@ -57,6 +75,13 @@ pub struct StarknetTransaction {
     pub(crate) block_number: Option<BlockNumber>,
     #[serde(skip)]
     pub(crate) execution_info: TransactionExecutionInfo,
+    /// Changes of the state made by the transaction
+    #[serde(skip)]
+    pub(crate) state_diff: ThinStateDiff,
+    /// Set if the transaction is the deployment of a counterfactual account, which Devnet sent on
+    /// behalf of the account
+    #[serde(skip)]
+    pub(crate) counterfactual_account: Option<CounterfactualAccount>,
 }
 
 impl StarknetTransaction {
@@ -79,49 +104,34 @@ impl StarknetTransaction {
             block_hash: None,
             block_number: None,
             execution_info,
+            state_diff: ThinStateDiff::default(),
+            counterfactual_account: None,
         }
     }
 
     pub fn get_events(&self) -> Vec<Event> {
-        let mut events: Vec<Event> = vec![];
-
-        fn get_blockifier_events_recursively(
-            call_info: &blockifier::execution::call_info::CallInfo,
-        ) -> Vec<(OrderedEvent, ContractAddress)> {
-            let mut events: Vec<(OrderedEvent, ContractAddress)> = vec![];
-
-            events.extend(
-                call_info
-                    .execution
-                    .events
-                    .iter()
-                    .map(|e| (OrderedEvent::from(e), call_info.call.storage_address.into())),
-            );
-
-            call_info.inner_calls.iter().for_each(|call| {
-                events.extend(get_blockifier_events_recursively(call));
-            });
-
-            events
-        }
-
         let call_infos = vec![
             self.execution_info.validate_call_info.as_ref(),
             self.execution_info.execute_call_info.as_ref(),
             self.execution_info.fee_transfer_call_info.as_ref(),
         ];
 
-        for inner_call_info in call_infos.into_iter().flatten() {
-            let mut not_sorted_events = get_blockifier_events_recursively(inner_call_info);
-            not_sorted_events.sort_by_key(|(ordered_event, _)| ordered_event.order);
-            events.extend(not_sorted_events.into_iter().map(|(ordered_event, address)| Event {
-                from_address: address,
-                keys: ordered_event.keys,
-                data: ordered_event.data,
-            }));
+        call_infos.into_iter().flatten().flat_map(get_events_from_call_info).collect()
+    }
+
+    /// Drops the events emitted during the transaction, so that they are no longer queryable
+    pub(crate) fn prune_events(&mut self) {
+        fn prune_events_recursively(call_info: &mut CallInfo) {
+            call_info.execution.events.clear();
+            call_info.inner_calls.iter_mut().for_each(prune_events_recursively);
         }
 
-        events
+        let call_infos = [
+            &mut self.execution_info.validate_call_info,
+            &mut self.execution_info.execute_call_info,
+            &mut self.execution_info.fee_transfer_call_info,
+        ];
+        call_infos.into_iter().flatten().for_each(prune_events_recursively);
     }
 
     /// Scans through events and gets information from Event generated from UDC with specific
@@ -129,14 +139,14 @@ impl StarknetTransaction {
     ///
     /// # Arguments
     /// * `events` - The events that will be searched
+    /// * `udc_address` - The address of the UDC emitting the event
     pub fn get_deployed_address_from_events(
         events: &[Event],
+        udc_address: ContractAddress,
     ) -> DevnetResult<Option<ContractAddress>> {
         let contract_deployed_event_key =
             Felt::from(get_selector_from_name("ContractDeployed").map_err(|_| Error::FormatError)?);
 
-        let udc_address = ContractAddress::new(Felt::from_prefixed_hex_str(UDC_CONTRACT_ADDRESS)?)?;
-
         let deployed_address = events
             .iter()
             .find(|e| {
@@ -151,7 +161,7 @@ impl StarknetTransaction {
         })
     }
 
-    pub fn get_receipt(&self) -> DevnetResult<TransactionReceipt> {
+    pub fn get_receipt(&self, udc_address: ContractAddress) -> DevnetResult<TransactionReceipt> {
         let transaction_events = self.get_events();
 
         let mut common_receipt = self.inner.create_common_receipt(
@@ -172,8 +182,10 @@ impl StarknetTransaction {
                 }))
             }
             Transaction::Invoke(_) => {
-                let deployed_address =
-                    StarknetTransaction::get_deployed_address_from_events(&transaction_events)?;
+                let deployed_address = StarknetTransaction::get_deployed_address_from_events(
+                    &transaction_events,
+                    udc_address,
+                )?;
 
                 let receipt = if let Some(contract_address) = deployed_address {
                     common_receipt.r#type = TransactionType::Deploy;
@@ -192,6 +204,97 @@ impl StarknetTransaction {
     }
 }
 
+/// Converts an accepted transaction back to the form in which it was received, so that it can be
+/// executed again
+pub(crate) fn to_broadcasted_transaction(
+    transaction: &Transaction,
+) -> DevnetResult<BroadcastedTransaction> {
+    let broadcasted_transaction = match transaction {
+        Transaction::Declare(DeclareTransaction::Version1(tx)) => BroadcastedTransaction::Declare(
+            BroadcastedDeclareTransaction::V1(Box::new(BroadcastedDeclareTransactionV1::new(
+                tx.sender_address,
+                tx.max_fee,
+                &tx.signature,
+                tx.nonce,
+                &tx.contract_class,
+                tx.version,
+            ))),
+        ),
+        Transaction::Declare(DeclareTransaction::Version2(tx)) => BroadcastedTransaction::Declare(
+            BroadcastedDeclareTransaction::V2(Box::new(BroadcastedDeclareTransactionV2::new(
+                &tx.contract_class,
+                tx.compiled_class_hash,
+                tx.sender_address,
+                tx.max_fee,
+                &tx.signature,
+                tx.nonce,
+                tx.version,
+            ))),
+        ),
+        Transaction::DeployAccount(tx) => {
+            BroadcastedTransaction::DeployAccount(BroadcastedDeployAccountTransaction::new(
+                &tx.constructor_calldata,
+                tx.max_fee,
+                &tx.signature,
+                tx.nonce,
+                tx.class_hash,
+                tx.contract_address_salt,
+                tx.version,
+            ))
+        }
+        Transaction::Invoke(InvokeTransaction::Version1(tx)) => {
+            BroadcastedTransaction::Invoke(BroadcastedInvokeTransaction::new(
+                tx.sender_address,
+                tx.max_fee,
+                &tx.signature,
+                tx.nonce,
+                &tx.calldata,
+                tx.version,
+            ))
+        }
+        Transaction::Declare(DeclareTransaction::Version0(_))
+        | Transaction::Deploy(_)
+        | Transaction::Invoke(InvokeTransaction::Version0(_))
+        | Transaction::L1Handler(_) => return Err(Error::SerializationNotSupported),
+    };
+
+    Ok(broadcasted_transaction)
+}
+
+/// Collects the events emitted in a call and all of its inner calls, in the order of emission
+pub(crate) fn get_events_from_call_info(call_info: &CallInfo) -> Vec<Event> {
+    fn get_blockifier_events_recursively(
+        call_info: &CallInfo,
+    ) -> Vec<(OrderedEvent, ContractAddress)> {
+        let mut events: Vec<(OrderedEvent, ContractAddress)> = vec![];
+
+        events.extend(
+            call_info
+                .execution
+                .events
+                .iter()
+                .map(|e| (OrderedEvent::from(e), call_info.call.storage_address.into())),
+        );
+
+        call_info.inner_calls.iter().for_each(|call| {
+            events.extend(get_blockifier_events_recursively(call));
+        });
+
+        events
+    }
+
+    let mut not_sorted_events = get_blockifier_events_recursively(call_info);
+    not_sorted_events.sort_by_key(|(ordered_event, _)| ordered_event.order);
+    not_sorted_events
+        .into_iter()
+        .map(|(ordered_event, address)| Event {
+            from_address: address,
+            keys: ordered_event.keys,
+            data: ordered_event.data,
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use blockifier::transaction::objects::TransactionExecutionInfo;
@@ -5,7 +5,7 @@ use starknet_rs_ff::FieldElement;
 
 use crate::felt::Felt;
 
-#[derive(Clone, Copy, Debug, clap::ValueEnum)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, clap::ValueEnum)]
 pub enum ChainId {
     #[clap(name = "MAINNET")]
     Mainnet,
@@ -4,7 +4,8 @@ use starknet_rs_core::types::{BlockId as ImportedBlockId, BlockTag as ImportedBl
 
 use crate::contract_address::ContractAddress;
 use crate::felt::{BlockHash, Felt};
-use crate::rpc::transactions::Transactions;
+use crate::rpc::transaction_receipt::TransactionReceipt;
+use crate::rpc::transactions::{Transaction, Transactions};
 pub type GlobalRootHex = Felt;
 
 #[derive(Copy, Clone, Debug, Deserialize, Eq, PartialEq, Serialize)]
@@ -105,6 +106,21 @@ pub struct Block {
     pub transactions: Transactions,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct BlockWithReceipts {
+    pub status: BlockStatus,
+    #[serde(flatten)]
+    pub header: BlockHeader,
+    pub transactions: Vec<TransactionWithReceipt>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+pub struct TransactionWithReceipt {
+    pub transaction: Transaction,
+    pub receipt: TransactionReceipt,
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 pub struct BlockHeader {
     pub block_hash: BlockHash,
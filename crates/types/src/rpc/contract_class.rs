@@ -1,5 +1,7 @@
 use core::fmt::Debug;
+use std::any::Any;
 use std::cmp::{Eq, PartialEq};
+use std::panic::AssertUnwindSafe;
 
 use cairo_lang_starknet::casm_contract_class::{CasmContractClass, CasmContractEntryPoint};
 use cairo_lang_starknet::contract_class::ContractClass as SierraContractClass;
@@ -108,8 +110,7 @@ impl TryFrom<ContractClass> for cairo_lang_starknet::casm_contract_class::CasmCo
     fn try_from(value: ContractClass) -> Result<Self, Self::Error> {
         match value {
             ContractClass::Cairo1(sierra_contract_class) => {
-                CasmContractClass::from_contract_class(sierra_contract_class, true)
-                    .map_err(|err| Error::SierraCompilationError { reason: err.to_string() })
+                compile_sierra_contract_class(sierra_contract_class)
             }
             _ => Err(Error::ConversionError(crate::error::ConversionError::InvalidFormat)),
         }
@@ -193,6 +194,45 @@ fn convert_sierra_to_codegen(
     })
 }
 
+/// Maximum number of felts of a Sierra program that is compiled, the same as the maximum bytecode
+/// length of compiled classes on Starknet. Longer programs are rejected without compiling them,
+/// since their compilation can take a lot of time and memory.
+pub const MAX_SIERRA_PROGRAM_LENGTH: usize = 81_920;
+
+/// Compiles `sierra_contract_class` to CASM. The class may be uploaded by anyone, so a panic of
+/// the compiler, e.g. on a malformed program, is returned as an error instead of unwinding into the
+/// caller.
+pub fn compile_sierra_contract_class(
+    sierra_contract_class: SierraContractClass,
+) -> DevnetResult<CasmContractClass> {
+    let program_length = sierra_contract_class.sierra_program.len();
+    if program_length > MAX_SIERRA_PROGRAM_LENGTH {
+        return Err(Error::SierraCompilationError {
+            reason: format!(
+                "The Sierra program has {program_length} felts, more than the maximum of \
+                 {MAX_SIERRA_PROGRAM_LENGTH}"
+            ),
+        });
+    }
+
+    // the class is moved into the closure, so no state can be observed after a panic
+    std::panic::catch_unwind(AssertUnwindSafe(move || {
+        CasmContractClass::from_contract_class(sierra_contract_class, true)
+    }))
+    .map_err(|panic| Error::SierraCompilationError {
+        reason: format!("The compiler panicked: {}", panic_message(panic.as_ref())),
+    })?
+    .map_err(|err| Error::SierraCompilationError { reason: err.to_string() })
+}
+
+fn panic_message(panic: &(dyn Any + Send)) -> &str {
+    panic
+        .downcast_ref::<&str>()
+        .copied()
+        .or_else(|| panic.downcast_ref::<String>().map(String::as_str))
+        .unwrap_or("unknown cause")
+}
+
 pub fn compute_sierra_class_hash(contract_class: &SierraContractClass) -> DevnetResult<Felt> {
     let mut contract_class_json_value =
         serde_json::to_value(contract_class).map_err(JsonError::SerdeJsonError)?;
@@ -305,8 +345,10 @@ mod tests {
 
     use crate::contract_class::deprecated::rpc_contract_class::ContractClassAbiEntryWithType;
     use crate::contract_class::{
-        convert_sierra_to_codegen, Cairo0Json, ContractClass, DeprecatedContractClass,
+        compile_sierra_contract_class, convert_sierra_to_codegen, Cairo0Json, ContractClass,
+        DeprecatedContractClass, MAX_SIERRA_PROGRAM_LENGTH,
     };
+    use crate::error::Error;
     use crate::felt::Felt;
     use crate::serde_helpers::rpc_sierra_contract_class_to_sierra_contract_class::deserialize_to_sierra_contract_class;
     use crate::traits::HashProducer;
@@ -315,6 +357,38 @@ mod tests {
         CAIRO_1_CONTRACT_SIERRA_HASH,
     };
 
+    fn cairo_1_contract_class() -> SierraContractClass {
+        ContractClass::cairo_1_from_sierra_json_str(
+            &std::fs::read_to_string(CAIRO_1_CONTRACT_PATH).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn sierra_program_above_maximum_length_is_not_compiled() {
+        let mut contract_class = cairo_1_contract_class();
+        let felt = contract_class.sierra_program[0].clone();
+        contract_class.sierra_program.resize(MAX_SIERRA_PROGRAM_LENGTH + 1, felt);
+
+        match compile_sierra_contract_class(contract_class) {
+            Err(Error::SierraCompilationError { reason }) => {
+                assert!(reason.contains("more than the maximum"), "{reason}")
+            }
+            other => panic!("Unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn malformed_sierra_program_is_a_compilation_error() {
+        let mut contract_class = cairo_1_contract_class();
+        contract_class.sierra_program.truncate(contract_class.sierra_program.len() / 2);
+
+        assert!(matches!(
+            compile_sierra_contract_class(contract_class),
+            Err(Error::SierraCompilationError { .. })
+        ));
+    }
+
     #[test]
     fn cairo_1_contract_class_hash_generated_successfully() {
         let cairo_1_contract_sierra = ContractClass::Cairo1(
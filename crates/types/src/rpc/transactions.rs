@@ -35,6 +35,7 @@ use crate::felt::{
 use crate::rpc::transaction_receipt::{
     CommonTransactionReceipt, MaybePendingProperties, TransactionOutput,
 };
+use crate::traits::ToHexString;
 
 pub mod broadcasted_declare_transaction_v1;
 pub mod broadcasted_declare_transaction_v2;
@@ -105,6 +106,20 @@ impl Transaction {
         }
     }
 
+    /// The account whose nonce the transaction uses; `None` for transactions not sent by an
+    /// account
+    pub fn get_sender_address(&self) -> Option<&ContractAddress> {
+        match self {
+            Transaction::Declare(DeclareTransaction::Version0(tx))
+            | Transaction::Declare(DeclareTransaction::Version1(tx)) => Some(&tx.sender_address),
+            Transaction::Declare(DeclareTransaction::Version2(tx)) => Some(&tx.sender_address),
+            Transaction::DeployAccount(tx) => Some(&tx.contract_address),
+            Transaction::Invoke(InvokeTransaction::Version0(tx)) => Some(&tx.contract_address),
+            Transaction::Invoke(InvokeTransaction::Version1(tx)) => Some(&tx.sender_address),
+            Transaction::Deploy(_) | Transaction::L1Handler(_) => None,
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn create_common_receipt(
         &self,
@@ -131,6 +146,23 @@ impl Transaction {
             Felt::from(resource as u128)
         }
 
+        fn get_messages_from_call_info(call_info: &CallInfo) -> Vec<OrderedMessageToL1> {
+            let mut messages: Vec<OrderedMessageToL1> = call_info
+                .execution
+                .l2_to_l1_messages
+                .iter()
+                .map(|msg| {
+                    OrderedMessageToL1::new(msg.clone(), call_info.call.storage_address.into())
+                })
+                .collect();
+
+            call_info.inner_calls.iter().for_each(|call| {
+                messages.extend(get_messages_from_call_info(call));
+            });
+
+            messages
+        }
+
         let total_memory_holes = get_memory_holes_from_call_info(&execution_info.execute_call_info)
             + get_memory_holes_from_call_info(&execution_info.validate_call_info)
             + get_memory_holes_from_call_info(&execution_info.fee_transfer_call_info);
@@ -168,9 +200,18 @@ impl Transaction {
             ),
         };
 
+        // messages are ordered within the whole transaction, so they are sorted after collecting
+        // them from all nested calls
+        let mut messages_sent = execution_info
+            .execute_call_info
+            .as_ref()
+            .map(get_messages_from_call_info)
+            .unwrap_or_default();
+        messages_sent.sort_by_key(|msg| msg.order);
+
         let output = TransactionOutput {
             actual_fee,
-            messages_sent: Vec::new(), // TODO wrong
+            messages_sent: messages_sent.into_iter().map(|msg| msg.message).collect(),
             events: transaction_events.to_vec(),
         };
 
@@ -281,7 +322,7 @@ pub struct FunctionCall {
     pub calldata: Calldata,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
 pub struct BroadcastedTransactionCommon {
     pub max_fee: Fee,
     pub version: TransactionVersion,
@@ -289,6 +330,88 @@ pub struct BroadcastedTransactionCommon {
     pub nonce: Nonce,
 }
 
+/// Accepted values of the data availability modes of transactions of version 3
+const DATA_AVAILABILITY_MODES: [&str; 2] = ["L1", "L2"];
+
+/// The common properties of a broadcasted transaction, along with the properties of transactions
+/// of version 3, so that a transaction of version 3 is rejected with an error naming them
+#[derive(Deserialize)]
+struct BroadcastedTransactionProperties {
+    max_fee: Option<Fee>,
+    version: TransactionVersion,
+    signature: TransactionSignature,
+    nonce: Nonce,
+    resource_bounds: Option<serde_json::Value>,
+    tip: Option<serde_json::Value>,
+    paymaster_data: Option<serde_json::Value>,
+    account_deployment_data: Option<serde_json::Value>,
+    nonce_data_availability_mode: Option<String>,
+    fee_data_availability_mode: Option<String>,
+}
+
+impl BroadcastedTransactionProperties {
+    /// Fails with an error naming the offending property if the properties are the ones of a
+    /// transaction of version 3, which is not supported
+    fn reject_version_3(&self) -> Result<(), String> {
+        for (name, mode) in [
+            ("nonce_data_availability_mode", &self.nonce_data_availability_mode),
+            ("fee_data_availability_mode", &self.fee_data_availability_mode),
+        ] {
+            if let Some(mode) =
+                mode.as_ref().filter(|mode| !DATA_AVAILABILITY_MODES.contains(&mode.as_str()))
+            {
+                return Err(format!(
+                    "Invalid {name} \"{mode}\": expected one of {DATA_AVAILABILITY_MODES:?}"
+                ));
+            }
+        }
+
+        let v3_property = [
+            ("resource_bounds", self.resource_bounds.is_some()),
+            ("tip", self.tip.is_some()),
+            ("paymaster_data", self.paymaster_data.is_some()),
+            ("account_deployment_data", self.account_deployment_data.is_some()),
+            ("nonce_data_availability_mode", self.nonce_data_availability_mode.is_some()),
+            ("fee_data_availability_mode", self.fee_data_availability_mode.is_some()),
+        ]
+        .into_iter()
+        .find_map(|(name, is_present)| is_present.then_some(name));
+        let version = self.version.to_prefixed_hex_str();
+        let unsupported = match v3_property {
+            Some(name) => Some(format!("Unsupported property {name}")),
+            None if ["0x3", "0x100000000000000000000000000000003"].contains(&version.as_str()) => {
+                Some(format!("Unsupported version {version}"))
+            }
+            None => None,
+        };
+        match unsupported {
+            Some(unsupported) => Err(format!(
+                "{unsupported}: transactions of version 0x3 are not supported; send a transaction \
+                 of version 0x1 (or 0x2 for declare) with max_fee instead"
+            )),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for BroadcastedTransactionCommon {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let properties = BroadcastedTransactionProperties::deserialize(deserializer)?;
+
+        properties.reject_version_3().map_err(serde::de::Error::custom)?;
+
+        Ok(Self {
+            max_fee: properties.max_fee.ok_or(serde::de::Error::missing_field("max_fee"))?,
+            version: properties.version,
+            signature: properties.signature,
+            nonce: properties.nonce,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Eq, PartialEq, Deserialize, Serialize)]
 #[serde(tag = "type")]
 pub enum BroadcastedTransaction {
@@ -361,9 +484,15 @@ impl<'de> Deserialize<'de> for BroadcastedDeclareTransaction {
                 })?;
                 Ok(BroadcastedDeclareTransaction::V2(Box::new(unpacked)))
             }
-            _ => Err(serde::de::Error::custom(format!(
-                "Invalid version of declare transaction: {version_raw}"
-            ))),
+            _ => {
+                // name the properties of transactions of version 3, if any, in the error
+                if let Ok(properties) = BroadcastedTransactionProperties::deserialize(&value) {
+                    properties.reject_version_3().map_err(serde::de::Error::custom)?;
+                }
+                Err(serde::de::Error::custom(format!(
+                    "Invalid version of declare transaction: {version_raw}; expected 0x1 or 0x2"
+                )))
+            }
         }
     }
 }
@@ -413,6 +542,8 @@ pub enum TransactionTrace {
     Declare(DeclareTransactionTrace),
     #[serde(rename = "DEPLOY_ACCOUNT")]
     DeployAccount(DeployAccountTransactionTrace),
+    #[serde(rename = "L1_HANDLER")]
+    L1Handler(L1HandlerTransactionTrace),
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -453,6 +584,20 @@ pub struct DeployAccountTransactionTrace {
     pub state_diff: Option<ThinStateDiff>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct L1HandlerTransactionTrace {
+    pub function_invocation: FunctionInvocation,
+}
+
+/// Trace of one of the transactions of a block
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BlockTransactionTrace {
+    pub transaction_hash: TransactionHash,
+    pub trace_root: TransactionTrace,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(deny_unknown_fields)]
 pub struct SimulatedTransaction {
@@ -462,11 +607,11 @@ pub struct SimulatedTransaction {
 
 impl FunctionInvocation {
     pub fn try_from_call_info(
-        mut call_info: blockifier::execution::call_info::CallInfo,
+        call_info: &blockifier::execution::call_info::CallInfo,
         address_to_class_hash: &HashMap<ContractAddress, Felt>,
     ) -> DevnetResult<Self> {
         let mut internal_calls: Vec<FunctionInvocation> = vec![];
-        for internal_call in call_info.inner_calls {
+        for internal_call in call_info.inner_calls.iter() {
             internal_calls.push(FunctionInvocation::try_from_call_info(
                 internal_call,
                 address_to_class_hash,
@@ -477,23 +622,18 @@ impl FunctionInvocation {
         // is creating an array with enough room for all objects + 1
         // then based on the order we use this index
 
-        call_info.execution.l2_to_l1_messages.sort_by_key(|msg| msg.order);
+        let mut sorted_messages: Vec<_> = call_info.execution.l2_to_l1_messages.iter().collect();
+        sorted_messages.sort_by_key(|msg| msg.order);
 
-        let messages: Vec<OrderedMessageToL1> = call_info
-            .execution
-            .l2_to_l1_messages
+        let messages: Vec<OrderedMessageToL1> = sorted_messages
             .into_iter()
-            .map(|msg| OrderedMessageToL1::new(msg, call_info.call.caller_address.into()))
+            .map(|msg| OrderedMessageToL1::new(msg.clone(), call_info.call.caller_address.into()))
             .collect();
 
-        call_info.execution.events.sort_by_key(|event| event.order);
+        let mut sorted_events: Vec<_> = call_info.execution.events.iter().collect();
+        sorted_events.sort_by_key(|event| event.order);
 
-        let events: Vec<OrderedEvent> = call_info
-            .execution
-            .events
-            .into_iter()
-            .map(|event| OrderedEvent::from(&event))
-            .collect();
+        let events: Vec<OrderedEvent> = sorted_events.into_iter().map(OrderedEvent::from).collect();
 
         let function_call = FunctionCall {
             contract_address: call_info.call.storage_address.into(),
@@ -523,7 +663,7 @@ impl FunctionInvocation {
                 blockifier::execution::entry_point::CallType::Call => CallType::Call,
                 blockifier::execution::entry_point::CallType::Delegate => CallType::Delegate,
             },
-            result: call_info.execution.retdata.0.into_iter().map(Felt::from).collect(),
+            result: call_info.execution.retdata.0.iter().map(|f| Felt::from(*f)).collect(),
             calls: internal_calls,
             events,
             messages,
@@ -56,11 +56,8 @@ impl BroadcastedDeployAccountTransaction {
         }
     }
 
-    pub fn create_blockifier_deploy_account(
-        &self,
-        chain_id: Felt,
-        only_query: bool,
-    ) -> DevnetResult<blockifier::transaction::transactions::DeployAccountTransaction> {
+    /// Address of the account deployed by the transaction
+    pub fn contract_address(&self) -> DevnetResult<ContractAddress> {
         let contract_address = calculate_contract_address(
             starknet_api::transaction::ContractAddressSalt(self.contract_address_salt.into()),
             starknet_api::core::ClassHash(self.class_hash.into()),
@@ -70,6 +67,17 @@ impl BroadcastedDeployAccountTransaction {
             starknet_api::core::ContractAddress::from(0u8),
         )?;
 
+        Ok(contract_address.into())
+    }
+
+    pub fn create_blockifier_deploy_account(
+        &self,
+        chain_id: Felt,
+        only_query: bool,
+    ) -> DevnetResult<blockifier::transaction::transactions::DeployAccountTransaction> {
+        let contract_address: starknet_api::core::ContractAddress =
+            self.contract_address()?.try_into()?;
+
         let mut calldata_to_hash = vec![self.class_hash, self.contract_address_salt];
         calldata_to_hash.extend(self.constructor_calldata.iter());
 